@@ -8,8 +8,80 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 part 'api.freezed.dart';
 
-// These functions are ignored because they are not marked as `pub`: `create_calendar_event`, `dto_to_event_for_occurs_on`, `dto_to_event`, `event_occurs_in_range`, `event_to_dto`, `extract_branch_name`, `get_credentials`, `git_add_all_impl`, `git_add_remote_impl`, `git_checkout_impl`, `git_commit_impl`, `git_diff_impl`, `git_fetch_impl`, `git_init_impl`, `git_merge_abort_impl`, `git_merge_prefer_remote_impl`, `git_pull_impl`, `git_push_impl`, `git_remove_remote_impl`, `git_stash_impl`, `git_status_impl`, `has_local_changes`, `parse_date`, `parse_recurrence`, `parse_time`, `validate_certificate`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`, `fmt`, `fmt`, `from`, `from`
+// These functions are ignored because they are not marked as `pub`: `add`, `apply_override`, `attach_checkout_hooks`, `attach_hooks`, `calendar_changes`, `calendar_snapshot`, `calendar_watchers`, `callbacks`, `canonical_dir`, `certificate_error`, `check_occurrence`, `clear`, `commit_local_changes`, `commit_merge`, `commit_signature`, `conflict_error`, `conflict_path`, `conflicted_paths`, `create_calendar_event`, `credential_callback`, `days_in_month`, `describe`, `dto_to_event_for_occurs_on`, `dto_to_event`, `edit_event`, `ensure_merging`, `entries`, `entries`, `error`, `escape_text`, `event_fields`, `event_file_paths`, `event_id_in_blob`, `event_in_zone`, `event_indexes`, `event_occurs_in_range`, `event_to_dto`, `events`, `expand_events`, `expand_rule`, `extra_lines`, `file_name`, `find`, `finish_merge`, `format_override`, `format_rrule`, `git_add_all_impl`, `git_add_remote_impl`, `git_checkout_impl`, `git_clone_impl`, `git_commit_impl`, `git_diff_impl`, `git_fetch_impl`, `git_get_identity_impl`, `git_init_impl`, `git_merge_abort_impl`, `git_merge_prefer_local_impl`, `git_merge_prefer_remote_impl`, `git_merge_resolve_events_impl`, `git_merge_resolve_paths_impl`, `git_pull_impl`, `git_push_impl`, `git_remove_remote_impl`, `git_set_identity_impl`, `git_stash_impl`, `git_status_impl`, `git_sync_impl`, `has_exceptions`, `has_local_changes`, `ics_local`, `ics_property_time`, `ics_span`, `ics_time_zone`, `id_is_stored`, `import_ics_event`, `import_ics_occurrence`, `import_rrule`, `in_range`, `insert`, `invalid_recurrence`, `is_cancelled`, `is_cancelled`, `is_event_file`, `is_non_fast_forward_status`, `is_rrule_text`, `known_hosts_store`, `legacy_credentials`, `list`, `load`, `localize`, `matches_ordinal`, `merge_conflicted_event`, `merge_event_fields`, `new`, `next_credential`, `occurrence_on`, `occurrence_overlaps`, `occurrence_starts`, `of`, `of`, `open_repository`, `operation_tokens`, `override_fields`, `param`, `parse_date`, `parse_display_zone`, `parse_event_content`, `parse_ics_duration`, `parse_ics_line`, `parse_ics_time`, `parse_ics`, `parse_override`, `parse_recurrence`, `parse_rrule`, `parse_rule_numbers`, `parse_staged_event`, `parse_time_zone`, `parse_time`, `pem_certificate`, `pem_certificates`, `pop_stash`, `presented_host_key`, `properties_named`, `property`, `read_event_files`, `read_extra_line`, `record_instances`, `record_to_dto`, `recurrence_as_rule`, `refresh`, `registered_ca_certs`, `reject`, `reload_event_files`, `reload`, `remote_default_branch`, `remove`, `remove`, `remove`, `render_event_content`, `render_event_file`, `report`, `resolve_conflicts`, `resolve_sync_branch`, `restore_pull_stash`, `rule_period_days`, `rule_period_occurrences`, `rule_period_start`, `rule_recurrence`, `run_cancellable`, `save`, `save`, `set_event_field`, `sha256_fingerprint`, `split_escaped`, `ssh_username`, `storage_error`, `sync_commit_message`, `sync_result`, `text`, `to_cred`, `trust_store`, `trust`, `unescape_ics_text`, `unfold_ics`, `unix_now`, `unreadable`, `upstream_branch_name`, `validate_certificate`, `validate_override`, `validate_rule`, `verify_certificate_name`, `verify`, `verify`, `watch_calendar_impl`, `with_credential_callback`, `with_event_index`, `with_path`, `with_path`, `write_event_file`, `zone`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CalendarWatcher`, `CertificateTrust`, `EventFile`, `EventIndex`, `EventRecord`, `FileStamp`, `IcsComponent`, `IcsProperty`, `IcsRejection`, `IcsSpan`, `IcsTime`, `KnownHostsStore`, `OperationHooks`, `RemoteSession`, `SyncBranch`, `TrustEntry`, `TrustStore`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`, `from`, `from`, `from`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`, `default`, `default`, `default`
+
+/// Registers a callback that supplies credentials when those passed to an operation
+/// are missing or rejected, e.g. read lazily from secure storage. It is asked again,
+/// with a higher attempt, each time the remote rejects what it returned; returning
+/// None gives up.
+Future<void> setCredentialCallback({
+  required FutureOr<GitCredentials?> Function(CredentialRequest) callback,
+}) => RustLib.instance.api.crateApiSetCredentialCallback(callback: callback);
+
+/// Removes the callback registered with set_credential_callback.
+Future<void> clearCredentialCallback() =>
+    RustLib.instance.api.crateApiClearCredentialCallback();
+
+/// Registers a cancellable operation and returns its handle.
+/// Pass the handle to a *_with_progress function, then cancel_operation to abort it.
+Future<BigInt> startOperation() =>
+    RustLib.instance.api.crateApiStartOperation();
+
+/// Asks the operation behind [handle] to stop at its next progress callback.
+/// Returns false if the handle is unknown or the operation already finished.
+Future<bool> cancelOperation({required BigInt handle}) =>
+    RustLib.instance.api.crateApiCancelOperation(handle: handle);
+
+/// Sets the known_hosts file SSH host keys are verified against. The app owns
+/// this file; keys trusted before it was set are written to it.
+Future<void> setKnownHostsPath({required String path}) =>
+    RustLib.instance.api.crateApiSetKnownHostsPath(path: path);
+
+/// Trusts a host key, usually the one carried by GitError::UnknownHostKey after
+/// the user confirmed its fingerprint. Retry the operation afterwards.
+Future<void> trustHostKey({required HostKey key}) =>
+    RustLib.instance.api.crateApiTrustHostKey(key: key);
+
+/// Lists the pinned host keys.
+Future<List<HostKey>> listKnownHosts() =>
+    RustLib.instance.api.crateApiListKnownHosts();
+
+/// Forgets the keys pinned for [host], e.g. after its server was reinstalled.
+/// Returns false if no key was pinned.
+Future<bool> removeHostKey({required String host}) =>
+    RustLib.instance.api.crateApiRemoveHostKey(host: host);
+
+/// Sets the file the trust store is kept in; certificates added before are written to it.
+Future<void> setTrustStorePath({required String path}) =>
+    RustLib.instance.api.crateApiSetTrustStorePath(path: path);
+
+/// Trusts the CA certificates in [pem] for [host], e.g. an internal CA of a self-hosted server.
+/// When the server's certificate was issued by an intermediate, add that intermediate.
+Future<List<TrustedCertificate>> trustAddCa({
+  required String host,
+  required String pem,
+}) => RustLib.instance.api.crateApiTrustAddCa(host: host, pem: pem);
+
+/// Pins the server certificates in [pem] for [host]; from then on the host must
+/// present one of its pinned certificates.
+Future<List<TrustedCertificate>> trustPinCertificate({
+  required String host,
+  required String pem,
+}) => RustLib.instance.api.crateApiTrustPinCertificate(host: host, pem: pem);
+
+/// Lists the trusted CAs and pins, of [host] only if given.
+Future<List<TrustedCertificate>> trustList({String? host}) =>
+    RustLib.instance.api.crateApiTrustList(host: host);
+
+/// Removes a CA or pin by its fingerprint. Returns false if [host] had no such certificate.
+Future<bool> trustRemove({required String host, required String fingerprint}) =>
+    RustLib.instance.api.crateApiTrustRemove(
+      host: host,
+      fingerprint: fingerprint,
+    );
 
 Future<int> add({required int left, required int right}) =>
     RustLib.instance.api.crateApiAdd(left: left, right: right);
@@ -31,6 +103,25 @@ Future<String> gitClone({
   sshKeyPath: sshKeyPath,
 );
 
+/// Like git_clone, but streams GitProgress events to [sink] while it runs.
+/// [operation] is an optional handle from start_operation that allows cancelling the clone;
+/// a cancelled clone removes the partially written directory.
+Stream<GitProgress> gitCloneWithProgress({
+  required String url,
+  required String path,
+  String? username,
+  String? password,
+  String? sshKeyPath,
+  BigInt? operation,
+}) => RustLib.instance.api.crateApiGitCloneWithProgress(
+  url: url,
+  path: path,
+  username: username,
+  password: password,
+  sshKeyPath: sshKeyPath,
+  operation: operation,
+);
+
 Future<String> gitCurrentBranch({required String path}) =>
     RustLib.instance.api.crateApiGitCurrentBranch(path: path);
 
@@ -40,28 +131,109 @@ Future<List<String>> gitListBranches({required String path}) =>
 Future<bool> gitHasLocalChanges({required String path}) =>
     RustLib.instance.api.crateApiGitHasLocalChanges(path: path);
 
-Future<String> gitPull({
+/// Pulls the current branch from origin.
+/// [branch] overrides the detected branch; pass None to follow HEAD and its upstream.
+Future<SyncResult> gitPull({
   required String path,
   String? username,
   String? password,
   String? sshKeyPath,
+  String? branch,
 }) => RustLib.instance.api.crateApiGitPull(
   path: path,
   username: username,
   password: password,
   sshKeyPath: sshKeyPath,
+  branch: branch,
+);
+
+/// Like git_pull, but streams GitProgress events to [sink] while it runs.
+/// [operation] is an optional handle from start_operation that allows cancelling the pull.
+Stream<GitProgress> gitPullWithProgress({
+  required String path,
+  String? username,
+  String? password,
+  String? sshKeyPath,
+  String? branch,
+  BigInt? operation,
+}) => RustLib.instance.api.crateApiGitPullWithProgress(
+  path: path,
+  username: username,
+  password: password,
+  sshKeyPath: sshKeyPath,
+  branch: branch,
+  operation: operation,
 );
 
-Future<String> gitPush({
+/// Pushes the current branch to origin.
+/// [branch] overrides the detected branch; pass None to follow HEAD and its upstream.
+Future<SyncResult> gitPush({
   required String path,
   String? username,
   String? password,
   String? sshKeyPath,
+  String? branch,
 }) => RustLib.instance.api.crateApiGitPush(
   path: path,
   username: username,
   password: password,
   sshKeyPath: sshKeyPath,
+  branch: branch,
+);
+
+/// Like git_push, but streams GitProgress events to [sink] while it runs.
+/// [operation] is an optional handle from start_operation that allows cancelling the push.
+Stream<GitProgress> gitPushWithProgress({
+  required String path,
+  String? username,
+  String? password,
+  String? sshKeyPath,
+  String? branch,
+  BigInt? operation,
+}) => RustLib.instance.api.crateApiGitPushWithProgress(
+  path: path,
+  username: username,
+  password: password,
+  sshKeyPath: sshKeyPath,
+  branch: branch,
+  operation: operation,
+);
+
+/// Commits local event changes with a generated message, pulls (fast-forward or merge)
+/// and pushes in one call; files other than events are not committed. A push rejected
+/// because the remote moved is retried after pulling again. Fails with MergeConflict
+/// when the pull conflicts, leaving the repository mid-merge as git_pull does.
+/// [branch] overrides the detected branch; pass None to follow HEAD and its upstream.
+Future<SyncReport> gitSync({
+  required String path,
+  String? username,
+  String? password,
+  String? sshKeyPath,
+  String? branch,
+}) => RustLib.instance.api.crateApiGitSync(
+  path: path,
+  username: username,
+  password: password,
+  sshKeyPath: sshKeyPath,
+  branch: branch,
+);
+
+/// Like git_sync, but streams GitProgress events to [sink] while it runs.
+/// [operation] is an optional handle from start_operation that allows cancelling the sync.
+Stream<GitProgress> gitSyncWithProgress({
+  required String path,
+  String? username,
+  String? password,
+  String? sshKeyPath,
+  String? branch,
+  BigInt? operation,
+}) => RustLib.instance.api.crateApiGitSyncWithProgress(
+  path: path,
+  username: username,
+  password: password,
+  sshKeyPath: sshKeyPath,
+  branch: branch,
+  operation: operation,
 );
 
 Future<List<StatusEntry>> gitStatus({required String path}) =>
@@ -77,18 +249,42 @@ Future<String> gitAddRemote({
 Future<String> gitRemoveRemote({required String path, required String name}) =>
     RustLib.instance.api.crateApiGitRemoveRemote(path: path, name: name);
 
-Future<String> gitFetch({
+/// Fetches the current branch from [remote].
+/// [branch] overrides the detected branch; pass None to follow HEAD and its upstream.
+Future<SyncResult> gitFetch({
   required String path,
   required String remote,
   String? username,
   String? password,
   String? sshKeyPath,
+  String? branch,
 }) => RustLib.instance.api.crateApiGitFetch(
   path: path,
   remote: remote,
   username: username,
   password: password,
   sshKeyPath: sshKeyPath,
+  branch: branch,
+);
+
+/// Like git_fetch, but streams GitProgress events to [sink] while it runs.
+/// [operation] is an optional handle from start_operation that allows cancelling the fetch.
+Stream<GitProgress> gitFetchWithProgress({
+  required String path,
+  required String remote,
+  String? username,
+  String? password,
+  String? sshKeyPath,
+  String? branch,
+  BigInt? operation,
+}) => RustLib.instance.api.crateApiGitFetchWithProgress(
+  path: path,
+  remote: remote,
+  username: username,
+  password: password,
+  sshKeyPath: sshKeyPath,
+  branch: branch,
+  operation: operation,
 );
 
 Future<String> gitCheckout({required String path, required String branch}) =>
@@ -97,12 +293,48 @@ Future<String> gitCheckout({required String path, required String branch}) =>
 Future<String> gitAddAll({required String path}) =>
     RustLib.instance.api.crateApiGitAddAll(path: path);
 
-Future<String> gitCommit({required String path, required String message}) =>
-    RustLib.instance.api.crateApiGitCommit(path: path, message: message);
+/// Commits the index. [author] overrides the configured identity for this commit only;
+/// the committer is always the repository's configured identity.
+Future<String> gitCommit({
+  required String path,
+  required String message,
+  GitIdentity? author,
+}) => RustLib.instance.api.crateApiGitCommit(
+  path: path,
+  message: message,
+  author: author,
+);
+
+/// Stores the commit identity for this repository only (its local user.name/user.email).
+Future<String> gitSetIdentity({
+  required String path,
+  required GitIdentity identity,
+}) =>
+    RustLib.instance.api.crateApiGitSetIdentity(path: path, identity: identity);
+
+/// Returns the identity commits will be made with, or None when the app default is used.
+Future<GitIdentity?> gitGetIdentity({required String path}) =>
+    RustLib.instance.api.crateApiGitGetIdentity(path: path);
 
 Future<String> gitMergePreferRemote({required String path}) =>
     RustLib.instance.api.crateApiGitMergePreferRemote(path: path);
 
+/// Resolves every conflict with the local version and commits the merge.
+Future<String> gitMergePreferLocal({required String path}) =>
+    RustLib.instance.api.crateApiGitMergePreferLocal(path: path);
+
+/// Resolves the listed conflicted files, each with its chosen side.
+/// Returns the paths that are still conflicted; once none remain the merge is committed.
+Future<List<String>> gitMergeResolvePaths({
+  required String path,
+  required List<PathResolution> resolutions,
+}) => RustLib.instance.api.crateApiGitMergeResolvePaths(
+  path: path,
+  resolutions: resolutions,
+);
+
+/// Aborts a merge stopped on conflicts and puts back the local changes
+/// git_pull stashed before merging.
 Future<String> gitMergeAbort({required String path}) =>
     RustLib.instance.api.crateApiGitMergeAbort(path: path);
 
@@ -112,38 +344,30 @@ Future<String> gitStash({required String path}) =>
 Future<String> gitDiff({required String path}) =>
     RustLib.instance.api.crateApiGitDiff(path: path);
 
+/// Trusts the CAs in [pem_certs] for every host until the app exits, in addition
+/// to webpki-roots. Replaces the CAs registered by an earlier call; use
+/// trust_add_ca to trust a CA for one host across restarts.
+/// When a server's certificate was issued by an intermediate, register that intermediate.
 Future<void> setSslCaCerts({required List<String> pemCerts}) =>
     RustLib.instance.api.crateApiSetSslCaCerts(pemCerts: pemCerts);
 
 /// Creates a new calendar event and saves it to the specified calendar directory.
 Future<String> createEvent({
-  required String title,
-  required String description,
-  required String startDate,
-  String? endDate,
-  String? startTime,
-  String? endTime,
-  required bool isAllDay,
-  required String recurrence,
+  required EventInput input,
   required String calendarDir,
 }) => RustLib.instance.api.crateApiCreateEvent(
-  title: title,
-  description: description,
-  startDate: startDate,
-  endDate: endDate,
-  startTime: startTime,
-  endTime: endTime,
-  isAllDay: isAllDay,
-  recurrence: recurrence,
+  input: input,
   calendarDir: calendarDir,
 );
 
-/// Gets all events from the specified calendar directory.
-Future<List<EventDto>> getAllEvents({required String calendarDir}) =>
+/// Gets all events from the specified calendar directory, along with the event
+/// files that couldn't be parsed.
+Future<EventsResult> getAllEvents({required String calendarDir}) =>
     RustLib.instance.api.crateApiGetAllEvents(calendarDir: calendarDir);
 
 /// Gets all events within a date range from the specified calendar directory.
-Future<List<EventDto>> getEventsInRange({
+/// Event files that couldn't be parsed are all reported, as their dates aren't known.
+Future<EventsResult> getEventsInRange({
   required String startDate,
   required String endDate,
   required String calendarDir,
@@ -153,38 +377,52 @@ Future<List<EventDto>> getEventsInRange({
   calendarDir: calendarDir,
 );
 
-/// Updates an existing event in the specified calendar directory.
+/// Updates an existing event in the specified calendar directory. Skipped and
+/// changed occurrences the new dates or recurrence no longer have are dropped.
 Future<void> updateEvent({
   required String id,
-  required String title,
-  required String description,
-  required String startDate,
-  String? endDate,
-  String? startTime,
-  String? endTime,
-  required bool isAllDay,
-  required String recurrence,
+  required EventInput input,
   required String calendarDir,
 }) => RustLib.instance.api.crateApiUpdateEvent(
   id: id,
-  title: title,
-  description: description,
-  startDate: startDate,
-  endDate: endDate,
-  startTime: startTime,
-  endTime: endTime,
-  isAllDay: isAllDay,
-  recurrence: recurrence,
+  input: input,
   calendarDir: calendarDir,
 );
 
-/// Deletes an event from the specified calendar directory.
-/// The [id] parameter is the event title, used to find and delete the event file.
+/// Deletes the event with the given [id] from the specified calendar directory.
 Future<void> deleteEvent({required String id, required String calendarDir}) =>
     RustLib.instance.api.crateApiDeleteEvent(id: id, calendarDir: calendarDir);
 
+/// Stores a stable id in every event file of the calendar directory.
+/// Event files written before ids were stored get the id rcal-lib derives for them
+/// written into the file, and copies that share another file's id get a fresh one.
+/// Returns the names of the rewritten files; files keep their names, so this is safe
+/// to run on every start.
+Future<List<String>> migrateEventIds({required String calendarDir}) =>
+    RustLib.instance.api.crateApiMigrateEventIds(calendarDir: calendarDir);
+
+/// Loads the events of [calendar_dir] into memory, so later queries only read the
+/// files that changed. Queries open a calendar on first use; calling this at start
+/// moves the initial load out of the first query.
+Future<void> openCalendar({required String calendarDir}) =>
+    RustLib.instance.api.crateApiOpenCalendar(calendarDir: calendarDir);
+
+/// Drops the in-memory events of [calendar_dir]; the next query loads them again.
+Future<void> closeCalendar({required String calendarDir}) =>
+    RustLib.instance.api.crateApiCloseCalendar(calendarDir: calendarDir);
+
+/// Watches [calendar_dir] for changes to its event files, from rcal, a pull or any
+/// other tool, and streams them to [sink] as they settle. Watching a directory again
+/// replaces its earlier watcher; unwatch_calendar stops it.
+Stream<CalendarChange> watchCalendar({required String calendarDir}) =>
+    RustLib.instance.api.crateApiWatchCalendar(calendarDir: calendarDir);
+
+/// Stops watching [calendar_dir]; its stream gets no further changes.
+Future<void> unwatchCalendar({required String calendarDir}) =>
+    RustLib.instance.api.crateApiUnwatchCalendar(calendarDir: calendarDir);
+
 /// Validates an event and returns validation errors.
-/// Returns Ok(()) if valid, Err(error_message) if invalid.
+/// Returns Ok(()) if valid, Err(EventError) naming the offending field if invalid.
 /// Uses the existing validation logic from rcal-lib.
 Future<void> validateEvent({
   required String title,
@@ -200,9 +438,60 @@ Future<void> validateEvent({
   endTime: endTime,
 );
 
+/// Parses RRULE text such as "FREQ=MONTHLY;BYDAY=1MO" into a recurrence rule,
+/// with or without the "RRULE:" prefix.
+Future<RecurrenceRule> parseRecurrenceRule({required String text}) =>
+    RustLib.instance.api.crateApiParseRecurrenceRule(text: text);
+
+/// Formats a recurrence rule as RRULE text, as it is stored in event files.
+Future<String> formatRecurrenceRule({required RecurrenceRule rule}) =>
+    RustLib.instance.api.crateApiFormatRecurrenceRule(rule: rule);
+
+/// Skips the occurrence of event [id] that falls on [date] by its recurrence (EXDATE).
+/// An override of that occurrence is dropped with it.
+Future<void> skipOccurrence({
+  required String id,
+  required String date,
+  required String calendarDir,
+}) => RustLib.instance.api.crateApiSkipOccurrence(
+  id: id,
+  date: date,
+  calendarDir: calendarDir,
+);
+
+/// Changes one occurrence of event [id], such as its time or title, leaving the rest
+/// of the series alone (RECURRENCE-ID). [changes] names the occurrence by the date it
+/// falls on by the recurrence and replaces an earlier override of it.
+Future<void> overrideOccurrence({
+  required String id,
+  required OccurrenceOverride changes,
+  required String calendarDir,
+}) => RustLib.instance.api.crateApiOverrideOccurrence(
+  id: id,
+  changes: changes,
+  calendarDir: calendarDir,
+);
+
+/// Splits the recurring event [id] at its occurrence on [date]: the series ends the
+/// day before, and a new series with a new id carries the occurrences from [date] on,
+/// with their skips and overrides. Edit the new series to change "this and following".
+/// Returns the new series' id. A series whose occurrences the two parts can't keep
+/// where they are, such as a yearly one from Feb 29, isn't split.
+Future<String> splitSeriesFrom({
+  required String id,
+  required String date,
+  required String calendarDir,
+}) => RustLib.instance.api.crateApiSplitSeriesFrom(
+  id: id,
+  date: date,
+  calendarDir: calendarDir,
+);
+
 /// Generates instances for recurring events within a date range.
-/// Uses rcal's generate_instances_for_range logic to expand recurring events.
-Future<List<EventDto>> generateInstances({
+/// Uses rcal's generate_instances_for_range logic to expand recurring events, and
+/// expands events with a recurrence rule itself. Fails if the range is malformed;
+/// events that don't convert are reported in the result's diagnostics.
+Future<InstancesResult> generateInstances({
   required List<EventDto> events,
   required String startDate,
   required String endDate,
@@ -213,10 +502,152 @@ Future<List<EventDto>> generateInstances({
 );
 
 /// Checks if an event occurs on a specific date.
-/// Uses rcal's CalendarEvent::occurs_on logic.
+/// Uses rcal's CalendarEvent::occurs_on logic, or the event's recurrence rule,
+/// skipped and changed occurrences. Fails if the event or the date is malformed.
 Future<bool> eventOccursOn({required EventDto event, required String date}) =>
     RustLib.instance.api.crateApiEventOccursOn(event: event, date: date);
 
+/// Like generate_instances, with the times of events that have a time zone shown in
+/// [display_zone] and the range read in it. Floating and all-day events keep their
+/// times. Instances shown in another zone carry [display_zone] as their time zone.
+Future<InstancesResult> generateInstancesInZone({
+  required List<EventDto> events,
+  required String startDate,
+  required String endDate,
+  required String displayZone,
+}) => RustLib.instance.api.crateApiGenerateInstancesInZone(
+  events: events,
+  startDate: startDate,
+  endDate: endDate,
+  displayZone: displayZone,
+);
+
+/// Shows the times of one event or instance in [display_zone]. Floating and
+/// all-day events come back unchanged.
+Future<EventDto> convertEventToZone({
+  required EventDto event,
+  required String displayZone,
+}) => RustLib.instance.api.crateApiConvertEventToZone(
+  event: event,
+  displayZone: displayZone,
+);
+
+/// The IANA time zone names events can use, for a zone picker.
+Future<List<String>> listTimeZones() =>
+    RustLib.instance.api.crateApiListTimeZones();
+
+/// Imports the events of an iCalendar file, such as a Google Calendar or
+/// Thunderbird export, into [calendar_dir]. An event keeps its UID as its id, so
+/// importing the same file again skips what is already there, unless
+/// [options] replace existing events. Moved or cancelled occurrences of a
+/// recurring event (RECURRENCE-ID) become overrides and skipped dates of its series.
+/// Only fails when the text isn't iCalendar or the calendar can't be written.
+Future<IcsImportReport> importIcs({
+  required String icsText,
+  required String calendarDir,
+  required IcsImportOptions options,
+}) => RustLib.instance.api.crateApiImportIcs(
+  icsText: icsText,
+  calendarDir: calendarDir,
+  options: options,
+);
+
+/// Resolves conflicted calendar event files by merging them field by field.
+/// Fields changed on only one side are combined; fields both sides changed
+/// differently are returned as conflicts and their files stay unresolved.
+/// When every conflict is resolved the merge is committed.
+Future<EventMergeReport> gitMergeResolveEvents({required String path}) =>
+    RustLib.instance.api.crateApiGitMergeResolveEvents(path: path);
+
+@freezed
+sealed class CalendarChange with _$CalendarChange {
+  const CalendarChange._();
+
+  const factory CalendarChange.eventAdded(EventDto field0) =
+      CalendarChange_EventAdded;
+  const factory CalendarChange.eventChanged(EventDto field0) =
+      CalendarChange_EventChanged;
+  /// Carries the event as it was last seen
+  const factory CalendarChange.eventRemoved(EventDto field0) =
+      CalendarChange_EventRemoved;
+}
+
+@freezed
+sealed class CertificateError with _$CertificateError {
+  const CertificateError._();
+
+  const factory CertificateError.expired() = CertificateError_Expired;
+  const factory CertificateError.notYetValid() = CertificateError_NotYetValid;
+  /// Not issued by webpki-roots or a CA registered through set_ssl_ca_certs
+  const factory CertificateError.unknownIssuer() =
+      CertificateError_UnknownIssuer;
+  /// Not valid for the host name or IP address that was connected to
+  const factory CertificateError.nameMismatch() = CertificateError_NameMismatch;
+  /// The host has pinned certificates and this isn't one of them
+  const factory CertificateError.pinMismatch() = CertificateError_PinMismatch;
+  const factory CertificateError.invalidSignature() =
+      CertificateError_InvalidSignature;
+  const factory CertificateError.malformed(String field0) =
+      CertificateError_Malformed;
+  const factory CertificateError.other(String field0) = CertificateError_Other;
+}
+
+/// What a remote asks credentials for, passed to the set_credential_callback callback.
+class CredentialRequest {
+  final String url;
+  final String? usernameFromUrl;
+  final bool allowPassword;
+  final bool allowSshKey;
+  /// 1 on the first request of an operation; higher once earlier credentials were rejected
+  final int attempt;
+
+  const CredentialRequest({
+    required this.url,
+    this.usernameFromUrl,
+    required this.allowPassword,
+    required this.allowSshKey,
+    required this.attempt,
+  });
+
+  @override
+  int get hashCode =>
+      url.hashCode ^
+      usernameFromUrl.hashCode ^
+      allowPassword.hashCode ^
+      allowSshKey.hashCode ^
+      attempt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CredentialRequest &&
+          runtimeType == other.runtimeType &&
+          url == other.url &&
+          usernameFromUrl == other.usernameFromUrl &&
+          allowPassword == other.allowPassword &&
+          allowSshKey == other.allowSshKey &&
+          attempt == other.attempt;
+}
+
+/// An event left out of a result, and why.
+class EventDiagnostic {
+  final String id;
+  final EventError reason;
+
+  const EventDiagnostic({required this.id, required this.reason});
+
+  @override
+  int get hashCode => id.hashCode ^ reason.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EventDiagnostic &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          reason == other.reason;
+}
+
 class EventDto {
   final String id;
   final String title;
@@ -226,7 +657,18 @@ class EventDto {
   final String? startTime;
   final String? endTime;
   final bool isAllDay;
+  /// IANA time zone of the times, e.g. "Europe/Berlin"; None for floating times
+  /// that read the same in every zone. All-day events are always floating
+  final String? timeZone;
   final String recurrence;
+  /// The full rule when the event repeats by one; [recurrence] then holds its frequency
+  final RecurrenceRule? rrule;
+  /// Dates whose occurrence is skipped (EXDATE), YYYY-MM-DD
+  final List<String> excludedDates;
+  final List<OccurrenceOverride> overrides;
+  /// For generated instances, the date the occurrence falls on by the recurrence,
+  /// also when an override moved it; skip_occurrence and override_occurrence take it
+  final String? occurrenceDate;
   final bool isRecurringInstance;
 
   const EventDto({
@@ -238,7 +680,12 @@ class EventDto {
     this.startTime,
     this.endTime,
     required this.isAllDay,
+    this.timeZone,
     required this.recurrence,
+    this.rrule,
+    required this.excludedDates,
+    required this.overrides,
+    this.occurrenceDate,
     required this.isRecurringInstance,
   });
 
@@ -252,7 +699,12 @@ class EventDto {
       startTime.hashCode ^
       endTime.hashCode ^
       isAllDay.hashCode ^
+      timeZone.hashCode ^
       recurrence.hashCode ^
+      rrule.hashCode ^
+      excludedDates.hashCode ^
+      overrides.hashCode ^
+      occurrenceDate.hashCode ^
       isRecurringInstance.hashCode;
 
   @override
@@ -268,20 +720,598 @@ class EventDto {
           startTime == other.startTime &&
           endTime == other.endTime &&
           isAllDay == other.isAllDay &&
+          timeZone == other.timeZone &&
           recurrence == other.recurrence &&
+          rrule == other.rrule &&
+          excludedDates == other.excludedDates &&
+          overrides == other.overrides &&
+          occurrenceDate == other.occurrenceDate &&
           isRecurringInstance == other.isRecurringInstance;
 }
 
+@freezed
+sealed class EventError with _$EventError implements FrbException {
+  const EventError._();
+
+  /// Not a YYYY-MM-DD date
+  const factory EventError.invalidDate({
+    required String field,
+    required String value,
+  }) = EventError_InvalidDate;
+  /// Not an HH:MM time
+  const factory EventError.invalidTime({
+    required String field,
+    required String value,
+  }) = EventError_InvalidTime;
+  /// A field the event needs is missing, e.g. the start time of a timed event
+  const factory EventError.missingField(String field0) =
+      EventError_MissingField;
+  /// The end lies before the start; [field] is the end field
+  const factory EventError.invalidRange({required String field}) =
+      EventError_InvalidRange;
+  /// Not a known recurrence or a supported RRULE
+  const factory EventError.invalidRecurrence({
+    required String value,
+    required String reason,
+  }) = EventError_InvalidRecurrence;
+  /// The event has no occurrence on this date to skip, override or split at
+  const factory EventError.noOccurrence({
+    required String id,
+    required String date,
+  }) = EventError_NoOccurrence;
+  /// Not an IANA time zone name
+  const factory EventError.unknownTimeZone(String field0) =
+      EventError_UnknownTimeZone;
+  /// Empty or longer than rcal-lib allows
+  const factory EventError.titleInvalid(String field0) =
+      EventError_TitleInvalid;
+  /// No event with this id exists in the calendar
+  const factory EventError.notFound(String field0) = EventError_NotFound;
+  /// More than one event file carries this id
+  const factory EventError.duplicateId(String field0) = EventError_DuplicateId;
+  /// Reading or writing the calendar directory failed
+  const factory EventError.storage(String field0) = EventError_Storage;
+  /// An event file couldn't be parsed
+  const factory EventError.parseFailure({
+    required String file,
+    required String message,
+  }) = EventError_ParseFailure;
+}
+
+/// A field that both sides of a merge changed to different values.
+class EventFieldConflict {
+  final String path;
+  final String eventId;
+  final String field;
+  final String? baseValue;
+  final String? localValue;
+  final String? remoteValue;
+
+  const EventFieldConflict({
+    required this.path,
+    required this.eventId,
+    required this.field,
+    this.baseValue,
+    this.localValue,
+    this.remoteValue,
+  });
+
+  @override
+  int get hashCode =>
+      path.hashCode ^
+      eventId.hashCode ^
+      field.hashCode ^
+      baseValue.hashCode ^
+      localValue.hashCode ^
+      remoteValue.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EventFieldConflict &&
+          runtimeType == other.runtimeType &&
+          path == other.path &&
+          eventId == other.eventId &&
+          field == other.field &&
+          baseValue == other.baseValue &&
+          localValue == other.localValue &&
+          remoteValue == other.remoteValue;
+}
+
+/// The fields of an event to create or update. [recurrence] is none, daily, weekly,
+/// monthly, yearly or RRULE text; [rrule] takes its place when given. [time_zone]
+/// is an IANA name, or None or "floating" for times that read the same in every zone.
+class EventInput {
+  final String title;
+  final String description;
+  final String startDate;
+  final String? endDate;
+  final String? startTime;
+  final String? endTime;
+  final bool isAllDay;
+  final String recurrence;
+  final RecurrenceRule? rrule;
+  final String? timeZone;
+
+  const EventInput({
+    required this.title,
+    required this.description,
+    required this.startDate,
+    this.endDate,
+    this.startTime,
+    this.endTime,
+    required this.isAllDay,
+    required this.recurrence,
+    this.rrule,
+    this.timeZone,
+  });
+
+  @override
+  int get hashCode =>
+      title.hashCode ^
+      description.hashCode ^
+      startDate.hashCode ^
+      endDate.hashCode ^
+      startTime.hashCode ^
+      endTime.hashCode ^
+      isAllDay.hashCode ^
+      recurrence.hashCode ^
+      rrule.hashCode ^
+      timeZone.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EventInput &&
+          runtimeType == other.runtimeType &&
+          title == other.title &&
+          description == other.description &&
+          startDate == other.startDate &&
+          endDate == other.endDate &&
+          startTime == other.startTime &&
+          endTime == other.endTime &&
+          isAllDay == other.isAllDay &&
+          recurrence == other.recurrence &&
+          rrule == other.rrule &&
+          timeZone == other.timeZone;
+}
+
+/// Outcome of merging conflicted event files field by field.
+class EventMergeReport {
+  /// Event files merged without contested fields and staged
+  final List<String> mergedPaths;
+  /// Files still conflicted in the index
+  final List<String> unresolvedPaths;
+  final List<EventFieldConflict> conflicts;
+  /// True when nothing was left unresolved and the merge commit was written
+  final bool committed;
+
+  const EventMergeReport({
+    required this.mergedPaths,
+    required this.unresolvedPaths,
+    required this.conflicts,
+    required this.committed,
+  });
+
+  @override
+  int get hashCode =>
+      mergedPaths.hashCode ^
+      unresolvedPaths.hashCode ^
+      conflicts.hashCode ^
+      committed.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EventMergeReport &&
+          runtimeType == other.runtimeType &&
+          mergedPaths == other.mergedPaths &&
+          unresolvedPaths == other.unresolvedPaths &&
+          conflicts == other.conflicts &&
+          committed == other.committed;
+}
+
+/// Events read from a calendar directory. Event files that couldn't be parsed are
+/// reported in [unreadable] as ParseFailure instead of being left out without a trace.
+class EventsResult {
+  final List<EventDto> events;
+  final List<EventError> unreadable;
+
+  const EventsResult({required this.events, required this.unreadable});
+
+  @override
+  int get hashCode => events.hashCode ^ unreadable.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EventsResult &&
+          runtimeType == other.runtimeType &&
+          events == other.events &&
+          unreadable == other.unreadable;
+}
+
+/// How often a recurrence rule repeats (RRULE FREQ).
+enum Frequency { daily, weekly, monthly, yearly }
+
+@freezed
+sealed class GitCredentials with _$GitCredentials {
+  const GitCredentials._();
+
+  const factory GitCredentials.userPassword({
+    required String username,
+    required String password,
+  }) = GitCredentials_UserPassword;
+  /// Personal access token, sent as the HTTPS password
+  const factory GitCredentials.token({
+    String? username,
+    required String token,
+  }) = GitCredentials_Token;
+  const factory GitCredentials.sshKeyFile({
+    String? username,
+    required String privateKeyPath,
+    String? publicKeyPath,
+    String? passphrase,
+  }) = GitCredentials_SshKeyFile;
+  /// PEM/OpenSSH key text, for apps that keep keys in secure storage instead of files
+  const factory GitCredentials.sshKeyMemory({
+    String? username,
+    required String privateKey,
+    String? publicKey,
+    String? passphrase,
+  }) = GitCredentials_SshKeyMemory;
+  const factory GitCredentials.sshAgent({String? username}) =
+      GitCredentials_SshAgent;
+}
+
 @freezed
 sealed class GitError with _$GitError implements FrbException {
   const GitError._();
 
   const factory GitError.io(String field0) = GitError_Io;
+  /// A libgit2 error without a more specific variant
   const factory GitError.git(String field0) = GitError_Git;
-  const factory GitError.auth(String field0) = GitError_Auth;
+  /// The remote rejected the credentials, or none were available
+  const factory GitError.authenticationFailed(String field0) =
+      GitError_AuthenticationFailed;
+  /// The repository path or remote repository doesn't exist
+  const factory GitError.repositoryNotFound(String field0) =
+      GitError_RepositoryNotFound;
+  /// A reference, object or remote the operation needs doesn't exist
+  const factory GitError.notFound(String field0) = GitError_NotFound;
+  /// The remote couldn't be reached: no network, DNS failure or connection refused
+  const factory GitError.networkUnreachable(String field0) =
+      GitError_NetworkUnreachable;
+  /// Another process holds a lock in the repository, e.g. index.lock
+  const factory GitError.locked(String field0) = GitError_Locked;
+  /// HEAD is not on a branch; carries the commit HEAD points to.
+  const factory GitError.detachedHead(String field0) = GitError_DetachedHead;
+  /// A merge stopped on conflicts; carries the conflicted paths.
+  /// The repository is left mid-merge for git_merge_prefer_remote or git_merge_abort.
+  const factory GitError.mergeConflict(List<String> field0) =
+      GitError_MergeConflict;
+  /// The pull or merge succeeded, but the local changes stashed before it conflict
+  /// with the result; carries the conflicted paths. The changes are kept in the stash.
+  const factory GitError.stashConflict(List<String> field0) =
+      GitError_StashConflict;
+  /// The remote rejected a push because it has commits the local branch lacks;
+  /// carries the rejected reference. Pull (or git_sync) and push again.
+  const factory GitError.nonFastForward(String field0) =
+      GitError_NonFastForward;
+  /// The server's TLS certificate was rejected.
+  const factory GitError.certificateInvalid({
+    required String host,
+    required CertificateError reason,
+  }) = GitError_CertificateInvalid;
+  /// The SSH server presented a host key that isn't in known_hosts yet.
+  /// Show the fingerprint to the user; call trust_host_key and retry to accept it.
+  const factory GitError.unknownHostKey(HostKey field0) =
+      GitError_UnknownHostKey;
+  /// The SSH server presented a different key than the one pinned for its host.
+  /// Either the server was reinstalled or the connection is being intercepted.
+  const factory GitError.hostKeyMismatch(HostKey field0) =
+      GitError_HostKeyMismatch;
+  /// The operation was stopped through cancel_operation.
+  const factory GitError.cancelled() = GitError_Cancelled;
+  /// HEAD points to a branch without commits and no remote default exists.
+  const factory GitError.unbornBranch(String field0) = GitError_UnbornBranch;
   const factory GitError.other(String field0) = GitError_Other;
 }
 
+/// Name and email recorded on commits.
+class GitIdentity {
+  final String name;
+  final String email;
+
+  const GitIdentity({required this.name, required this.email});
+
+  @override
+  int get hashCode => name.hashCode ^ email.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GitIdentity &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          email == other.email;
+}
+
+@freezed
+sealed class GitProgress with _$GitProgress {
+  const GitProgress._();
+
+  /// Objects and bytes received while fetching or cloning
+  const factory GitProgress.transfer({
+    required BigInt receivedObjects,
+    required BigInt totalObjects,
+    required BigInt indexedObjects,
+    required BigInt receivedBytes,
+    required BigInt totalDeltas,
+    required BigInt indexedDeltas,
+  }) = GitProgress_Transfer;
+  /// Files written to the working directory
+  const factory GitProgress.checkout({
+    String? path,
+    required BigInt completed,
+    required BigInt total,
+  }) = GitProgress_Checkout;
+  /// Objects sent while pushing
+  const factory GitProgress.pushTransfer({
+    required BigInt current,
+    required BigInt total,
+    required BigInt bytes,
+  }) = GitProgress_PushTransfer;
+  /// Text the server sends, e.g. "Counting objects: 42% (21/50)"
+  const factory GitProgress.sideband(String field0) = GitProgress_Sideband;
+}
+
+/// An SSH host key as stored in known_hosts.
+class HostKey {
+  final String host;
+  /// Key type as written in known_hosts, e.g. "ssh-ed25519"
+  final String keyType;
+  /// Base64 encoded public key
+  final String key;
+  /// OpenSSH style fingerprint, e.g. "SHA256:uNiVztksCsDhcc0u9e8BujQXVUpKZIDTMczCvj3tD2s"
+  final String fingerprint;
+
+  const HostKey({
+    required this.host,
+    required this.keyType,
+    required this.key,
+    required this.fingerprint,
+  });
+
+  @override
+  int get hashCode =>
+      host.hashCode ^ keyType.hashCode ^ key.hashCode ^ fingerprint.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is HostKey &&
+          runtimeType == other.runtimeType &&
+          host == other.host &&
+          keyType == other.keyType &&
+          key == other.key &&
+          fingerprint == other.fingerprint;
+}
+
+/// A calendar item import_ics left out, and why.
+class IcsImportIssue {
+  final String? uid;
+  final String summary;
+  final String reason;
+
+  const IcsImportIssue({this.uid, required this.summary, required this.reason});
+
+  @override
+  int get hashCode => uid.hashCode ^ summary.hashCode ^ reason.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is IcsImportIssue &&
+          runtimeType == other.runtimeType &&
+          uid == other.uid &&
+          summary == other.summary &&
+          reason == other.reason;
+}
+
+/// Options for import_ics.
+class IcsImportOptions {
+  /// Replace events already in the calendar whose id is an imported UID,
+  /// instead of skipping them
+  final bool replaceExisting;
+
+  const IcsImportOptions({required this.replaceExisting});
+
+  static Future<IcsImportOptions> default_() =>
+      RustLib.instance.api.crateApiIcsImportOptionsDefault();
+
+  @override
+  int get hashCode => replaceExisting.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is IcsImportOptions &&
+          runtimeType == other.runtimeType &&
+          replaceExisting == other.replaceExisting;
+}
+
+/// Outcome of import_ics.
+class IcsImportReport {
+  /// Ids of the events written; an event's id is its UID when it has one
+  final List<String> imported;
+  /// Duplicates, cancelled events, events already in the calendar and malformed items
+  final List<IcsImportIssue> skipped;
+  /// Items using what events here can't hold, such as to-dos or hourly rules
+  final List<IcsImportIssue> unsupported;
+
+  const IcsImportReport({
+    required this.imported,
+    required this.skipped,
+    required this.unsupported,
+  });
+
+  @override
+  int get hashCode =>
+      imported.hashCode ^ skipped.hashCode ^ unsupported.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is IcsImportReport &&
+          runtimeType == other.runtimeType &&
+          imported == other.imported &&
+          skipped == other.skipped &&
+          unsupported == other.unsupported;
+}
+
+/// Occurrences expanded from a list of events. Events that couldn't be expanded
+/// are reported in [diagnostics] instead of being dropped without a trace.
+class InstancesResult {
+  final List<EventDto> instances;
+  final List<EventDiagnostic> diagnostics;
+
+  const InstancesResult({required this.instances, required this.diagnostics});
+
+  @override
+  int get hashCode => instances.hashCode ^ diagnostics.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is InstancesResult &&
+          runtimeType == other.runtimeType &&
+          instances == other.instances &&
+          diagnostics == other.diagnostics;
+}
+
+/// Which side of a conflicted merge to keep.
+enum MergeSide { local, remote }
+
+/// Changes to one occurrence of a recurring event (RECURRENCE-ID). Fields left None
+/// keep the series' values; moving [start_date] moves the end date with it.
+class OccurrenceOverride {
+  /// The date the occurrence falls on by the recurrence
+  final String occurrenceDate;
+  final String? title;
+  final String? description;
+  final String? startDate;
+  final String? endDate;
+  final String? startTime;
+  final String? endTime;
+  final bool? isAllDay;
+
+  const OccurrenceOverride({
+    required this.occurrenceDate,
+    this.title,
+    this.description,
+    this.startDate,
+    this.endDate,
+    this.startTime,
+    this.endTime,
+    this.isAllDay,
+  });
+
+  @override
+  int get hashCode =>
+      occurrenceDate.hashCode ^
+      title.hashCode ^
+      description.hashCode ^
+      startDate.hashCode ^
+      endDate.hashCode ^
+      startTime.hashCode ^
+      endTime.hashCode ^
+      isAllDay.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is OccurrenceOverride &&
+          runtimeType == other.runtimeType &&
+          occurrenceDate == other.occurrenceDate &&
+          title == other.title &&
+          description == other.description &&
+          startDate == other.startDate &&
+          endDate == other.endDate &&
+          startTime == other.startTime &&
+          endTime == other.endTime &&
+          isAllDay == other.isAllDay;
+}
+
+/// The side chosen for one conflicted file.
+class PathResolution {
+  final String path;
+  final MergeSide side;
+
+  const PathResolution({required this.path, required this.side});
+
+  @override
+  int get hashCode => path.hashCode ^ side.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PathResolution &&
+          runtimeType == other.runtimeType &&
+          path == other.path &&
+          side == other.side;
+}
+
+/// An RFC 5545 recurrence rule (RRULE). The event's start date is always the
+/// first occurrence; the rule picks the ones after it.
+class RecurrenceRule {
+  final Frequency frequency;
+  /// Repeats every [interval] days, weeks, months or years
+  final int interval;
+  final List<WeekdayRule> byDay;
+  /// Days of the month, negative counting from the end (-1 is the last day)
+  final Int32List byMonthDay;
+  /// Picks the nth of the dates each period yields, negative counting from the end
+  final Int32List bySetPos;
+  /// Total number of occurrences
+  final int? count;
+  /// Last date an occurrence may start on (YYYY-MM-DD, inclusive)
+  final String? until;
+
+  const RecurrenceRule({
+    required this.frequency,
+    required this.interval,
+    required this.byDay,
+    required this.byMonthDay,
+    required this.bySetPos,
+    this.count,
+    this.until,
+  });
+
+  @override
+  int get hashCode =>
+      frequency.hashCode ^
+      interval.hashCode ^
+      byDay.hashCode ^
+      byMonthDay.hashCode ^
+      bySetPos.hashCode ^
+      count.hashCode ^
+      until.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RecurrenceRule &&
+          runtimeType == other.runtimeType &&
+          frequency == other.frequency &&
+          interval == other.interval &&
+          byDay == other.byDay &&
+          byMonthDay == other.byMonthDay &&
+          bySetPos == other.bySetPos &&
+          count == other.count &&
+          until == other.until;
+}
+
 class StatusEntry {
   final String path;
   final String status;
@@ -299,3 +1329,154 @@ class StatusEntry {
           path == other.path &&
           status == other.status;
 }
+
+/// What a sync operation did.
+enum SyncOutcome { upToDate, fastForward, merged, pushed, fetched }
+
+/// Result of git_sync.
+class SyncReport {
+  /// Id of the commit created for local changes, None when there was nothing to commit
+  final String? localCommit;
+  /// The last pull, run before the push that succeeded
+  final SyncResult pull;
+  final SyncResult push;
+  /// Number of push attempts, including the one that succeeded
+  final int pushAttempts;
+
+  const SyncReport({
+    this.localCommit,
+    required this.pull,
+    required this.push,
+    required this.pushAttempts,
+  });
+
+  @override
+  int get hashCode =>
+      localCommit.hashCode ^
+      pull.hashCode ^
+      push.hashCode ^
+      pushAttempts.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SyncReport &&
+          runtimeType == other.runtimeType &&
+          localCommit == other.localCommit &&
+          pull == other.pull &&
+          push == other.push &&
+          pushAttempts == other.pushAttempts;
+}
+
+/// Result of pull, push or fetch, describing the commits and files that changed.
+class SyncResult {
+  final SyncOutcome outcome;
+  final String? oldCommit;
+  final String? newCommit;
+  final List<String> addedPaths;
+  final List<String> modifiedPaths;
+  final List<String> deletedPaths;
+  /// Ids of the events stored in the added, modified and deleted files
+  final List<String> affectedEventIds;
+
+  const SyncResult({
+    required this.outcome,
+    this.oldCommit,
+    this.newCommit,
+    required this.addedPaths,
+    required this.modifiedPaths,
+    required this.deletedPaths,
+    required this.affectedEventIds,
+  });
+
+  @override
+  int get hashCode =>
+      outcome.hashCode ^
+      oldCommit.hashCode ^
+      newCommit.hashCode ^
+      addedPaths.hashCode ^
+      modifiedPaths.hashCode ^
+      deletedPaths.hashCode ^
+      affectedEventIds.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SyncResult &&
+          runtimeType == other.runtimeType &&
+          outcome == other.outcome &&
+          oldCommit == other.oldCommit &&
+          newCommit == other.newCommit &&
+          addedPaths == other.addedPaths &&
+          modifiedPaths == other.modifiedPaths &&
+          deletedPaths == other.deletedPaths &&
+          affectedEventIds == other.affectedEventIds;
+}
+
+/// How a certificate in the trust store is trusted.
+enum TrustKind {
+  /// A CA whose certificates are trusted for the host
+  certificateAuthority,
+  /// A server certificate the host must present; any other is rejected
+  pinnedLeaf,
+}
+
+/// A certificate in the trust store.
+class TrustedCertificate {
+  final String host;
+  final TrustKind kind;
+  /// SHA-256 of the DER encoding, e.g. "SHA256:47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU"
+  final String fingerprint;
+  final String subject;
+  /// RFC 3339 end of the validity period
+  final String notAfter;
+
+  const TrustedCertificate({
+    required this.host,
+    required this.kind,
+    required this.fingerprint,
+    required this.subject,
+    required this.notAfter,
+  });
+
+  @override
+  int get hashCode =>
+      host.hashCode ^
+      kind.hashCode ^
+      fingerprint.hashCode ^
+      subject.hashCode ^
+      notAfter.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TrustedCertificate &&
+          runtimeType == other.runtimeType &&
+          host == other.host &&
+          kind == other.kind &&
+          fingerprint == other.fingerprint &&
+          subject == other.subject &&
+          notAfter == other.notAfter;
+}
+
+enum Weekday { monday, tuesday, wednesday, thursday, friday, saturday, sunday }
+
+/// A BYDAY entry: a weekday, optionally the nth of the month or year, such as the
+/// first Monday (1MO) or the last Friday (-1FR).
+class WeekdayRule {
+  final Weekday weekday;
+  final int? ordinal;
+
+  const WeekdayRule({required this.weekday, this.ordinal});
+
+  @override
+  int get hashCode => weekday.hashCode ^ ordinal.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WeekdayRule &&
+          runtimeType == other.runtimeType &&
+          weekday == other.weekday &&
+          ordinal == other.ordinal;
+}
@@ -16,79 +16,73 @@ final _privateConstructorUsedError = UnsupportedError(
 );
 
 /// @nodoc
-mixin _$GitError {
-  String get field0 => throw _privateConstructorUsedError;
+mixin _$CalendarChange {
+  EventDto get field0 => throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(String field0) io,
-    required TResult Function(String field0) git,
-    required TResult Function(String field0) auth,
-    required TResult Function(String field0) other,
+    required TResult Function(EventDto field0) eventAdded,
+    required TResult Function(EventDto field0) eventChanged,
+    required TResult Function(EventDto field0) eventRemoved,
   }) => throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(String field0)? io,
-    TResult? Function(String field0)? git,
-    TResult? Function(String field0)? auth,
-    TResult? Function(String field0)? other,
+    TResult? Function(EventDto field0)? eventAdded,
+    TResult? Function(EventDto field0)? eventChanged,
+    TResult? Function(EventDto field0)? eventRemoved,
   }) => throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(String field0)? io,
-    TResult Function(String field0)? git,
-    TResult Function(String field0)? auth,
-    TResult Function(String field0)? other,
+    TResult Function(EventDto field0)? eventAdded,
+    TResult Function(EventDto field0)? eventChanged,
+    TResult Function(EventDto field0)? eventRemoved,
     required TResult orElse(),
   }) => throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(GitError_Io value) io,
-    required TResult Function(GitError_Git value) git,
-    required TResult Function(GitError_Auth value) auth,
-    required TResult Function(GitError_Other value) other,
+    required TResult Function(CalendarChange_EventAdded value) eventAdded,
+    required TResult Function(CalendarChange_EventChanged value) eventChanged,
+    required TResult Function(CalendarChange_EventRemoved value) eventRemoved,
   }) => throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(GitError_Io value)? io,
-    TResult? Function(GitError_Git value)? git,
-    TResult? Function(GitError_Auth value)? auth,
-    TResult? Function(GitError_Other value)? other,
+    TResult? Function(CalendarChange_EventAdded value)? eventAdded,
+    TResult? Function(CalendarChange_EventChanged value)? eventChanged,
+    TResult? Function(CalendarChange_EventRemoved value)? eventRemoved,
   }) => throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(GitError_Io value)? io,
-    TResult Function(GitError_Git value)? git,
-    TResult Function(GitError_Auth value)? auth,
-    TResult Function(GitError_Other value)? other,
+    TResult Function(CalendarChange_EventAdded value)? eventAdded,
+    TResult Function(CalendarChange_EventChanged value)? eventChanged,
+    TResult Function(CalendarChange_EventRemoved value)? eventRemoved,
     required TResult orElse(),
   }) => throw _privateConstructorUsedError;
 
-  /// Create a copy of GitError
+  /// Create a copy of CalendarChange
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
-  $GitErrorCopyWith<GitError> get copyWith =>
+  $CalendarChangeCopyWith<CalendarChange> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class $GitErrorCopyWith<$Res> {
-  factory $GitErrorCopyWith(GitError value, $Res Function(GitError) then) =
-      _$GitErrorCopyWithImpl<$Res, GitError>;
+abstract class $CalendarChangeCopyWith<$Res> {
+  factory $CalendarChangeCopyWith(CalendarChange value, $Res Function(CalendarChange) then) =
+      _$CalendarChangeCopyWithImpl<$Res, CalendarChange>;
   @useResult
-  $Res call({String field0});
+  $Res call({EventDto field0});
 }
 
 /// @nodoc
-class _$GitErrorCopyWithImpl<$Res, $Val extends GitError>
-    implements $GitErrorCopyWith<$Res> {
-  _$GitErrorCopyWithImpl(this._value, this._then);
+class _$CalendarChangeCopyWithImpl<$Res, $Val extends CalendarChange>
+    implements $CalendarChangeCopyWith<$Res> {
+  _$CalendarChangeCopyWithImpl(this._value, this._then);
 
   // ignore: unused_field
   final $Val _value;
   // ignore: unused_field
   final $Res Function($Val) _then;
 
-  /// Create a copy of GitError
+  /// Create a copy of CalendarChange
   /// with the given fields replaced by the non-null parameter values.
   @pragma('vm:prefer-inline')
   @override
@@ -98,7 +92,7 @@ class _$GitErrorCopyWithImpl<$Res, $Val extends GitError>
             field0: null == field0
                 ? _value.field0
                 : field0 // ignore: cast_nullable_to_non_nullable
-                      as String,
+                      as EventDto,
           )
           as $Val,
     );
@@ -106,37 +100,37 @@ class _$GitErrorCopyWithImpl<$Res, $Val extends GitError>
 }
 
 /// @nodoc
-abstract class _$$GitError_IoImplCopyWith<$Res>
-    implements $GitErrorCopyWith<$Res> {
-  factory _$$GitError_IoImplCopyWith(
-    _$GitError_IoImpl value,
-    $Res Function(_$GitError_IoImpl) then,
-  ) = __$$GitError_IoImplCopyWithImpl<$Res>;
+abstract class _$$CalendarChange_EventAddedImplCopyWith<$Res>
+    implements $CalendarChangeCopyWith<$Res> {
+  factory _$$CalendarChange_EventAddedImplCopyWith(
+    _$CalendarChange_EventAddedImpl value,
+    $Res Function(_$CalendarChange_EventAddedImpl) then,
+  ) = __$$CalendarChange_EventAddedImplCopyWithImpl<$Res>;
   @override
   @useResult
-  $Res call({String field0});
+  $Res call({EventDto field0});
 }
 
 /// @nodoc
-class __$$GitError_IoImplCopyWithImpl<$Res>
-    extends _$GitErrorCopyWithImpl<$Res, _$GitError_IoImpl>
-    implements _$$GitError_IoImplCopyWith<$Res> {
-  __$$GitError_IoImplCopyWithImpl(
-    _$GitError_IoImpl _value,
-    $Res Function(_$GitError_IoImpl) _then,
+class __$$CalendarChange_EventAddedImplCopyWithImpl<$Res>
+    extends _$CalendarChangeCopyWithImpl<$Res, _$CalendarChange_EventAddedImpl>
+    implements _$$CalendarChange_EventAddedImplCopyWith<$Res> {
+  __$$CalendarChange_EventAddedImplCopyWithImpl(
+    _$CalendarChange_EventAddedImpl _value,
+    $Res Function(_$CalendarChange_EventAddedImpl) _then,
   ) : super(_value, _then);
 
-  /// Create a copy of GitError
+  /// Create a copy of CalendarChange
   /// with the given fields replaced by the non-null parameter values.
   @pragma('vm:prefer-inline')
   @override
   $Res call({Object? field0 = null}) {
     return _then(
-      _$GitError_IoImpl(
+      _$CalendarChange_EventAddedImpl(
         null == field0
             ? _value.field0
             : field0 // ignore: cast_nullable_to_non_nullable
-                  as String,
+                  as EventDto,
       ),
     );
   }
@@ -144,69 +138,69 @@ class __$$GitError_IoImplCopyWithImpl<$Res>
 
 /// @nodoc
 
-class _$GitError_IoImpl extends GitError_Io {
-  const _$GitError_IoImpl(this.field0) : super._();
+class _$CalendarChange_EventAddedImpl extends CalendarChange_EventAdded {
+  const _$CalendarChange_EventAddedImpl(this.field0) : super._();
 
   @override
-  final String field0;
+  final EventDto field0;
 
   @override
   String toString() {
-    return 'GitError.io(field0: $field0)';
+    return 'CalendarChange.eventAdded(field0: $field0)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$GitError_IoImpl &&
+            other is _$CalendarChange_EventAddedImpl &&
             (identical(other.field0, field0) || other.field0 == field0));
   }
 
   @override
   int get hashCode => Object.hash(runtimeType, field0);
 
-  /// Create a copy of GitError
+  /// Create a copy of CalendarChange
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
   @override
   @pragma('vm:prefer-inline')
-  _$$GitError_IoImplCopyWith<_$GitError_IoImpl> get copyWith =>
-      __$$GitError_IoImplCopyWithImpl<_$GitError_IoImpl>(this, _$identity);
+  _$$CalendarChange_EventAddedImplCopyWith<_$CalendarChange_EventAddedImpl> get copyWith =>
+      __$$CalendarChange_EventAddedImplCopyWithImpl<_$CalendarChange_EventAddedImpl>(
+        this,
+        _$identity,
+      );
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(String field0) io,
-    required TResult Function(String field0) git,
-    required TResult Function(String field0) auth,
-    required TResult Function(String field0) other,
+    required TResult Function(EventDto field0) eventAdded,
+    required TResult Function(EventDto field0) eventChanged,
+    required TResult Function(EventDto field0) eventRemoved,
   }) {
-    return io(field0);
+    return eventAdded(field0);
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(String field0)? io,
-    TResult? Function(String field0)? git,
-    TResult? Function(String field0)? auth,
-    TResult? Function(String field0)? other,
+    TResult? Function(EventDto field0)? eventAdded,
+    TResult? Function(EventDto field0)? eventChanged,
+    TResult? Function(EventDto field0)? eventRemoved,
   }) {
-    return io?.call(field0);
+    return eventAdded?.call(field0);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(String field0)? io,
-    TResult Function(String field0)? git,
-    TResult Function(String field0)? auth,
-    TResult Function(String field0)? other,
+    TResult Function(EventDto field0)? eventAdded,
+    TResult Function(EventDto field0)? eventChanged,
+    TResult Function(EventDto field0)? eventRemoved,
     required TResult orElse(),
   }) {
-    if (io != null) {
-      return io(field0);
+    if (eventAdded != null) {
+      return eventAdded(field0);
     }
     return orElse();
   }
@@ -214,88 +208,85 @@ class _$GitError_IoImpl extends GitError_Io {
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(GitError_Io value) io,
-    required TResult Function(GitError_Git value) git,
-    required TResult Function(GitError_Auth value) auth,
-    required TResult Function(GitError_Other value) other,
+    required TResult Function(CalendarChange_EventAdded value) eventAdded,
+    required TResult Function(CalendarChange_EventChanged value) eventChanged,
+    required TResult Function(CalendarChange_EventRemoved value) eventRemoved,
   }) {
-    return io(this);
+    return eventAdded(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(GitError_Io value)? io,
-    TResult? Function(GitError_Git value)? git,
-    TResult? Function(GitError_Auth value)? auth,
-    TResult? Function(GitError_Other value)? other,
+    TResult? Function(CalendarChange_EventAdded value)? eventAdded,
+    TResult? Function(CalendarChange_EventChanged value)? eventChanged,
+    TResult? Function(CalendarChange_EventRemoved value)? eventRemoved,
   }) {
-    return io?.call(this);
+    return eventAdded?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(GitError_Io value)? io,
-    TResult Function(GitError_Git value)? git,
-    TResult Function(GitError_Auth value)? auth,
-    TResult Function(GitError_Other value)? other,
+    TResult Function(CalendarChange_EventAdded value)? eventAdded,
+    TResult Function(CalendarChange_EventChanged value)? eventChanged,
+    TResult Function(CalendarChange_EventRemoved value)? eventRemoved,
     required TResult orElse(),
   }) {
-    if (io != null) {
-      return io(this);
+    if (eventAdded != null) {
+      return eventAdded(this);
     }
     return orElse();
   }
 }
 
-abstract class GitError_Io extends GitError {
-  const factory GitError_Io(final String field0) = _$GitError_IoImpl;
-  const GitError_Io._() : super._();
+abstract class CalendarChange_EventAdded extends CalendarChange {
+  const factory CalendarChange_EventAdded(final EventDto field0) = _$CalendarChange_EventAddedImpl;
+  const CalendarChange_EventAdded._() : super._();
 
   @override
-  String get field0;
+  EventDto get field0;
 
-  /// Create a copy of GitError
+  /// Create a copy of CalendarChange
   /// with the given fields replaced by the non-null parameter values.
   @override
   @JsonKey(includeFromJson: false, includeToJson: false)
-  _$$GitError_IoImplCopyWith<_$GitError_IoImpl> get copyWith =>
+  _$$CalendarChange_EventAddedImplCopyWith<_$CalendarChange_EventAddedImpl> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$GitError_GitImplCopyWith<$Res>
-    implements $GitErrorCopyWith<$Res> {
-  factory _$$GitError_GitImplCopyWith(
-    _$GitError_GitImpl value,
-    $Res Function(_$GitError_GitImpl) then,
-  ) = __$$GitError_GitImplCopyWithImpl<$Res>;
+abstract class _$$CalendarChange_EventChangedImplCopyWith<$Res>
+    implements $CalendarChangeCopyWith<$Res> {
+  factory _$$CalendarChange_EventChangedImplCopyWith(
+    _$CalendarChange_EventChangedImpl value,
+    $Res Function(_$CalendarChange_EventChangedImpl) then,
+  ) = __$$CalendarChange_EventChangedImplCopyWithImpl<$Res>;
   @override
   @useResult
-  $Res call({String field0});
+  $Res call({EventDto field0});
 }
 
 /// @nodoc
-class __$$GitError_GitImplCopyWithImpl<$Res>
-    extends _$GitErrorCopyWithImpl<$Res, _$GitError_GitImpl>
-    implements _$$GitError_GitImplCopyWith<$Res> {
-  __$$GitError_GitImplCopyWithImpl(
-    _$GitError_GitImpl _value,
-    $Res Function(_$GitError_GitImpl) _then,
+class __$$CalendarChange_EventChangedImplCopyWithImpl<$Res>
+    extends _$CalendarChangeCopyWithImpl<$Res, _$CalendarChange_EventChangedImpl>
+    implements _$$CalendarChange_EventChangedImplCopyWith<$Res> {
+  __$$CalendarChange_EventChangedImplCopyWithImpl(
+    _$CalendarChange_EventChangedImpl _value,
+    $Res Function(_$CalendarChange_EventChangedImpl) _then,
   ) : super(_value, _then);
 
-  /// Create a copy of GitError
+  /// Create a copy of CalendarChange
   /// with the given fields replaced by the non-null parameter values.
   @pragma('vm:prefer-inline')
   @override
   $Res call({Object? field0 = null}) {
     return _then(
-      _$GitError_GitImpl(
+      _$CalendarChange_EventChangedImpl(
         null == field0
             ? _value.field0
             : field0 // ignore: cast_nullable_to_non_nullable
-                  as String,
+                  as EventDto,
       ),
     );
   }
@@ -303,69 +294,69 @@ class __$$GitError_GitImplCopyWithImpl<$Res>
 
 /// @nodoc
 
-class _$GitError_GitImpl extends GitError_Git {
-  const _$GitError_GitImpl(this.field0) : super._();
+class _$CalendarChange_EventChangedImpl extends CalendarChange_EventChanged {
+  const _$CalendarChange_EventChangedImpl(this.field0) : super._();
 
   @override
-  final String field0;
+  final EventDto field0;
 
   @override
   String toString() {
-    return 'GitError.git(field0: $field0)';
+    return 'CalendarChange.eventChanged(field0: $field0)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$GitError_GitImpl &&
+            other is _$CalendarChange_EventChangedImpl &&
             (identical(other.field0, field0) || other.field0 == field0));
   }
 
   @override
   int get hashCode => Object.hash(runtimeType, field0);
 
-  /// Create a copy of GitError
+  /// Create a copy of CalendarChange
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
   @override
   @pragma('vm:prefer-inline')
-  _$$GitError_GitImplCopyWith<_$GitError_GitImpl> get copyWith =>
-      __$$GitError_GitImplCopyWithImpl<_$GitError_GitImpl>(this, _$identity);
+  _$$CalendarChange_EventChangedImplCopyWith<_$CalendarChange_EventChangedImpl> get copyWith =>
+      __$$CalendarChange_EventChangedImplCopyWithImpl<_$CalendarChange_EventChangedImpl>(
+        this,
+        _$identity,
+      );
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(String field0) io,
-    required TResult Function(String field0) git,
-    required TResult Function(String field0) auth,
-    required TResult Function(String field0) other,
+    required TResult Function(EventDto field0) eventAdded,
+    required TResult Function(EventDto field0) eventChanged,
+    required TResult Function(EventDto field0) eventRemoved,
   }) {
-    return git(field0);
+    return eventChanged(field0);
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(String field0)? io,
-    TResult? Function(String field0)? git,
-    TResult? Function(String field0)? auth,
-    TResult? Function(String field0)? other,
+    TResult? Function(EventDto field0)? eventAdded,
+    TResult? Function(EventDto field0)? eventChanged,
+    TResult? Function(EventDto field0)? eventRemoved,
   }) {
-    return git?.call(field0);
+    return eventChanged?.call(field0);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(String field0)? io,
-    TResult Function(String field0)? git,
-    TResult Function(String field0)? auth,
-    TResult Function(String field0)? other,
+    TResult Function(EventDto field0)? eventAdded,
+    TResult Function(EventDto field0)? eventChanged,
+    TResult Function(EventDto field0)? eventRemoved,
     required TResult orElse(),
   }) {
-    if (git != null) {
-      return git(field0);
+    if (eventChanged != null) {
+      return eventChanged(field0);
     }
     return orElse();
   }
@@ -373,88 +364,85 @@ class _$GitError_GitImpl extends GitError_Git {
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(GitError_Io value) io,
-    required TResult Function(GitError_Git value) git,
-    required TResult Function(GitError_Auth value) auth,
-    required TResult Function(GitError_Other value) other,
+    required TResult Function(CalendarChange_EventAdded value) eventAdded,
+    required TResult Function(CalendarChange_EventChanged value) eventChanged,
+    required TResult Function(CalendarChange_EventRemoved value) eventRemoved,
   }) {
-    return git(this);
+    return eventChanged(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(GitError_Io value)? io,
-    TResult? Function(GitError_Git value)? git,
-    TResult? Function(GitError_Auth value)? auth,
-    TResult? Function(GitError_Other value)? other,
+    TResult? Function(CalendarChange_EventAdded value)? eventAdded,
+    TResult? Function(CalendarChange_EventChanged value)? eventChanged,
+    TResult? Function(CalendarChange_EventRemoved value)? eventRemoved,
   }) {
-    return git?.call(this);
+    return eventChanged?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(GitError_Io value)? io,
-    TResult Function(GitError_Git value)? git,
-    TResult Function(GitError_Auth value)? auth,
-    TResult Function(GitError_Other value)? other,
+    TResult Function(CalendarChange_EventAdded value)? eventAdded,
+    TResult Function(CalendarChange_EventChanged value)? eventChanged,
+    TResult Function(CalendarChange_EventRemoved value)? eventRemoved,
     required TResult orElse(),
   }) {
-    if (git != null) {
-      return git(this);
+    if (eventChanged != null) {
+      return eventChanged(this);
     }
     return orElse();
   }
 }
 
-abstract class GitError_Git extends GitError {
-  const factory GitError_Git(final String field0) = _$GitError_GitImpl;
-  const GitError_Git._() : super._();
+abstract class CalendarChange_EventChanged extends CalendarChange {
+  const factory CalendarChange_EventChanged(final EventDto field0) = _$CalendarChange_EventChangedImpl;
+  const CalendarChange_EventChanged._() : super._();
 
   @override
-  String get field0;
+  EventDto get field0;
 
-  /// Create a copy of GitError
+  /// Create a copy of CalendarChange
   /// with the given fields replaced by the non-null parameter values.
   @override
   @JsonKey(includeFromJson: false, includeToJson: false)
-  _$$GitError_GitImplCopyWith<_$GitError_GitImpl> get copyWith =>
+  _$$CalendarChange_EventChangedImplCopyWith<_$CalendarChange_EventChangedImpl> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$GitError_AuthImplCopyWith<$Res>
-    implements $GitErrorCopyWith<$Res> {
-  factory _$$GitError_AuthImplCopyWith(
-    _$GitError_AuthImpl value,
-    $Res Function(_$GitError_AuthImpl) then,
-  ) = __$$GitError_AuthImplCopyWithImpl<$Res>;
+abstract class _$$CalendarChange_EventRemovedImplCopyWith<$Res>
+    implements $CalendarChangeCopyWith<$Res> {
+  factory _$$CalendarChange_EventRemovedImplCopyWith(
+    _$CalendarChange_EventRemovedImpl value,
+    $Res Function(_$CalendarChange_EventRemovedImpl) then,
+  ) = __$$CalendarChange_EventRemovedImplCopyWithImpl<$Res>;
   @override
   @useResult
-  $Res call({String field0});
+  $Res call({EventDto field0});
 }
 
 /// @nodoc
-class __$$GitError_AuthImplCopyWithImpl<$Res>
-    extends _$GitErrorCopyWithImpl<$Res, _$GitError_AuthImpl>
-    implements _$$GitError_AuthImplCopyWith<$Res> {
-  __$$GitError_AuthImplCopyWithImpl(
-    _$GitError_AuthImpl _value,
-    $Res Function(_$GitError_AuthImpl) _then,
+class __$$CalendarChange_EventRemovedImplCopyWithImpl<$Res>
+    extends _$CalendarChangeCopyWithImpl<$Res, _$CalendarChange_EventRemovedImpl>
+    implements _$$CalendarChange_EventRemovedImplCopyWith<$Res> {
+  __$$CalendarChange_EventRemovedImplCopyWithImpl(
+    _$CalendarChange_EventRemovedImpl _value,
+    $Res Function(_$CalendarChange_EventRemovedImpl) _then,
   ) : super(_value, _then);
 
-  /// Create a copy of GitError
+  /// Create a copy of CalendarChange
   /// with the given fields replaced by the non-null parameter values.
   @pragma('vm:prefer-inline')
   @override
   $Res call({Object? field0 = null}) {
     return _then(
-      _$GitError_AuthImpl(
+      _$CalendarChange_EventRemovedImpl(
         null == field0
             ? _value.field0
             : field0 // ignore: cast_nullable_to_non_nullable
-                  as String,
+                  as EventDto,
       ),
     );
   }
@@ -462,69 +450,69 @@ class __$$GitError_AuthImplCopyWithImpl<$Res>
 
 /// @nodoc
 
-class _$GitError_AuthImpl extends GitError_Auth {
-  const _$GitError_AuthImpl(this.field0) : super._();
+class _$CalendarChange_EventRemovedImpl extends CalendarChange_EventRemoved {
+  const _$CalendarChange_EventRemovedImpl(this.field0) : super._();
 
   @override
-  final String field0;
+  final EventDto field0;
 
   @override
   String toString() {
-    return 'GitError.auth(field0: $field0)';
+    return 'CalendarChange.eventRemoved(field0: $field0)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$GitError_AuthImpl &&
+            other is _$CalendarChange_EventRemovedImpl &&
             (identical(other.field0, field0) || other.field0 == field0));
   }
 
   @override
   int get hashCode => Object.hash(runtimeType, field0);
 
-  /// Create a copy of GitError
+  /// Create a copy of CalendarChange
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
   @override
   @pragma('vm:prefer-inline')
-  _$$GitError_AuthImplCopyWith<_$GitError_AuthImpl> get copyWith =>
-      __$$GitError_AuthImplCopyWithImpl<_$GitError_AuthImpl>(this, _$identity);
+  _$$CalendarChange_EventRemovedImplCopyWith<_$CalendarChange_EventRemovedImpl> get copyWith =>
+      __$$CalendarChange_EventRemovedImplCopyWithImpl<_$CalendarChange_EventRemovedImpl>(
+        this,
+        _$identity,
+      );
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(String field0) io,
-    required TResult Function(String field0) git,
-    required TResult Function(String field0) auth,
-    required TResult Function(String field0) other,
+    required TResult Function(EventDto field0) eventAdded,
+    required TResult Function(EventDto field0) eventChanged,
+    required TResult Function(EventDto field0) eventRemoved,
   }) {
-    return auth(field0);
+    return eventRemoved(field0);
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(String field0)? io,
-    TResult? Function(String field0)? git,
-    TResult? Function(String field0)? auth,
-    TResult? Function(String field0)? other,
+    TResult? Function(EventDto field0)? eventAdded,
+    TResult? Function(EventDto field0)? eventChanged,
+    TResult? Function(EventDto field0)? eventRemoved,
   }) {
-    return auth?.call(field0);
+    return eventRemoved?.call(field0);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(String field0)? io,
-    TResult Function(String field0)? git,
-    TResult Function(String field0)? auth,
-    TResult Function(String field0)? other,
+    TResult Function(EventDto field0)? eventAdded,
+    TResult Function(EventDto field0)? eventChanged,
+    TResult Function(EventDto field0)? eventRemoved,
     required TResult orElse(),
   }) {
-    if (auth != null) {
-      return auth(field0);
+    if (eventRemoved != null) {
+      return eventRemoved(field0);
     }
     return orElse();
   }
@@ -532,161 +520,9554 @@ class _$GitError_AuthImpl extends GitError_Auth {
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(GitError_Io value) io,
-    required TResult Function(GitError_Git value) git,
-    required TResult Function(GitError_Auth value) auth,
-    required TResult Function(GitError_Other value) other,
+    required TResult Function(CalendarChange_EventAdded value) eventAdded,
+    required TResult Function(CalendarChange_EventChanged value) eventChanged,
+    required TResult Function(CalendarChange_EventRemoved value) eventRemoved,
   }) {
-    return auth(this);
+    return eventRemoved(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(GitError_Io value)? io,
-    TResult? Function(GitError_Git value)? git,
-    TResult? Function(GitError_Auth value)? auth,
-    TResult? Function(GitError_Other value)? other,
+    TResult? Function(CalendarChange_EventAdded value)? eventAdded,
+    TResult? Function(CalendarChange_EventChanged value)? eventChanged,
+    TResult? Function(CalendarChange_EventRemoved value)? eventRemoved,
   }) {
-    return auth?.call(this);
+    return eventRemoved?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(GitError_Io value)? io,
-    TResult Function(GitError_Git value)? git,
-    TResult Function(GitError_Auth value)? auth,
-    TResult Function(GitError_Other value)? other,
+    TResult Function(CalendarChange_EventAdded value)? eventAdded,
+    TResult Function(CalendarChange_EventChanged value)? eventChanged,
+    TResult Function(CalendarChange_EventRemoved value)? eventRemoved,
     required TResult orElse(),
   }) {
-    if (auth != null) {
-      return auth(this);
+    if (eventRemoved != null) {
+      return eventRemoved(this);
     }
     return orElse();
   }
 }
 
-abstract class GitError_Auth extends GitError {
-  const factory GitError_Auth(final String field0) = _$GitError_AuthImpl;
-  const GitError_Auth._() : super._();
+abstract class CalendarChange_EventRemoved extends CalendarChange {
+  const factory CalendarChange_EventRemoved(final EventDto field0) = _$CalendarChange_EventRemovedImpl;
+  const CalendarChange_EventRemoved._() : super._();
 
   @override
-  String get field0;
+  EventDto get field0;
 
-  /// Create a copy of GitError
+  /// Create a copy of CalendarChange
   /// with the given fields replaced by the non-null parameter values.
   @override
   @JsonKey(includeFromJson: false, includeToJson: false)
-  _$$GitError_AuthImplCopyWith<_$GitError_AuthImpl> get copyWith =>
+  _$$CalendarChange_EventRemovedImplCopyWith<_$CalendarChange_EventRemovedImpl> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$GitError_OtherImplCopyWith<$Res>
-    implements $GitErrorCopyWith<$Res> {
-  factory _$$GitError_OtherImplCopyWith(
-    _$GitError_OtherImpl value,
-    $Res Function(_$GitError_OtherImpl) then,
-  ) = __$$GitError_OtherImplCopyWithImpl<$Res>;
-  @override
-  @useResult
-  $Res call({String field0});
+mixin _$CertificateError {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() expired,
+    required TResult Function() notYetValid,
+    required TResult Function() unknownIssuer,
+    required TResult Function() nameMismatch,
+    required TResult Function() pinMismatch,
+    required TResult Function() invalidSignature,
+    required TResult Function(String field0) malformed,
+    required TResult Function(String field0) other,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? expired,
+    TResult? Function()? notYetValid,
+    TResult? Function()? unknownIssuer,
+    TResult? Function()? nameMismatch,
+    TResult? Function()? pinMismatch,
+    TResult? Function()? invalidSignature,
+    TResult? Function(String field0)? malformed,
+    TResult? Function(String field0)? other,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? expired,
+    TResult Function()? notYetValid,
+    TResult Function()? unknownIssuer,
+    TResult Function()? nameMismatch,
+    TResult Function()? pinMismatch,
+    TResult Function()? invalidSignature,
+    TResult Function(String field0)? malformed,
+    TResult Function(String field0)? other,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(CertificateError_Expired value) expired,
+    required TResult Function(CertificateError_NotYetValid value) notYetValid,
+    required TResult Function(CertificateError_UnknownIssuer value) unknownIssuer,
+    required TResult Function(CertificateError_NameMismatch value) nameMismatch,
+    required TResult Function(CertificateError_PinMismatch value) pinMismatch,
+    required TResult Function(CertificateError_InvalidSignature value) invalidSignature,
+    required TResult Function(CertificateError_Malformed value) malformed,
+    required TResult Function(CertificateError_Other value) other,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(CertificateError_Expired value)? expired,
+    TResult? Function(CertificateError_NotYetValid value)? notYetValid,
+    TResult? Function(CertificateError_UnknownIssuer value)? unknownIssuer,
+    TResult? Function(CertificateError_NameMismatch value)? nameMismatch,
+    TResult? Function(CertificateError_PinMismatch value)? pinMismatch,
+    TResult? Function(CertificateError_InvalidSignature value)? invalidSignature,
+    TResult? Function(CertificateError_Malformed value)? malformed,
+    TResult? Function(CertificateError_Other value)? other,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(CertificateError_Expired value)? expired,
+    TResult Function(CertificateError_NotYetValid value)? notYetValid,
+    TResult Function(CertificateError_UnknownIssuer value)? unknownIssuer,
+    TResult Function(CertificateError_NameMismatch value)? nameMismatch,
+    TResult Function(CertificateError_PinMismatch value)? pinMismatch,
+    TResult Function(CertificateError_InvalidSignature value)? invalidSignature,
+    TResult Function(CertificateError_Malformed value)? malformed,
+    TResult Function(CertificateError_Other value)? other,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-class __$$GitError_OtherImplCopyWithImpl<$Res>
-    extends _$GitErrorCopyWithImpl<$Res, _$GitError_OtherImpl>
-    implements _$$GitError_OtherImplCopyWith<$Res> {
-  __$$GitError_OtherImplCopyWithImpl(
-    _$GitError_OtherImpl _value,
-    $Res Function(_$GitError_OtherImpl) _then,
-  ) : super(_value, _then);
+abstract class $CertificateErrorCopyWith<$Res> {
+  factory $CertificateErrorCopyWith(CertificateError value, $Res Function(CertificateError) then) =
+      _$CertificateErrorCopyWithImpl<$Res, CertificateError>;
+}
 
-  /// Create a copy of GitError
+/// @nodoc
+class _$CertificateErrorCopyWithImpl<$Res, $Val extends CertificateError>
+    implements $CertificateErrorCopyWith<$Res> {
+  _$CertificateErrorCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of CertificateError
   /// with the given fields replaced by the non-null parameter values.
-  @pragma('vm:prefer-inline')
-  @override
-  $Res call({Object? field0 = null}) {
-    return _then(
-      _$GitError_OtherImpl(
-        null == field0
-            ? _value.field0
-            : field0 // ignore: cast_nullable_to_non_nullable
-                  as String,
-      ),
-    );
-  }
 }
 
 /// @nodoc
+abstract class _$$CertificateError_ExpiredImplCopyWith<$Res> {
+  factory _$$CertificateError_ExpiredImplCopyWith(
+    _$CertificateError_ExpiredImpl value,
+    $Res Function(_$CertificateError_ExpiredImpl) then,
+  ) = __$$CertificateError_ExpiredImplCopyWithImpl<$Res>;
+}
 
-class _$GitError_OtherImpl extends GitError_Other {
-  const _$GitError_OtherImpl(this.field0) : super._();
+/// @nodoc
+class __$$CertificateError_ExpiredImplCopyWithImpl<$Res>
+    extends _$CertificateErrorCopyWithImpl<$Res, _$CertificateError_ExpiredImpl>
+    implements _$$CertificateError_ExpiredImplCopyWith<$Res> {
+  __$$CertificateError_ExpiredImplCopyWithImpl(
+    _$CertificateError_ExpiredImpl _value,
+    $Res Function(_$CertificateError_ExpiredImpl) _then,
+  ) : super(_value, _then);
 
-  @override
-  final String field0;
+  /// Create a copy of CertificateError
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$CertificateError_ExpiredImpl extends CertificateError_Expired {
+  const _$CertificateError_ExpiredImpl() : super._();
 
   @override
   String toString() {
-    return 'GitError.other(field0: $field0)';
+    return 'CertificateError.expired()';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
-        (other.runtimeType == runtimeType &&
-            other is _$GitError_OtherImpl &&
-            (identical(other.field0, field0) || other.field0 == field0));
+        (other.runtimeType == runtimeType && other is _$CertificateError_ExpiredImpl);
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, field0);
+  int get hashCode => runtimeType.hashCode;
 
-  /// Create a copy of GitError
-  /// with the given fields replaced by the non-null parameter values.
-  @JsonKey(includeFromJson: false, includeToJson: false)
   @override
-  @pragma('vm:prefer-inline')
-  _$$GitError_OtherImplCopyWith<_$GitError_OtherImpl> get copyWith =>
-      __$$GitError_OtherImplCopyWithImpl<_$GitError_OtherImpl>(
-        this,
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() expired,
+    required TResult Function() notYetValid,
+    required TResult Function() unknownIssuer,
+    required TResult Function() nameMismatch,
+    required TResult Function() pinMismatch,
+    required TResult Function() invalidSignature,
+    required TResult Function(String field0) malformed,
+    required TResult Function(String field0) other,
+  }) {
+    return expired();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? expired,
+    TResult? Function()? notYetValid,
+    TResult? Function()? unknownIssuer,
+    TResult? Function()? nameMismatch,
+    TResult? Function()? pinMismatch,
+    TResult? Function()? invalidSignature,
+    TResult? Function(String field0)? malformed,
+    TResult? Function(String field0)? other,
+  }) {
+    return expired?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? expired,
+    TResult Function()? notYetValid,
+    TResult Function()? unknownIssuer,
+    TResult Function()? nameMismatch,
+    TResult Function()? pinMismatch,
+    TResult Function()? invalidSignature,
+    TResult Function(String field0)? malformed,
+    TResult Function(String field0)? other,
+    required TResult orElse(),
+  }) {
+    if (expired != null) {
+      return expired();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(CertificateError_Expired value) expired,
+    required TResult Function(CertificateError_NotYetValid value) notYetValid,
+    required TResult Function(CertificateError_UnknownIssuer value) unknownIssuer,
+    required TResult Function(CertificateError_NameMismatch value) nameMismatch,
+    required TResult Function(CertificateError_PinMismatch value) pinMismatch,
+    required TResult Function(CertificateError_InvalidSignature value) invalidSignature,
+    required TResult Function(CertificateError_Malformed value) malformed,
+    required TResult Function(CertificateError_Other value) other,
+  }) {
+    return expired(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(CertificateError_Expired value)? expired,
+    TResult? Function(CertificateError_NotYetValid value)? notYetValid,
+    TResult? Function(CertificateError_UnknownIssuer value)? unknownIssuer,
+    TResult? Function(CertificateError_NameMismatch value)? nameMismatch,
+    TResult? Function(CertificateError_PinMismatch value)? pinMismatch,
+    TResult? Function(CertificateError_InvalidSignature value)? invalidSignature,
+    TResult? Function(CertificateError_Malformed value)? malformed,
+    TResult? Function(CertificateError_Other value)? other,
+  }) {
+    return expired?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(CertificateError_Expired value)? expired,
+    TResult Function(CertificateError_NotYetValid value)? notYetValid,
+    TResult Function(CertificateError_UnknownIssuer value)? unknownIssuer,
+    TResult Function(CertificateError_NameMismatch value)? nameMismatch,
+    TResult Function(CertificateError_PinMismatch value)? pinMismatch,
+    TResult Function(CertificateError_InvalidSignature value)? invalidSignature,
+    TResult Function(CertificateError_Malformed value)? malformed,
+    TResult Function(CertificateError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (expired != null) {
+      return expired(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class CertificateError_Expired extends CertificateError {
+  const factory CertificateError_Expired() = _$CertificateError_ExpiredImpl;
+  const CertificateError_Expired._() : super._();
+}
+
+/// @nodoc
+abstract class _$$CertificateError_NotYetValidImplCopyWith<$Res> {
+  factory _$$CertificateError_NotYetValidImplCopyWith(
+    _$CertificateError_NotYetValidImpl value,
+    $Res Function(_$CertificateError_NotYetValidImpl) then,
+  ) = __$$CertificateError_NotYetValidImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$CertificateError_NotYetValidImplCopyWithImpl<$Res>
+    extends _$CertificateErrorCopyWithImpl<$Res, _$CertificateError_NotYetValidImpl>
+    implements _$$CertificateError_NotYetValidImplCopyWith<$Res> {
+  __$$CertificateError_NotYetValidImplCopyWithImpl(
+    _$CertificateError_NotYetValidImpl _value,
+    $Res Function(_$CertificateError_NotYetValidImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of CertificateError
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$CertificateError_NotYetValidImpl extends CertificateError_NotYetValid {
+  const _$CertificateError_NotYetValidImpl() : super._();
+
+  @override
+  String toString() {
+    return 'CertificateError.notYetValid()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType && other is _$CertificateError_NotYetValidImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() expired,
+    required TResult Function() notYetValid,
+    required TResult Function() unknownIssuer,
+    required TResult Function() nameMismatch,
+    required TResult Function() pinMismatch,
+    required TResult Function() invalidSignature,
+    required TResult Function(String field0) malformed,
+    required TResult Function(String field0) other,
+  }) {
+    return notYetValid();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? expired,
+    TResult? Function()? notYetValid,
+    TResult? Function()? unknownIssuer,
+    TResult? Function()? nameMismatch,
+    TResult? Function()? pinMismatch,
+    TResult? Function()? invalidSignature,
+    TResult? Function(String field0)? malformed,
+    TResult? Function(String field0)? other,
+  }) {
+    return notYetValid?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? expired,
+    TResult Function()? notYetValid,
+    TResult Function()? unknownIssuer,
+    TResult Function()? nameMismatch,
+    TResult Function()? pinMismatch,
+    TResult Function()? invalidSignature,
+    TResult Function(String field0)? malformed,
+    TResult Function(String field0)? other,
+    required TResult orElse(),
+  }) {
+    if (notYetValid != null) {
+      return notYetValid();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(CertificateError_Expired value) expired,
+    required TResult Function(CertificateError_NotYetValid value) notYetValid,
+    required TResult Function(CertificateError_UnknownIssuer value) unknownIssuer,
+    required TResult Function(CertificateError_NameMismatch value) nameMismatch,
+    required TResult Function(CertificateError_PinMismatch value) pinMismatch,
+    required TResult Function(CertificateError_InvalidSignature value) invalidSignature,
+    required TResult Function(CertificateError_Malformed value) malformed,
+    required TResult Function(CertificateError_Other value) other,
+  }) {
+    return notYetValid(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(CertificateError_Expired value)? expired,
+    TResult? Function(CertificateError_NotYetValid value)? notYetValid,
+    TResult? Function(CertificateError_UnknownIssuer value)? unknownIssuer,
+    TResult? Function(CertificateError_NameMismatch value)? nameMismatch,
+    TResult? Function(CertificateError_PinMismatch value)? pinMismatch,
+    TResult? Function(CertificateError_InvalidSignature value)? invalidSignature,
+    TResult? Function(CertificateError_Malformed value)? malformed,
+    TResult? Function(CertificateError_Other value)? other,
+  }) {
+    return notYetValid?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(CertificateError_Expired value)? expired,
+    TResult Function(CertificateError_NotYetValid value)? notYetValid,
+    TResult Function(CertificateError_UnknownIssuer value)? unknownIssuer,
+    TResult Function(CertificateError_NameMismatch value)? nameMismatch,
+    TResult Function(CertificateError_PinMismatch value)? pinMismatch,
+    TResult Function(CertificateError_InvalidSignature value)? invalidSignature,
+    TResult Function(CertificateError_Malformed value)? malformed,
+    TResult Function(CertificateError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (notYetValid != null) {
+      return notYetValid(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class CertificateError_NotYetValid extends CertificateError {
+  const factory CertificateError_NotYetValid() = _$CertificateError_NotYetValidImpl;
+  const CertificateError_NotYetValid._() : super._();
+}
+
+/// @nodoc
+abstract class _$$CertificateError_UnknownIssuerImplCopyWith<$Res> {
+  factory _$$CertificateError_UnknownIssuerImplCopyWith(
+    _$CertificateError_UnknownIssuerImpl value,
+    $Res Function(_$CertificateError_UnknownIssuerImpl) then,
+  ) = __$$CertificateError_UnknownIssuerImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$CertificateError_UnknownIssuerImplCopyWithImpl<$Res>
+    extends _$CertificateErrorCopyWithImpl<$Res, _$CertificateError_UnknownIssuerImpl>
+    implements _$$CertificateError_UnknownIssuerImplCopyWith<$Res> {
+  __$$CertificateError_UnknownIssuerImplCopyWithImpl(
+    _$CertificateError_UnknownIssuerImpl _value,
+    $Res Function(_$CertificateError_UnknownIssuerImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of CertificateError
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$CertificateError_UnknownIssuerImpl extends CertificateError_UnknownIssuer {
+  const _$CertificateError_UnknownIssuerImpl() : super._();
+
+  @override
+  String toString() {
+    return 'CertificateError.unknownIssuer()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType && other is _$CertificateError_UnknownIssuerImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() expired,
+    required TResult Function() notYetValid,
+    required TResult Function() unknownIssuer,
+    required TResult Function() nameMismatch,
+    required TResult Function() pinMismatch,
+    required TResult Function() invalidSignature,
+    required TResult Function(String field0) malformed,
+    required TResult Function(String field0) other,
+  }) {
+    return unknownIssuer();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? expired,
+    TResult? Function()? notYetValid,
+    TResult? Function()? unknownIssuer,
+    TResult? Function()? nameMismatch,
+    TResult? Function()? pinMismatch,
+    TResult? Function()? invalidSignature,
+    TResult? Function(String field0)? malformed,
+    TResult? Function(String field0)? other,
+  }) {
+    return unknownIssuer?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? expired,
+    TResult Function()? notYetValid,
+    TResult Function()? unknownIssuer,
+    TResult Function()? nameMismatch,
+    TResult Function()? pinMismatch,
+    TResult Function()? invalidSignature,
+    TResult Function(String field0)? malformed,
+    TResult Function(String field0)? other,
+    required TResult orElse(),
+  }) {
+    if (unknownIssuer != null) {
+      return unknownIssuer();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(CertificateError_Expired value) expired,
+    required TResult Function(CertificateError_NotYetValid value) notYetValid,
+    required TResult Function(CertificateError_UnknownIssuer value) unknownIssuer,
+    required TResult Function(CertificateError_NameMismatch value) nameMismatch,
+    required TResult Function(CertificateError_PinMismatch value) pinMismatch,
+    required TResult Function(CertificateError_InvalidSignature value) invalidSignature,
+    required TResult Function(CertificateError_Malformed value) malformed,
+    required TResult Function(CertificateError_Other value) other,
+  }) {
+    return unknownIssuer(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(CertificateError_Expired value)? expired,
+    TResult? Function(CertificateError_NotYetValid value)? notYetValid,
+    TResult? Function(CertificateError_UnknownIssuer value)? unknownIssuer,
+    TResult? Function(CertificateError_NameMismatch value)? nameMismatch,
+    TResult? Function(CertificateError_PinMismatch value)? pinMismatch,
+    TResult? Function(CertificateError_InvalidSignature value)? invalidSignature,
+    TResult? Function(CertificateError_Malformed value)? malformed,
+    TResult? Function(CertificateError_Other value)? other,
+  }) {
+    return unknownIssuer?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(CertificateError_Expired value)? expired,
+    TResult Function(CertificateError_NotYetValid value)? notYetValid,
+    TResult Function(CertificateError_UnknownIssuer value)? unknownIssuer,
+    TResult Function(CertificateError_NameMismatch value)? nameMismatch,
+    TResult Function(CertificateError_PinMismatch value)? pinMismatch,
+    TResult Function(CertificateError_InvalidSignature value)? invalidSignature,
+    TResult Function(CertificateError_Malformed value)? malformed,
+    TResult Function(CertificateError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (unknownIssuer != null) {
+      return unknownIssuer(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class CertificateError_UnknownIssuer extends CertificateError {
+  const factory CertificateError_UnknownIssuer() = _$CertificateError_UnknownIssuerImpl;
+  const CertificateError_UnknownIssuer._() : super._();
+}
+
+/// @nodoc
+abstract class _$$CertificateError_NameMismatchImplCopyWith<$Res> {
+  factory _$$CertificateError_NameMismatchImplCopyWith(
+    _$CertificateError_NameMismatchImpl value,
+    $Res Function(_$CertificateError_NameMismatchImpl) then,
+  ) = __$$CertificateError_NameMismatchImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$CertificateError_NameMismatchImplCopyWithImpl<$Res>
+    extends _$CertificateErrorCopyWithImpl<$Res, _$CertificateError_NameMismatchImpl>
+    implements _$$CertificateError_NameMismatchImplCopyWith<$Res> {
+  __$$CertificateError_NameMismatchImplCopyWithImpl(
+    _$CertificateError_NameMismatchImpl _value,
+    $Res Function(_$CertificateError_NameMismatchImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of CertificateError
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$CertificateError_NameMismatchImpl extends CertificateError_NameMismatch {
+  const _$CertificateError_NameMismatchImpl() : super._();
+
+  @override
+  String toString() {
+    return 'CertificateError.nameMismatch()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType && other is _$CertificateError_NameMismatchImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() expired,
+    required TResult Function() notYetValid,
+    required TResult Function() unknownIssuer,
+    required TResult Function() nameMismatch,
+    required TResult Function() pinMismatch,
+    required TResult Function() invalidSignature,
+    required TResult Function(String field0) malformed,
+    required TResult Function(String field0) other,
+  }) {
+    return nameMismatch();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? expired,
+    TResult? Function()? notYetValid,
+    TResult? Function()? unknownIssuer,
+    TResult? Function()? nameMismatch,
+    TResult? Function()? pinMismatch,
+    TResult? Function()? invalidSignature,
+    TResult? Function(String field0)? malformed,
+    TResult? Function(String field0)? other,
+  }) {
+    return nameMismatch?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? expired,
+    TResult Function()? notYetValid,
+    TResult Function()? unknownIssuer,
+    TResult Function()? nameMismatch,
+    TResult Function()? pinMismatch,
+    TResult Function()? invalidSignature,
+    TResult Function(String field0)? malformed,
+    TResult Function(String field0)? other,
+    required TResult orElse(),
+  }) {
+    if (nameMismatch != null) {
+      return nameMismatch();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(CertificateError_Expired value) expired,
+    required TResult Function(CertificateError_NotYetValid value) notYetValid,
+    required TResult Function(CertificateError_UnknownIssuer value) unknownIssuer,
+    required TResult Function(CertificateError_NameMismatch value) nameMismatch,
+    required TResult Function(CertificateError_PinMismatch value) pinMismatch,
+    required TResult Function(CertificateError_InvalidSignature value) invalidSignature,
+    required TResult Function(CertificateError_Malformed value) malformed,
+    required TResult Function(CertificateError_Other value) other,
+  }) {
+    return nameMismatch(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(CertificateError_Expired value)? expired,
+    TResult? Function(CertificateError_NotYetValid value)? notYetValid,
+    TResult? Function(CertificateError_UnknownIssuer value)? unknownIssuer,
+    TResult? Function(CertificateError_NameMismatch value)? nameMismatch,
+    TResult? Function(CertificateError_PinMismatch value)? pinMismatch,
+    TResult? Function(CertificateError_InvalidSignature value)? invalidSignature,
+    TResult? Function(CertificateError_Malformed value)? malformed,
+    TResult? Function(CertificateError_Other value)? other,
+  }) {
+    return nameMismatch?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(CertificateError_Expired value)? expired,
+    TResult Function(CertificateError_NotYetValid value)? notYetValid,
+    TResult Function(CertificateError_UnknownIssuer value)? unknownIssuer,
+    TResult Function(CertificateError_NameMismatch value)? nameMismatch,
+    TResult Function(CertificateError_PinMismatch value)? pinMismatch,
+    TResult Function(CertificateError_InvalidSignature value)? invalidSignature,
+    TResult Function(CertificateError_Malformed value)? malformed,
+    TResult Function(CertificateError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (nameMismatch != null) {
+      return nameMismatch(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class CertificateError_NameMismatch extends CertificateError {
+  const factory CertificateError_NameMismatch() = _$CertificateError_NameMismatchImpl;
+  const CertificateError_NameMismatch._() : super._();
+}
+
+/// @nodoc
+abstract class _$$CertificateError_PinMismatchImplCopyWith<$Res> {
+  factory _$$CertificateError_PinMismatchImplCopyWith(
+    _$CertificateError_PinMismatchImpl value,
+    $Res Function(_$CertificateError_PinMismatchImpl) then,
+  ) = __$$CertificateError_PinMismatchImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$CertificateError_PinMismatchImplCopyWithImpl<$Res>
+    extends _$CertificateErrorCopyWithImpl<$Res, _$CertificateError_PinMismatchImpl>
+    implements _$$CertificateError_PinMismatchImplCopyWith<$Res> {
+  __$$CertificateError_PinMismatchImplCopyWithImpl(
+    _$CertificateError_PinMismatchImpl _value,
+    $Res Function(_$CertificateError_PinMismatchImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of CertificateError
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$CertificateError_PinMismatchImpl extends CertificateError_PinMismatch {
+  const _$CertificateError_PinMismatchImpl() : super._();
+
+  @override
+  String toString() {
+    return 'CertificateError.pinMismatch()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType && other is _$CertificateError_PinMismatchImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() expired,
+    required TResult Function() notYetValid,
+    required TResult Function() unknownIssuer,
+    required TResult Function() nameMismatch,
+    required TResult Function() pinMismatch,
+    required TResult Function() invalidSignature,
+    required TResult Function(String field0) malformed,
+    required TResult Function(String field0) other,
+  }) {
+    return pinMismatch();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? expired,
+    TResult? Function()? notYetValid,
+    TResult? Function()? unknownIssuer,
+    TResult? Function()? nameMismatch,
+    TResult? Function()? pinMismatch,
+    TResult? Function()? invalidSignature,
+    TResult? Function(String field0)? malformed,
+    TResult? Function(String field0)? other,
+  }) {
+    return pinMismatch?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? expired,
+    TResult Function()? notYetValid,
+    TResult Function()? unknownIssuer,
+    TResult Function()? nameMismatch,
+    TResult Function()? pinMismatch,
+    TResult Function()? invalidSignature,
+    TResult Function(String field0)? malformed,
+    TResult Function(String field0)? other,
+    required TResult orElse(),
+  }) {
+    if (pinMismatch != null) {
+      return pinMismatch();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(CertificateError_Expired value) expired,
+    required TResult Function(CertificateError_NotYetValid value) notYetValid,
+    required TResult Function(CertificateError_UnknownIssuer value) unknownIssuer,
+    required TResult Function(CertificateError_NameMismatch value) nameMismatch,
+    required TResult Function(CertificateError_PinMismatch value) pinMismatch,
+    required TResult Function(CertificateError_InvalidSignature value) invalidSignature,
+    required TResult Function(CertificateError_Malformed value) malformed,
+    required TResult Function(CertificateError_Other value) other,
+  }) {
+    return pinMismatch(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(CertificateError_Expired value)? expired,
+    TResult? Function(CertificateError_NotYetValid value)? notYetValid,
+    TResult? Function(CertificateError_UnknownIssuer value)? unknownIssuer,
+    TResult? Function(CertificateError_NameMismatch value)? nameMismatch,
+    TResult? Function(CertificateError_PinMismatch value)? pinMismatch,
+    TResult? Function(CertificateError_InvalidSignature value)? invalidSignature,
+    TResult? Function(CertificateError_Malformed value)? malformed,
+    TResult? Function(CertificateError_Other value)? other,
+  }) {
+    return pinMismatch?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(CertificateError_Expired value)? expired,
+    TResult Function(CertificateError_NotYetValid value)? notYetValid,
+    TResult Function(CertificateError_UnknownIssuer value)? unknownIssuer,
+    TResult Function(CertificateError_NameMismatch value)? nameMismatch,
+    TResult Function(CertificateError_PinMismatch value)? pinMismatch,
+    TResult Function(CertificateError_InvalidSignature value)? invalidSignature,
+    TResult Function(CertificateError_Malformed value)? malformed,
+    TResult Function(CertificateError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (pinMismatch != null) {
+      return pinMismatch(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class CertificateError_PinMismatch extends CertificateError {
+  const factory CertificateError_PinMismatch() = _$CertificateError_PinMismatchImpl;
+  const CertificateError_PinMismatch._() : super._();
+}
+
+/// @nodoc
+abstract class _$$CertificateError_InvalidSignatureImplCopyWith<$Res> {
+  factory _$$CertificateError_InvalidSignatureImplCopyWith(
+    _$CertificateError_InvalidSignatureImpl value,
+    $Res Function(_$CertificateError_InvalidSignatureImpl) then,
+  ) = __$$CertificateError_InvalidSignatureImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$CertificateError_InvalidSignatureImplCopyWithImpl<$Res>
+    extends _$CertificateErrorCopyWithImpl<$Res, _$CertificateError_InvalidSignatureImpl>
+    implements _$$CertificateError_InvalidSignatureImplCopyWith<$Res> {
+  __$$CertificateError_InvalidSignatureImplCopyWithImpl(
+    _$CertificateError_InvalidSignatureImpl _value,
+    $Res Function(_$CertificateError_InvalidSignatureImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of CertificateError
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$CertificateError_InvalidSignatureImpl extends CertificateError_InvalidSignature {
+  const _$CertificateError_InvalidSignatureImpl() : super._();
+
+  @override
+  String toString() {
+    return 'CertificateError.invalidSignature()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType && other is _$CertificateError_InvalidSignatureImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() expired,
+    required TResult Function() notYetValid,
+    required TResult Function() unknownIssuer,
+    required TResult Function() nameMismatch,
+    required TResult Function() pinMismatch,
+    required TResult Function() invalidSignature,
+    required TResult Function(String field0) malformed,
+    required TResult Function(String field0) other,
+  }) {
+    return invalidSignature();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? expired,
+    TResult? Function()? notYetValid,
+    TResult? Function()? unknownIssuer,
+    TResult? Function()? nameMismatch,
+    TResult? Function()? pinMismatch,
+    TResult? Function()? invalidSignature,
+    TResult? Function(String field0)? malformed,
+    TResult? Function(String field0)? other,
+  }) {
+    return invalidSignature?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? expired,
+    TResult Function()? notYetValid,
+    TResult Function()? unknownIssuer,
+    TResult Function()? nameMismatch,
+    TResult Function()? pinMismatch,
+    TResult Function()? invalidSignature,
+    TResult Function(String field0)? malformed,
+    TResult Function(String field0)? other,
+    required TResult orElse(),
+  }) {
+    if (invalidSignature != null) {
+      return invalidSignature();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(CertificateError_Expired value) expired,
+    required TResult Function(CertificateError_NotYetValid value) notYetValid,
+    required TResult Function(CertificateError_UnknownIssuer value) unknownIssuer,
+    required TResult Function(CertificateError_NameMismatch value) nameMismatch,
+    required TResult Function(CertificateError_PinMismatch value) pinMismatch,
+    required TResult Function(CertificateError_InvalidSignature value) invalidSignature,
+    required TResult Function(CertificateError_Malformed value) malformed,
+    required TResult Function(CertificateError_Other value) other,
+  }) {
+    return invalidSignature(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(CertificateError_Expired value)? expired,
+    TResult? Function(CertificateError_NotYetValid value)? notYetValid,
+    TResult? Function(CertificateError_UnknownIssuer value)? unknownIssuer,
+    TResult? Function(CertificateError_NameMismatch value)? nameMismatch,
+    TResult? Function(CertificateError_PinMismatch value)? pinMismatch,
+    TResult? Function(CertificateError_InvalidSignature value)? invalidSignature,
+    TResult? Function(CertificateError_Malformed value)? malformed,
+    TResult? Function(CertificateError_Other value)? other,
+  }) {
+    return invalidSignature?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(CertificateError_Expired value)? expired,
+    TResult Function(CertificateError_NotYetValid value)? notYetValid,
+    TResult Function(CertificateError_UnknownIssuer value)? unknownIssuer,
+    TResult Function(CertificateError_NameMismatch value)? nameMismatch,
+    TResult Function(CertificateError_PinMismatch value)? pinMismatch,
+    TResult Function(CertificateError_InvalidSignature value)? invalidSignature,
+    TResult Function(CertificateError_Malformed value)? malformed,
+    TResult Function(CertificateError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (invalidSignature != null) {
+      return invalidSignature(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class CertificateError_InvalidSignature extends CertificateError {
+  const factory CertificateError_InvalidSignature() = _$CertificateError_InvalidSignatureImpl;
+  const CertificateError_InvalidSignature._() : super._();
+}
+
+/// @nodoc
+abstract class _$$CertificateError_MalformedImplCopyWith<$Res> {
+  factory _$$CertificateError_MalformedImplCopyWith(
+    _$CertificateError_MalformedImpl value,
+    $Res Function(_$CertificateError_MalformedImpl) then,
+  ) = __$$CertificateError_MalformedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class __$$CertificateError_MalformedImplCopyWithImpl<$Res>
+    extends _$CertificateErrorCopyWithImpl<$Res, _$CertificateError_MalformedImpl>
+    implements _$$CertificateError_MalformedImplCopyWith<$Res> {
+  __$$CertificateError_MalformedImplCopyWithImpl(
+    _$CertificateError_MalformedImpl _value,
+    $Res Function(_$CertificateError_MalformedImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of CertificateError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field0 = null}) {
+    return _then(
+      _$CertificateError_MalformedImpl(
+        null == field0
+            ? _value.field0
+            : field0 // ignore: cast_nullable_to_non_nullable
+                  as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$CertificateError_MalformedImpl extends CertificateError_Malformed {
+  const _$CertificateError_MalformedImpl(this.field0) : super._();
+
+  @override
+  final String field0;
+
+  @override
+  String toString() {
+    return 'CertificateError.malformed(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$CertificateError_MalformedImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of CertificateError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$CertificateError_MalformedImplCopyWith<_$CertificateError_MalformedImpl> get copyWith =>
+      __$$CertificateError_MalformedImplCopyWithImpl<_$CertificateError_MalformedImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() expired,
+    required TResult Function() notYetValid,
+    required TResult Function() unknownIssuer,
+    required TResult Function() nameMismatch,
+    required TResult Function() pinMismatch,
+    required TResult Function() invalidSignature,
+    required TResult Function(String field0) malformed,
+    required TResult Function(String field0) other,
+  }) {
+    return malformed(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? expired,
+    TResult? Function()? notYetValid,
+    TResult? Function()? unknownIssuer,
+    TResult? Function()? nameMismatch,
+    TResult? Function()? pinMismatch,
+    TResult? Function()? invalidSignature,
+    TResult? Function(String field0)? malformed,
+    TResult? Function(String field0)? other,
+  }) {
+    return malformed?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? expired,
+    TResult Function()? notYetValid,
+    TResult Function()? unknownIssuer,
+    TResult Function()? nameMismatch,
+    TResult Function()? pinMismatch,
+    TResult Function()? invalidSignature,
+    TResult Function(String field0)? malformed,
+    TResult Function(String field0)? other,
+    required TResult orElse(),
+  }) {
+    if (malformed != null) {
+      return malformed(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(CertificateError_Expired value) expired,
+    required TResult Function(CertificateError_NotYetValid value) notYetValid,
+    required TResult Function(CertificateError_UnknownIssuer value) unknownIssuer,
+    required TResult Function(CertificateError_NameMismatch value) nameMismatch,
+    required TResult Function(CertificateError_PinMismatch value) pinMismatch,
+    required TResult Function(CertificateError_InvalidSignature value) invalidSignature,
+    required TResult Function(CertificateError_Malformed value) malformed,
+    required TResult Function(CertificateError_Other value) other,
+  }) {
+    return malformed(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(CertificateError_Expired value)? expired,
+    TResult? Function(CertificateError_NotYetValid value)? notYetValid,
+    TResult? Function(CertificateError_UnknownIssuer value)? unknownIssuer,
+    TResult? Function(CertificateError_NameMismatch value)? nameMismatch,
+    TResult? Function(CertificateError_PinMismatch value)? pinMismatch,
+    TResult? Function(CertificateError_InvalidSignature value)? invalidSignature,
+    TResult? Function(CertificateError_Malformed value)? malformed,
+    TResult? Function(CertificateError_Other value)? other,
+  }) {
+    return malformed?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(CertificateError_Expired value)? expired,
+    TResult Function(CertificateError_NotYetValid value)? notYetValid,
+    TResult Function(CertificateError_UnknownIssuer value)? unknownIssuer,
+    TResult Function(CertificateError_NameMismatch value)? nameMismatch,
+    TResult Function(CertificateError_PinMismatch value)? pinMismatch,
+    TResult Function(CertificateError_InvalidSignature value)? invalidSignature,
+    TResult Function(CertificateError_Malformed value)? malformed,
+    TResult Function(CertificateError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (malformed != null) {
+      return malformed(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class CertificateError_Malformed extends CertificateError {
+  const factory CertificateError_Malformed(final String field0) = _$CertificateError_MalformedImpl;
+  const CertificateError_Malformed._() : super._();
+
+  String get field0;
+
+  /// Create a copy of CertificateError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$CertificateError_MalformedImplCopyWith<_$CertificateError_MalformedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$CertificateError_OtherImplCopyWith<$Res> {
+  factory _$$CertificateError_OtherImplCopyWith(
+    _$CertificateError_OtherImpl value,
+    $Res Function(_$CertificateError_OtherImpl) then,
+  ) = __$$CertificateError_OtherImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class __$$CertificateError_OtherImplCopyWithImpl<$Res>
+    extends _$CertificateErrorCopyWithImpl<$Res, _$CertificateError_OtherImpl>
+    implements _$$CertificateError_OtherImplCopyWith<$Res> {
+  __$$CertificateError_OtherImplCopyWithImpl(
+    _$CertificateError_OtherImpl _value,
+    $Res Function(_$CertificateError_OtherImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of CertificateError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field0 = null}) {
+    return _then(
+      _$CertificateError_OtherImpl(
+        null == field0
+            ? _value.field0
+            : field0 // ignore: cast_nullable_to_non_nullable
+                  as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$CertificateError_OtherImpl extends CertificateError_Other {
+  const _$CertificateError_OtherImpl(this.field0) : super._();
+
+  @override
+  final String field0;
+
+  @override
+  String toString() {
+    return 'CertificateError.other(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$CertificateError_OtherImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of CertificateError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$CertificateError_OtherImplCopyWith<_$CertificateError_OtherImpl> get copyWith =>
+      __$$CertificateError_OtherImplCopyWithImpl<_$CertificateError_OtherImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() expired,
+    required TResult Function() notYetValid,
+    required TResult Function() unknownIssuer,
+    required TResult Function() nameMismatch,
+    required TResult Function() pinMismatch,
+    required TResult Function() invalidSignature,
+    required TResult Function(String field0) malformed,
+    required TResult Function(String field0) other,
+  }) {
+    return other(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? expired,
+    TResult? Function()? notYetValid,
+    TResult? Function()? unknownIssuer,
+    TResult? Function()? nameMismatch,
+    TResult? Function()? pinMismatch,
+    TResult? Function()? invalidSignature,
+    TResult? Function(String field0)? malformed,
+    TResult? Function(String field0)? other,
+  }) {
+    return other?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? expired,
+    TResult Function()? notYetValid,
+    TResult Function()? unknownIssuer,
+    TResult Function()? nameMismatch,
+    TResult Function()? pinMismatch,
+    TResult Function()? invalidSignature,
+    TResult Function(String field0)? malformed,
+    TResult Function(String field0)? other,
+    required TResult orElse(),
+  }) {
+    if (other != null) {
+      return other(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(CertificateError_Expired value) expired,
+    required TResult Function(CertificateError_NotYetValid value) notYetValid,
+    required TResult Function(CertificateError_UnknownIssuer value) unknownIssuer,
+    required TResult Function(CertificateError_NameMismatch value) nameMismatch,
+    required TResult Function(CertificateError_PinMismatch value) pinMismatch,
+    required TResult Function(CertificateError_InvalidSignature value) invalidSignature,
+    required TResult Function(CertificateError_Malformed value) malformed,
+    required TResult Function(CertificateError_Other value) other,
+  }) {
+    return other(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(CertificateError_Expired value)? expired,
+    TResult? Function(CertificateError_NotYetValid value)? notYetValid,
+    TResult? Function(CertificateError_UnknownIssuer value)? unknownIssuer,
+    TResult? Function(CertificateError_NameMismatch value)? nameMismatch,
+    TResult? Function(CertificateError_PinMismatch value)? pinMismatch,
+    TResult? Function(CertificateError_InvalidSignature value)? invalidSignature,
+    TResult? Function(CertificateError_Malformed value)? malformed,
+    TResult? Function(CertificateError_Other value)? other,
+  }) {
+    return other?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(CertificateError_Expired value)? expired,
+    TResult Function(CertificateError_NotYetValid value)? notYetValid,
+    TResult Function(CertificateError_UnknownIssuer value)? unknownIssuer,
+    TResult Function(CertificateError_NameMismatch value)? nameMismatch,
+    TResult Function(CertificateError_PinMismatch value)? pinMismatch,
+    TResult Function(CertificateError_InvalidSignature value)? invalidSignature,
+    TResult Function(CertificateError_Malformed value)? malformed,
+    TResult Function(CertificateError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (other != null) {
+      return other(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class CertificateError_Other extends CertificateError {
+  const factory CertificateError_Other(final String field0) = _$CertificateError_OtherImpl;
+  const CertificateError_Other._() : super._();
+
+  String get field0;
+
+  /// Create a copy of CertificateError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$CertificateError_OtherImplCopyWith<_$CertificateError_OtherImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$EventError {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String field, String value) invalidDate,
+    required TResult Function(String field, String value) invalidTime,
+    required TResult Function(String field0) missingField,
+    required TResult Function(String field) invalidRange,
+    required TResult Function(String value, String reason) invalidRecurrence,
+    required TResult Function(String id, String date) noOccurrence,
+    required TResult Function(String field0) unknownTimeZone,
+    required TResult Function(String field0) titleInvalid,
+    required TResult Function(String field0) notFound,
+    required TResult Function(String field0) duplicateId,
+    required TResult Function(String field0) storage,
+    required TResult Function(String file, String message) parseFailure,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String field, String value)? invalidDate,
+    TResult? Function(String field, String value)? invalidTime,
+    TResult? Function(String field0)? missingField,
+    TResult? Function(String field)? invalidRange,
+    TResult? Function(String value, String reason)? invalidRecurrence,
+    TResult? Function(String id, String date)? noOccurrence,
+    TResult? Function(String field0)? unknownTimeZone,
+    TResult? Function(String field0)? titleInvalid,
+    TResult? Function(String field0)? notFound,
+    TResult? Function(String field0)? duplicateId,
+    TResult? Function(String field0)? storage,
+    TResult? Function(String file, String message)? parseFailure,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String field, String value)? invalidDate,
+    TResult Function(String field, String value)? invalidTime,
+    TResult Function(String field0)? missingField,
+    TResult Function(String field)? invalidRange,
+    TResult Function(String value, String reason)? invalidRecurrence,
+    TResult Function(String id, String date)? noOccurrence,
+    TResult Function(String field0)? unknownTimeZone,
+    TResult Function(String field0)? titleInvalid,
+    TResult Function(String field0)? notFound,
+    TResult Function(String field0)? duplicateId,
+    TResult Function(String field0)? storage,
+    TResult Function(String file, String message)? parseFailure,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(EventError_InvalidDate value) invalidDate,
+    required TResult Function(EventError_InvalidTime value) invalidTime,
+    required TResult Function(EventError_MissingField value) missingField,
+    required TResult Function(EventError_InvalidRange value) invalidRange,
+    required TResult Function(EventError_InvalidRecurrence value) invalidRecurrence,
+    required TResult Function(EventError_NoOccurrence value) noOccurrence,
+    required TResult Function(EventError_UnknownTimeZone value) unknownTimeZone,
+    required TResult Function(EventError_TitleInvalid value) titleInvalid,
+    required TResult Function(EventError_NotFound value) notFound,
+    required TResult Function(EventError_DuplicateId value) duplicateId,
+    required TResult Function(EventError_Storage value) storage,
+    required TResult Function(EventError_ParseFailure value) parseFailure,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(EventError_InvalidDate value)? invalidDate,
+    TResult? Function(EventError_InvalidTime value)? invalidTime,
+    TResult? Function(EventError_MissingField value)? missingField,
+    TResult? Function(EventError_InvalidRange value)? invalidRange,
+    TResult? Function(EventError_InvalidRecurrence value)? invalidRecurrence,
+    TResult? Function(EventError_NoOccurrence value)? noOccurrence,
+    TResult? Function(EventError_UnknownTimeZone value)? unknownTimeZone,
+    TResult? Function(EventError_TitleInvalid value)? titleInvalid,
+    TResult? Function(EventError_NotFound value)? notFound,
+    TResult? Function(EventError_DuplicateId value)? duplicateId,
+    TResult? Function(EventError_Storage value)? storage,
+    TResult? Function(EventError_ParseFailure value)? parseFailure,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(EventError_InvalidDate value)? invalidDate,
+    TResult Function(EventError_InvalidTime value)? invalidTime,
+    TResult Function(EventError_MissingField value)? missingField,
+    TResult Function(EventError_InvalidRange value)? invalidRange,
+    TResult Function(EventError_InvalidRecurrence value)? invalidRecurrence,
+    TResult Function(EventError_NoOccurrence value)? noOccurrence,
+    TResult Function(EventError_UnknownTimeZone value)? unknownTimeZone,
+    TResult Function(EventError_TitleInvalid value)? titleInvalid,
+    TResult Function(EventError_NotFound value)? notFound,
+    TResult Function(EventError_DuplicateId value)? duplicateId,
+    TResult Function(EventError_Storage value)? storage,
+    TResult Function(EventError_ParseFailure value)? parseFailure,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $EventErrorCopyWith<$Res> {
+  factory $EventErrorCopyWith(EventError value, $Res Function(EventError) then) =
+      _$EventErrorCopyWithImpl<$Res, EventError>;
+}
+
+/// @nodoc
+class _$EventErrorCopyWithImpl<$Res, $Val extends EventError>
+    implements $EventErrorCopyWith<$Res> {
+  _$EventErrorCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of EventError
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$EventError_InvalidDateImplCopyWith<$Res> {
+  factory _$$EventError_InvalidDateImplCopyWith(
+    _$EventError_InvalidDateImpl value,
+    $Res Function(_$EventError_InvalidDateImpl) then,
+  ) = __$$EventError_InvalidDateImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String field, String value});
+}
+
+/// @nodoc
+class __$$EventError_InvalidDateImplCopyWithImpl<$Res>
+    extends _$EventErrorCopyWithImpl<$Res, _$EventError_InvalidDateImpl>
+    implements _$$EventError_InvalidDateImplCopyWith<$Res> {
+  __$$EventError_InvalidDateImplCopyWithImpl(
+    _$EventError_InvalidDateImpl _value,
+    $Res Function(_$EventError_InvalidDateImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of EventError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field = null, Object? value = null}) {
+    return _then(
+      _$EventError_InvalidDateImpl(
+        field: null == field
+            ? _value.field
+            : field // ignore: cast_nullable_to_non_nullable
+                  as String,
+        value: null == value
+            ? _value.value
+            : value // ignore: cast_nullable_to_non_nullable
+                  as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$EventError_InvalidDateImpl extends EventError_InvalidDate {
+  const _$EventError_InvalidDateImpl({required this.field, required this.value}) : super._();
+
+  @override
+  final String field;
+  @override
+  final String value;
+
+  @override
+  String toString() {
+    return 'EventError.invalidDate(field: $field, value: $value)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$EventError_InvalidDateImpl &&
+            (identical(other.field, field) || other.field == field) &&
+            (identical(other.value, value) || other.value == value));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field, value);
+
+  /// Create a copy of EventError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$EventError_InvalidDateImplCopyWith<_$EventError_InvalidDateImpl> get copyWith =>
+      __$$EventError_InvalidDateImplCopyWithImpl<_$EventError_InvalidDateImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String field, String value) invalidDate,
+    required TResult Function(String field, String value) invalidTime,
+    required TResult Function(String field0) missingField,
+    required TResult Function(String field) invalidRange,
+    required TResult Function(String value, String reason) invalidRecurrence,
+    required TResult Function(String id, String date) noOccurrence,
+    required TResult Function(String field0) unknownTimeZone,
+    required TResult Function(String field0) titleInvalid,
+    required TResult Function(String field0) notFound,
+    required TResult Function(String field0) duplicateId,
+    required TResult Function(String field0) storage,
+    required TResult Function(String file, String message) parseFailure,
+  }) {
+    return invalidDate(field, value);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String field, String value)? invalidDate,
+    TResult? Function(String field, String value)? invalidTime,
+    TResult? Function(String field0)? missingField,
+    TResult? Function(String field)? invalidRange,
+    TResult? Function(String value, String reason)? invalidRecurrence,
+    TResult? Function(String id, String date)? noOccurrence,
+    TResult? Function(String field0)? unknownTimeZone,
+    TResult? Function(String field0)? titleInvalid,
+    TResult? Function(String field0)? notFound,
+    TResult? Function(String field0)? duplicateId,
+    TResult? Function(String field0)? storage,
+    TResult? Function(String file, String message)? parseFailure,
+  }) {
+    return invalidDate?.call(field, value);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String field, String value)? invalidDate,
+    TResult Function(String field, String value)? invalidTime,
+    TResult Function(String field0)? missingField,
+    TResult Function(String field)? invalidRange,
+    TResult Function(String value, String reason)? invalidRecurrence,
+    TResult Function(String id, String date)? noOccurrence,
+    TResult Function(String field0)? unknownTimeZone,
+    TResult Function(String field0)? titleInvalid,
+    TResult Function(String field0)? notFound,
+    TResult Function(String field0)? duplicateId,
+    TResult Function(String field0)? storage,
+    TResult Function(String file, String message)? parseFailure,
+    required TResult orElse(),
+  }) {
+    if (invalidDate != null) {
+      return invalidDate(field, value);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(EventError_InvalidDate value) invalidDate,
+    required TResult Function(EventError_InvalidTime value) invalidTime,
+    required TResult Function(EventError_MissingField value) missingField,
+    required TResult Function(EventError_InvalidRange value) invalidRange,
+    required TResult Function(EventError_InvalidRecurrence value) invalidRecurrence,
+    required TResult Function(EventError_NoOccurrence value) noOccurrence,
+    required TResult Function(EventError_UnknownTimeZone value) unknownTimeZone,
+    required TResult Function(EventError_TitleInvalid value) titleInvalid,
+    required TResult Function(EventError_NotFound value) notFound,
+    required TResult Function(EventError_DuplicateId value) duplicateId,
+    required TResult Function(EventError_Storage value) storage,
+    required TResult Function(EventError_ParseFailure value) parseFailure,
+  }) {
+    return invalidDate(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(EventError_InvalidDate value)? invalidDate,
+    TResult? Function(EventError_InvalidTime value)? invalidTime,
+    TResult? Function(EventError_MissingField value)? missingField,
+    TResult? Function(EventError_InvalidRange value)? invalidRange,
+    TResult? Function(EventError_InvalidRecurrence value)? invalidRecurrence,
+    TResult? Function(EventError_NoOccurrence value)? noOccurrence,
+    TResult? Function(EventError_UnknownTimeZone value)? unknownTimeZone,
+    TResult? Function(EventError_TitleInvalid value)? titleInvalid,
+    TResult? Function(EventError_NotFound value)? notFound,
+    TResult? Function(EventError_DuplicateId value)? duplicateId,
+    TResult? Function(EventError_Storage value)? storage,
+    TResult? Function(EventError_ParseFailure value)? parseFailure,
+  }) {
+    return invalidDate?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(EventError_InvalidDate value)? invalidDate,
+    TResult Function(EventError_InvalidTime value)? invalidTime,
+    TResult Function(EventError_MissingField value)? missingField,
+    TResult Function(EventError_InvalidRange value)? invalidRange,
+    TResult Function(EventError_InvalidRecurrence value)? invalidRecurrence,
+    TResult Function(EventError_NoOccurrence value)? noOccurrence,
+    TResult Function(EventError_UnknownTimeZone value)? unknownTimeZone,
+    TResult Function(EventError_TitleInvalid value)? titleInvalid,
+    TResult Function(EventError_NotFound value)? notFound,
+    TResult Function(EventError_DuplicateId value)? duplicateId,
+    TResult Function(EventError_Storage value)? storage,
+    TResult Function(EventError_ParseFailure value)? parseFailure,
+    required TResult orElse(),
+  }) {
+    if (invalidDate != null) {
+      return invalidDate(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class EventError_InvalidDate extends EventError {
+  const factory EventError_InvalidDate({
+    required final String field,
+    required final String value,
+  }) = _$EventError_InvalidDateImpl;
+  const EventError_InvalidDate._() : super._();
+
+  String get field;
+
+  String get value;
+
+  /// Create a copy of EventError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$EventError_InvalidDateImplCopyWith<_$EventError_InvalidDateImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$EventError_InvalidTimeImplCopyWith<$Res> {
+  factory _$$EventError_InvalidTimeImplCopyWith(
+    _$EventError_InvalidTimeImpl value,
+    $Res Function(_$EventError_InvalidTimeImpl) then,
+  ) = __$$EventError_InvalidTimeImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String field, String value});
+}
+
+/// @nodoc
+class __$$EventError_InvalidTimeImplCopyWithImpl<$Res>
+    extends _$EventErrorCopyWithImpl<$Res, _$EventError_InvalidTimeImpl>
+    implements _$$EventError_InvalidTimeImplCopyWith<$Res> {
+  __$$EventError_InvalidTimeImplCopyWithImpl(
+    _$EventError_InvalidTimeImpl _value,
+    $Res Function(_$EventError_InvalidTimeImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of EventError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field = null, Object? value = null}) {
+    return _then(
+      _$EventError_InvalidTimeImpl(
+        field: null == field
+            ? _value.field
+            : field // ignore: cast_nullable_to_non_nullable
+                  as String,
+        value: null == value
+            ? _value.value
+            : value // ignore: cast_nullable_to_non_nullable
+                  as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$EventError_InvalidTimeImpl extends EventError_InvalidTime {
+  const _$EventError_InvalidTimeImpl({required this.field, required this.value}) : super._();
+
+  @override
+  final String field;
+  @override
+  final String value;
+
+  @override
+  String toString() {
+    return 'EventError.invalidTime(field: $field, value: $value)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$EventError_InvalidTimeImpl &&
+            (identical(other.field, field) || other.field == field) &&
+            (identical(other.value, value) || other.value == value));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field, value);
+
+  /// Create a copy of EventError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$EventError_InvalidTimeImplCopyWith<_$EventError_InvalidTimeImpl> get copyWith =>
+      __$$EventError_InvalidTimeImplCopyWithImpl<_$EventError_InvalidTimeImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String field, String value) invalidDate,
+    required TResult Function(String field, String value) invalidTime,
+    required TResult Function(String field0) missingField,
+    required TResult Function(String field) invalidRange,
+    required TResult Function(String value, String reason) invalidRecurrence,
+    required TResult Function(String id, String date) noOccurrence,
+    required TResult Function(String field0) unknownTimeZone,
+    required TResult Function(String field0) titleInvalid,
+    required TResult Function(String field0) notFound,
+    required TResult Function(String field0) duplicateId,
+    required TResult Function(String field0) storage,
+    required TResult Function(String file, String message) parseFailure,
+  }) {
+    return invalidTime(field, value);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String field, String value)? invalidDate,
+    TResult? Function(String field, String value)? invalidTime,
+    TResult? Function(String field0)? missingField,
+    TResult? Function(String field)? invalidRange,
+    TResult? Function(String value, String reason)? invalidRecurrence,
+    TResult? Function(String id, String date)? noOccurrence,
+    TResult? Function(String field0)? unknownTimeZone,
+    TResult? Function(String field0)? titleInvalid,
+    TResult? Function(String field0)? notFound,
+    TResult? Function(String field0)? duplicateId,
+    TResult? Function(String field0)? storage,
+    TResult? Function(String file, String message)? parseFailure,
+  }) {
+    return invalidTime?.call(field, value);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String field, String value)? invalidDate,
+    TResult Function(String field, String value)? invalidTime,
+    TResult Function(String field0)? missingField,
+    TResult Function(String field)? invalidRange,
+    TResult Function(String value, String reason)? invalidRecurrence,
+    TResult Function(String id, String date)? noOccurrence,
+    TResult Function(String field0)? unknownTimeZone,
+    TResult Function(String field0)? titleInvalid,
+    TResult Function(String field0)? notFound,
+    TResult Function(String field0)? duplicateId,
+    TResult Function(String field0)? storage,
+    TResult Function(String file, String message)? parseFailure,
+    required TResult orElse(),
+  }) {
+    if (invalidTime != null) {
+      return invalidTime(field, value);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(EventError_InvalidDate value) invalidDate,
+    required TResult Function(EventError_InvalidTime value) invalidTime,
+    required TResult Function(EventError_MissingField value) missingField,
+    required TResult Function(EventError_InvalidRange value) invalidRange,
+    required TResult Function(EventError_InvalidRecurrence value) invalidRecurrence,
+    required TResult Function(EventError_NoOccurrence value) noOccurrence,
+    required TResult Function(EventError_UnknownTimeZone value) unknownTimeZone,
+    required TResult Function(EventError_TitleInvalid value) titleInvalid,
+    required TResult Function(EventError_NotFound value) notFound,
+    required TResult Function(EventError_DuplicateId value) duplicateId,
+    required TResult Function(EventError_Storage value) storage,
+    required TResult Function(EventError_ParseFailure value) parseFailure,
+  }) {
+    return invalidTime(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(EventError_InvalidDate value)? invalidDate,
+    TResult? Function(EventError_InvalidTime value)? invalidTime,
+    TResult? Function(EventError_MissingField value)? missingField,
+    TResult? Function(EventError_InvalidRange value)? invalidRange,
+    TResult? Function(EventError_InvalidRecurrence value)? invalidRecurrence,
+    TResult? Function(EventError_NoOccurrence value)? noOccurrence,
+    TResult? Function(EventError_UnknownTimeZone value)? unknownTimeZone,
+    TResult? Function(EventError_TitleInvalid value)? titleInvalid,
+    TResult? Function(EventError_NotFound value)? notFound,
+    TResult? Function(EventError_DuplicateId value)? duplicateId,
+    TResult? Function(EventError_Storage value)? storage,
+    TResult? Function(EventError_ParseFailure value)? parseFailure,
+  }) {
+    return invalidTime?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(EventError_InvalidDate value)? invalidDate,
+    TResult Function(EventError_InvalidTime value)? invalidTime,
+    TResult Function(EventError_MissingField value)? missingField,
+    TResult Function(EventError_InvalidRange value)? invalidRange,
+    TResult Function(EventError_InvalidRecurrence value)? invalidRecurrence,
+    TResult Function(EventError_NoOccurrence value)? noOccurrence,
+    TResult Function(EventError_UnknownTimeZone value)? unknownTimeZone,
+    TResult Function(EventError_TitleInvalid value)? titleInvalid,
+    TResult Function(EventError_NotFound value)? notFound,
+    TResult Function(EventError_DuplicateId value)? duplicateId,
+    TResult Function(EventError_Storage value)? storage,
+    TResult Function(EventError_ParseFailure value)? parseFailure,
+    required TResult orElse(),
+  }) {
+    if (invalidTime != null) {
+      return invalidTime(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class EventError_InvalidTime extends EventError {
+  const factory EventError_InvalidTime({
+    required final String field,
+    required final String value,
+  }) = _$EventError_InvalidTimeImpl;
+  const EventError_InvalidTime._() : super._();
+
+  String get field;
+
+  String get value;
+
+  /// Create a copy of EventError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$EventError_InvalidTimeImplCopyWith<_$EventError_InvalidTimeImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$EventError_MissingFieldImplCopyWith<$Res> {
+  factory _$$EventError_MissingFieldImplCopyWith(
+    _$EventError_MissingFieldImpl value,
+    $Res Function(_$EventError_MissingFieldImpl) then,
+  ) = __$$EventError_MissingFieldImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class __$$EventError_MissingFieldImplCopyWithImpl<$Res>
+    extends _$EventErrorCopyWithImpl<$Res, _$EventError_MissingFieldImpl>
+    implements _$$EventError_MissingFieldImplCopyWith<$Res> {
+  __$$EventError_MissingFieldImplCopyWithImpl(
+    _$EventError_MissingFieldImpl _value,
+    $Res Function(_$EventError_MissingFieldImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of EventError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field0 = null}) {
+    return _then(
+      _$EventError_MissingFieldImpl(
+        null == field0
+            ? _value.field0
+            : field0 // ignore: cast_nullable_to_non_nullable
+                  as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$EventError_MissingFieldImpl extends EventError_MissingField {
+  const _$EventError_MissingFieldImpl(this.field0) : super._();
+
+  @override
+  final String field0;
+
+  @override
+  String toString() {
+    return 'EventError.missingField(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$EventError_MissingFieldImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of EventError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$EventError_MissingFieldImplCopyWith<_$EventError_MissingFieldImpl> get copyWith =>
+      __$$EventError_MissingFieldImplCopyWithImpl<_$EventError_MissingFieldImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String field, String value) invalidDate,
+    required TResult Function(String field, String value) invalidTime,
+    required TResult Function(String field0) missingField,
+    required TResult Function(String field) invalidRange,
+    required TResult Function(String value, String reason) invalidRecurrence,
+    required TResult Function(String id, String date) noOccurrence,
+    required TResult Function(String field0) unknownTimeZone,
+    required TResult Function(String field0) titleInvalid,
+    required TResult Function(String field0) notFound,
+    required TResult Function(String field0) duplicateId,
+    required TResult Function(String field0) storage,
+    required TResult Function(String file, String message) parseFailure,
+  }) {
+    return missingField(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String field, String value)? invalidDate,
+    TResult? Function(String field, String value)? invalidTime,
+    TResult? Function(String field0)? missingField,
+    TResult? Function(String field)? invalidRange,
+    TResult? Function(String value, String reason)? invalidRecurrence,
+    TResult? Function(String id, String date)? noOccurrence,
+    TResult? Function(String field0)? unknownTimeZone,
+    TResult? Function(String field0)? titleInvalid,
+    TResult? Function(String field0)? notFound,
+    TResult? Function(String field0)? duplicateId,
+    TResult? Function(String field0)? storage,
+    TResult? Function(String file, String message)? parseFailure,
+  }) {
+    return missingField?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String field, String value)? invalidDate,
+    TResult Function(String field, String value)? invalidTime,
+    TResult Function(String field0)? missingField,
+    TResult Function(String field)? invalidRange,
+    TResult Function(String value, String reason)? invalidRecurrence,
+    TResult Function(String id, String date)? noOccurrence,
+    TResult Function(String field0)? unknownTimeZone,
+    TResult Function(String field0)? titleInvalid,
+    TResult Function(String field0)? notFound,
+    TResult Function(String field0)? duplicateId,
+    TResult Function(String field0)? storage,
+    TResult Function(String file, String message)? parseFailure,
+    required TResult orElse(),
+  }) {
+    if (missingField != null) {
+      return missingField(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(EventError_InvalidDate value) invalidDate,
+    required TResult Function(EventError_InvalidTime value) invalidTime,
+    required TResult Function(EventError_MissingField value) missingField,
+    required TResult Function(EventError_InvalidRange value) invalidRange,
+    required TResult Function(EventError_InvalidRecurrence value) invalidRecurrence,
+    required TResult Function(EventError_NoOccurrence value) noOccurrence,
+    required TResult Function(EventError_UnknownTimeZone value) unknownTimeZone,
+    required TResult Function(EventError_TitleInvalid value) titleInvalid,
+    required TResult Function(EventError_NotFound value) notFound,
+    required TResult Function(EventError_DuplicateId value) duplicateId,
+    required TResult Function(EventError_Storage value) storage,
+    required TResult Function(EventError_ParseFailure value) parseFailure,
+  }) {
+    return missingField(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(EventError_InvalidDate value)? invalidDate,
+    TResult? Function(EventError_InvalidTime value)? invalidTime,
+    TResult? Function(EventError_MissingField value)? missingField,
+    TResult? Function(EventError_InvalidRange value)? invalidRange,
+    TResult? Function(EventError_InvalidRecurrence value)? invalidRecurrence,
+    TResult? Function(EventError_NoOccurrence value)? noOccurrence,
+    TResult? Function(EventError_UnknownTimeZone value)? unknownTimeZone,
+    TResult? Function(EventError_TitleInvalid value)? titleInvalid,
+    TResult? Function(EventError_NotFound value)? notFound,
+    TResult? Function(EventError_DuplicateId value)? duplicateId,
+    TResult? Function(EventError_Storage value)? storage,
+    TResult? Function(EventError_ParseFailure value)? parseFailure,
+  }) {
+    return missingField?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(EventError_InvalidDate value)? invalidDate,
+    TResult Function(EventError_InvalidTime value)? invalidTime,
+    TResult Function(EventError_MissingField value)? missingField,
+    TResult Function(EventError_InvalidRange value)? invalidRange,
+    TResult Function(EventError_InvalidRecurrence value)? invalidRecurrence,
+    TResult Function(EventError_NoOccurrence value)? noOccurrence,
+    TResult Function(EventError_UnknownTimeZone value)? unknownTimeZone,
+    TResult Function(EventError_TitleInvalid value)? titleInvalid,
+    TResult Function(EventError_NotFound value)? notFound,
+    TResult Function(EventError_DuplicateId value)? duplicateId,
+    TResult Function(EventError_Storage value)? storage,
+    TResult Function(EventError_ParseFailure value)? parseFailure,
+    required TResult orElse(),
+  }) {
+    if (missingField != null) {
+      return missingField(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class EventError_MissingField extends EventError {
+  const factory EventError_MissingField(final String field0) = _$EventError_MissingFieldImpl;
+  const EventError_MissingField._() : super._();
+
+  String get field0;
+
+  /// Create a copy of EventError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$EventError_MissingFieldImplCopyWith<_$EventError_MissingFieldImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$EventError_InvalidRangeImplCopyWith<$Res> {
+  factory _$$EventError_InvalidRangeImplCopyWith(
+    _$EventError_InvalidRangeImpl value,
+    $Res Function(_$EventError_InvalidRangeImpl) then,
+  ) = __$$EventError_InvalidRangeImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String field});
+}
+
+/// @nodoc
+class __$$EventError_InvalidRangeImplCopyWithImpl<$Res>
+    extends _$EventErrorCopyWithImpl<$Res, _$EventError_InvalidRangeImpl>
+    implements _$$EventError_InvalidRangeImplCopyWith<$Res> {
+  __$$EventError_InvalidRangeImplCopyWithImpl(
+    _$EventError_InvalidRangeImpl _value,
+    $Res Function(_$EventError_InvalidRangeImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of EventError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field = null}) {
+    return _then(
+      _$EventError_InvalidRangeImpl(
+        field: null == field
+            ? _value.field
+            : field // ignore: cast_nullable_to_non_nullable
+                  as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$EventError_InvalidRangeImpl extends EventError_InvalidRange {
+  const _$EventError_InvalidRangeImpl({required this.field}) : super._();
+
+  @override
+  final String field;
+
+  @override
+  String toString() {
+    return 'EventError.invalidRange(field: $field)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$EventError_InvalidRangeImpl &&
+            (identical(other.field, field) || other.field == field));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field);
+
+  /// Create a copy of EventError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$EventError_InvalidRangeImplCopyWith<_$EventError_InvalidRangeImpl> get copyWith =>
+      __$$EventError_InvalidRangeImplCopyWithImpl<_$EventError_InvalidRangeImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String field, String value) invalidDate,
+    required TResult Function(String field, String value) invalidTime,
+    required TResult Function(String field0) missingField,
+    required TResult Function(String field) invalidRange,
+    required TResult Function(String value, String reason) invalidRecurrence,
+    required TResult Function(String id, String date) noOccurrence,
+    required TResult Function(String field0) unknownTimeZone,
+    required TResult Function(String field0) titleInvalid,
+    required TResult Function(String field0) notFound,
+    required TResult Function(String field0) duplicateId,
+    required TResult Function(String field0) storage,
+    required TResult Function(String file, String message) parseFailure,
+  }) {
+    return invalidRange(field);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String field, String value)? invalidDate,
+    TResult? Function(String field, String value)? invalidTime,
+    TResult? Function(String field0)? missingField,
+    TResult? Function(String field)? invalidRange,
+    TResult? Function(String value, String reason)? invalidRecurrence,
+    TResult? Function(String id, String date)? noOccurrence,
+    TResult? Function(String field0)? unknownTimeZone,
+    TResult? Function(String field0)? titleInvalid,
+    TResult? Function(String field0)? notFound,
+    TResult? Function(String field0)? duplicateId,
+    TResult? Function(String field0)? storage,
+    TResult? Function(String file, String message)? parseFailure,
+  }) {
+    return invalidRange?.call(field);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String field, String value)? invalidDate,
+    TResult Function(String field, String value)? invalidTime,
+    TResult Function(String field0)? missingField,
+    TResult Function(String field)? invalidRange,
+    TResult Function(String value, String reason)? invalidRecurrence,
+    TResult Function(String id, String date)? noOccurrence,
+    TResult Function(String field0)? unknownTimeZone,
+    TResult Function(String field0)? titleInvalid,
+    TResult Function(String field0)? notFound,
+    TResult Function(String field0)? duplicateId,
+    TResult Function(String field0)? storage,
+    TResult Function(String file, String message)? parseFailure,
+    required TResult orElse(),
+  }) {
+    if (invalidRange != null) {
+      return invalidRange(field);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(EventError_InvalidDate value) invalidDate,
+    required TResult Function(EventError_InvalidTime value) invalidTime,
+    required TResult Function(EventError_MissingField value) missingField,
+    required TResult Function(EventError_InvalidRange value) invalidRange,
+    required TResult Function(EventError_InvalidRecurrence value) invalidRecurrence,
+    required TResult Function(EventError_NoOccurrence value) noOccurrence,
+    required TResult Function(EventError_UnknownTimeZone value) unknownTimeZone,
+    required TResult Function(EventError_TitleInvalid value) titleInvalid,
+    required TResult Function(EventError_NotFound value) notFound,
+    required TResult Function(EventError_DuplicateId value) duplicateId,
+    required TResult Function(EventError_Storage value) storage,
+    required TResult Function(EventError_ParseFailure value) parseFailure,
+  }) {
+    return invalidRange(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(EventError_InvalidDate value)? invalidDate,
+    TResult? Function(EventError_InvalidTime value)? invalidTime,
+    TResult? Function(EventError_MissingField value)? missingField,
+    TResult? Function(EventError_InvalidRange value)? invalidRange,
+    TResult? Function(EventError_InvalidRecurrence value)? invalidRecurrence,
+    TResult? Function(EventError_NoOccurrence value)? noOccurrence,
+    TResult? Function(EventError_UnknownTimeZone value)? unknownTimeZone,
+    TResult? Function(EventError_TitleInvalid value)? titleInvalid,
+    TResult? Function(EventError_NotFound value)? notFound,
+    TResult? Function(EventError_DuplicateId value)? duplicateId,
+    TResult? Function(EventError_Storage value)? storage,
+    TResult? Function(EventError_ParseFailure value)? parseFailure,
+  }) {
+    return invalidRange?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(EventError_InvalidDate value)? invalidDate,
+    TResult Function(EventError_InvalidTime value)? invalidTime,
+    TResult Function(EventError_MissingField value)? missingField,
+    TResult Function(EventError_InvalidRange value)? invalidRange,
+    TResult Function(EventError_InvalidRecurrence value)? invalidRecurrence,
+    TResult Function(EventError_NoOccurrence value)? noOccurrence,
+    TResult Function(EventError_UnknownTimeZone value)? unknownTimeZone,
+    TResult Function(EventError_TitleInvalid value)? titleInvalid,
+    TResult Function(EventError_NotFound value)? notFound,
+    TResult Function(EventError_DuplicateId value)? duplicateId,
+    TResult Function(EventError_Storage value)? storage,
+    TResult Function(EventError_ParseFailure value)? parseFailure,
+    required TResult orElse(),
+  }) {
+    if (invalidRange != null) {
+      return invalidRange(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class EventError_InvalidRange extends EventError {
+  const factory EventError_InvalidRange({
+    required final String field,
+  }) = _$EventError_InvalidRangeImpl;
+  const EventError_InvalidRange._() : super._();
+
+  String get field;
+
+  /// Create a copy of EventError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$EventError_InvalidRangeImplCopyWith<_$EventError_InvalidRangeImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$EventError_InvalidRecurrenceImplCopyWith<$Res> {
+  factory _$$EventError_InvalidRecurrenceImplCopyWith(
+    _$EventError_InvalidRecurrenceImpl value,
+    $Res Function(_$EventError_InvalidRecurrenceImpl) then,
+  ) = __$$EventError_InvalidRecurrenceImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String value, String reason});
+}
+
+/// @nodoc
+class __$$EventError_InvalidRecurrenceImplCopyWithImpl<$Res>
+    extends _$EventErrorCopyWithImpl<$Res, _$EventError_InvalidRecurrenceImpl>
+    implements _$$EventError_InvalidRecurrenceImplCopyWith<$Res> {
+  __$$EventError_InvalidRecurrenceImplCopyWithImpl(
+    _$EventError_InvalidRecurrenceImpl _value,
+    $Res Function(_$EventError_InvalidRecurrenceImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of EventError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? value = null, Object? reason = null}) {
+    return _then(
+      _$EventError_InvalidRecurrenceImpl(
+        value: null == value
+            ? _value.value
+            : value // ignore: cast_nullable_to_non_nullable
+                  as String,
+        reason: null == reason
+            ? _value.reason
+            : reason // ignore: cast_nullable_to_non_nullable
+                  as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$EventError_InvalidRecurrenceImpl extends EventError_InvalidRecurrence {
+  const _$EventError_InvalidRecurrenceImpl({required this.value, required this.reason}) : super._();
+
+  @override
+  final String value;
+  @override
+  final String reason;
+
+  @override
+  String toString() {
+    return 'EventError.invalidRecurrence(value: $value, reason: $reason)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$EventError_InvalidRecurrenceImpl &&
+            (identical(other.value, value) || other.value == value) &&
+            (identical(other.reason, reason) || other.reason == reason));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, value, reason);
+
+  /// Create a copy of EventError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$EventError_InvalidRecurrenceImplCopyWith<_$EventError_InvalidRecurrenceImpl> get copyWith =>
+      __$$EventError_InvalidRecurrenceImplCopyWithImpl<_$EventError_InvalidRecurrenceImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String field, String value) invalidDate,
+    required TResult Function(String field, String value) invalidTime,
+    required TResult Function(String field0) missingField,
+    required TResult Function(String field) invalidRange,
+    required TResult Function(String value, String reason) invalidRecurrence,
+    required TResult Function(String id, String date) noOccurrence,
+    required TResult Function(String field0) unknownTimeZone,
+    required TResult Function(String field0) titleInvalid,
+    required TResult Function(String field0) notFound,
+    required TResult Function(String field0) duplicateId,
+    required TResult Function(String field0) storage,
+    required TResult Function(String file, String message) parseFailure,
+  }) {
+    return invalidRecurrence(value, reason);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String field, String value)? invalidDate,
+    TResult? Function(String field, String value)? invalidTime,
+    TResult? Function(String field0)? missingField,
+    TResult? Function(String field)? invalidRange,
+    TResult? Function(String value, String reason)? invalidRecurrence,
+    TResult? Function(String id, String date)? noOccurrence,
+    TResult? Function(String field0)? unknownTimeZone,
+    TResult? Function(String field0)? titleInvalid,
+    TResult? Function(String field0)? notFound,
+    TResult? Function(String field0)? duplicateId,
+    TResult? Function(String field0)? storage,
+    TResult? Function(String file, String message)? parseFailure,
+  }) {
+    return invalidRecurrence?.call(value, reason);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String field, String value)? invalidDate,
+    TResult Function(String field, String value)? invalidTime,
+    TResult Function(String field0)? missingField,
+    TResult Function(String field)? invalidRange,
+    TResult Function(String value, String reason)? invalidRecurrence,
+    TResult Function(String id, String date)? noOccurrence,
+    TResult Function(String field0)? unknownTimeZone,
+    TResult Function(String field0)? titleInvalid,
+    TResult Function(String field0)? notFound,
+    TResult Function(String field0)? duplicateId,
+    TResult Function(String field0)? storage,
+    TResult Function(String file, String message)? parseFailure,
+    required TResult orElse(),
+  }) {
+    if (invalidRecurrence != null) {
+      return invalidRecurrence(value, reason);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(EventError_InvalidDate value) invalidDate,
+    required TResult Function(EventError_InvalidTime value) invalidTime,
+    required TResult Function(EventError_MissingField value) missingField,
+    required TResult Function(EventError_InvalidRange value) invalidRange,
+    required TResult Function(EventError_InvalidRecurrence value) invalidRecurrence,
+    required TResult Function(EventError_NoOccurrence value) noOccurrence,
+    required TResult Function(EventError_UnknownTimeZone value) unknownTimeZone,
+    required TResult Function(EventError_TitleInvalid value) titleInvalid,
+    required TResult Function(EventError_NotFound value) notFound,
+    required TResult Function(EventError_DuplicateId value) duplicateId,
+    required TResult Function(EventError_Storage value) storage,
+    required TResult Function(EventError_ParseFailure value) parseFailure,
+  }) {
+    return invalidRecurrence(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(EventError_InvalidDate value)? invalidDate,
+    TResult? Function(EventError_InvalidTime value)? invalidTime,
+    TResult? Function(EventError_MissingField value)? missingField,
+    TResult? Function(EventError_InvalidRange value)? invalidRange,
+    TResult? Function(EventError_InvalidRecurrence value)? invalidRecurrence,
+    TResult? Function(EventError_NoOccurrence value)? noOccurrence,
+    TResult? Function(EventError_UnknownTimeZone value)? unknownTimeZone,
+    TResult? Function(EventError_TitleInvalid value)? titleInvalid,
+    TResult? Function(EventError_NotFound value)? notFound,
+    TResult? Function(EventError_DuplicateId value)? duplicateId,
+    TResult? Function(EventError_Storage value)? storage,
+    TResult? Function(EventError_ParseFailure value)? parseFailure,
+  }) {
+    return invalidRecurrence?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(EventError_InvalidDate value)? invalidDate,
+    TResult Function(EventError_InvalidTime value)? invalidTime,
+    TResult Function(EventError_MissingField value)? missingField,
+    TResult Function(EventError_InvalidRange value)? invalidRange,
+    TResult Function(EventError_InvalidRecurrence value)? invalidRecurrence,
+    TResult Function(EventError_NoOccurrence value)? noOccurrence,
+    TResult Function(EventError_UnknownTimeZone value)? unknownTimeZone,
+    TResult Function(EventError_TitleInvalid value)? titleInvalid,
+    TResult Function(EventError_NotFound value)? notFound,
+    TResult Function(EventError_DuplicateId value)? duplicateId,
+    TResult Function(EventError_Storage value)? storage,
+    TResult Function(EventError_ParseFailure value)? parseFailure,
+    required TResult orElse(),
+  }) {
+    if (invalidRecurrence != null) {
+      return invalidRecurrence(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class EventError_InvalidRecurrence extends EventError {
+  const factory EventError_InvalidRecurrence({
+    required final String value,
+    required final String reason,
+  }) = _$EventError_InvalidRecurrenceImpl;
+  const EventError_InvalidRecurrence._() : super._();
+
+  String get value;
+
+  String get reason;
+
+  /// Create a copy of EventError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$EventError_InvalidRecurrenceImplCopyWith<_$EventError_InvalidRecurrenceImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$EventError_NoOccurrenceImplCopyWith<$Res> {
+  factory _$$EventError_NoOccurrenceImplCopyWith(
+    _$EventError_NoOccurrenceImpl value,
+    $Res Function(_$EventError_NoOccurrenceImpl) then,
+  ) = __$$EventError_NoOccurrenceImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String id, String date});
+}
+
+/// @nodoc
+class __$$EventError_NoOccurrenceImplCopyWithImpl<$Res>
+    extends _$EventErrorCopyWithImpl<$Res, _$EventError_NoOccurrenceImpl>
+    implements _$$EventError_NoOccurrenceImplCopyWith<$Res> {
+  __$$EventError_NoOccurrenceImplCopyWithImpl(
+    _$EventError_NoOccurrenceImpl _value,
+    $Res Function(_$EventError_NoOccurrenceImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of EventError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? id = null, Object? date = null}) {
+    return _then(
+      _$EventError_NoOccurrenceImpl(
+        id: null == id
+            ? _value.id
+            : id // ignore: cast_nullable_to_non_nullable
+                  as String,
+        date: null == date
+            ? _value.date
+            : date // ignore: cast_nullable_to_non_nullable
+                  as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$EventError_NoOccurrenceImpl extends EventError_NoOccurrence {
+  const _$EventError_NoOccurrenceImpl({required this.id, required this.date}) : super._();
+
+  @override
+  final String id;
+  @override
+  final String date;
+
+  @override
+  String toString() {
+    return 'EventError.noOccurrence(id: $id, date: $date)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$EventError_NoOccurrenceImpl &&
+            (identical(other.id, id) || other.id == id) &&
+            (identical(other.date, date) || other.date == date));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, id, date);
+
+  /// Create a copy of EventError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$EventError_NoOccurrenceImplCopyWith<_$EventError_NoOccurrenceImpl> get copyWith =>
+      __$$EventError_NoOccurrenceImplCopyWithImpl<_$EventError_NoOccurrenceImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String field, String value) invalidDate,
+    required TResult Function(String field, String value) invalidTime,
+    required TResult Function(String field0) missingField,
+    required TResult Function(String field) invalidRange,
+    required TResult Function(String value, String reason) invalidRecurrence,
+    required TResult Function(String id, String date) noOccurrence,
+    required TResult Function(String field0) unknownTimeZone,
+    required TResult Function(String field0) titleInvalid,
+    required TResult Function(String field0) notFound,
+    required TResult Function(String field0) duplicateId,
+    required TResult Function(String field0) storage,
+    required TResult Function(String file, String message) parseFailure,
+  }) {
+    return noOccurrence(id, date);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String field, String value)? invalidDate,
+    TResult? Function(String field, String value)? invalidTime,
+    TResult? Function(String field0)? missingField,
+    TResult? Function(String field)? invalidRange,
+    TResult? Function(String value, String reason)? invalidRecurrence,
+    TResult? Function(String id, String date)? noOccurrence,
+    TResult? Function(String field0)? unknownTimeZone,
+    TResult? Function(String field0)? titleInvalid,
+    TResult? Function(String field0)? notFound,
+    TResult? Function(String field0)? duplicateId,
+    TResult? Function(String field0)? storage,
+    TResult? Function(String file, String message)? parseFailure,
+  }) {
+    return noOccurrence?.call(id, date);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String field, String value)? invalidDate,
+    TResult Function(String field, String value)? invalidTime,
+    TResult Function(String field0)? missingField,
+    TResult Function(String field)? invalidRange,
+    TResult Function(String value, String reason)? invalidRecurrence,
+    TResult Function(String id, String date)? noOccurrence,
+    TResult Function(String field0)? unknownTimeZone,
+    TResult Function(String field0)? titleInvalid,
+    TResult Function(String field0)? notFound,
+    TResult Function(String field0)? duplicateId,
+    TResult Function(String field0)? storage,
+    TResult Function(String file, String message)? parseFailure,
+    required TResult orElse(),
+  }) {
+    if (noOccurrence != null) {
+      return noOccurrence(id, date);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(EventError_InvalidDate value) invalidDate,
+    required TResult Function(EventError_InvalidTime value) invalidTime,
+    required TResult Function(EventError_MissingField value) missingField,
+    required TResult Function(EventError_InvalidRange value) invalidRange,
+    required TResult Function(EventError_InvalidRecurrence value) invalidRecurrence,
+    required TResult Function(EventError_NoOccurrence value) noOccurrence,
+    required TResult Function(EventError_UnknownTimeZone value) unknownTimeZone,
+    required TResult Function(EventError_TitleInvalid value) titleInvalid,
+    required TResult Function(EventError_NotFound value) notFound,
+    required TResult Function(EventError_DuplicateId value) duplicateId,
+    required TResult Function(EventError_Storage value) storage,
+    required TResult Function(EventError_ParseFailure value) parseFailure,
+  }) {
+    return noOccurrence(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(EventError_InvalidDate value)? invalidDate,
+    TResult? Function(EventError_InvalidTime value)? invalidTime,
+    TResult? Function(EventError_MissingField value)? missingField,
+    TResult? Function(EventError_InvalidRange value)? invalidRange,
+    TResult? Function(EventError_InvalidRecurrence value)? invalidRecurrence,
+    TResult? Function(EventError_NoOccurrence value)? noOccurrence,
+    TResult? Function(EventError_UnknownTimeZone value)? unknownTimeZone,
+    TResult? Function(EventError_TitleInvalid value)? titleInvalid,
+    TResult? Function(EventError_NotFound value)? notFound,
+    TResult? Function(EventError_DuplicateId value)? duplicateId,
+    TResult? Function(EventError_Storage value)? storage,
+    TResult? Function(EventError_ParseFailure value)? parseFailure,
+  }) {
+    return noOccurrence?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(EventError_InvalidDate value)? invalidDate,
+    TResult Function(EventError_InvalidTime value)? invalidTime,
+    TResult Function(EventError_MissingField value)? missingField,
+    TResult Function(EventError_InvalidRange value)? invalidRange,
+    TResult Function(EventError_InvalidRecurrence value)? invalidRecurrence,
+    TResult Function(EventError_NoOccurrence value)? noOccurrence,
+    TResult Function(EventError_UnknownTimeZone value)? unknownTimeZone,
+    TResult Function(EventError_TitleInvalid value)? titleInvalid,
+    TResult Function(EventError_NotFound value)? notFound,
+    TResult Function(EventError_DuplicateId value)? duplicateId,
+    TResult Function(EventError_Storage value)? storage,
+    TResult Function(EventError_ParseFailure value)? parseFailure,
+    required TResult orElse(),
+  }) {
+    if (noOccurrence != null) {
+      return noOccurrence(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class EventError_NoOccurrence extends EventError {
+  const factory EventError_NoOccurrence({
+    required final String id,
+    required final String date,
+  }) = _$EventError_NoOccurrenceImpl;
+  const EventError_NoOccurrence._() : super._();
+
+  String get id;
+
+  String get date;
+
+  /// Create a copy of EventError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$EventError_NoOccurrenceImplCopyWith<_$EventError_NoOccurrenceImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$EventError_UnknownTimeZoneImplCopyWith<$Res> {
+  factory _$$EventError_UnknownTimeZoneImplCopyWith(
+    _$EventError_UnknownTimeZoneImpl value,
+    $Res Function(_$EventError_UnknownTimeZoneImpl) then,
+  ) = __$$EventError_UnknownTimeZoneImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class __$$EventError_UnknownTimeZoneImplCopyWithImpl<$Res>
+    extends _$EventErrorCopyWithImpl<$Res, _$EventError_UnknownTimeZoneImpl>
+    implements _$$EventError_UnknownTimeZoneImplCopyWith<$Res> {
+  __$$EventError_UnknownTimeZoneImplCopyWithImpl(
+    _$EventError_UnknownTimeZoneImpl _value,
+    $Res Function(_$EventError_UnknownTimeZoneImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of EventError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field0 = null}) {
+    return _then(
+      _$EventError_UnknownTimeZoneImpl(
+        null == field0
+            ? _value.field0
+            : field0 // ignore: cast_nullable_to_non_nullable
+                  as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$EventError_UnknownTimeZoneImpl extends EventError_UnknownTimeZone {
+  const _$EventError_UnknownTimeZoneImpl(this.field0) : super._();
+
+  @override
+  final String field0;
+
+  @override
+  String toString() {
+    return 'EventError.unknownTimeZone(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$EventError_UnknownTimeZoneImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of EventError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$EventError_UnknownTimeZoneImplCopyWith<_$EventError_UnknownTimeZoneImpl> get copyWith =>
+      __$$EventError_UnknownTimeZoneImplCopyWithImpl<_$EventError_UnknownTimeZoneImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String field, String value) invalidDate,
+    required TResult Function(String field, String value) invalidTime,
+    required TResult Function(String field0) missingField,
+    required TResult Function(String field) invalidRange,
+    required TResult Function(String value, String reason) invalidRecurrence,
+    required TResult Function(String id, String date) noOccurrence,
+    required TResult Function(String field0) unknownTimeZone,
+    required TResult Function(String field0) titleInvalid,
+    required TResult Function(String field0) notFound,
+    required TResult Function(String field0) duplicateId,
+    required TResult Function(String field0) storage,
+    required TResult Function(String file, String message) parseFailure,
+  }) {
+    return unknownTimeZone(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String field, String value)? invalidDate,
+    TResult? Function(String field, String value)? invalidTime,
+    TResult? Function(String field0)? missingField,
+    TResult? Function(String field)? invalidRange,
+    TResult? Function(String value, String reason)? invalidRecurrence,
+    TResult? Function(String id, String date)? noOccurrence,
+    TResult? Function(String field0)? unknownTimeZone,
+    TResult? Function(String field0)? titleInvalid,
+    TResult? Function(String field0)? notFound,
+    TResult? Function(String field0)? duplicateId,
+    TResult? Function(String field0)? storage,
+    TResult? Function(String file, String message)? parseFailure,
+  }) {
+    return unknownTimeZone?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String field, String value)? invalidDate,
+    TResult Function(String field, String value)? invalidTime,
+    TResult Function(String field0)? missingField,
+    TResult Function(String field)? invalidRange,
+    TResult Function(String value, String reason)? invalidRecurrence,
+    TResult Function(String id, String date)? noOccurrence,
+    TResult Function(String field0)? unknownTimeZone,
+    TResult Function(String field0)? titleInvalid,
+    TResult Function(String field0)? notFound,
+    TResult Function(String field0)? duplicateId,
+    TResult Function(String field0)? storage,
+    TResult Function(String file, String message)? parseFailure,
+    required TResult orElse(),
+  }) {
+    if (unknownTimeZone != null) {
+      return unknownTimeZone(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(EventError_InvalidDate value) invalidDate,
+    required TResult Function(EventError_InvalidTime value) invalidTime,
+    required TResult Function(EventError_MissingField value) missingField,
+    required TResult Function(EventError_InvalidRange value) invalidRange,
+    required TResult Function(EventError_InvalidRecurrence value) invalidRecurrence,
+    required TResult Function(EventError_NoOccurrence value) noOccurrence,
+    required TResult Function(EventError_UnknownTimeZone value) unknownTimeZone,
+    required TResult Function(EventError_TitleInvalid value) titleInvalid,
+    required TResult Function(EventError_NotFound value) notFound,
+    required TResult Function(EventError_DuplicateId value) duplicateId,
+    required TResult Function(EventError_Storage value) storage,
+    required TResult Function(EventError_ParseFailure value) parseFailure,
+  }) {
+    return unknownTimeZone(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(EventError_InvalidDate value)? invalidDate,
+    TResult? Function(EventError_InvalidTime value)? invalidTime,
+    TResult? Function(EventError_MissingField value)? missingField,
+    TResult? Function(EventError_InvalidRange value)? invalidRange,
+    TResult? Function(EventError_InvalidRecurrence value)? invalidRecurrence,
+    TResult? Function(EventError_NoOccurrence value)? noOccurrence,
+    TResult? Function(EventError_UnknownTimeZone value)? unknownTimeZone,
+    TResult? Function(EventError_TitleInvalid value)? titleInvalid,
+    TResult? Function(EventError_NotFound value)? notFound,
+    TResult? Function(EventError_DuplicateId value)? duplicateId,
+    TResult? Function(EventError_Storage value)? storage,
+    TResult? Function(EventError_ParseFailure value)? parseFailure,
+  }) {
+    return unknownTimeZone?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(EventError_InvalidDate value)? invalidDate,
+    TResult Function(EventError_InvalidTime value)? invalidTime,
+    TResult Function(EventError_MissingField value)? missingField,
+    TResult Function(EventError_InvalidRange value)? invalidRange,
+    TResult Function(EventError_InvalidRecurrence value)? invalidRecurrence,
+    TResult Function(EventError_NoOccurrence value)? noOccurrence,
+    TResult Function(EventError_UnknownTimeZone value)? unknownTimeZone,
+    TResult Function(EventError_TitleInvalid value)? titleInvalid,
+    TResult Function(EventError_NotFound value)? notFound,
+    TResult Function(EventError_DuplicateId value)? duplicateId,
+    TResult Function(EventError_Storage value)? storage,
+    TResult Function(EventError_ParseFailure value)? parseFailure,
+    required TResult orElse(),
+  }) {
+    if (unknownTimeZone != null) {
+      return unknownTimeZone(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class EventError_UnknownTimeZone extends EventError {
+  const factory EventError_UnknownTimeZone(final String field0) = _$EventError_UnknownTimeZoneImpl;
+  const EventError_UnknownTimeZone._() : super._();
+
+  String get field0;
+
+  /// Create a copy of EventError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$EventError_UnknownTimeZoneImplCopyWith<_$EventError_UnknownTimeZoneImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$EventError_TitleInvalidImplCopyWith<$Res> {
+  factory _$$EventError_TitleInvalidImplCopyWith(
+    _$EventError_TitleInvalidImpl value,
+    $Res Function(_$EventError_TitleInvalidImpl) then,
+  ) = __$$EventError_TitleInvalidImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class __$$EventError_TitleInvalidImplCopyWithImpl<$Res>
+    extends _$EventErrorCopyWithImpl<$Res, _$EventError_TitleInvalidImpl>
+    implements _$$EventError_TitleInvalidImplCopyWith<$Res> {
+  __$$EventError_TitleInvalidImplCopyWithImpl(
+    _$EventError_TitleInvalidImpl _value,
+    $Res Function(_$EventError_TitleInvalidImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of EventError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field0 = null}) {
+    return _then(
+      _$EventError_TitleInvalidImpl(
+        null == field0
+            ? _value.field0
+            : field0 // ignore: cast_nullable_to_non_nullable
+                  as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$EventError_TitleInvalidImpl extends EventError_TitleInvalid {
+  const _$EventError_TitleInvalidImpl(this.field0) : super._();
+
+  @override
+  final String field0;
+
+  @override
+  String toString() {
+    return 'EventError.titleInvalid(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$EventError_TitleInvalidImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of EventError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$EventError_TitleInvalidImplCopyWith<_$EventError_TitleInvalidImpl> get copyWith =>
+      __$$EventError_TitleInvalidImplCopyWithImpl<_$EventError_TitleInvalidImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String field, String value) invalidDate,
+    required TResult Function(String field, String value) invalidTime,
+    required TResult Function(String field0) missingField,
+    required TResult Function(String field) invalidRange,
+    required TResult Function(String value, String reason) invalidRecurrence,
+    required TResult Function(String id, String date) noOccurrence,
+    required TResult Function(String field0) unknownTimeZone,
+    required TResult Function(String field0) titleInvalid,
+    required TResult Function(String field0) notFound,
+    required TResult Function(String field0) duplicateId,
+    required TResult Function(String field0) storage,
+    required TResult Function(String file, String message) parseFailure,
+  }) {
+    return titleInvalid(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String field, String value)? invalidDate,
+    TResult? Function(String field, String value)? invalidTime,
+    TResult? Function(String field0)? missingField,
+    TResult? Function(String field)? invalidRange,
+    TResult? Function(String value, String reason)? invalidRecurrence,
+    TResult? Function(String id, String date)? noOccurrence,
+    TResult? Function(String field0)? unknownTimeZone,
+    TResult? Function(String field0)? titleInvalid,
+    TResult? Function(String field0)? notFound,
+    TResult? Function(String field0)? duplicateId,
+    TResult? Function(String field0)? storage,
+    TResult? Function(String file, String message)? parseFailure,
+  }) {
+    return titleInvalid?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String field, String value)? invalidDate,
+    TResult Function(String field, String value)? invalidTime,
+    TResult Function(String field0)? missingField,
+    TResult Function(String field)? invalidRange,
+    TResult Function(String value, String reason)? invalidRecurrence,
+    TResult Function(String id, String date)? noOccurrence,
+    TResult Function(String field0)? unknownTimeZone,
+    TResult Function(String field0)? titleInvalid,
+    TResult Function(String field0)? notFound,
+    TResult Function(String field0)? duplicateId,
+    TResult Function(String field0)? storage,
+    TResult Function(String file, String message)? parseFailure,
+    required TResult orElse(),
+  }) {
+    if (titleInvalid != null) {
+      return titleInvalid(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(EventError_InvalidDate value) invalidDate,
+    required TResult Function(EventError_InvalidTime value) invalidTime,
+    required TResult Function(EventError_MissingField value) missingField,
+    required TResult Function(EventError_InvalidRange value) invalidRange,
+    required TResult Function(EventError_InvalidRecurrence value) invalidRecurrence,
+    required TResult Function(EventError_NoOccurrence value) noOccurrence,
+    required TResult Function(EventError_UnknownTimeZone value) unknownTimeZone,
+    required TResult Function(EventError_TitleInvalid value) titleInvalid,
+    required TResult Function(EventError_NotFound value) notFound,
+    required TResult Function(EventError_DuplicateId value) duplicateId,
+    required TResult Function(EventError_Storage value) storage,
+    required TResult Function(EventError_ParseFailure value) parseFailure,
+  }) {
+    return titleInvalid(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(EventError_InvalidDate value)? invalidDate,
+    TResult? Function(EventError_InvalidTime value)? invalidTime,
+    TResult? Function(EventError_MissingField value)? missingField,
+    TResult? Function(EventError_InvalidRange value)? invalidRange,
+    TResult? Function(EventError_InvalidRecurrence value)? invalidRecurrence,
+    TResult? Function(EventError_NoOccurrence value)? noOccurrence,
+    TResult? Function(EventError_UnknownTimeZone value)? unknownTimeZone,
+    TResult? Function(EventError_TitleInvalid value)? titleInvalid,
+    TResult? Function(EventError_NotFound value)? notFound,
+    TResult? Function(EventError_DuplicateId value)? duplicateId,
+    TResult? Function(EventError_Storage value)? storage,
+    TResult? Function(EventError_ParseFailure value)? parseFailure,
+  }) {
+    return titleInvalid?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(EventError_InvalidDate value)? invalidDate,
+    TResult Function(EventError_InvalidTime value)? invalidTime,
+    TResult Function(EventError_MissingField value)? missingField,
+    TResult Function(EventError_InvalidRange value)? invalidRange,
+    TResult Function(EventError_InvalidRecurrence value)? invalidRecurrence,
+    TResult Function(EventError_NoOccurrence value)? noOccurrence,
+    TResult Function(EventError_UnknownTimeZone value)? unknownTimeZone,
+    TResult Function(EventError_TitleInvalid value)? titleInvalid,
+    TResult Function(EventError_NotFound value)? notFound,
+    TResult Function(EventError_DuplicateId value)? duplicateId,
+    TResult Function(EventError_Storage value)? storage,
+    TResult Function(EventError_ParseFailure value)? parseFailure,
+    required TResult orElse(),
+  }) {
+    if (titleInvalid != null) {
+      return titleInvalid(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class EventError_TitleInvalid extends EventError {
+  const factory EventError_TitleInvalid(final String field0) = _$EventError_TitleInvalidImpl;
+  const EventError_TitleInvalid._() : super._();
+
+  String get field0;
+
+  /// Create a copy of EventError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$EventError_TitleInvalidImplCopyWith<_$EventError_TitleInvalidImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$EventError_NotFoundImplCopyWith<$Res> {
+  factory _$$EventError_NotFoundImplCopyWith(
+    _$EventError_NotFoundImpl value,
+    $Res Function(_$EventError_NotFoundImpl) then,
+  ) = __$$EventError_NotFoundImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class __$$EventError_NotFoundImplCopyWithImpl<$Res>
+    extends _$EventErrorCopyWithImpl<$Res, _$EventError_NotFoundImpl>
+    implements _$$EventError_NotFoundImplCopyWith<$Res> {
+  __$$EventError_NotFoundImplCopyWithImpl(
+    _$EventError_NotFoundImpl _value,
+    $Res Function(_$EventError_NotFoundImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of EventError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field0 = null}) {
+    return _then(
+      _$EventError_NotFoundImpl(
+        null == field0
+            ? _value.field0
+            : field0 // ignore: cast_nullable_to_non_nullable
+                  as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$EventError_NotFoundImpl extends EventError_NotFound {
+  const _$EventError_NotFoundImpl(this.field0) : super._();
+
+  @override
+  final String field0;
+
+  @override
+  String toString() {
+    return 'EventError.notFound(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$EventError_NotFoundImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of EventError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$EventError_NotFoundImplCopyWith<_$EventError_NotFoundImpl> get copyWith =>
+      __$$EventError_NotFoundImplCopyWithImpl<_$EventError_NotFoundImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String field, String value) invalidDate,
+    required TResult Function(String field, String value) invalidTime,
+    required TResult Function(String field0) missingField,
+    required TResult Function(String field) invalidRange,
+    required TResult Function(String value, String reason) invalidRecurrence,
+    required TResult Function(String id, String date) noOccurrence,
+    required TResult Function(String field0) unknownTimeZone,
+    required TResult Function(String field0) titleInvalid,
+    required TResult Function(String field0) notFound,
+    required TResult Function(String field0) duplicateId,
+    required TResult Function(String field0) storage,
+    required TResult Function(String file, String message) parseFailure,
+  }) {
+    return notFound(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String field, String value)? invalidDate,
+    TResult? Function(String field, String value)? invalidTime,
+    TResult? Function(String field0)? missingField,
+    TResult? Function(String field)? invalidRange,
+    TResult? Function(String value, String reason)? invalidRecurrence,
+    TResult? Function(String id, String date)? noOccurrence,
+    TResult? Function(String field0)? unknownTimeZone,
+    TResult? Function(String field0)? titleInvalid,
+    TResult? Function(String field0)? notFound,
+    TResult? Function(String field0)? duplicateId,
+    TResult? Function(String field0)? storage,
+    TResult? Function(String file, String message)? parseFailure,
+  }) {
+    return notFound?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String field, String value)? invalidDate,
+    TResult Function(String field, String value)? invalidTime,
+    TResult Function(String field0)? missingField,
+    TResult Function(String field)? invalidRange,
+    TResult Function(String value, String reason)? invalidRecurrence,
+    TResult Function(String id, String date)? noOccurrence,
+    TResult Function(String field0)? unknownTimeZone,
+    TResult Function(String field0)? titleInvalid,
+    TResult Function(String field0)? notFound,
+    TResult Function(String field0)? duplicateId,
+    TResult Function(String field0)? storage,
+    TResult Function(String file, String message)? parseFailure,
+    required TResult orElse(),
+  }) {
+    if (notFound != null) {
+      return notFound(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(EventError_InvalidDate value) invalidDate,
+    required TResult Function(EventError_InvalidTime value) invalidTime,
+    required TResult Function(EventError_MissingField value) missingField,
+    required TResult Function(EventError_InvalidRange value) invalidRange,
+    required TResult Function(EventError_InvalidRecurrence value) invalidRecurrence,
+    required TResult Function(EventError_NoOccurrence value) noOccurrence,
+    required TResult Function(EventError_UnknownTimeZone value) unknownTimeZone,
+    required TResult Function(EventError_TitleInvalid value) titleInvalid,
+    required TResult Function(EventError_NotFound value) notFound,
+    required TResult Function(EventError_DuplicateId value) duplicateId,
+    required TResult Function(EventError_Storage value) storage,
+    required TResult Function(EventError_ParseFailure value) parseFailure,
+  }) {
+    return notFound(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(EventError_InvalidDate value)? invalidDate,
+    TResult? Function(EventError_InvalidTime value)? invalidTime,
+    TResult? Function(EventError_MissingField value)? missingField,
+    TResult? Function(EventError_InvalidRange value)? invalidRange,
+    TResult? Function(EventError_InvalidRecurrence value)? invalidRecurrence,
+    TResult? Function(EventError_NoOccurrence value)? noOccurrence,
+    TResult? Function(EventError_UnknownTimeZone value)? unknownTimeZone,
+    TResult? Function(EventError_TitleInvalid value)? titleInvalid,
+    TResult? Function(EventError_NotFound value)? notFound,
+    TResult? Function(EventError_DuplicateId value)? duplicateId,
+    TResult? Function(EventError_Storage value)? storage,
+    TResult? Function(EventError_ParseFailure value)? parseFailure,
+  }) {
+    return notFound?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(EventError_InvalidDate value)? invalidDate,
+    TResult Function(EventError_InvalidTime value)? invalidTime,
+    TResult Function(EventError_MissingField value)? missingField,
+    TResult Function(EventError_InvalidRange value)? invalidRange,
+    TResult Function(EventError_InvalidRecurrence value)? invalidRecurrence,
+    TResult Function(EventError_NoOccurrence value)? noOccurrence,
+    TResult Function(EventError_UnknownTimeZone value)? unknownTimeZone,
+    TResult Function(EventError_TitleInvalid value)? titleInvalid,
+    TResult Function(EventError_NotFound value)? notFound,
+    TResult Function(EventError_DuplicateId value)? duplicateId,
+    TResult Function(EventError_Storage value)? storage,
+    TResult Function(EventError_ParseFailure value)? parseFailure,
+    required TResult orElse(),
+  }) {
+    if (notFound != null) {
+      return notFound(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class EventError_NotFound extends EventError {
+  const factory EventError_NotFound(final String field0) = _$EventError_NotFoundImpl;
+  const EventError_NotFound._() : super._();
+
+  String get field0;
+
+  /// Create a copy of EventError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$EventError_NotFoundImplCopyWith<_$EventError_NotFoundImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$EventError_DuplicateIdImplCopyWith<$Res> {
+  factory _$$EventError_DuplicateIdImplCopyWith(
+    _$EventError_DuplicateIdImpl value,
+    $Res Function(_$EventError_DuplicateIdImpl) then,
+  ) = __$$EventError_DuplicateIdImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class __$$EventError_DuplicateIdImplCopyWithImpl<$Res>
+    extends _$EventErrorCopyWithImpl<$Res, _$EventError_DuplicateIdImpl>
+    implements _$$EventError_DuplicateIdImplCopyWith<$Res> {
+  __$$EventError_DuplicateIdImplCopyWithImpl(
+    _$EventError_DuplicateIdImpl _value,
+    $Res Function(_$EventError_DuplicateIdImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of EventError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field0 = null}) {
+    return _then(
+      _$EventError_DuplicateIdImpl(
+        null == field0
+            ? _value.field0
+            : field0 // ignore: cast_nullable_to_non_nullable
+                  as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$EventError_DuplicateIdImpl extends EventError_DuplicateId {
+  const _$EventError_DuplicateIdImpl(this.field0) : super._();
+
+  @override
+  final String field0;
+
+  @override
+  String toString() {
+    return 'EventError.duplicateId(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$EventError_DuplicateIdImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of EventError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$EventError_DuplicateIdImplCopyWith<_$EventError_DuplicateIdImpl> get copyWith =>
+      __$$EventError_DuplicateIdImplCopyWithImpl<_$EventError_DuplicateIdImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String field, String value) invalidDate,
+    required TResult Function(String field, String value) invalidTime,
+    required TResult Function(String field0) missingField,
+    required TResult Function(String field) invalidRange,
+    required TResult Function(String value, String reason) invalidRecurrence,
+    required TResult Function(String id, String date) noOccurrence,
+    required TResult Function(String field0) unknownTimeZone,
+    required TResult Function(String field0) titleInvalid,
+    required TResult Function(String field0) notFound,
+    required TResult Function(String field0) duplicateId,
+    required TResult Function(String field0) storage,
+    required TResult Function(String file, String message) parseFailure,
+  }) {
+    return duplicateId(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String field, String value)? invalidDate,
+    TResult? Function(String field, String value)? invalidTime,
+    TResult? Function(String field0)? missingField,
+    TResult? Function(String field)? invalidRange,
+    TResult? Function(String value, String reason)? invalidRecurrence,
+    TResult? Function(String id, String date)? noOccurrence,
+    TResult? Function(String field0)? unknownTimeZone,
+    TResult? Function(String field0)? titleInvalid,
+    TResult? Function(String field0)? notFound,
+    TResult? Function(String field0)? duplicateId,
+    TResult? Function(String field0)? storage,
+    TResult? Function(String file, String message)? parseFailure,
+  }) {
+    return duplicateId?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String field, String value)? invalidDate,
+    TResult Function(String field, String value)? invalidTime,
+    TResult Function(String field0)? missingField,
+    TResult Function(String field)? invalidRange,
+    TResult Function(String value, String reason)? invalidRecurrence,
+    TResult Function(String id, String date)? noOccurrence,
+    TResult Function(String field0)? unknownTimeZone,
+    TResult Function(String field0)? titleInvalid,
+    TResult Function(String field0)? notFound,
+    TResult Function(String field0)? duplicateId,
+    TResult Function(String field0)? storage,
+    TResult Function(String file, String message)? parseFailure,
+    required TResult orElse(),
+  }) {
+    if (duplicateId != null) {
+      return duplicateId(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(EventError_InvalidDate value) invalidDate,
+    required TResult Function(EventError_InvalidTime value) invalidTime,
+    required TResult Function(EventError_MissingField value) missingField,
+    required TResult Function(EventError_InvalidRange value) invalidRange,
+    required TResult Function(EventError_InvalidRecurrence value) invalidRecurrence,
+    required TResult Function(EventError_NoOccurrence value) noOccurrence,
+    required TResult Function(EventError_UnknownTimeZone value) unknownTimeZone,
+    required TResult Function(EventError_TitleInvalid value) titleInvalid,
+    required TResult Function(EventError_NotFound value) notFound,
+    required TResult Function(EventError_DuplicateId value) duplicateId,
+    required TResult Function(EventError_Storage value) storage,
+    required TResult Function(EventError_ParseFailure value) parseFailure,
+  }) {
+    return duplicateId(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(EventError_InvalidDate value)? invalidDate,
+    TResult? Function(EventError_InvalidTime value)? invalidTime,
+    TResult? Function(EventError_MissingField value)? missingField,
+    TResult? Function(EventError_InvalidRange value)? invalidRange,
+    TResult? Function(EventError_InvalidRecurrence value)? invalidRecurrence,
+    TResult? Function(EventError_NoOccurrence value)? noOccurrence,
+    TResult? Function(EventError_UnknownTimeZone value)? unknownTimeZone,
+    TResult? Function(EventError_TitleInvalid value)? titleInvalid,
+    TResult? Function(EventError_NotFound value)? notFound,
+    TResult? Function(EventError_DuplicateId value)? duplicateId,
+    TResult? Function(EventError_Storage value)? storage,
+    TResult? Function(EventError_ParseFailure value)? parseFailure,
+  }) {
+    return duplicateId?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(EventError_InvalidDate value)? invalidDate,
+    TResult Function(EventError_InvalidTime value)? invalidTime,
+    TResult Function(EventError_MissingField value)? missingField,
+    TResult Function(EventError_InvalidRange value)? invalidRange,
+    TResult Function(EventError_InvalidRecurrence value)? invalidRecurrence,
+    TResult Function(EventError_NoOccurrence value)? noOccurrence,
+    TResult Function(EventError_UnknownTimeZone value)? unknownTimeZone,
+    TResult Function(EventError_TitleInvalid value)? titleInvalid,
+    TResult Function(EventError_NotFound value)? notFound,
+    TResult Function(EventError_DuplicateId value)? duplicateId,
+    TResult Function(EventError_Storage value)? storage,
+    TResult Function(EventError_ParseFailure value)? parseFailure,
+    required TResult orElse(),
+  }) {
+    if (duplicateId != null) {
+      return duplicateId(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class EventError_DuplicateId extends EventError {
+  const factory EventError_DuplicateId(final String field0) = _$EventError_DuplicateIdImpl;
+  const EventError_DuplicateId._() : super._();
+
+  String get field0;
+
+  /// Create a copy of EventError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$EventError_DuplicateIdImplCopyWith<_$EventError_DuplicateIdImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$EventError_StorageImplCopyWith<$Res> {
+  factory _$$EventError_StorageImplCopyWith(
+    _$EventError_StorageImpl value,
+    $Res Function(_$EventError_StorageImpl) then,
+  ) = __$$EventError_StorageImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class __$$EventError_StorageImplCopyWithImpl<$Res>
+    extends _$EventErrorCopyWithImpl<$Res, _$EventError_StorageImpl>
+    implements _$$EventError_StorageImplCopyWith<$Res> {
+  __$$EventError_StorageImplCopyWithImpl(
+    _$EventError_StorageImpl _value,
+    $Res Function(_$EventError_StorageImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of EventError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field0 = null}) {
+    return _then(
+      _$EventError_StorageImpl(
+        null == field0
+            ? _value.field0
+            : field0 // ignore: cast_nullable_to_non_nullable
+                  as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$EventError_StorageImpl extends EventError_Storage {
+  const _$EventError_StorageImpl(this.field0) : super._();
+
+  @override
+  final String field0;
+
+  @override
+  String toString() {
+    return 'EventError.storage(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$EventError_StorageImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of EventError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$EventError_StorageImplCopyWith<_$EventError_StorageImpl> get copyWith =>
+      __$$EventError_StorageImplCopyWithImpl<_$EventError_StorageImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String field, String value) invalidDate,
+    required TResult Function(String field, String value) invalidTime,
+    required TResult Function(String field0) missingField,
+    required TResult Function(String field) invalidRange,
+    required TResult Function(String value, String reason) invalidRecurrence,
+    required TResult Function(String id, String date) noOccurrence,
+    required TResult Function(String field0) unknownTimeZone,
+    required TResult Function(String field0) titleInvalid,
+    required TResult Function(String field0) notFound,
+    required TResult Function(String field0) duplicateId,
+    required TResult Function(String field0) storage,
+    required TResult Function(String file, String message) parseFailure,
+  }) {
+    return storage(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String field, String value)? invalidDate,
+    TResult? Function(String field, String value)? invalidTime,
+    TResult? Function(String field0)? missingField,
+    TResult? Function(String field)? invalidRange,
+    TResult? Function(String value, String reason)? invalidRecurrence,
+    TResult? Function(String id, String date)? noOccurrence,
+    TResult? Function(String field0)? unknownTimeZone,
+    TResult? Function(String field0)? titleInvalid,
+    TResult? Function(String field0)? notFound,
+    TResult? Function(String field0)? duplicateId,
+    TResult? Function(String field0)? storage,
+    TResult? Function(String file, String message)? parseFailure,
+  }) {
+    return storage?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String field, String value)? invalidDate,
+    TResult Function(String field, String value)? invalidTime,
+    TResult Function(String field0)? missingField,
+    TResult Function(String field)? invalidRange,
+    TResult Function(String value, String reason)? invalidRecurrence,
+    TResult Function(String id, String date)? noOccurrence,
+    TResult Function(String field0)? unknownTimeZone,
+    TResult Function(String field0)? titleInvalid,
+    TResult Function(String field0)? notFound,
+    TResult Function(String field0)? duplicateId,
+    TResult Function(String field0)? storage,
+    TResult Function(String file, String message)? parseFailure,
+    required TResult orElse(),
+  }) {
+    if (storage != null) {
+      return storage(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(EventError_InvalidDate value) invalidDate,
+    required TResult Function(EventError_InvalidTime value) invalidTime,
+    required TResult Function(EventError_MissingField value) missingField,
+    required TResult Function(EventError_InvalidRange value) invalidRange,
+    required TResult Function(EventError_InvalidRecurrence value) invalidRecurrence,
+    required TResult Function(EventError_NoOccurrence value) noOccurrence,
+    required TResult Function(EventError_UnknownTimeZone value) unknownTimeZone,
+    required TResult Function(EventError_TitleInvalid value) titleInvalid,
+    required TResult Function(EventError_NotFound value) notFound,
+    required TResult Function(EventError_DuplicateId value) duplicateId,
+    required TResult Function(EventError_Storage value) storage,
+    required TResult Function(EventError_ParseFailure value) parseFailure,
+  }) {
+    return storage(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(EventError_InvalidDate value)? invalidDate,
+    TResult? Function(EventError_InvalidTime value)? invalidTime,
+    TResult? Function(EventError_MissingField value)? missingField,
+    TResult? Function(EventError_InvalidRange value)? invalidRange,
+    TResult? Function(EventError_InvalidRecurrence value)? invalidRecurrence,
+    TResult? Function(EventError_NoOccurrence value)? noOccurrence,
+    TResult? Function(EventError_UnknownTimeZone value)? unknownTimeZone,
+    TResult? Function(EventError_TitleInvalid value)? titleInvalid,
+    TResult? Function(EventError_NotFound value)? notFound,
+    TResult? Function(EventError_DuplicateId value)? duplicateId,
+    TResult? Function(EventError_Storage value)? storage,
+    TResult? Function(EventError_ParseFailure value)? parseFailure,
+  }) {
+    return storage?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(EventError_InvalidDate value)? invalidDate,
+    TResult Function(EventError_InvalidTime value)? invalidTime,
+    TResult Function(EventError_MissingField value)? missingField,
+    TResult Function(EventError_InvalidRange value)? invalidRange,
+    TResult Function(EventError_InvalidRecurrence value)? invalidRecurrence,
+    TResult Function(EventError_NoOccurrence value)? noOccurrence,
+    TResult Function(EventError_UnknownTimeZone value)? unknownTimeZone,
+    TResult Function(EventError_TitleInvalid value)? titleInvalid,
+    TResult Function(EventError_NotFound value)? notFound,
+    TResult Function(EventError_DuplicateId value)? duplicateId,
+    TResult Function(EventError_Storage value)? storage,
+    TResult Function(EventError_ParseFailure value)? parseFailure,
+    required TResult orElse(),
+  }) {
+    if (storage != null) {
+      return storage(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class EventError_Storage extends EventError {
+  const factory EventError_Storage(final String field0) = _$EventError_StorageImpl;
+  const EventError_Storage._() : super._();
+
+  String get field0;
+
+  /// Create a copy of EventError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$EventError_StorageImplCopyWith<_$EventError_StorageImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$EventError_ParseFailureImplCopyWith<$Res> {
+  factory _$$EventError_ParseFailureImplCopyWith(
+    _$EventError_ParseFailureImpl value,
+    $Res Function(_$EventError_ParseFailureImpl) then,
+  ) = __$$EventError_ParseFailureImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String file, String message});
+}
+
+/// @nodoc
+class __$$EventError_ParseFailureImplCopyWithImpl<$Res>
+    extends _$EventErrorCopyWithImpl<$Res, _$EventError_ParseFailureImpl>
+    implements _$$EventError_ParseFailureImplCopyWith<$Res> {
+  __$$EventError_ParseFailureImplCopyWithImpl(
+    _$EventError_ParseFailureImpl _value,
+    $Res Function(_$EventError_ParseFailureImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of EventError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? file = null, Object? message = null}) {
+    return _then(
+      _$EventError_ParseFailureImpl(
+        file: null == file
+            ? _value.file
+            : file // ignore: cast_nullable_to_non_nullable
+                  as String,
+        message: null == message
+            ? _value.message
+            : message // ignore: cast_nullable_to_non_nullable
+                  as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$EventError_ParseFailureImpl extends EventError_ParseFailure {
+  const _$EventError_ParseFailureImpl({required this.file, required this.message}) : super._();
+
+  @override
+  final String file;
+  @override
+  final String message;
+
+  @override
+  String toString() {
+    return 'EventError.parseFailure(file: $file, message: $message)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$EventError_ParseFailureImpl &&
+            (identical(other.file, file) || other.file == file) &&
+            (identical(other.message, message) || other.message == message));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, file, message);
+
+  /// Create a copy of EventError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$EventError_ParseFailureImplCopyWith<_$EventError_ParseFailureImpl> get copyWith =>
+      __$$EventError_ParseFailureImplCopyWithImpl<_$EventError_ParseFailureImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String field, String value) invalidDate,
+    required TResult Function(String field, String value) invalidTime,
+    required TResult Function(String field0) missingField,
+    required TResult Function(String field) invalidRange,
+    required TResult Function(String value, String reason) invalidRecurrence,
+    required TResult Function(String id, String date) noOccurrence,
+    required TResult Function(String field0) unknownTimeZone,
+    required TResult Function(String field0) titleInvalid,
+    required TResult Function(String field0) notFound,
+    required TResult Function(String field0) duplicateId,
+    required TResult Function(String field0) storage,
+    required TResult Function(String file, String message) parseFailure,
+  }) {
+    return parseFailure(file, message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String field, String value)? invalidDate,
+    TResult? Function(String field, String value)? invalidTime,
+    TResult? Function(String field0)? missingField,
+    TResult? Function(String field)? invalidRange,
+    TResult? Function(String value, String reason)? invalidRecurrence,
+    TResult? Function(String id, String date)? noOccurrence,
+    TResult? Function(String field0)? unknownTimeZone,
+    TResult? Function(String field0)? titleInvalid,
+    TResult? Function(String field0)? notFound,
+    TResult? Function(String field0)? duplicateId,
+    TResult? Function(String field0)? storage,
+    TResult? Function(String file, String message)? parseFailure,
+  }) {
+    return parseFailure?.call(file, message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String field, String value)? invalidDate,
+    TResult Function(String field, String value)? invalidTime,
+    TResult Function(String field0)? missingField,
+    TResult Function(String field)? invalidRange,
+    TResult Function(String value, String reason)? invalidRecurrence,
+    TResult Function(String id, String date)? noOccurrence,
+    TResult Function(String field0)? unknownTimeZone,
+    TResult Function(String field0)? titleInvalid,
+    TResult Function(String field0)? notFound,
+    TResult Function(String field0)? duplicateId,
+    TResult Function(String field0)? storage,
+    TResult Function(String file, String message)? parseFailure,
+    required TResult orElse(),
+  }) {
+    if (parseFailure != null) {
+      return parseFailure(file, message);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(EventError_InvalidDate value) invalidDate,
+    required TResult Function(EventError_InvalidTime value) invalidTime,
+    required TResult Function(EventError_MissingField value) missingField,
+    required TResult Function(EventError_InvalidRange value) invalidRange,
+    required TResult Function(EventError_InvalidRecurrence value) invalidRecurrence,
+    required TResult Function(EventError_NoOccurrence value) noOccurrence,
+    required TResult Function(EventError_UnknownTimeZone value) unknownTimeZone,
+    required TResult Function(EventError_TitleInvalid value) titleInvalid,
+    required TResult Function(EventError_NotFound value) notFound,
+    required TResult Function(EventError_DuplicateId value) duplicateId,
+    required TResult Function(EventError_Storage value) storage,
+    required TResult Function(EventError_ParseFailure value) parseFailure,
+  }) {
+    return parseFailure(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(EventError_InvalidDate value)? invalidDate,
+    TResult? Function(EventError_InvalidTime value)? invalidTime,
+    TResult? Function(EventError_MissingField value)? missingField,
+    TResult? Function(EventError_InvalidRange value)? invalidRange,
+    TResult? Function(EventError_InvalidRecurrence value)? invalidRecurrence,
+    TResult? Function(EventError_NoOccurrence value)? noOccurrence,
+    TResult? Function(EventError_UnknownTimeZone value)? unknownTimeZone,
+    TResult? Function(EventError_TitleInvalid value)? titleInvalid,
+    TResult? Function(EventError_NotFound value)? notFound,
+    TResult? Function(EventError_DuplicateId value)? duplicateId,
+    TResult? Function(EventError_Storage value)? storage,
+    TResult? Function(EventError_ParseFailure value)? parseFailure,
+  }) {
+    return parseFailure?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(EventError_InvalidDate value)? invalidDate,
+    TResult Function(EventError_InvalidTime value)? invalidTime,
+    TResult Function(EventError_MissingField value)? missingField,
+    TResult Function(EventError_InvalidRange value)? invalidRange,
+    TResult Function(EventError_InvalidRecurrence value)? invalidRecurrence,
+    TResult Function(EventError_NoOccurrence value)? noOccurrence,
+    TResult Function(EventError_UnknownTimeZone value)? unknownTimeZone,
+    TResult Function(EventError_TitleInvalid value)? titleInvalid,
+    TResult Function(EventError_NotFound value)? notFound,
+    TResult Function(EventError_DuplicateId value)? duplicateId,
+    TResult Function(EventError_Storage value)? storage,
+    TResult Function(EventError_ParseFailure value)? parseFailure,
+    required TResult orElse(),
+  }) {
+    if (parseFailure != null) {
+      return parseFailure(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class EventError_ParseFailure extends EventError {
+  const factory EventError_ParseFailure({
+    required final String file,
+    required final String message,
+  }) = _$EventError_ParseFailureImpl;
+  const EventError_ParseFailure._() : super._();
+
+  String get file;
+
+  String get message;
+
+  /// Create a copy of EventError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$EventError_ParseFailureImplCopyWith<_$EventError_ParseFailureImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$GitCredentials {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String username, String password) userPassword,
+    required TResult Function(String? username, String token) token,
+    required TResult Function(String? username, String privateKeyPath, String? publicKeyPath, String? passphrase) sshKeyFile,
+    required TResult Function(String? username, String privateKey, String? publicKey, String? passphrase) sshKeyMemory,
+    required TResult Function(String? username) sshAgent,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String username, String password)? userPassword,
+    TResult? Function(String? username, String token)? token,
+    TResult? Function(String? username, String privateKeyPath, String? publicKeyPath, String? passphrase)? sshKeyFile,
+    TResult? Function(String? username, String privateKey, String? publicKey, String? passphrase)? sshKeyMemory,
+    TResult? Function(String? username)? sshAgent,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String username, String password)? userPassword,
+    TResult Function(String? username, String token)? token,
+    TResult Function(String? username, String privateKeyPath, String? publicKeyPath, String? passphrase)? sshKeyFile,
+    TResult Function(String? username, String privateKey, String? publicKey, String? passphrase)? sshKeyMemory,
+    TResult Function(String? username)? sshAgent,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GitCredentials_UserPassword value) userPassword,
+    required TResult Function(GitCredentials_Token value) token,
+    required TResult Function(GitCredentials_SshKeyFile value) sshKeyFile,
+    required TResult Function(GitCredentials_SshKeyMemory value) sshKeyMemory,
+    required TResult Function(GitCredentials_SshAgent value) sshAgent,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GitCredentials_UserPassword value)? userPassword,
+    TResult? Function(GitCredentials_Token value)? token,
+    TResult? Function(GitCredentials_SshKeyFile value)? sshKeyFile,
+    TResult? Function(GitCredentials_SshKeyMemory value)? sshKeyMemory,
+    TResult? Function(GitCredentials_SshAgent value)? sshAgent,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GitCredentials_UserPassword value)? userPassword,
+    TResult Function(GitCredentials_Token value)? token,
+    TResult Function(GitCredentials_SshKeyFile value)? sshKeyFile,
+    TResult Function(GitCredentials_SshKeyMemory value)? sshKeyMemory,
+    TResult Function(GitCredentials_SshAgent value)? sshAgent,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $GitCredentialsCopyWith<$Res> {
+  factory $GitCredentialsCopyWith(GitCredentials value, $Res Function(GitCredentials) then) =
+      _$GitCredentialsCopyWithImpl<$Res, GitCredentials>;
+}
+
+/// @nodoc
+class _$GitCredentialsCopyWithImpl<$Res, $Val extends GitCredentials>
+    implements $GitCredentialsCopyWith<$Res> {
+  _$GitCredentialsCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of GitCredentials
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$GitCredentials_UserPasswordImplCopyWith<$Res> {
+  factory _$$GitCredentials_UserPasswordImplCopyWith(
+    _$GitCredentials_UserPasswordImpl value,
+    $Res Function(_$GitCredentials_UserPasswordImpl) then,
+  ) = __$$GitCredentials_UserPasswordImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String username, String password});
+}
+
+/// @nodoc
+class __$$GitCredentials_UserPasswordImplCopyWithImpl<$Res>
+    extends _$GitCredentialsCopyWithImpl<$Res, _$GitCredentials_UserPasswordImpl>
+    implements _$$GitCredentials_UserPasswordImplCopyWith<$Res> {
+  __$$GitCredentials_UserPasswordImplCopyWithImpl(
+    _$GitCredentials_UserPasswordImpl _value,
+    $Res Function(_$GitCredentials_UserPasswordImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of GitCredentials
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? username = null, Object? password = null}) {
+    return _then(
+      _$GitCredentials_UserPasswordImpl(
+        username: null == username
+            ? _value.username
+            : username // ignore: cast_nullable_to_non_nullable
+                  as String,
+        password: null == password
+            ? _value.password
+            : password // ignore: cast_nullable_to_non_nullable
+                  as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$GitCredentials_UserPasswordImpl extends GitCredentials_UserPassword {
+  const _$GitCredentials_UserPasswordImpl({required this.username, required this.password}) : super._();
+
+  @override
+  final String username;
+  @override
+  final String password;
+
+  @override
+  String toString() {
+    return 'GitCredentials.userPassword(username: $username, password: $password)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GitCredentials_UserPasswordImpl &&
+            (identical(other.username, username) || other.username == username) &&
+            (identical(other.password, password) || other.password == password));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, username, password);
+
+  /// Create a copy of GitCredentials
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GitCredentials_UserPasswordImplCopyWith<_$GitCredentials_UserPasswordImpl> get copyWith =>
+      __$$GitCredentials_UserPasswordImplCopyWithImpl<_$GitCredentials_UserPasswordImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String username, String password) userPassword,
+    required TResult Function(String? username, String token) token,
+    required TResult Function(String? username, String privateKeyPath, String? publicKeyPath, String? passphrase) sshKeyFile,
+    required TResult Function(String? username, String privateKey, String? publicKey, String? passphrase) sshKeyMemory,
+    required TResult Function(String? username) sshAgent,
+  }) {
+    return userPassword(username, password);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String username, String password)? userPassword,
+    TResult? Function(String? username, String token)? token,
+    TResult? Function(String? username, String privateKeyPath, String? publicKeyPath, String? passphrase)? sshKeyFile,
+    TResult? Function(String? username, String privateKey, String? publicKey, String? passphrase)? sshKeyMemory,
+    TResult? Function(String? username)? sshAgent,
+  }) {
+    return userPassword?.call(username, password);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String username, String password)? userPassword,
+    TResult Function(String? username, String token)? token,
+    TResult Function(String? username, String privateKeyPath, String? publicKeyPath, String? passphrase)? sshKeyFile,
+    TResult Function(String? username, String privateKey, String? publicKey, String? passphrase)? sshKeyMemory,
+    TResult Function(String? username)? sshAgent,
+    required TResult orElse(),
+  }) {
+    if (userPassword != null) {
+      return userPassword(username, password);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GitCredentials_UserPassword value) userPassword,
+    required TResult Function(GitCredentials_Token value) token,
+    required TResult Function(GitCredentials_SshKeyFile value) sshKeyFile,
+    required TResult Function(GitCredentials_SshKeyMemory value) sshKeyMemory,
+    required TResult Function(GitCredentials_SshAgent value) sshAgent,
+  }) {
+    return userPassword(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GitCredentials_UserPassword value)? userPassword,
+    TResult? Function(GitCredentials_Token value)? token,
+    TResult? Function(GitCredentials_SshKeyFile value)? sshKeyFile,
+    TResult? Function(GitCredentials_SshKeyMemory value)? sshKeyMemory,
+    TResult? Function(GitCredentials_SshAgent value)? sshAgent,
+  }) {
+    return userPassword?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GitCredentials_UserPassword value)? userPassword,
+    TResult Function(GitCredentials_Token value)? token,
+    TResult Function(GitCredentials_SshKeyFile value)? sshKeyFile,
+    TResult Function(GitCredentials_SshKeyMemory value)? sshKeyMemory,
+    TResult Function(GitCredentials_SshAgent value)? sshAgent,
+    required TResult orElse(),
+  }) {
+    if (userPassword != null) {
+      return userPassword(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GitCredentials_UserPassword extends GitCredentials {
+  const factory GitCredentials_UserPassword({
+    required final String username,
+    required final String password,
+  }) = _$GitCredentials_UserPasswordImpl;
+  const GitCredentials_UserPassword._() : super._();
+
+  String get username;
+
+  String get password;
+
+  /// Create a copy of GitCredentials
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GitCredentials_UserPasswordImplCopyWith<_$GitCredentials_UserPasswordImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$GitCredentials_TokenImplCopyWith<$Res> {
+  factory _$$GitCredentials_TokenImplCopyWith(
+    _$GitCredentials_TokenImpl value,
+    $Res Function(_$GitCredentials_TokenImpl) then,
+  ) = __$$GitCredentials_TokenImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String? username, String token});
+}
+
+/// @nodoc
+class __$$GitCredentials_TokenImplCopyWithImpl<$Res>
+    extends _$GitCredentialsCopyWithImpl<$Res, _$GitCredentials_TokenImpl>
+    implements _$$GitCredentials_TokenImplCopyWith<$Res> {
+  __$$GitCredentials_TokenImplCopyWithImpl(
+    _$GitCredentials_TokenImpl _value,
+    $Res Function(_$GitCredentials_TokenImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of GitCredentials
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? username = freezed, Object? token = null}) {
+    return _then(
+      _$GitCredentials_TokenImpl(
+        username: freezed == username
+            ? _value.username
+            : username // ignore: cast_nullable_to_non_nullable
+                  as String?,
+        token: null == token
+            ? _value.token
+            : token // ignore: cast_nullable_to_non_nullable
+                  as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$GitCredentials_TokenImpl extends GitCredentials_Token {
+  const _$GitCredentials_TokenImpl({this.username, required this.token}) : super._();
+
+  @override
+  final String? username;
+  @override
+  final String token;
+
+  @override
+  String toString() {
+    return 'GitCredentials.token(username: $username, token: $token)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GitCredentials_TokenImpl &&
+            (identical(other.username, username) || other.username == username) &&
+            (identical(other.token, token) || other.token == token));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, username, token);
+
+  /// Create a copy of GitCredentials
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GitCredentials_TokenImplCopyWith<_$GitCredentials_TokenImpl> get copyWith =>
+      __$$GitCredentials_TokenImplCopyWithImpl<_$GitCredentials_TokenImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String username, String password) userPassword,
+    required TResult Function(String? username, String token) token,
+    required TResult Function(String? username, String privateKeyPath, String? publicKeyPath, String? passphrase) sshKeyFile,
+    required TResult Function(String? username, String privateKey, String? publicKey, String? passphrase) sshKeyMemory,
+    required TResult Function(String? username) sshAgent,
+  }) {
+    return token(username, token);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String username, String password)? userPassword,
+    TResult? Function(String? username, String token)? token,
+    TResult? Function(String? username, String privateKeyPath, String? publicKeyPath, String? passphrase)? sshKeyFile,
+    TResult? Function(String? username, String privateKey, String? publicKey, String? passphrase)? sshKeyMemory,
+    TResult? Function(String? username)? sshAgent,
+  }) {
+    return token?.call(username, token);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String username, String password)? userPassword,
+    TResult Function(String? username, String token)? token,
+    TResult Function(String? username, String privateKeyPath, String? publicKeyPath, String? passphrase)? sshKeyFile,
+    TResult Function(String? username, String privateKey, String? publicKey, String? passphrase)? sshKeyMemory,
+    TResult Function(String? username)? sshAgent,
+    required TResult orElse(),
+  }) {
+    if (token != null) {
+      return token(username, token);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GitCredentials_UserPassword value) userPassword,
+    required TResult Function(GitCredentials_Token value) token,
+    required TResult Function(GitCredentials_SshKeyFile value) sshKeyFile,
+    required TResult Function(GitCredentials_SshKeyMemory value) sshKeyMemory,
+    required TResult Function(GitCredentials_SshAgent value) sshAgent,
+  }) {
+    return token(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GitCredentials_UserPassword value)? userPassword,
+    TResult? Function(GitCredentials_Token value)? token,
+    TResult? Function(GitCredentials_SshKeyFile value)? sshKeyFile,
+    TResult? Function(GitCredentials_SshKeyMemory value)? sshKeyMemory,
+    TResult? Function(GitCredentials_SshAgent value)? sshAgent,
+  }) {
+    return token?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GitCredentials_UserPassword value)? userPassword,
+    TResult Function(GitCredentials_Token value)? token,
+    TResult Function(GitCredentials_SshKeyFile value)? sshKeyFile,
+    TResult Function(GitCredentials_SshKeyMemory value)? sshKeyMemory,
+    TResult Function(GitCredentials_SshAgent value)? sshAgent,
+    required TResult orElse(),
+  }) {
+    if (token != null) {
+      return token(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GitCredentials_Token extends GitCredentials {
+  const factory GitCredentials_Token({
+    final String? username,
+    required final String token,
+  }) = _$GitCredentials_TokenImpl;
+  const GitCredentials_Token._() : super._();
+
+  String? get username;
+
+  String get token;
+
+  /// Create a copy of GitCredentials
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GitCredentials_TokenImplCopyWith<_$GitCredentials_TokenImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$GitCredentials_SshKeyFileImplCopyWith<$Res> {
+  factory _$$GitCredentials_SshKeyFileImplCopyWith(
+    _$GitCredentials_SshKeyFileImpl value,
+    $Res Function(_$GitCredentials_SshKeyFileImpl) then,
+  ) = __$$GitCredentials_SshKeyFileImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String? username, String privateKeyPath, String? publicKeyPath, String? passphrase});
+}
+
+/// @nodoc
+class __$$GitCredentials_SshKeyFileImplCopyWithImpl<$Res>
+    extends _$GitCredentialsCopyWithImpl<$Res, _$GitCredentials_SshKeyFileImpl>
+    implements _$$GitCredentials_SshKeyFileImplCopyWith<$Res> {
+  __$$GitCredentials_SshKeyFileImplCopyWithImpl(
+    _$GitCredentials_SshKeyFileImpl _value,
+    $Res Function(_$GitCredentials_SshKeyFileImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of GitCredentials
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? username = freezed, Object? privateKeyPath = null, Object? publicKeyPath = freezed, Object? passphrase = freezed}) {
+    return _then(
+      _$GitCredentials_SshKeyFileImpl(
+        username: freezed == username
+            ? _value.username
+            : username // ignore: cast_nullable_to_non_nullable
+                  as String?,
+        privateKeyPath: null == privateKeyPath
+            ? _value.privateKeyPath
+            : privateKeyPath // ignore: cast_nullable_to_non_nullable
+                  as String,
+        publicKeyPath: freezed == publicKeyPath
+            ? _value.publicKeyPath
+            : publicKeyPath // ignore: cast_nullable_to_non_nullable
+                  as String?,
+        passphrase: freezed == passphrase
+            ? _value.passphrase
+            : passphrase // ignore: cast_nullable_to_non_nullable
+                  as String?,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$GitCredentials_SshKeyFileImpl extends GitCredentials_SshKeyFile {
+  const _$GitCredentials_SshKeyFileImpl({this.username, required this.privateKeyPath, this.publicKeyPath, this.passphrase}) : super._();
+
+  @override
+  final String? username;
+  @override
+  final String privateKeyPath;
+  @override
+  final String? publicKeyPath;
+  @override
+  final String? passphrase;
+
+  @override
+  String toString() {
+    return 'GitCredentials.sshKeyFile(username: $username, privateKeyPath: $privateKeyPath, publicKeyPath: $publicKeyPath, passphrase: $passphrase)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GitCredentials_SshKeyFileImpl &&
+            (identical(other.username, username) || other.username == username) &&
+            (identical(other.privateKeyPath, privateKeyPath) || other.privateKeyPath == privateKeyPath) &&
+            (identical(other.publicKeyPath, publicKeyPath) || other.publicKeyPath == publicKeyPath) &&
+            (identical(other.passphrase, passphrase) || other.passphrase == passphrase));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, username, privateKeyPath, publicKeyPath, passphrase);
+
+  /// Create a copy of GitCredentials
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GitCredentials_SshKeyFileImplCopyWith<_$GitCredentials_SshKeyFileImpl> get copyWith =>
+      __$$GitCredentials_SshKeyFileImplCopyWithImpl<_$GitCredentials_SshKeyFileImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String username, String password) userPassword,
+    required TResult Function(String? username, String token) token,
+    required TResult Function(String? username, String privateKeyPath, String? publicKeyPath, String? passphrase) sshKeyFile,
+    required TResult Function(String? username, String privateKey, String? publicKey, String? passphrase) sshKeyMemory,
+    required TResult Function(String? username) sshAgent,
+  }) {
+    return sshKeyFile(username, privateKeyPath, publicKeyPath, passphrase);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String username, String password)? userPassword,
+    TResult? Function(String? username, String token)? token,
+    TResult? Function(String? username, String privateKeyPath, String? publicKeyPath, String? passphrase)? sshKeyFile,
+    TResult? Function(String? username, String privateKey, String? publicKey, String? passphrase)? sshKeyMemory,
+    TResult? Function(String? username)? sshAgent,
+  }) {
+    return sshKeyFile?.call(username, privateKeyPath, publicKeyPath, passphrase);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String username, String password)? userPassword,
+    TResult Function(String? username, String token)? token,
+    TResult Function(String? username, String privateKeyPath, String? publicKeyPath, String? passphrase)? sshKeyFile,
+    TResult Function(String? username, String privateKey, String? publicKey, String? passphrase)? sshKeyMemory,
+    TResult Function(String? username)? sshAgent,
+    required TResult orElse(),
+  }) {
+    if (sshKeyFile != null) {
+      return sshKeyFile(username, privateKeyPath, publicKeyPath, passphrase);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GitCredentials_UserPassword value) userPassword,
+    required TResult Function(GitCredentials_Token value) token,
+    required TResult Function(GitCredentials_SshKeyFile value) sshKeyFile,
+    required TResult Function(GitCredentials_SshKeyMemory value) sshKeyMemory,
+    required TResult Function(GitCredentials_SshAgent value) sshAgent,
+  }) {
+    return sshKeyFile(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GitCredentials_UserPassword value)? userPassword,
+    TResult? Function(GitCredentials_Token value)? token,
+    TResult? Function(GitCredentials_SshKeyFile value)? sshKeyFile,
+    TResult? Function(GitCredentials_SshKeyMemory value)? sshKeyMemory,
+    TResult? Function(GitCredentials_SshAgent value)? sshAgent,
+  }) {
+    return sshKeyFile?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GitCredentials_UserPassword value)? userPassword,
+    TResult Function(GitCredentials_Token value)? token,
+    TResult Function(GitCredentials_SshKeyFile value)? sshKeyFile,
+    TResult Function(GitCredentials_SshKeyMemory value)? sshKeyMemory,
+    TResult Function(GitCredentials_SshAgent value)? sshAgent,
+    required TResult orElse(),
+  }) {
+    if (sshKeyFile != null) {
+      return sshKeyFile(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GitCredentials_SshKeyFile extends GitCredentials {
+  const factory GitCredentials_SshKeyFile({
+    final String? username,
+    required final String privateKeyPath,
+    final String? publicKeyPath,
+    final String? passphrase,
+  }) = _$GitCredentials_SshKeyFileImpl;
+  const GitCredentials_SshKeyFile._() : super._();
+
+  String? get username;
+
+  String get privateKeyPath;
+
+  String? get publicKeyPath;
+
+  String? get passphrase;
+
+  /// Create a copy of GitCredentials
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GitCredentials_SshKeyFileImplCopyWith<_$GitCredentials_SshKeyFileImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$GitCredentials_SshKeyMemoryImplCopyWith<$Res> {
+  factory _$$GitCredentials_SshKeyMemoryImplCopyWith(
+    _$GitCredentials_SshKeyMemoryImpl value,
+    $Res Function(_$GitCredentials_SshKeyMemoryImpl) then,
+  ) = __$$GitCredentials_SshKeyMemoryImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String? username, String privateKey, String? publicKey, String? passphrase});
+}
+
+/// @nodoc
+class __$$GitCredentials_SshKeyMemoryImplCopyWithImpl<$Res>
+    extends _$GitCredentialsCopyWithImpl<$Res, _$GitCredentials_SshKeyMemoryImpl>
+    implements _$$GitCredentials_SshKeyMemoryImplCopyWith<$Res> {
+  __$$GitCredentials_SshKeyMemoryImplCopyWithImpl(
+    _$GitCredentials_SshKeyMemoryImpl _value,
+    $Res Function(_$GitCredentials_SshKeyMemoryImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of GitCredentials
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? username = freezed, Object? privateKey = null, Object? publicKey = freezed, Object? passphrase = freezed}) {
+    return _then(
+      _$GitCredentials_SshKeyMemoryImpl(
+        username: freezed == username
+            ? _value.username
+            : username // ignore: cast_nullable_to_non_nullable
+                  as String?,
+        privateKey: null == privateKey
+            ? _value.privateKey
+            : privateKey // ignore: cast_nullable_to_non_nullable
+                  as String,
+        publicKey: freezed == publicKey
+            ? _value.publicKey
+            : publicKey // ignore: cast_nullable_to_non_nullable
+                  as String?,
+        passphrase: freezed == passphrase
+            ? _value.passphrase
+            : passphrase // ignore: cast_nullable_to_non_nullable
+                  as String?,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$GitCredentials_SshKeyMemoryImpl extends GitCredentials_SshKeyMemory {
+  const _$GitCredentials_SshKeyMemoryImpl({this.username, required this.privateKey, this.publicKey, this.passphrase}) : super._();
+
+  @override
+  final String? username;
+  @override
+  final String privateKey;
+  @override
+  final String? publicKey;
+  @override
+  final String? passphrase;
+
+  @override
+  String toString() {
+    return 'GitCredentials.sshKeyMemory(username: $username, privateKey: $privateKey, publicKey: $publicKey, passphrase: $passphrase)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GitCredentials_SshKeyMemoryImpl &&
+            (identical(other.username, username) || other.username == username) &&
+            (identical(other.privateKey, privateKey) || other.privateKey == privateKey) &&
+            (identical(other.publicKey, publicKey) || other.publicKey == publicKey) &&
+            (identical(other.passphrase, passphrase) || other.passphrase == passphrase));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, username, privateKey, publicKey, passphrase);
+
+  /// Create a copy of GitCredentials
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GitCredentials_SshKeyMemoryImplCopyWith<_$GitCredentials_SshKeyMemoryImpl> get copyWith =>
+      __$$GitCredentials_SshKeyMemoryImplCopyWithImpl<_$GitCredentials_SshKeyMemoryImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String username, String password) userPassword,
+    required TResult Function(String? username, String token) token,
+    required TResult Function(String? username, String privateKeyPath, String? publicKeyPath, String? passphrase) sshKeyFile,
+    required TResult Function(String? username, String privateKey, String? publicKey, String? passphrase) sshKeyMemory,
+    required TResult Function(String? username) sshAgent,
+  }) {
+    return sshKeyMemory(username, privateKey, publicKey, passphrase);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String username, String password)? userPassword,
+    TResult? Function(String? username, String token)? token,
+    TResult? Function(String? username, String privateKeyPath, String? publicKeyPath, String? passphrase)? sshKeyFile,
+    TResult? Function(String? username, String privateKey, String? publicKey, String? passphrase)? sshKeyMemory,
+    TResult? Function(String? username)? sshAgent,
+  }) {
+    return sshKeyMemory?.call(username, privateKey, publicKey, passphrase);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String username, String password)? userPassword,
+    TResult Function(String? username, String token)? token,
+    TResult Function(String? username, String privateKeyPath, String? publicKeyPath, String? passphrase)? sshKeyFile,
+    TResult Function(String? username, String privateKey, String? publicKey, String? passphrase)? sshKeyMemory,
+    TResult Function(String? username)? sshAgent,
+    required TResult orElse(),
+  }) {
+    if (sshKeyMemory != null) {
+      return sshKeyMemory(username, privateKey, publicKey, passphrase);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GitCredentials_UserPassword value) userPassword,
+    required TResult Function(GitCredentials_Token value) token,
+    required TResult Function(GitCredentials_SshKeyFile value) sshKeyFile,
+    required TResult Function(GitCredentials_SshKeyMemory value) sshKeyMemory,
+    required TResult Function(GitCredentials_SshAgent value) sshAgent,
+  }) {
+    return sshKeyMemory(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GitCredentials_UserPassword value)? userPassword,
+    TResult? Function(GitCredentials_Token value)? token,
+    TResult? Function(GitCredentials_SshKeyFile value)? sshKeyFile,
+    TResult? Function(GitCredentials_SshKeyMemory value)? sshKeyMemory,
+    TResult? Function(GitCredentials_SshAgent value)? sshAgent,
+  }) {
+    return sshKeyMemory?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GitCredentials_UserPassword value)? userPassword,
+    TResult Function(GitCredentials_Token value)? token,
+    TResult Function(GitCredentials_SshKeyFile value)? sshKeyFile,
+    TResult Function(GitCredentials_SshKeyMemory value)? sshKeyMemory,
+    TResult Function(GitCredentials_SshAgent value)? sshAgent,
+    required TResult orElse(),
+  }) {
+    if (sshKeyMemory != null) {
+      return sshKeyMemory(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GitCredentials_SshKeyMemory extends GitCredentials {
+  const factory GitCredentials_SshKeyMemory({
+    final String? username,
+    required final String privateKey,
+    final String? publicKey,
+    final String? passphrase,
+  }) = _$GitCredentials_SshKeyMemoryImpl;
+  const GitCredentials_SshKeyMemory._() : super._();
+
+  String? get username;
+
+  String get privateKey;
+
+  String? get publicKey;
+
+  String? get passphrase;
+
+  /// Create a copy of GitCredentials
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GitCredentials_SshKeyMemoryImplCopyWith<_$GitCredentials_SshKeyMemoryImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$GitCredentials_SshAgentImplCopyWith<$Res> {
+  factory _$$GitCredentials_SshAgentImplCopyWith(
+    _$GitCredentials_SshAgentImpl value,
+    $Res Function(_$GitCredentials_SshAgentImpl) then,
+  ) = __$$GitCredentials_SshAgentImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String? username});
+}
+
+/// @nodoc
+class __$$GitCredentials_SshAgentImplCopyWithImpl<$Res>
+    extends _$GitCredentialsCopyWithImpl<$Res, _$GitCredentials_SshAgentImpl>
+    implements _$$GitCredentials_SshAgentImplCopyWith<$Res> {
+  __$$GitCredentials_SshAgentImplCopyWithImpl(
+    _$GitCredentials_SshAgentImpl _value,
+    $Res Function(_$GitCredentials_SshAgentImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of GitCredentials
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? username = freezed}) {
+    return _then(
+      _$GitCredentials_SshAgentImpl(
+        username: freezed == username
+            ? _value.username
+            : username // ignore: cast_nullable_to_non_nullable
+                  as String?,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$GitCredentials_SshAgentImpl extends GitCredentials_SshAgent {
+  const _$GitCredentials_SshAgentImpl({this.username}) : super._();
+
+  @override
+  final String? username;
+
+  @override
+  String toString() {
+    return 'GitCredentials.sshAgent(username: $username)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GitCredentials_SshAgentImpl &&
+            (identical(other.username, username) || other.username == username));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, username);
+
+  /// Create a copy of GitCredentials
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GitCredentials_SshAgentImplCopyWith<_$GitCredentials_SshAgentImpl> get copyWith =>
+      __$$GitCredentials_SshAgentImplCopyWithImpl<_$GitCredentials_SshAgentImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String username, String password) userPassword,
+    required TResult Function(String? username, String token) token,
+    required TResult Function(String? username, String privateKeyPath, String? publicKeyPath, String? passphrase) sshKeyFile,
+    required TResult Function(String? username, String privateKey, String? publicKey, String? passphrase) sshKeyMemory,
+    required TResult Function(String? username) sshAgent,
+  }) {
+    return sshAgent(username);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String username, String password)? userPassword,
+    TResult? Function(String? username, String token)? token,
+    TResult? Function(String? username, String privateKeyPath, String? publicKeyPath, String? passphrase)? sshKeyFile,
+    TResult? Function(String? username, String privateKey, String? publicKey, String? passphrase)? sshKeyMemory,
+    TResult? Function(String? username)? sshAgent,
+  }) {
+    return sshAgent?.call(username);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String username, String password)? userPassword,
+    TResult Function(String? username, String token)? token,
+    TResult Function(String? username, String privateKeyPath, String? publicKeyPath, String? passphrase)? sshKeyFile,
+    TResult Function(String? username, String privateKey, String? publicKey, String? passphrase)? sshKeyMemory,
+    TResult Function(String? username)? sshAgent,
+    required TResult orElse(),
+  }) {
+    if (sshAgent != null) {
+      return sshAgent(username);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GitCredentials_UserPassword value) userPassword,
+    required TResult Function(GitCredentials_Token value) token,
+    required TResult Function(GitCredentials_SshKeyFile value) sshKeyFile,
+    required TResult Function(GitCredentials_SshKeyMemory value) sshKeyMemory,
+    required TResult Function(GitCredentials_SshAgent value) sshAgent,
+  }) {
+    return sshAgent(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GitCredentials_UserPassword value)? userPassword,
+    TResult? Function(GitCredentials_Token value)? token,
+    TResult? Function(GitCredentials_SshKeyFile value)? sshKeyFile,
+    TResult? Function(GitCredentials_SshKeyMemory value)? sshKeyMemory,
+    TResult? Function(GitCredentials_SshAgent value)? sshAgent,
+  }) {
+    return sshAgent?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GitCredentials_UserPassword value)? userPassword,
+    TResult Function(GitCredentials_Token value)? token,
+    TResult Function(GitCredentials_SshKeyFile value)? sshKeyFile,
+    TResult Function(GitCredentials_SshKeyMemory value)? sshKeyMemory,
+    TResult Function(GitCredentials_SshAgent value)? sshAgent,
+    required TResult orElse(),
+  }) {
+    if (sshAgent != null) {
+      return sshAgent(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GitCredentials_SshAgent extends GitCredentials {
+  const factory GitCredentials_SshAgent({
+    final String? username,
+  }) = _$GitCredentials_SshAgentImpl;
+  const GitCredentials_SshAgent._() : super._();
+
+  String? get username;
+
+  /// Create a copy of GitCredentials
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GitCredentials_SshAgentImplCopyWith<_$GitCredentials_SshAgentImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$GitError {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String field0) io,
+    required TResult Function(String field0) git,
+    required TResult Function(String field0) authenticationFailed,
+    required TResult Function(String field0) repositoryNotFound,
+    required TResult Function(String field0) notFound,
+    required TResult Function(String field0) networkUnreachable,
+    required TResult Function(String field0) locked,
+    required TResult Function(String field0) detachedHead,
+    required TResult Function(List<String> field0) mergeConflict,
+    required TResult Function(List<String> field0) stashConflict,
+    required TResult Function(String field0) nonFastForward,
+    required TResult Function(String host, CertificateError reason) certificateInvalid,
+    required TResult Function(HostKey field0) unknownHostKey,
+    required TResult Function(HostKey field0) hostKeyMismatch,
+    required TResult Function() cancelled,
+    required TResult Function(String field0) unbornBranch,
+    required TResult Function(String field0) other,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String field0)? io,
+    TResult? Function(String field0)? git,
+    TResult? Function(String field0)? authenticationFailed,
+    TResult? Function(String field0)? repositoryNotFound,
+    TResult? Function(String field0)? notFound,
+    TResult? Function(String field0)? networkUnreachable,
+    TResult? Function(String field0)? locked,
+    TResult? Function(String field0)? detachedHead,
+    TResult? Function(List<String> field0)? mergeConflict,
+    TResult? Function(List<String> field0)? stashConflict,
+    TResult? Function(String field0)? nonFastForward,
+    TResult? Function(String host, CertificateError reason)? certificateInvalid,
+    TResult? Function(HostKey field0)? unknownHostKey,
+    TResult? Function(HostKey field0)? hostKeyMismatch,
+    TResult? Function()? cancelled,
+    TResult? Function(String field0)? unbornBranch,
+    TResult? Function(String field0)? other,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String field0)? io,
+    TResult Function(String field0)? git,
+    TResult Function(String field0)? authenticationFailed,
+    TResult Function(String field0)? repositoryNotFound,
+    TResult Function(String field0)? notFound,
+    TResult Function(String field0)? networkUnreachable,
+    TResult Function(String field0)? locked,
+    TResult Function(String field0)? detachedHead,
+    TResult Function(List<String> field0)? mergeConflict,
+    TResult Function(List<String> field0)? stashConflict,
+    TResult Function(String field0)? nonFastForward,
+    TResult Function(String host, CertificateError reason)? certificateInvalid,
+    TResult Function(HostKey field0)? unknownHostKey,
+    TResult Function(HostKey field0)? hostKeyMismatch,
+    TResult Function()? cancelled,
+    TResult Function(String field0)? unbornBranch,
+    TResult Function(String field0)? other,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GitError_Io value) io,
+    required TResult Function(GitError_Git value) git,
+    required TResult Function(GitError_AuthenticationFailed value) authenticationFailed,
+    required TResult Function(GitError_RepositoryNotFound value) repositoryNotFound,
+    required TResult Function(GitError_NotFound value) notFound,
+    required TResult Function(GitError_NetworkUnreachable value) networkUnreachable,
+    required TResult Function(GitError_Locked value) locked,
+    required TResult Function(GitError_DetachedHead value) detachedHead,
+    required TResult Function(GitError_MergeConflict value) mergeConflict,
+    required TResult Function(GitError_StashConflict value) stashConflict,
+    required TResult Function(GitError_NonFastForward value) nonFastForward,
+    required TResult Function(GitError_CertificateInvalid value) certificateInvalid,
+    required TResult Function(GitError_UnknownHostKey value) unknownHostKey,
+    required TResult Function(GitError_HostKeyMismatch value) hostKeyMismatch,
+    required TResult Function(GitError_Cancelled value) cancelled,
+    required TResult Function(GitError_UnbornBranch value) unbornBranch,
+    required TResult Function(GitError_Other value) other,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GitError_Io value)? io,
+    TResult? Function(GitError_Git value)? git,
+    TResult? Function(GitError_AuthenticationFailed value)? authenticationFailed,
+    TResult? Function(GitError_RepositoryNotFound value)? repositoryNotFound,
+    TResult? Function(GitError_NotFound value)? notFound,
+    TResult? Function(GitError_NetworkUnreachable value)? networkUnreachable,
+    TResult? Function(GitError_Locked value)? locked,
+    TResult? Function(GitError_DetachedHead value)? detachedHead,
+    TResult? Function(GitError_MergeConflict value)? mergeConflict,
+    TResult? Function(GitError_StashConflict value)? stashConflict,
+    TResult? Function(GitError_NonFastForward value)? nonFastForward,
+    TResult? Function(GitError_CertificateInvalid value)? certificateInvalid,
+    TResult? Function(GitError_UnknownHostKey value)? unknownHostKey,
+    TResult? Function(GitError_HostKeyMismatch value)? hostKeyMismatch,
+    TResult? Function(GitError_Cancelled value)? cancelled,
+    TResult? Function(GitError_UnbornBranch value)? unbornBranch,
+    TResult? Function(GitError_Other value)? other,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GitError_Io value)? io,
+    TResult Function(GitError_Git value)? git,
+    TResult Function(GitError_AuthenticationFailed value)? authenticationFailed,
+    TResult Function(GitError_RepositoryNotFound value)? repositoryNotFound,
+    TResult Function(GitError_NotFound value)? notFound,
+    TResult Function(GitError_NetworkUnreachable value)? networkUnreachable,
+    TResult Function(GitError_Locked value)? locked,
+    TResult Function(GitError_DetachedHead value)? detachedHead,
+    TResult Function(GitError_MergeConflict value)? mergeConflict,
+    TResult Function(GitError_StashConflict value)? stashConflict,
+    TResult Function(GitError_NonFastForward value)? nonFastForward,
+    TResult Function(GitError_CertificateInvalid value)? certificateInvalid,
+    TResult Function(GitError_UnknownHostKey value)? unknownHostKey,
+    TResult Function(GitError_HostKeyMismatch value)? hostKeyMismatch,
+    TResult Function(GitError_Cancelled value)? cancelled,
+    TResult Function(GitError_UnbornBranch value)? unbornBranch,
+    TResult Function(GitError_Other value)? other,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $GitErrorCopyWith<$Res> {
+  factory $GitErrorCopyWith(GitError value, $Res Function(GitError) then) =
+      _$GitErrorCopyWithImpl<$Res, GitError>;
+}
+
+/// @nodoc
+class _$GitErrorCopyWithImpl<$Res, $Val extends GitError>
+    implements $GitErrorCopyWith<$Res> {
+  _$GitErrorCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$GitError_IoImplCopyWith<$Res> {
+  factory _$$GitError_IoImplCopyWith(
+    _$GitError_IoImpl value,
+    $Res Function(_$GitError_IoImpl) then,
+  ) = __$$GitError_IoImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class __$$GitError_IoImplCopyWithImpl<$Res>
+    extends _$GitErrorCopyWithImpl<$Res, _$GitError_IoImpl>
+    implements _$$GitError_IoImplCopyWith<$Res> {
+  __$$GitError_IoImplCopyWithImpl(
+    _$GitError_IoImpl _value,
+    $Res Function(_$GitError_IoImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field0 = null}) {
+    return _then(
+      _$GitError_IoImpl(
+        null == field0
+            ? _value.field0
+            : field0 // ignore: cast_nullable_to_non_nullable
+                  as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$GitError_IoImpl extends GitError_Io {
+  const _$GitError_IoImpl(this.field0) : super._();
+
+  @override
+  final String field0;
+
+  @override
+  String toString() {
+    return 'GitError.io(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GitError_IoImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GitError_IoImplCopyWith<_$GitError_IoImpl> get copyWith =>
+      __$$GitError_IoImplCopyWithImpl<_$GitError_IoImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String field0) io,
+    required TResult Function(String field0) git,
+    required TResult Function(String field0) authenticationFailed,
+    required TResult Function(String field0) repositoryNotFound,
+    required TResult Function(String field0) notFound,
+    required TResult Function(String field0) networkUnreachable,
+    required TResult Function(String field0) locked,
+    required TResult Function(String field0) detachedHead,
+    required TResult Function(List<String> field0) mergeConflict,
+    required TResult Function(List<String> field0) stashConflict,
+    required TResult Function(String field0) nonFastForward,
+    required TResult Function(String host, CertificateError reason) certificateInvalid,
+    required TResult Function(HostKey field0) unknownHostKey,
+    required TResult Function(HostKey field0) hostKeyMismatch,
+    required TResult Function() cancelled,
+    required TResult Function(String field0) unbornBranch,
+    required TResult Function(String field0) other,
+  }) {
+    return io(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String field0)? io,
+    TResult? Function(String field0)? git,
+    TResult? Function(String field0)? authenticationFailed,
+    TResult? Function(String field0)? repositoryNotFound,
+    TResult? Function(String field0)? notFound,
+    TResult? Function(String field0)? networkUnreachable,
+    TResult? Function(String field0)? locked,
+    TResult? Function(String field0)? detachedHead,
+    TResult? Function(List<String> field0)? mergeConflict,
+    TResult? Function(List<String> field0)? stashConflict,
+    TResult? Function(String field0)? nonFastForward,
+    TResult? Function(String host, CertificateError reason)? certificateInvalid,
+    TResult? Function(HostKey field0)? unknownHostKey,
+    TResult? Function(HostKey field0)? hostKeyMismatch,
+    TResult? Function()? cancelled,
+    TResult? Function(String field0)? unbornBranch,
+    TResult? Function(String field0)? other,
+  }) {
+    return io?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String field0)? io,
+    TResult Function(String field0)? git,
+    TResult Function(String field0)? authenticationFailed,
+    TResult Function(String field0)? repositoryNotFound,
+    TResult Function(String field0)? notFound,
+    TResult Function(String field0)? networkUnreachable,
+    TResult Function(String field0)? locked,
+    TResult Function(String field0)? detachedHead,
+    TResult Function(List<String> field0)? mergeConflict,
+    TResult Function(List<String> field0)? stashConflict,
+    TResult Function(String field0)? nonFastForward,
+    TResult Function(String host, CertificateError reason)? certificateInvalid,
+    TResult Function(HostKey field0)? unknownHostKey,
+    TResult Function(HostKey field0)? hostKeyMismatch,
+    TResult Function()? cancelled,
+    TResult Function(String field0)? unbornBranch,
+    TResult Function(String field0)? other,
+    required TResult orElse(),
+  }) {
+    if (io != null) {
+      return io(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GitError_Io value) io,
+    required TResult Function(GitError_Git value) git,
+    required TResult Function(GitError_AuthenticationFailed value) authenticationFailed,
+    required TResult Function(GitError_RepositoryNotFound value) repositoryNotFound,
+    required TResult Function(GitError_NotFound value) notFound,
+    required TResult Function(GitError_NetworkUnreachable value) networkUnreachable,
+    required TResult Function(GitError_Locked value) locked,
+    required TResult Function(GitError_DetachedHead value) detachedHead,
+    required TResult Function(GitError_MergeConflict value) mergeConflict,
+    required TResult Function(GitError_StashConflict value) stashConflict,
+    required TResult Function(GitError_NonFastForward value) nonFastForward,
+    required TResult Function(GitError_CertificateInvalid value) certificateInvalid,
+    required TResult Function(GitError_UnknownHostKey value) unknownHostKey,
+    required TResult Function(GitError_HostKeyMismatch value) hostKeyMismatch,
+    required TResult Function(GitError_Cancelled value) cancelled,
+    required TResult Function(GitError_UnbornBranch value) unbornBranch,
+    required TResult Function(GitError_Other value) other,
+  }) {
+    return io(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GitError_Io value)? io,
+    TResult? Function(GitError_Git value)? git,
+    TResult? Function(GitError_AuthenticationFailed value)? authenticationFailed,
+    TResult? Function(GitError_RepositoryNotFound value)? repositoryNotFound,
+    TResult? Function(GitError_NotFound value)? notFound,
+    TResult? Function(GitError_NetworkUnreachable value)? networkUnreachable,
+    TResult? Function(GitError_Locked value)? locked,
+    TResult? Function(GitError_DetachedHead value)? detachedHead,
+    TResult? Function(GitError_MergeConflict value)? mergeConflict,
+    TResult? Function(GitError_StashConflict value)? stashConflict,
+    TResult? Function(GitError_NonFastForward value)? nonFastForward,
+    TResult? Function(GitError_CertificateInvalid value)? certificateInvalid,
+    TResult? Function(GitError_UnknownHostKey value)? unknownHostKey,
+    TResult? Function(GitError_HostKeyMismatch value)? hostKeyMismatch,
+    TResult? Function(GitError_Cancelled value)? cancelled,
+    TResult? Function(GitError_UnbornBranch value)? unbornBranch,
+    TResult? Function(GitError_Other value)? other,
+  }) {
+    return io?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GitError_Io value)? io,
+    TResult Function(GitError_Git value)? git,
+    TResult Function(GitError_AuthenticationFailed value)? authenticationFailed,
+    TResult Function(GitError_RepositoryNotFound value)? repositoryNotFound,
+    TResult Function(GitError_NotFound value)? notFound,
+    TResult Function(GitError_NetworkUnreachable value)? networkUnreachable,
+    TResult Function(GitError_Locked value)? locked,
+    TResult Function(GitError_DetachedHead value)? detachedHead,
+    TResult Function(GitError_MergeConflict value)? mergeConflict,
+    TResult Function(GitError_StashConflict value)? stashConflict,
+    TResult Function(GitError_NonFastForward value)? nonFastForward,
+    TResult Function(GitError_CertificateInvalid value)? certificateInvalid,
+    TResult Function(GitError_UnknownHostKey value)? unknownHostKey,
+    TResult Function(GitError_HostKeyMismatch value)? hostKeyMismatch,
+    TResult Function(GitError_Cancelled value)? cancelled,
+    TResult Function(GitError_UnbornBranch value)? unbornBranch,
+    TResult Function(GitError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (io != null) {
+      return io(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GitError_Io extends GitError {
+  const factory GitError_Io(final String field0) = _$GitError_IoImpl;
+  const GitError_Io._() : super._();
+
+  String get field0;
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GitError_IoImplCopyWith<_$GitError_IoImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$GitError_GitImplCopyWith<$Res> {
+  factory _$$GitError_GitImplCopyWith(
+    _$GitError_GitImpl value,
+    $Res Function(_$GitError_GitImpl) then,
+  ) = __$$GitError_GitImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class __$$GitError_GitImplCopyWithImpl<$Res>
+    extends _$GitErrorCopyWithImpl<$Res, _$GitError_GitImpl>
+    implements _$$GitError_GitImplCopyWith<$Res> {
+  __$$GitError_GitImplCopyWithImpl(
+    _$GitError_GitImpl _value,
+    $Res Function(_$GitError_GitImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field0 = null}) {
+    return _then(
+      _$GitError_GitImpl(
+        null == field0
+            ? _value.field0
+            : field0 // ignore: cast_nullable_to_non_nullable
+                  as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$GitError_GitImpl extends GitError_Git {
+  const _$GitError_GitImpl(this.field0) : super._();
+
+  @override
+  final String field0;
+
+  @override
+  String toString() {
+    return 'GitError.git(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GitError_GitImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GitError_GitImplCopyWith<_$GitError_GitImpl> get copyWith =>
+      __$$GitError_GitImplCopyWithImpl<_$GitError_GitImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String field0) io,
+    required TResult Function(String field0) git,
+    required TResult Function(String field0) authenticationFailed,
+    required TResult Function(String field0) repositoryNotFound,
+    required TResult Function(String field0) notFound,
+    required TResult Function(String field0) networkUnreachable,
+    required TResult Function(String field0) locked,
+    required TResult Function(String field0) detachedHead,
+    required TResult Function(List<String> field0) mergeConflict,
+    required TResult Function(List<String> field0) stashConflict,
+    required TResult Function(String field0) nonFastForward,
+    required TResult Function(String host, CertificateError reason) certificateInvalid,
+    required TResult Function(HostKey field0) unknownHostKey,
+    required TResult Function(HostKey field0) hostKeyMismatch,
+    required TResult Function() cancelled,
+    required TResult Function(String field0) unbornBranch,
+    required TResult Function(String field0) other,
+  }) {
+    return git(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String field0)? io,
+    TResult? Function(String field0)? git,
+    TResult? Function(String field0)? authenticationFailed,
+    TResult? Function(String field0)? repositoryNotFound,
+    TResult? Function(String field0)? notFound,
+    TResult? Function(String field0)? networkUnreachable,
+    TResult? Function(String field0)? locked,
+    TResult? Function(String field0)? detachedHead,
+    TResult? Function(List<String> field0)? mergeConflict,
+    TResult? Function(List<String> field0)? stashConflict,
+    TResult? Function(String field0)? nonFastForward,
+    TResult? Function(String host, CertificateError reason)? certificateInvalid,
+    TResult? Function(HostKey field0)? unknownHostKey,
+    TResult? Function(HostKey field0)? hostKeyMismatch,
+    TResult? Function()? cancelled,
+    TResult? Function(String field0)? unbornBranch,
+    TResult? Function(String field0)? other,
+  }) {
+    return git?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String field0)? io,
+    TResult Function(String field0)? git,
+    TResult Function(String field0)? authenticationFailed,
+    TResult Function(String field0)? repositoryNotFound,
+    TResult Function(String field0)? notFound,
+    TResult Function(String field0)? networkUnreachable,
+    TResult Function(String field0)? locked,
+    TResult Function(String field0)? detachedHead,
+    TResult Function(List<String> field0)? mergeConflict,
+    TResult Function(List<String> field0)? stashConflict,
+    TResult Function(String field0)? nonFastForward,
+    TResult Function(String host, CertificateError reason)? certificateInvalid,
+    TResult Function(HostKey field0)? unknownHostKey,
+    TResult Function(HostKey field0)? hostKeyMismatch,
+    TResult Function()? cancelled,
+    TResult Function(String field0)? unbornBranch,
+    TResult Function(String field0)? other,
+    required TResult orElse(),
+  }) {
+    if (git != null) {
+      return git(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GitError_Io value) io,
+    required TResult Function(GitError_Git value) git,
+    required TResult Function(GitError_AuthenticationFailed value) authenticationFailed,
+    required TResult Function(GitError_RepositoryNotFound value) repositoryNotFound,
+    required TResult Function(GitError_NotFound value) notFound,
+    required TResult Function(GitError_NetworkUnreachable value) networkUnreachable,
+    required TResult Function(GitError_Locked value) locked,
+    required TResult Function(GitError_DetachedHead value) detachedHead,
+    required TResult Function(GitError_MergeConflict value) mergeConflict,
+    required TResult Function(GitError_StashConflict value) stashConflict,
+    required TResult Function(GitError_NonFastForward value) nonFastForward,
+    required TResult Function(GitError_CertificateInvalid value) certificateInvalid,
+    required TResult Function(GitError_UnknownHostKey value) unknownHostKey,
+    required TResult Function(GitError_HostKeyMismatch value) hostKeyMismatch,
+    required TResult Function(GitError_Cancelled value) cancelled,
+    required TResult Function(GitError_UnbornBranch value) unbornBranch,
+    required TResult Function(GitError_Other value) other,
+  }) {
+    return git(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GitError_Io value)? io,
+    TResult? Function(GitError_Git value)? git,
+    TResult? Function(GitError_AuthenticationFailed value)? authenticationFailed,
+    TResult? Function(GitError_RepositoryNotFound value)? repositoryNotFound,
+    TResult? Function(GitError_NotFound value)? notFound,
+    TResult? Function(GitError_NetworkUnreachable value)? networkUnreachable,
+    TResult? Function(GitError_Locked value)? locked,
+    TResult? Function(GitError_DetachedHead value)? detachedHead,
+    TResult? Function(GitError_MergeConflict value)? mergeConflict,
+    TResult? Function(GitError_StashConflict value)? stashConflict,
+    TResult? Function(GitError_NonFastForward value)? nonFastForward,
+    TResult? Function(GitError_CertificateInvalid value)? certificateInvalid,
+    TResult? Function(GitError_UnknownHostKey value)? unknownHostKey,
+    TResult? Function(GitError_HostKeyMismatch value)? hostKeyMismatch,
+    TResult? Function(GitError_Cancelled value)? cancelled,
+    TResult? Function(GitError_UnbornBranch value)? unbornBranch,
+    TResult? Function(GitError_Other value)? other,
+  }) {
+    return git?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GitError_Io value)? io,
+    TResult Function(GitError_Git value)? git,
+    TResult Function(GitError_AuthenticationFailed value)? authenticationFailed,
+    TResult Function(GitError_RepositoryNotFound value)? repositoryNotFound,
+    TResult Function(GitError_NotFound value)? notFound,
+    TResult Function(GitError_NetworkUnreachable value)? networkUnreachable,
+    TResult Function(GitError_Locked value)? locked,
+    TResult Function(GitError_DetachedHead value)? detachedHead,
+    TResult Function(GitError_MergeConflict value)? mergeConflict,
+    TResult Function(GitError_StashConflict value)? stashConflict,
+    TResult Function(GitError_NonFastForward value)? nonFastForward,
+    TResult Function(GitError_CertificateInvalid value)? certificateInvalid,
+    TResult Function(GitError_UnknownHostKey value)? unknownHostKey,
+    TResult Function(GitError_HostKeyMismatch value)? hostKeyMismatch,
+    TResult Function(GitError_Cancelled value)? cancelled,
+    TResult Function(GitError_UnbornBranch value)? unbornBranch,
+    TResult Function(GitError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (git != null) {
+      return git(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GitError_Git extends GitError {
+  const factory GitError_Git(final String field0) = _$GitError_GitImpl;
+  const GitError_Git._() : super._();
+
+  String get field0;
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GitError_GitImplCopyWith<_$GitError_GitImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$GitError_AuthenticationFailedImplCopyWith<$Res> {
+  factory _$$GitError_AuthenticationFailedImplCopyWith(
+    _$GitError_AuthenticationFailedImpl value,
+    $Res Function(_$GitError_AuthenticationFailedImpl) then,
+  ) = __$$GitError_AuthenticationFailedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class __$$GitError_AuthenticationFailedImplCopyWithImpl<$Res>
+    extends _$GitErrorCopyWithImpl<$Res, _$GitError_AuthenticationFailedImpl>
+    implements _$$GitError_AuthenticationFailedImplCopyWith<$Res> {
+  __$$GitError_AuthenticationFailedImplCopyWithImpl(
+    _$GitError_AuthenticationFailedImpl _value,
+    $Res Function(_$GitError_AuthenticationFailedImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field0 = null}) {
+    return _then(
+      _$GitError_AuthenticationFailedImpl(
+        null == field0
+            ? _value.field0
+            : field0 // ignore: cast_nullable_to_non_nullable
+                  as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$GitError_AuthenticationFailedImpl extends GitError_AuthenticationFailed {
+  const _$GitError_AuthenticationFailedImpl(this.field0) : super._();
+
+  @override
+  final String field0;
+
+  @override
+  String toString() {
+    return 'GitError.authenticationFailed(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GitError_AuthenticationFailedImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GitError_AuthenticationFailedImplCopyWith<_$GitError_AuthenticationFailedImpl> get copyWith =>
+      __$$GitError_AuthenticationFailedImplCopyWithImpl<_$GitError_AuthenticationFailedImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String field0) io,
+    required TResult Function(String field0) git,
+    required TResult Function(String field0) authenticationFailed,
+    required TResult Function(String field0) repositoryNotFound,
+    required TResult Function(String field0) notFound,
+    required TResult Function(String field0) networkUnreachable,
+    required TResult Function(String field0) locked,
+    required TResult Function(String field0) detachedHead,
+    required TResult Function(List<String> field0) mergeConflict,
+    required TResult Function(List<String> field0) stashConflict,
+    required TResult Function(String field0) nonFastForward,
+    required TResult Function(String host, CertificateError reason) certificateInvalid,
+    required TResult Function(HostKey field0) unknownHostKey,
+    required TResult Function(HostKey field0) hostKeyMismatch,
+    required TResult Function() cancelled,
+    required TResult Function(String field0) unbornBranch,
+    required TResult Function(String field0) other,
+  }) {
+    return authenticationFailed(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String field0)? io,
+    TResult? Function(String field0)? git,
+    TResult? Function(String field0)? authenticationFailed,
+    TResult? Function(String field0)? repositoryNotFound,
+    TResult? Function(String field0)? notFound,
+    TResult? Function(String field0)? networkUnreachable,
+    TResult? Function(String field0)? locked,
+    TResult? Function(String field0)? detachedHead,
+    TResult? Function(List<String> field0)? mergeConflict,
+    TResult? Function(List<String> field0)? stashConflict,
+    TResult? Function(String field0)? nonFastForward,
+    TResult? Function(String host, CertificateError reason)? certificateInvalid,
+    TResult? Function(HostKey field0)? unknownHostKey,
+    TResult? Function(HostKey field0)? hostKeyMismatch,
+    TResult? Function()? cancelled,
+    TResult? Function(String field0)? unbornBranch,
+    TResult? Function(String field0)? other,
+  }) {
+    return authenticationFailed?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String field0)? io,
+    TResult Function(String field0)? git,
+    TResult Function(String field0)? authenticationFailed,
+    TResult Function(String field0)? repositoryNotFound,
+    TResult Function(String field0)? notFound,
+    TResult Function(String field0)? networkUnreachable,
+    TResult Function(String field0)? locked,
+    TResult Function(String field0)? detachedHead,
+    TResult Function(List<String> field0)? mergeConflict,
+    TResult Function(List<String> field0)? stashConflict,
+    TResult Function(String field0)? nonFastForward,
+    TResult Function(String host, CertificateError reason)? certificateInvalid,
+    TResult Function(HostKey field0)? unknownHostKey,
+    TResult Function(HostKey field0)? hostKeyMismatch,
+    TResult Function()? cancelled,
+    TResult Function(String field0)? unbornBranch,
+    TResult Function(String field0)? other,
+    required TResult orElse(),
+  }) {
+    if (authenticationFailed != null) {
+      return authenticationFailed(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GitError_Io value) io,
+    required TResult Function(GitError_Git value) git,
+    required TResult Function(GitError_AuthenticationFailed value) authenticationFailed,
+    required TResult Function(GitError_RepositoryNotFound value) repositoryNotFound,
+    required TResult Function(GitError_NotFound value) notFound,
+    required TResult Function(GitError_NetworkUnreachable value) networkUnreachable,
+    required TResult Function(GitError_Locked value) locked,
+    required TResult Function(GitError_DetachedHead value) detachedHead,
+    required TResult Function(GitError_MergeConflict value) mergeConflict,
+    required TResult Function(GitError_StashConflict value) stashConflict,
+    required TResult Function(GitError_NonFastForward value) nonFastForward,
+    required TResult Function(GitError_CertificateInvalid value) certificateInvalid,
+    required TResult Function(GitError_UnknownHostKey value) unknownHostKey,
+    required TResult Function(GitError_HostKeyMismatch value) hostKeyMismatch,
+    required TResult Function(GitError_Cancelled value) cancelled,
+    required TResult Function(GitError_UnbornBranch value) unbornBranch,
+    required TResult Function(GitError_Other value) other,
+  }) {
+    return authenticationFailed(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GitError_Io value)? io,
+    TResult? Function(GitError_Git value)? git,
+    TResult? Function(GitError_AuthenticationFailed value)? authenticationFailed,
+    TResult? Function(GitError_RepositoryNotFound value)? repositoryNotFound,
+    TResult? Function(GitError_NotFound value)? notFound,
+    TResult? Function(GitError_NetworkUnreachable value)? networkUnreachable,
+    TResult? Function(GitError_Locked value)? locked,
+    TResult? Function(GitError_DetachedHead value)? detachedHead,
+    TResult? Function(GitError_MergeConflict value)? mergeConflict,
+    TResult? Function(GitError_StashConflict value)? stashConflict,
+    TResult? Function(GitError_NonFastForward value)? nonFastForward,
+    TResult? Function(GitError_CertificateInvalid value)? certificateInvalid,
+    TResult? Function(GitError_UnknownHostKey value)? unknownHostKey,
+    TResult? Function(GitError_HostKeyMismatch value)? hostKeyMismatch,
+    TResult? Function(GitError_Cancelled value)? cancelled,
+    TResult? Function(GitError_UnbornBranch value)? unbornBranch,
+    TResult? Function(GitError_Other value)? other,
+  }) {
+    return authenticationFailed?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GitError_Io value)? io,
+    TResult Function(GitError_Git value)? git,
+    TResult Function(GitError_AuthenticationFailed value)? authenticationFailed,
+    TResult Function(GitError_RepositoryNotFound value)? repositoryNotFound,
+    TResult Function(GitError_NotFound value)? notFound,
+    TResult Function(GitError_NetworkUnreachable value)? networkUnreachable,
+    TResult Function(GitError_Locked value)? locked,
+    TResult Function(GitError_DetachedHead value)? detachedHead,
+    TResult Function(GitError_MergeConflict value)? mergeConflict,
+    TResult Function(GitError_StashConflict value)? stashConflict,
+    TResult Function(GitError_NonFastForward value)? nonFastForward,
+    TResult Function(GitError_CertificateInvalid value)? certificateInvalid,
+    TResult Function(GitError_UnknownHostKey value)? unknownHostKey,
+    TResult Function(GitError_HostKeyMismatch value)? hostKeyMismatch,
+    TResult Function(GitError_Cancelled value)? cancelled,
+    TResult Function(GitError_UnbornBranch value)? unbornBranch,
+    TResult Function(GitError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (authenticationFailed != null) {
+      return authenticationFailed(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GitError_AuthenticationFailed extends GitError {
+  const factory GitError_AuthenticationFailed(final String field0) = _$GitError_AuthenticationFailedImpl;
+  const GitError_AuthenticationFailed._() : super._();
+
+  String get field0;
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GitError_AuthenticationFailedImplCopyWith<_$GitError_AuthenticationFailedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$GitError_RepositoryNotFoundImplCopyWith<$Res> {
+  factory _$$GitError_RepositoryNotFoundImplCopyWith(
+    _$GitError_RepositoryNotFoundImpl value,
+    $Res Function(_$GitError_RepositoryNotFoundImpl) then,
+  ) = __$$GitError_RepositoryNotFoundImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class __$$GitError_RepositoryNotFoundImplCopyWithImpl<$Res>
+    extends _$GitErrorCopyWithImpl<$Res, _$GitError_RepositoryNotFoundImpl>
+    implements _$$GitError_RepositoryNotFoundImplCopyWith<$Res> {
+  __$$GitError_RepositoryNotFoundImplCopyWithImpl(
+    _$GitError_RepositoryNotFoundImpl _value,
+    $Res Function(_$GitError_RepositoryNotFoundImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field0 = null}) {
+    return _then(
+      _$GitError_RepositoryNotFoundImpl(
+        null == field0
+            ? _value.field0
+            : field0 // ignore: cast_nullable_to_non_nullable
+                  as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$GitError_RepositoryNotFoundImpl extends GitError_RepositoryNotFound {
+  const _$GitError_RepositoryNotFoundImpl(this.field0) : super._();
+
+  @override
+  final String field0;
+
+  @override
+  String toString() {
+    return 'GitError.repositoryNotFound(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GitError_RepositoryNotFoundImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GitError_RepositoryNotFoundImplCopyWith<_$GitError_RepositoryNotFoundImpl> get copyWith =>
+      __$$GitError_RepositoryNotFoundImplCopyWithImpl<_$GitError_RepositoryNotFoundImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String field0) io,
+    required TResult Function(String field0) git,
+    required TResult Function(String field0) authenticationFailed,
+    required TResult Function(String field0) repositoryNotFound,
+    required TResult Function(String field0) notFound,
+    required TResult Function(String field0) networkUnreachable,
+    required TResult Function(String field0) locked,
+    required TResult Function(String field0) detachedHead,
+    required TResult Function(List<String> field0) mergeConflict,
+    required TResult Function(List<String> field0) stashConflict,
+    required TResult Function(String field0) nonFastForward,
+    required TResult Function(String host, CertificateError reason) certificateInvalid,
+    required TResult Function(HostKey field0) unknownHostKey,
+    required TResult Function(HostKey field0) hostKeyMismatch,
+    required TResult Function() cancelled,
+    required TResult Function(String field0) unbornBranch,
+    required TResult Function(String field0) other,
+  }) {
+    return repositoryNotFound(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String field0)? io,
+    TResult? Function(String field0)? git,
+    TResult? Function(String field0)? authenticationFailed,
+    TResult? Function(String field0)? repositoryNotFound,
+    TResult? Function(String field0)? notFound,
+    TResult? Function(String field0)? networkUnreachable,
+    TResult? Function(String field0)? locked,
+    TResult? Function(String field0)? detachedHead,
+    TResult? Function(List<String> field0)? mergeConflict,
+    TResult? Function(List<String> field0)? stashConflict,
+    TResult? Function(String field0)? nonFastForward,
+    TResult? Function(String host, CertificateError reason)? certificateInvalid,
+    TResult? Function(HostKey field0)? unknownHostKey,
+    TResult? Function(HostKey field0)? hostKeyMismatch,
+    TResult? Function()? cancelled,
+    TResult? Function(String field0)? unbornBranch,
+    TResult? Function(String field0)? other,
+  }) {
+    return repositoryNotFound?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String field0)? io,
+    TResult Function(String field0)? git,
+    TResult Function(String field0)? authenticationFailed,
+    TResult Function(String field0)? repositoryNotFound,
+    TResult Function(String field0)? notFound,
+    TResult Function(String field0)? networkUnreachable,
+    TResult Function(String field0)? locked,
+    TResult Function(String field0)? detachedHead,
+    TResult Function(List<String> field0)? mergeConflict,
+    TResult Function(List<String> field0)? stashConflict,
+    TResult Function(String field0)? nonFastForward,
+    TResult Function(String host, CertificateError reason)? certificateInvalid,
+    TResult Function(HostKey field0)? unknownHostKey,
+    TResult Function(HostKey field0)? hostKeyMismatch,
+    TResult Function()? cancelled,
+    TResult Function(String field0)? unbornBranch,
+    TResult Function(String field0)? other,
+    required TResult orElse(),
+  }) {
+    if (repositoryNotFound != null) {
+      return repositoryNotFound(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GitError_Io value) io,
+    required TResult Function(GitError_Git value) git,
+    required TResult Function(GitError_AuthenticationFailed value) authenticationFailed,
+    required TResult Function(GitError_RepositoryNotFound value) repositoryNotFound,
+    required TResult Function(GitError_NotFound value) notFound,
+    required TResult Function(GitError_NetworkUnreachable value) networkUnreachable,
+    required TResult Function(GitError_Locked value) locked,
+    required TResult Function(GitError_DetachedHead value) detachedHead,
+    required TResult Function(GitError_MergeConflict value) mergeConflict,
+    required TResult Function(GitError_StashConflict value) stashConflict,
+    required TResult Function(GitError_NonFastForward value) nonFastForward,
+    required TResult Function(GitError_CertificateInvalid value) certificateInvalid,
+    required TResult Function(GitError_UnknownHostKey value) unknownHostKey,
+    required TResult Function(GitError_HostKeyMismatch value) hostKeyMismatch,
+    required TResult Function(GitError_Cancelled value) cancelled,
+    required TResult Function(GitError_UnbornBranch value) unbornBranch,
+    required TResult Function(GitError_Other value) other,
+  }) {
+    return repositoryNotFound(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GitError_Io value)? io,
+    TResult? Function(GitError_Git value)? git,
+    TResult? Function(GitError_AuthenticationFailed value)? authenticationFailed,
+    TResult? Function(GitError_RepositoryNotFound value)? repositoryNotFound,
+    TResult? Function(GitError_NotFound value)? notFound,
+    TResult? Function(GitError_NetworkUnreachable value)? networkUnreachable,
+    TResult? Function(GitError_Locked value)? locked,
+    TResult? Function(GitError_DetachedHead value)? detachedHead,
+    TResult? Function(GitError_MergeConflict value)? mergeConflict,
+    TResult? Function(GitError_StashConflict value)? stashConflict,
+    TResult? Function(GitError_NonFastForward value)? nonFastForward,
+    TResult? Function(GitError_CertificateInvalid value)? certificateInvalid,
+    TResult? Function(GitError_UnknownHostKey value)? unknownHostKey,
+    TResult? Function(GitError_HostKeyMismatch value)? hostKeyMismatch,
+    TResult? Function(GitError_Cancelled value)? cancelled,
+    TResult? Function(GitError_UnbornBranch value)? unbornBranch,
+    TResult? Function(GitError_Other value)? other,
+  }) {
+    return repositoryNotFound?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GitError_Io value)? io,
+    TResult Function(GitError_Git value)? git,
+    TResult Function(GitError_AuthenticationFailed value)? authenticationFailed,
+    TResult Function(GitError_RepositoryNotFound value)? repositoryNotFound,
+    TResult Function(GitError_NotFound value)? notFound,
+    TResult Function(GitError_NetworkUnreachable value)? networkUnreachable,
+    TResult Function(GitError_Locked value)? locked,
+    TResult Function(GitError_DetachedHead value)? detachedHead,
+    TResult Function(GitError_MergeConflict value)? mergeConflict,
+    TResult Function(GitError_StashConflict value)? stashConflict,
+    TResult Function(GitError_NonFastForward value)? nonFastForward,
+    TResult Function(GitError_CertificateInvalid value)? certificateInvalid,
+    TResult Function(GitError_UnknownHostKey value)? unknownHostKey,
+    TResult Function(GitError_HostKeyMismatch value)? hostKeyMismatch,
+    TResult Function(GitError_Cancelled value)? cancelled,
+    TResult Function(GitError_UnbornBranch value)? unbornBranch,
+    TResult Function(GitError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (repositoryNotFound != null) {
+      return repositoryNotFound(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GitError_RepositoryNotFound extends GitError {
+  const factory GitError_RepositoryNotFound(final String field0) = _$GitError_RepositoryNotFoundImpl;
+  const GitError_RepositoryNotFound._() : super._();
+
+  String get field0;
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GitError_RepositoryNotFoundImplCopyWith<_$GitError_RepositoryNotFoundImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$GitError_NotFoundImplCopyWith<$Res> {
+  factory _$$GitError_NotFoundImplCopyWith(
+    _$GitError_NotFoundImpl value,
+    $Res Function(_$GitError_NotFoundImpl) then,
+  ) = __$$GitError_NotFoundImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class __$$GitError_NotFoundImplCopyWithImpl<$Res>
+    extends _$GitErrorCopyWithImpl<$Res, _$GitError_NotFoundImpl>
+    implements _$$GitError_NotFoundImplCopyWith<$Res> {
+  __$$GitError_NotFoundImplCopyWithImpl(
+    _$GitError_NotFoundImpl _value,
+    $Res Function(_$GitError_NotFoundImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field0 = null}) {
+    return _then(
+      _$GitError_NotFoundImpl(
+        null == field0
+            ? _value.field0
+            : field0 // ignore: cast_nullable_to_non_nullable
+                  as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$GitError_NotFoundImpl extends GitError_NotFound {
+  const _$GitError_NotFoundImpl(this.field0) : super._();
+
+  @override
+  final String field0;
+
+  @override
+  String toString() {
+    return 'GitError.notFound(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GitError_NotFoundImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GitError_NotFoundImplCopyWith<_$GitError_NotFoundImpl> get copyWith =>
+      __$$GitError_NotFoundImplCopyWithImpl<_$GitError_NotFoundImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String field0) io,
+    required TResult Function(String field0) git,
+    required TResult Function(String field0) authenticationFailed,
+    required TResult Function(String field0) repositoryNotFound,
+    required TResult Function(String field0) notFound,
+    required TResult Function(String field0) networkUnreachable,
+    required TResult Function(String field0) locked,
+    required TResult Function(String field0) detachedHead,
+    required TResult Function(List<String> field0) mergeConflict,
+    required TResult Function(List<String> field0) stashConflict,
+    required TResult Function(String field0) nonFastForward,
+    required TResult Function(String host, CertificateError reason) certificateInvalid,
+    required TResult Function(HostKey field0) unknownHostKey,
+    required TResult Function(HostKey field0) hostKeyMismatch,
+    required TResult Function() cancelled,
+    required TResult Function(String field0) unbornBranch,
+    required TResult Function(String field0) other,
+  }) {
+    return notFound(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String field0)? io,
+    TResult? Function(String field0)? git,
+    TResult? Function(String field0)? authenticationFailed,
+    TResult? Function(String field0)? repositoryNotFound,
+    TResult? Function(String field0)? notFound,
+    TResult? Function(String field0)? networkUnreachable,
+    TResult? Function(String field0)? locked,
+    TResult? Function(String field0)? detachedHead,
+    TResult? Function(List<String> field0)? mergeConflict,
+    TResult? Function(List<String> field0)? stashConflict,
+    TResult? Function(String field0)? nonFastForward,
+    TResult? Function(String host, CertificateError reason)? certificateInvalid,
+    TResult? Function(HostKey field0)? unknownHostKey,
+    TResult? Function(HostKey field0)? hostKeyMismatch,
+    TResult? Function()? cancelled,
+    TResult? Function(String field0)? unbornBranch,
+    TResult? Function(String field0)? other,
+  }) {
+    return notFound?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String field0)? io,
+    TResult Function(String field0)? git,
+    TResult Function(String field0)? authenticationFailed,
+    TResult Function(String field0)? repositoryNotFound,
+    TResult Function(String field0)? notFound,
+    TResult Function(String field0)? networkUnreachable,
+    TResult Function(String field0)? locked,
+    TResult Function(String field0)? detachedHead,
+    TResult Function(List<String> field0)? mergeConflict,
+    TResult Function(List<String> field0)? stashConflict,
+    TResult Function(String field0)? nonFastForward,
+    TResult Function(String host, CertificateError reason)? certificateInvalid,
+    TResult Function(HostKey field0)? unknownHostKey,
+    TResult Function(HostKey field0)? hostKeyMismatch,
+    TResult Function()? cancelled,
+    TResult Function(String field0)? unbornBranch,
+    TResult Function(String field0)? other,
+    required TResult orElse(),
+  }) {
+    if (notFound != null) {
+      return notFound(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GitError_Io value) io,
+    required TResult Function(GitError_Git value) git,
+    required TResult Function(GitError_AuthenticationFailed value) authenticationFailed,
+    required TResult Function(GitError_RepositoryNotFound value) repositoryNotFound,
+    required TResult Function(GitError_NotFound value) notFound,
+    required TResult Function(GitError_NetworkUnreachable value) networkUnreachable,
+    required TResult Function(GitError_Locked value) locked,
+    required TResult Function(GitError_DetachedHead value) detachedHead,
+    required TResult Function(GitError_MergeConflict value) mergeConflict,
+    required TResult Function(GitError_StashConflict value) stashConflict,
+    required TResult Function(GitError_NonFastForward value) nonFastForward,
+    required TResult Function(GitError_CertificateInvalid value) certificateInvalid,
+    required TResult Function(GitError_UnknownHostKey value) unknownHostKey,
+    required TResult Function(GitError_HostKeyMismatch value) hostKeyMismatch,
+    required TResult Function(GitError_Cancelled value) cancelled,
+    required TResult Function(GitError_UnbornBranch value) unbornBranch,
+    required TResult Function(GitError_Other value) other,
+  }) {
+    return notFound(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GitError_Io value)? io,
+    TResult? Function(GitError_Git value)? git,
+    TResult? Function(GitError_AuthenticationFailed value)? authenticationFailed,
+    TResult? Function(GitError_RepositoryNotFound value)? repositoryNotFound,
+    TResult? Function(GitError_NotFound value)? notFound,
+    TResult? Function(GitError_NetworkUnreachable value)? networkUnreachable,
+    TResult? Function(GitError_Locked value)? locked,
+    TResult? Function(GitError_DetachedHead value)? detachedHead,
+    TResult? Function(GitError_MergeConflict value)? mergeConflict,
+    TResult? Function(GitError_StashConflict value)? stashConflict,
+    TResult? Function(GitError_NonFastForward value)? nonFastForward,
+    TResult? Function(GitError_CertificateInvalid value)? certificateInvalid,
+    TResult? Function(GitError_UnknownHostKey value)? unknownHostKey,
+    TResult? Function(GitError_HostKeyMismatch value)? hostKeyMismatch,
+    TResult? Function(GitError_Cancelled value)? cancelled,
+    TResult? Function(GitError_UnbornBranch value)? unbornBranch,
+    TResult? Function(GitError_Other value)? other,
+  }) {
+    return notFound?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GitError_Io value)? io,
+    TResult Function(GitError_Git value)? git,
+    TResult Function(GitError_AuthenticationFailed value)? authenticationFailed,
+    TResult Function(GitError_RepositoryNotFound value)? repositoryNotFound,
+    TResult Function(GitError_NotFound value)? notFound,
+    TResult Function(GitError_NetworkUnreachable value)? networkUnreachable,
+    TResult Function(GitError_Locked value)? locked,
+    TResult Function(GitError_DetachedHead value)? detachedHead,
+    TResult Function(GitError_MergeConflict value)? mergeConflict,
+    TResult Function(GitError_StashConflict value)? stashConflict,
+    TResult Function(GitError_NonFastForward value)? nonFastForward,
+    TResult Function(GitError_CertificateInvalid value)? certificateInvalid,
+    TResult Function(GitError_UnknownHostKey value)? unknownHostKey,
+    TResult Function(GitError_HostKeyMismatch value)? hostKeyMismatch,
+    TResult Function(GitError_Cancelled value)? cancelled,
+    TResult Function(GitError_UnbornBranch value)? unbornBranch,
+    TResult Function(GitError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (notFound != null) {
+      return notFound(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GitError_NotFound extends GitError {
+  const factory GitError_NotFound(final String field0) = _$GitError_NotFoundImpl;
+  const GitError_NotFound._() : super._();
+
+  String get field0;
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GitError_NotFoundImplCopyWith<_$GitError_NotFoundImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$GitError_NetworkUnreachableImplCopyWith<$Res> {
+  factory _$$GitError_NetworkUnreachableImplCopyWith(
+    _$GitError_NetworkUnreachableImpl value,
+    $Res Function(_$GitError_NetworkUnreachableImpl) then,
+  ) = __$$GitError_NetworkUnreachableImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class __$$GitError_NetworkUnreachableImplCopyWithImpl<$Res>
+    extends _$GitErrorCopyWithImpl<$Res, _$GitError_NetworkUnreachableImpl>
+    implements _$$GitError_NetworkUnreachableImplCopyWith<$Res> {
+  __$$GitError_NetworkUnreachableImplCopyWithImpl(
+    _$GitError_NetworkUnreachableImpl _value,
+    $Res Function(_$GitError_NetworkUnreachableImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field0 = null}) {
+    return _then(
+      _$GitError_NetworkUnreachableImpl(
+        null == field0
+            ? _value.field0
+            : field0 // ignore: cast_nullable_to_non_nullable
+                  as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$GitError_NetworkUnreachableImpl extends GitError_NetworkUnreachable {
+  const _$GitError_NetworkUnreachableImpl(this.field0) : super._();
+
+  @override
+  final String field0;
+
+  @override
+  String toString() {
+    return 'GitError.networkUnreachable(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GitError_NetworkUnreachableImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GitError_NetworkUnreachableImplCopyWith<_$GitError_NetworkUnreachableImpl> get copyWith =>
+      __$$GitError_NetworkUnreachableImplCopyWithImpl<_$GitError_NetworkUnreachableImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String field0) io,
+    required TResult Function(String field0) git,
+    required TResult Function(String field0) authenticationFailed,
+    required TResult Function(String field0) repositoryNotFound,
+    required TResult Function(String field0) notFound,
+    required TResult Function(String field0) networkUnreachable,
+    required TResult Function(String field0) locked,
+    required TResult Function(String field0) detachedHead,
+    required TResult Function(List<String> field0) mergeConflict,
+    required TResult Function(List<String> field0) stashConflict,
+    required TResult Function(String field0) nonFastForward,
+    required TResult Function(String host, CertificateError reason) certificateInvalid,
+    required TResult Function(HostKey field0) unknownHostKey,
+    required TResult Function(HostKey field0) hostKeyMismatch,
+    required TResult Function() cancelled,
+    required TResult Function(String field0) unbornBranch,
+    required TResult Function(String field0) other,
+  }) {
+    return networkUnreachable(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String field0)? io,
+    TResult? Function(String field0)? git,
+    TResult? Function(String field0)? authenticationFailed,
+    TResult? Function(String field0)? repositoryNotFound,
+    TResult? Function(String field0)? notFound,
+    TResult? Function(String field0)? networkUnreachable,
+    TResult? Function(String field0)? locked,
+    TResult? Function(String field0)? detachedHead,
+    TResult? Function(List<String> field0)? mergeConflict,
+    TResult? Function(List<String> field0)? stashConflict,
+    TResult? Function(String field0)? nonFastForward,
+    TResult? Function(String host, CertificateError reason)? certificateInvalid,
+    TResult? Function(HostKey field0)? unknownHostKey,
+    TResult? Function(HostKey field0)? hostKeyMismatch,
+    TResult? Function()? cancelled,
+    TResult? Function(String field0)? unbornBranch,
+    TResult? Function(String field0)? other,
+  }) {
+    return networkUnreachable?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String field0)? io,
+    TResult Function(String field0)? git,
+    TResult Function(String field0)? authenticationFailed,
+    TResult Function(String field0)? repositoryNotFound,
+    TResult Function(String field0)? notFound,
+    TResult Function(String field0)? networkUnreachable,
+    TResult Function(String field0)? locked,
+    TResult Function(String field0)? detachedHead,
+    TResult Function(List<String> field0)? mergeConflict,
+    TResult Function(List<String> field0)? stashConflict,
+    TResult Function(String field0)? nonFastForward,
+    TResult Function(String host, CertificateError reason)? certificateInvalid,
+    TResult Function(HostKey field0)? unknownHostKey,
+    TResult Function(HostKey field0)? hostKeyMismatch,
+    TResult Function()? cancelled,
+    TResult Function(String field0)? unbornBranch,
+    TResult Function(String field0)? other,
+    required TResult orElse(),
+  }) {
+    if (networkUnreachable != null) {
+      return networkUnreachable(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GitError_Io value) io,
+    required TResult Function(GitError_Git value) git,
+    required TResult Function(GitError_AuthenticationFailed value) authenticationFailed,
+    required TResult Function(GitError_RepositoryNotFound value) repositoryNotFound,
+    required TResult Function(GitError_NotFound value) notFound,
+    required TResult Function(GitError_NetworkUnreachable value) networkUnreachable,
+    required TResult Function(GitError_Locked value) locked,
+    required TResult Function(GitError_DetachedHead value) detachedHead,
+    required TResult Function(GitError_MergeConflict value) mergeConflict,
+    required TResult Function(GitError_StashConflict value) stashConflict,
+    required TResult Function(GitError_NonFastForward value) nonFastForward,
+    required TResult Function(GitError_CertificateInvalid value) certificateInvalid,
+    required TResult Function(GitError_UnknownHostKey value) unknownHostKey,
+    required TResult Function(GitError_HostKeyMismatch value) hostKeyMismatch,
+    required TResult Function(GitError_Cancelled value) cancelled,
+    required TResult Function(GitError_UnbornBranch value) unbornBranch,
+    required TResult Function(GitError_Other value) other,
+  }) {
+    return networkUnreachable(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GitError_Io value)? io,
+    TResult? Function(GitError_Git value)? git,
+    TResult? Function(GitError_AuthenticationFailed value)? authenticationFailed,
+    TResult? Function(GitError_RepositoryNotFound value)? repositoryNotFound,
+    TResult? Function(GitError_NotFound value)? notFound,
+    TResult? Function(GitError_NetworkUnreachable value)? networkUnreachable,
+    TResult? Function(GitError_Locked value)? locked,
+    TResult? Function(GitError_DetachedHead value)? detachedHead,
+    TResult? Function(GitError_MergeConflict value)? mergeConflict,
+    TResult? Function(GitError_StashConflict value)? stashConflict,
+    TResult? Function(GitError_NonFastForward value)? nonFastForward,
+    TResult? Function(GitError_CertificateInvalid value)? certificateInvalid,
+    TResult? Function(GitError_UnknownHostKey value)? unknownHostKey,
+    TResult? Function(GitError_HostKeyMismatch value)? hostKeyMismatch,
+    TResult? Function(GitError_Cancelled value)? cancelled,
+    TResult? Function(GitError_UnbornBranch value)? unbornBranch,
+    TResult? Function(GitError_Other value)? other,
+  }) {
+    return networkUnreachable?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GitError_Io value)? io,
+    TResult Function(GitError_Git value)? git,
+    TResult Function(GitError_AuthenticationFailed value)? authenticationFailed,
+    TResult Function(GitError_RepositoryNotFound value)? repositoryNotFound,
+    TResult Function(GitError_NotFound value)? notFound,
+    TResult Function(GitError_NetworkUnreachable value)? networkUnreachable,
+    TResult Function(GitError_Locked value)? locked,
+    TResult Function(GitError_DetachedHead value)? detachedHead,
+    TResult Function(GitError_MergeConflict value)? mergeConflict,
+    TResult Function(GitError_StashConflict value)? stashConflict,
+    TResult Function(GitError_NonFastForward value)? nonFastForward,
+    TResult Function(GitError_CertificateInvalid value)? certificateInvalid,
+    TResult Function(GitError_UnknownHostKey value)? unknownHostKey,
+    TResult Function(GitError_HostKeyMismatch value)? hostKeyMismatch,
+    TResult Function(GitError_Cancelled value)? cancelled,
+    TResult Function(GitError_UnbornBranch value)? unbornBranch,
+    TResult Function(GitError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (networkUnreachable != null) {
+      return networkUnreachable(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GitError_NetworkUnreachable extends GitError {
+  const factory GitError_NetworkUnreachable(final String field0) = _$GitError_NetworkUnreachableImpl;
+  const GitError_NetworkUnreachable._() : super._();
+
+  String get field0;
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GitError_NetworkUnreachableImplCopyWith<_$GitError_NetworkUnreachableImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$GitError_LockedImplCopyWith<$Res> {
+  factory _$$GitError_LockedImplCopyWith(
+    _$GitError_LockedImpl value,
+    $Res Function(_$GitError_LockedImpl) then,
+  ) = __$$GitError_LockedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class __$$GitError_LockedImplCopyWithImpl<$Res>
+    extends _$GitErrorCopyWithImpl<$Res, _$GitError_LockedImpl>
+    implements _$$GitError_LockedImplCopyWith<$Res> {
+  __$$GitError_LockedImplCopyWithImpl(
+    _$GitError_LockedImpl _value,
+    $Res Function(_$GitError_LockedImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field0 = null}) {
+    return _then(
+      _$GitError_LockedImpl(
+        null == field0
+            ? _value.field0
+            : field0 // ignore: cast_nullable_to_non_nullable
+                  as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$GitError_LockedImpl extends GitError_Locked {
+  const _$GitError_LockedImpl(this.field0) : super._();
+
+  @override
+  final String field0;
+
+  @override
+  String toString() {
+    return 'GitError.locked(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GitError_LockedImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GitError_LockedImplCopyWith<_$GitError_LockedImpl> get copyWith =>
+      __$$GitError_LockedImplCopyWithImpl<_$GitError_LockedImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String field0) io,
+    required TResult Function(String field0) git,
+    required TResult Function(String field0) authenticationFailed,
+    required TResult Function(String field0) repositoryNotFound,
+    required TResult Function(String field0) notFound,
+    required TResult Function(String field0) networkUnreachable,
+    required TResult Function(String field0) locked,
+    required TResult Function(String field0) detachedHead,
+    required TResult Function(List<String> field0) mergeConflict,
+    required TResult Function(List<String> field0) stashConflict,
+    required TResult Function(String field0) nonFastForward,
+    required TResult Function(String host, CertificateError reason) certificateInvalid,
+    required TResult Function(HostKey field0) unknownHostKey,
+    required TResult Function(HostKey field0) hostKeyMismatch,
+    required TResult Function() cancelled,
+    required TResult Function(String field0) unbornBranch,
+    required TResult Function(String field0) other,
+  }) {
+    return locked(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String field0)? io,
+    TResult? Function(String field0)? git,
+    TResult? Function(String field0)? authenticationFailed,
+    TResult? Function(String field0)? repositoryNotFound,
+    TResult? Function(String field0)? notFound,
+    TResult? Function(String field0)? networkUnreachable,
+    TResult? Function(String field0)? locked,
+    TResult? Function(String field0)? detachedHead,
+    TResult? Function(List<String> field0)? mergeConflict,
+    TResult? Function(List<String> field0)? stashConflict,
+    TResult? Function(String field0)? nonFastForward,
+    TResult? Function(String host, CertificateError reason)? certificateInvalid,
+    TResult? Function(HostKey field0)? unknownHostKey,
+    TResult? Function(HostKey field0)? hostKeyMismatch,
+    TResult? Function()? cancelled,
+    TResult? Function(String field0)? unbornBranch,
+    TResult? Function(String field0)? other,
+  }) {
+    return locked?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String field0)? io,
+    TResult Function(String field0)? git,
+    TResult Function(String field0)? authenticationFailed,
+    TResult Function(String field0)? repositoryNotFound,
+    TResult Function(String field0)? notFound,
+    TResult Function(String field0)? networkUnreachable,
+    TResult Function(String field0)? locked,
+    TResult Function(String field0)? detachedHead,
+    TResult Function(List<String> field0)? mergeConflict,
+    TResult Function(List<String> field0)? stashConflict,
+    TResult Function(String field0)? nonFastForward,
+    TResult Function(String host, CertificateError reason)? certificateInvalid,
+    TResult Function(HostKey field0)? unknownHostKey,
+    TResult Function(HostKey field0)? hostKeyMismatch,
+    TResult Function()? cancelled,
+    TResult Function(String field0)? unbornBranch,
+    TResult Function(String field0)? other,
+    required TResult orElse(),
+  }) {
+    if (locked != null) {
+      return locked(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GitError_Io value) io,
+    required TResult Function(GitError_Git value) git,
+    required TResult Function(GitError_AuthenticationFailed value) authenticationFailed,
+    required TResult Function(GitError_RepositoryNotFound value) repositoryNotFound,
+    required TResult Function(GitError_NotFound value) notFound,
+    required TResult Function(GitError_NetworkUnreachable value) networkUnreachable,
+    required TResult Function(GitError_Locked value) locked,
+    required TResult Function(GitError_DetachedHead value) detachedHead,
+    required TResult Function(GitError_MergeConflict value) mergeConflict,
+    required TResult Function(GitError_StashConflict value) stashConflict,
+    required TResult Function(GitError_NonFastForward value) nonFastForward,
+    required TResult Function(GitError_CertificateInvalid value) certificateInvalid,
+    required TResult Function(GitError_UnknownHostKey value) unknownHostKey,
+    required TResult Function(GitError_HostKeyMismatch value) hostKeyMismatch,
+    required TResult Function(GitError_Cancelled value) cancelled,
+    required TResult Function(GitError_UnbornBranch value) unbornBranch,
+    required TResult Function(GitError_Other value) other,
+  }) {
+    return locked(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GitError_Io value)? io,
+    TResult? Function(GitError_Git value)? git,
+    TResult? Function(GitError_AuthenticationFailed value)? authenticationFailed,
+    TResult? Function(GitError_RepositoryNotFound value)? repositoryNotFound,
+    TResult? Function(GitError_NotFound value)? notFound,
+    TResult? Function(GitError_NetworkUnreachable value)? networkUnreachable,
+    TResult? Function(GitError_Locked value)? locked,
+    TResult? Function(GitError_DetachedHead value)? detachedHead,
+    TResult? Function(GitError_MergeConflict value)? mergeConflict,
+    TResult? Function(GitError_StashConflict value)? stashConflict,
+    TResult? Function(GitError_NonFastForward value)? nonFastForward,
+    TResult? Function(GitError_CertificateInvalid value)? certificateInvalid,
+    TResult? Function(GitError_UnknownHostKey value)? unknownHostKey,
+    TResult? Function(GitError_HostKeyMismatch value)? hostKeyMismatch,
+    TResult? Function(GitError_Cancelled value)? cancelled,
+    TResult? Function(GitError_UnbornBranch value)? unbornBranch,
+    TResult? Function(GitError_Other value)? other,
+  }) {
+    return locked?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GitError_Io value)? io,
+    TResult Function(GitError_Git value)? git,
+    TResult Function(GitError_AuthenticationFailed value)? authenticationFailed,
+    TResult Function(GitError_RepositoryNotFound value)? repositoryNotFound,
+    TResult Function(GitError_NotFound value)? notFound,
+    TResult Function(GitError_NetworkUnreachable value)? networkUnreachable,
+    TResult Function(GitError_Locked value)? locked,
+    TResult Function(GitError_DetachedHead value)? detachedHead,
+    TResult Function(GitError_MergeConflict value)? mergeConflict,
+    TResult Function(GitError_StashConflict value)? stashConflict,
+    TResult Function(GitError_NonFastForward value)? nonFastForward,
+    TResult Function(GitError_CertificateInvalid value)? certificateInvalid,
+    TResult Function(GitError_UnknownHostKey value)? unknownHostKey,
+    TResult Function(GitError_HostKeyMismatch value)? hostKeyMismatch,
+    TResult Function(GitError_Cancelled value)? cancelled,
+    TResult Function(GitError_UnbornBranch value)? unbornBranch,
+    TResult Function(GitError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (locked != null) {
+      return locked(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GitError_Locked extends GitError {
+  const factory GitError_Locked(final String field0) = _$GitError_LockedImpl;
+  const GitError_Locked._() : super._();
+
+  String get field0;
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GitError_LockedImplCopyWith<_$GitError_LockedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$GitError_DetachedHeadImplCopyWith<$Res> {
+  factory _$$GitError_DetachedHeadImplCopyWith(
+    _$GitError_DetachedHeadImpl value,
+    $Res Function(_$GitError_DetachedHeadImpl) then,
+  ) = __$$GitError_DetachedHeadImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class __$$GitError_DetachedHeadImplCopyWithImpl<$Res>
+    extends _$GitErrorCopyWithImpl<$Res, _$GitError_DetachedHeadImpl>
+    implements _$$GitError_DetachedHeadImplCopyWith<$Res> {
+  __$$GitError_DetachedHeadImplCopyWithImpl(
+    _$GitError_DetachedHeadImpl _value,
+    $Res Function(_$GitError_DetachedHeadImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field0 = null}) {
+    return _then(
+      _$GitError_DetachedHeadImpl(
+        null == field0
+            ? _value.field0
+            : field0 // ignore: cast_nullable_to_non_nullable
+                  as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$GitError_DetachedHeadImpl extends GitError_DetachedHead {
+  const _$GitError_DetachedHeadImpl(this.field0) : super._();
+
+  @override
+  final String field0;
+
+  @override
+  String toString() {
+    return 'GitError.detachedHead(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GitError_DetachedHeadImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GitError_DetachedHeadImplCopyWith<_$GitError_DetachedHeadImpl> get copyWith =>
+      __$$GitError_DetachedHeadImplCopyWithImpl<_$GitError_DetachedHeadImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String field0) io,
+    required TResult Function(String field0) git,
+    required TResult Function(String field0) authenticationFailed,
+    required TResult Function(String field0) repositoryNotFound,
+    required TResult Function(String field0) notFound,
+    required TResult Function(String field0) networkUnreachable,
+    required TResult Function(String field0) locked,
+    required TResult Function(String field0) detachedHead,
+    required TResult Function(List<String> field0) mergeConflict,
+    required TResult Function(List<String> field0) stashConflict,
+    required TResult Function(String field0) nonFastForward,
+    required TResult Function(String host, CertificateError reason) certificateInvalid,
+    required TResult Function(HostKey field0) unknownHostKey,
+    required TResult Function(HostKey field0) hostKeyMismatch,
+    required TResult Function() cancelled,
+    required TResult Function(String field0) unbornBranch,
+    required TResult Function(String field0) other,
+  }) {
+    return detachedHead(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String field0)? io,
+    TResult? Function(String field0)? git,
+    TResult? Function(String field0)? authenticationFailed,
+    TResult? Function(String field0)? repositoryNotFound,
+    TResult? Function(String field0)? notFound,
+    TResult? Function(String field0)? networkUnreachable,
+    TResult? Function(String field0)? locked,
+    TResult? Function(String field0)? detachedHead,
+    TResult? Function(List<String> field0)? mergeConflict,
+    TResult? Function(List<String> field0)? stashConflict,
+    TResult? Function(String field0)? nonFastForward,
+    TResult? Function(String host, CertificateError reason)? certificateInvalid,
+    TResult? Function(HostKey field0)? unknownHostKey,
+    TResult? Function(HostKey field0)? hostKeyMismatch,
+    TResult? Function()? cancelled,
+    TResult? Function(String field0)? unbornBranch,
+    TResult? Function(String field0)? other,
+  }) {
+    return detachedHead?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String field0)? io,
+    TResult Function(String field0)? git,
+    TResult Function(String field0)? authenticationFailed,
+    TResult Function(String field0)? repositoryNotFound,
+    TResult Function(String field0)? notFound,
+    TResult Function(String field0)? networkUnreachable,
+    TResult Function(String field0)? locked,
+    TResult Function(String field0)? detachedHead,
+    TResult Function(List<String> field0)? mergeConflict,
+    TResult Function(List<String> field0)? stashConflict,
+    TResult Function(String field0)? nonFastForward,
+    TResult Function(String host, CertificateError reason)? certificateInvalid,
+    TResult Function(HostKey field0)? unknownHostKey,
+    TResult Function(HostKey field0)? hostKeyMismatch,
+    TResult Function()? cancelled,
+    TResult Function(String field0)? unbornBranch,
+    TResult Function(String field0)? other,
+    required TResult orElse(),
+  }) {
+    if (detachedHead != null) {
+      return detachedHead(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GitError_Io value) io,
+    required TResult Function(GitError_Git value) git,
+    required TResult Function(GitError_AuthenticationFailed value) authenticationFailed,
+    required TResult Function(GitError_RepositoryNotFound value) repositoryNotFound,
+    required TResult Function(GitError_NotFound value) notFound,
+    required TResult Function(GitError_NetworkUnreachable value) networkUnreachable,
+    required TResult Function(GitError_Locked value) locked,
+    required TResult Function(GitError_DetachedHead value) detachedHead,
+    required TResult Function(GitError_MergeConflict value) mergeConflict,
+    required TResult Function(GitError_StashConflict value) stashConflict,
+    required TResult Function(GitError_NonFastForward value) nonFastForward,
+    required TResult Function(GitError_CertificateInvalid value) certificateInvalid,
+    required TResult Function(GitError_UnknownHostKey value) unknownHostKey,
+    required TResult Function(GitError_HostKeyMismatch value) hostKeyMismatch,
+    required TResult Function(GitError_Cancelled value) cancelled,
+    required TResult Function(GitError_UnbornBranch value) unbornBranch,
+    required TResult Function(GitError_Other value) other,
+  }) {
+    return detachedHead(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GitError_Io value)? io,
+    TResult? Function(GitError_Git value)? git,
+    TResult? Function(GitError_AuthenticationFailed value)? authenticationFailed,
+    TResult? Function(GitError_RepositoryNotFound value)? repositoryNotFound,
+    TResult? Function(GitError_NotFound value)? notFound,
+    TResult? Function(GitError_NetworkUnreachable value)? networkUnreachable,
+    TResult? Function(GitError_Locked value)? locked,
+    TResult? Function(GitError_DetachedHead value)? detachedHead,
+    TResult? Function(GitError_MergeConflict value)? mergeConflict,
+    TResult? Function(GitError_StashConflict value)? stashConflict,
+    TResult? Function(GitError_NonFastForward value)? nonFastForward,
+    TResult? Function(GitError_CertificateInvalid value)? certificateInvalid,
+    TResult? Function(GitError_UnknownHostKey value)? unknownHostKey,
+    TResult? Function(GitError_HostKeyMismatch value)? hostKeyMismatch,
+    TResult? Function(GitError_Cancelled value)? cancelled,
+    TResult? Function(GitError_UnbornBranch value)? unbornBranch,
+    TResult? Function(GitError_Other value)? other,
+  }) {
+    return detachedHead?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GitError_Io value)? io,
+    TResult Function(GitError_Git value)? git,
+    TResult Function(GitError_AuthenticationFailed value)? authenticationFailed,
+    TResult Function(GitError_RepositoryNotFound value)? repositoryNotFound,
+    TResult Function(GitError_NotFound value)? notFound,
+    TResult Function(GitError_NetworkUnreachable value)? networkUnreachable,
+    TResult Function(GitError_Locked value)? locked,
+    TResult Function(GitError_DetachedHead value)? detachedHead,
+    TResult Function(GitError_MergeConflict value)? mergeConflict,
+    TResult Function(GitError_StashConflict value)? stashConflict,
+    TResult Function(GitError_NonFastForward value)? nonFastForward,
+    TResult Function(GitError_CertificateInvalid value)? certificateInvalid,
+    TResult Function(GitError_UnknownHostKey value)? unknownHostKey,
+    TResult Function(GitError_HostKeyMismatch value)? hostKeyMismatch,
+    TResult Function(GitError_Cancelled value)? cancelled,
+    TResult Function(GitError_UnbornBranch value)? unbornBranch,
+    TResult Function(GitError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (detachedHead != null) {
+      return detachedHead(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GitError_DetachedHead extends GitError {
+  const factory GitError_DetachedHead(final String field0) = _$GitError_DetachedHeadImpl;
+  const GitError_DetachedHead._() : super._();
+
+  String get field0;
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GitError_DetachedHeadImplCopyWith<_$GitError_DetachedHeadImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$GitError_MergeConflictImplCopyWith<$Res> {
+  factory _$$GitError_MergeConflictImplCopyWith(
+    _$GitError_MergeConflictImpl value,
+    $Res Function(_$GitError_MergeConflictImpl) then,
+  ) = __$$GitError_MergeConflictImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({List<String> field0});
+}
+
+/// @nodoc
+class __$$GitError_MergeConflictImplCopyWithImpl<$Res>
+    extends _$GitErrorCopyWithImpl<$Res, _$GitError_MergeConflictImpl>
+    implements _$$GitError_MergeConflictImplCopyWith<$Res> {
+  __$$GitError_MergeConflictImplCopyWithImpl(
+    _$GitError_MergeConflictImpl _value,
+    $Res Function(_$GitError_MergeConflictImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field0 = null}) {
+    return _then(
+      _$GitError_MergeConflictImpl(
+        null == field0
+            ? _value._field0
+            : field0 // ignore: cast_nullable_to_non_nullable
+                  as List<String>,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$GitError_MergeConflictImpl extends GitError_MergeConflict {
+  const _$GitError_MergeConflictImpl(final List<String> field0)
+    : _field0 = field0,
+      super._();
+
+  final List<String> _field0;
+  @override
+  List<String> get field0 {
+    if (_field0 is EqualUnmodifiableListView) return _field0;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_field0);
+  }
+
+  @override
+  String toString() {
+    return 'GitError.mergeConflict(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GitError_MergeConflictImpl &&
+            const DeepCollectionEquality().equals(other._field0, _field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, const DeepCollectionEquality().hash(_field0));
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GitError_MergeConflictImplCopyWith<_$GitError_MergeConflictImpl> get copyWith =>
+      __$$GitError_MergeConflictImplCopyWithImpl<_$GitError_MergeConflictImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String field0) io,
+    required TResult Function(String field0) git,
+    required TResult Function(String field0) authenticationFailed,
+    required TResult Function(String field0) repositoryNotFound,
+    required TResult Function(String field0) notFound,
+    required TResult Function(String field0) networkUnreachable,
+    required TResult Function(String field0) locked,
+    required TResult Function(String field0) detachedHead,
+    required TResult Function(List<String> field0) mergeConflict,
+    required TResult Function(List<String> field0) stashConflict,
+    required TResult Function(String field0) nonFastForward,
+    required TResult Function(String host, CertificateError reason) certificateInvalid,
+    required TResult Function(HostKey field0) unknownHostKey,
+    required TResult Function(HostKey field0) hostKeyMismatch,
+    required TResult Function() cancelled,
+    required TResult Function(String field0) unbornBranch,
+    required TResult Function(String field0) other,
+  }) {
+    return mergeConflict(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String field0)? io,
+    TResult? Function(String field0)? git,
+    TResult? Function(String field0)? authenticationFailed,
+    TResult? Function(String field0)? repositoryNotFound,
+    TResult? Function(String field0)? notFound,
+    TResult? Function(String field0)? networkUnreachable,
+    TResult? Function(String field0)? locked,
+    TResult? Function(String field0)? detachedHead,
+    TResult? Function(List<String> field0)? mergeConflict,
+    TResult? Function(List<String> field0)? stashConflict,
+    TResult? Function(String field0)? nonFastForward,
+    TResult? Function(String host, CertificateError reason)? certificateInvalid,
+    TResult? Function(HostKey field0)? unknownHostKey,
+    TResult? Function(HostKey field0)? hostKeyMismatch,
+    TResult? Function()? cancelled,
+    TResult? Function(String field0)? unbornBranch,
+    TResult? Function(String field0)? other,
+  }) {
+    return mergeConflict?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String field0)? io,
+    TResult Function(String field0)? git,
+    TResult Function(String field0)? authenticationFailed,
+    TResult Function(String field0)? repositoryNotFound,
+    TResult Function(String field0)? notFound,
+    TResult Function(String field0)? networkUnreachable,
+    TResult Function(String field0)? locked,
+    TResult Function(String field0)? detachedHead,
+    TResult Function(List<String> field0)? mergeConflict,
+    TResult Function(List<String> field0)? stashConflict,
+    TResult Function(String field0)? nonFastForward,
+    TResult Function(String host, CertificateError reason)? certificateInvalid,
+    TResult Function(HostKey field0)? unknownHostKey,
+    TResult Function(HostKey field0)? hostKeyMismatch,
+    TResult Function()? cancelled,
+    TResult Function(String field0)? unbornBranch,
+    TResult Function(String field0)? other,
+    required TResult orElse(),
+  }) {
+    if (mergeConflict != null) {
+      return mergeConflict(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GitError_Io value) io,
+    required TResult Function(GitError_Git value) git,
+    required TResult Function(GitError_AuthenticationFailed value) authenticationFailed,
+    required TResult Function(GitError_RepositoryNotFound value) repositoryNotFound,
+    required TResult Function(GitError_NotFound value) notFound,
+    required TResult Function(GitError_NetworkUnreachable value) networkUnreachable,
+    required TResult Function(GitError_Locked value) locked,
+    required TResult Function(GitError_DetachedHead value) detachedHead,
+    required TResult Function(GitError_MergeConflict value) mergeConflict,
+    required TResult Function(GitError_StashConflict value) stashConflict,
+    required TResult Function(GitError_NonFastForward value) nonFastForward,
+    required TResult Function(GitError_CertificateInvalid value) certificateInvalid,
+    required TResult Function(GitError_UnknownHostKey value) unknownHostKey,
+    required TResult Function(GitError_HostKeyMismatch value) hostKeyMismatch,
+    required TResult Function(GitError_Cancelled value) cancelled,
+    required TResult Function(GitError_UnbornBranch value) unbornBranch,
+    required TResult Function(GitError_Other value) other,
+  }) {
+    return mergeConflict(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GitError_Io value)? io,
+    TResult? Function(GitError_Git value)? git,
+    TResult? Function(GitError_AuthenticationFailed value)? authenticationFailed,
+    TResult? Function(GitError_RepositoryNotFound value)? repositoryNotFound,
+    TResult? Function(GitError_NotFound value)? notFound,
+    TResult? Function(GitError_NetworkUnreachable value)? networkUnreachable,
+    TResult? Function(GitError_Locked value)? locked,
+    TResult? Function(GitError_DetachedHead value)? detachedHead,
+    TResult? Function(GitError_MergeConflict value)? mergeConflict,
+    TResult? Function(GitError_StashConflict value)? stashConflict,
+    TResult? Function(GitError_NonFastForward value)? nonFastForward,
+    TResult? Function(GitError_CertificateInvalid value)? certificateInvalid,
+    TResult? Function(GitError_UnknownHostKey value)? unknownHostKey,
+    TResult? Function(GitError_HostKeyMismatch value)? hostKeyMismatch,
+    TResult? Function(GitError_Cancelled value)? cancelled,
+    TResult? Function(GitError_UnbornBranch value)? unbornBranch,
+    TResult? Function(GitError_Other value)? other,
+  }) {
+    return mergeConflict?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GitError_Io value)? io,
+    TResult Function(GitError_Git value)? git,
+    TResult Function(GitError_AuthenticationFailed value)? authenticationFailed,
+    TResult Function(GitError_RepositoryNotFound value)? repositoryNotFound,
+    TResult Function(GitError_NotFound value)? notFound,
+    TResult Function(GitError_NetworkUnreachable value)? networkUnreachable,
+    TResult Function(GitError_Locked value)? locked,
+    TResult Function(GitError_DetachedHead value)? detachedHead,
+    TResult Function(GitError_MergeConflict value)? mergeConflict,
+    TResult Function(GitError_StashConflict value)? stashConflict,
+    TResult Function(GitError_NonFastForward value)? nonFastForward,
+    TResult Function(GitError_CertificateInvalid value)? certificateInvalid,
+    TResult Function(GitError_UnknownHostKey value)? unknownHostKey,
+    TResult Function(GitError_HostKeyMismatch value)? hostKeyMismatch,
+    TResult Function(GitError_Cancelled value)? cancelled,
+    TResult Function(GitError_UnbornBranch value)? unbornBranch,
+    TResult Function(GitError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (mergeConflict != null) {
+      return mergeConflict(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GitError_MergeConflict extends GitError {
+  const factory GitError_MergeConflict(final List<String> field0) = _$GitError_MergeConflictImpl;
+  const GitError_MergeConflict._() : super._();
+
+  List<String> get field0;
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GitError_MergeConflictImplCopyWith<_$GitError_MergeConflictImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$GitError_StashConflictImplCopyWith<$Res> {
+  factory _$$GitError_StashConflictImplCopyWith(
+    _$GitError_StashConflictImpl value,
+    $Res Function(_$GitError_StashConflictImpl) then,
+  ) = __$$GitError_StashConflictImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({List<String> field0});
+}
+
+/// @nodoc
+class __$$GitError_StashConflictImplCopyWithImpl<$Res>
+    extends _$GitErrorCopyWithImpl<$Res, _$GitError_StashConflictImpl>
+    implements _$$GitError_StashConflictImplCopyWith<$Res> {
+  __$$GitError_StashConflictImplCopyWithImpl(
+    _$GitError_StashConflictImpl _value,
+    $Res Function(_$GitError_StashConflictImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field0 = null}) {
+    return _then(
+      _$GitError_StashConflictImpl(
+        null == field0
+            ? _value._field0
+            : field0 // ignore: cast_nullable_to_non_nullable
+                  as List<String>,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$GitError_StashConflictImpl extends GitError_StashConflict {
+  const _$GitError_StashConflictImpl(final List<String> field0)
+    : _field0 = field0,
+      super._();
+
+  final List<String> _field0;
+  @override
+  List<String> get field0 {
+    if (_field0 is EqualUnmodifiableListView) return _field0;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_field0);
+  }
+
+  @override
+  String toString() {
+    return 'GitError.stashConflict(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GitError_StashConflictImpl &&
+            const DeepCollectionEquality().equals(other._field0, _field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, const DeepCollectionEquality().hash(_field0));
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GitError_StashConflictImplCopyWith<_$GitError_StashConflictImpl> get copyWith =>
+      __$$GitError_StashConflictImplCopyWithImpl<_$GitError_StashConflictImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String field0) io,
+    required TResult Function(String field0) git,
+    required TResult Function(String field0) authenticationFailed,
+    required TResult Function(String field0) repositoryNotFound,
+    required TResult Function(String field0) notFound,
+    required TResult Function(String field0) networkUnreachable,
+    required TResult Function(String field0) locked,
+    required TResult Function(String field0) detachedHead,
+    required TResult Function(List<String> field0) mergeConflict,
+    required TResult Function(List<String> field0) stashConflict,
+    required TResult Function(String field0) nonFastForward,
+    required TResult Function(String host, CertificateError reason) certificateInvalid,
+    required TResult Function(HostKey field0) unknownHostKey,
+    required TResult Function(HostKey field0) hostKeyMismatch,
+    required TResult Function() cancelled,
+    required TResult Function(String field0) unbornBranch,
+    required TResult Function(String field0) other,
+  }) {
+    return stashConflict(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String field0)? io,
+    TResult? Function(String field0)? git,
+    TResult? Function(String field0)? authenticationFailed,
+    TResult? Function(String field0)? repositoryNotFound,
+    TResult? Function(String field0)? notFound,
+    TResult? Function(String field0)? networkUnreachable,
+    TResult? Function(String field0)? locked,
+    TResult? Function(String field0)? detachedHead,
+    TResult? Function(List<String> field0)? mergeConflict,
+    TResult? Function(List<String> field0)? stashConflict,
+    TResult? Function(String field0)? nonFastForward,
+    TResult? Function(String host, CertificateError reason)? certificateInvalid,
+    TResult? Function(HostKey field0)? unknownHostKey,
+    TResult? Function(HostKey field0)? hostKeyMismatch,
+    TResult? Function()? cancelled,
+    TResult? Function(String field0)? unbornBranch,
+    TResult? Function(String field0)? other,
+  }) {
+    return stashConflict?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String field0)? io,
+    TResult Function(String field0)? git,
+    TResult Function(String field0)? authenticationFailed,
+    TResult Function(String field0)? repositoryNotFound,
+    TResult Function(String field0)? notFound,
+    TResult Function(String field0)? networkUnreachable,
+    TResult Function(String field0)? locked,
+    TResult Function(String field0)? detachedHead,
+    TResult Function(List<String> field0)? mergeConflict,
+    TResult Function(List<String> field0)? stashConflict,
+    TResult Function(String field0)? nonFastForward,
+    TResult Function(String host, CertificateError reason)? certificateInvalid,
+    TResult Function(HostKey field0)? unknownHostKey,
+    TResult Function(HostKey field0)? hostKeyMismatch,
+    TResult Function()? cancelled,
+    TResult Function(String field0)? unbornBranch,
+    TResult Function(String field0)? other,
+    required TResult orElse(),
+  }) {
+    if (stashConflict != null) {
+      return stashConflict(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GitError_Io value) io,
+    required TResult Function(GitError_Git value) git,
+    required TResult Function(GitError_AuthenticationFailed value) authenticationFailed,
+    required TResult Function(GitError_RepositoryNotFound value) repositoryNotFound,
+    required TResult Function(GitError_NotFound value) notFound,
+    required TResult Function(GitError_NetworkUnreachable value) networkUnreachable,
+    required TResult Function(GitError_Locked value) locked,
+    required TResult Function(GitError_DetachedHead value) detachedHead,
+    required TResult Function(GitError_MergeConflict value) mergeConflict,
+    required TResult Function(GitError_StashConflict value) stashConflict,
+    required TResult Function(GitError_NonFastForward value) nonFastForward,
+    required TResult Function(GitError_CertificateInvalid value) certificateInvalid,
+    required TResult Function(GitError_UnknownHostKey value) unknownHostKey,
+    required TResult Function(GitError_HostKeyMismatch value) hostKeyMismatch,
+    required TResult Function(GitError_Cancelled value) cancelled,
+    required TResult Function(GitError_UnbornBranch value) unbornBranch,
+    required TResult Function(GitError_Other value) other,
+  }) {
+    return stashConflict(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GitError_Io value)? io,
+    TResult? Function(GitError_Git value)? git,
+    TResult? Function(GitError_AuthenticationFailed value)? authenticationFailed,
+    TResult? Function(GitError_RepositoryNotFound value)? repositoryNotFound,
+    TResult? Function(GitError_NotFound value)? notFound,
+    TResult? Function(GitError_NetworkUnreachable value)? networkUnreachable,
+    TResult? Function(GitError_Locked value)? locked,
+    TResult? Function(GitError_DetachedHead value)? detachedHead,
+    TResult? Function(GitError_MergeConflict value)? mergeConflict,
+    TResult? Function(GitError_StashConflict value)? stashConflict,
+    TResult? Function(GitError_NonFastForward value)? nonFastForward,
+    TResult? Function(GitError_CertificateInvalid value)? certificateInvalid,
+    TResult? Function(GitError_UnknownHostKey value)? unknownHostKey,
+    TResult? Function(GitError_HostKeyMismatch value)? hostKeyMismatch,
+    TResult? Function(GitError_Cancelled value)? cancelled,
+    TResult? Function(GitError_UnbornBranch value)? unbornBranch,
+    TResult? Function(GitError_Other value)? other,
+  }) {
+    return stashConflict?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GitError_Io value)? io,
+    TResult Function(GitError_Git value)? git,
+    TResult Function(GitError_AuthenticationFailed value)? authenticationFailed,
+    TResult Function(GitError_RepositoryNotFound value)? repositoryNotFound,
+    TResult Function(GitError_NotFound value)? notFound,
+    TResult Function(GitError_NetworkUnreachable value)? networkUnreachable,
+    TResult Function(GitError_Locked value)? locked,
+    TResult Function(GitError_DetachedHead value)? detachedHead,
+    TResult Function(GitError_MergeConflict value)? mergeConflict,
+    TResult Function(GitError_StashConflict value)? stashConflict,
+    TResult Function(GitError_NonFastForward value)? nonFastForward,
+    TResult Function(GitError_CertificateInvalid value)? certificateInvalid,
+    TResult Function(GitError_UnknownHostKey value)? unknownHostKey,
+    TResult Function(GitError_HostKeyMismatch value)? hostKeyMismatch,
+    TResult Function(GitError_Cancelled value)? cancelled,
+    TResult Function(GitError_UnbornBranch value)? unbornBranch,
+    TResult Function(GitError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (stashConflict != null) {
+      return stashConflict(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GitError_StashConflict extends GitError {
+  const factory GitError_StashConflict(final List<String> field0) = _$GitError_StashConflictImpl;
+  const GitError_StashConflict._() : super._();
+
+  List<String> get field0;
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GitError_StashConflictImplCopyWith<_$GitError_StashConflictImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$GitError_NonFastForwardImplCopyWith<$Res> {
+  factory _$$GitError_NonFastForwardImplCopyWith(
+    _$GitError_NonFastForwardImpl value,
+    $Res Function(_$GitError_NonFastForwardImpl) then,
+  ) = __$$GitError_NonFastForwardImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class __$$GitError_NonFastForwardImplCopyWithImpl<$Res>
+    extends _$GitErrorCopyWithImpl<$Res, _$GitError_NonFastForwardImpl>
+    implements _$$GitError_NonFastForwardImplCopyWith<$Res> {
+  __$$GitError_NonFastForwardImplCopyWithImpl(
+    _$GitError_NonFastForwardImpl _value,
+    $Res Function(_$GitError_NonFastForwardImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field0 = null}) {
+    return _then(
+      _$GitError_NonFastForwardImpl(
+        null == field0
+            ? _value.field0
+            : field0 // ignore: cast_nullable_to_non_nullable
+                  as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$GitError_NonFastForwardImpl extends GitError_NonFastForward {
+  const _$GitError_NonFastForwardImpl(this.field0) : super._();
+
+  @override
+  final String field0;
+
+  @override
+  String toString() {
+    return 'GitError.nonFastForward(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GitError_NonFastForwardImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GitError_NonFastForwardImplCopyWith<_$GitError_NonFastForwardImpl> get copyWith =>
+      __$$GitError_NonFastForwardImplCopyWithImpl<_$GitError_NonFastForwardImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String field0) io,
+    required TResult Function(String field0) git,
+    required TResult Function(String field0) authenticationFailed,
+    required TResult Function(String field0) repositoryNotFound,
+    required TResult Function(String field0) notFound,
+    required TResult Function(String field0) networkUnreachable,
+    required TResult Function(String field0) locked,
+    required TResult Function(String field0) detachedHead,
+    required TResult Function(List<String> field0) mergeConflict,
+    required TResult Function(List<String> field0) stashConflict,
+    required TResult Function(String field0) nonFastForward,
+    required TResult Function(String host, CertificateError reason) certificateInvalid,
+    required TResult Function(HostKey field0) unknownHostKey,
+    required TResult Function(HostKey field0) hostKeyMismatch,
+    required TResult Function() cancelled,
+    required TResult Function(String field0) unbornBranch,
+    required TResult Function(String field0) other,
+  }) {
+    return nonFastForward(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String field0)? io,
+    TResult? Function(String field0)? git,
+    TResult? Function(String field0)? authenticationFailed,
+    TResult? Function(String field0)? repositoryNotFound,
+    TResult? Function(String field0)? notFound,
+    TResult? Function(String field0)? networkUnreachable,
+    TResult? Function(String field0)? locked,
+    TResult? Function(String field0)? detachedHead,
+    TResult? Function(List<String> field0)? mergeConflict,
+    TResult? Function(List<String> field0)? stashConflict,
+    TResult? Function(String field0)? nonFastForward,
+    TResult? Function(String host, CertificateError reason)? certificateInvalid,
+    TResult? Function(HostKey field0)? unknownHostKey,
+    TResult? Function(HostKey field0)? hostKeyMismatch,
+    TResult? Function()? cancelled,
+    TResult? Function(String field0)? unbornBranch,
+    TResult? Function(String field0)? other,
+  }) {
+    return nonFastForward?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String field0)? io,
+    TResult Function(String field0)? git,
+    TResult Function(String field0)? authenticationFailed,
+    TResult Function(String field0)? repositoryNotFound,
+    TResult Function(String field0)? notFound,
+    TResult Function(String field0)? networkUnreachable,
+    TResult Function(String field0)? locked,
+    TResult Function(String field0)? detachedHead,
+    TResult Function(List<String> field0)? mergeConflict,
+    TResult Function(List<String> field0)? stashConflict,
+    TResult Function(String field0)? nonFastForward,
+    TResult Function(String host, CertificateError reason)? certificateInvalid,
+    TResult Function(HostKey field0)? unknownHostKey,
+    TResult Function(HostKey field0)? hostKeyMismatch,
+    TResult Function()? cancelled,
+    TResult Function(String field0)? unbornBranch,
+    TResult Function(String field0)? other,
+    required TResult orElse(),
+  }) {
+    if (nonFastForward != null) {
+      return nonFastForward(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GitError_Io value) io,
+    required TResult Function(GitError_Git value) git,
+    required TResult Function(GitError_AuthenticationFailed value) authenticationFailed,
+    required TResult Function(GitError_RepositoryNotFound value) repositoryNotFound,
+    required TResult Function(GitError_NotFound value) notFound,
+    required TResult Function(GitError_NetworkUnreachable value) networkUnreachable,
+    required TResult Function(GitError_Locked value) locked,
+    required TResult Function(GitError_DetachedHead value) detachedHead,
+    required TResult Function(GitError_MergeConflict value) mergeConflict,
+    required TResult Function(GitError_StashConflict value) stashConflict,
+    required TResult Function(GitError_NonFastForward value) nonFastForward,
+    required TResult Function(GitError_CertificateInvalid value) certificateInvalid,
+    required TResult Function(GitError_UnknownHostKey value) unknownHostKey,
+    required TResult Function(GitError_HostKeyMismatch value) hostKeyMismatch,
+    required TResult Function(GitError_Cancelled value) cancelled,
+    required TResult Function(GitError_UnbornBranch value) unbornBranch,
+    required TResult Function(GitError_Other value) other,
+  }) {
+    return nonFastForward(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GitError_Io value)? io,
+    TResult? Function(GitError_Git value)? git,
+    TResult? Function(GitError_AuthenticationFailed value)? authenticationFailed,
+    TResult? Function(GitError_RepositoryNotFound value)? repositoryNotFound,
+    TResult? Function(GitError_NotFound value)? notFound,
+    TResult? Function(GitError_NetworkUnreachable value)? networkUnreachable,
+    TResult? Function(GitError_Locked value)? locked,
+    TResult? Function(GitError_DetachedHead value)? detachedHead,
+    TResult? Function(GitError_MergeConflict value)? mergeConflict,
+    TResult? Function(GitError_StashConflict value)? stashConflict,
+    TResult? Function(GitError_NonFastForward value)? nonFastForward,
+    TResult? Function(GitError_CertificateInvalid value)? certificateInvalid,
+    TResult? Function(GitError_UnknownHostKey value)? unknownHostKey,
+    TResult? Function(GitError_HostKeyMismatch value)? hostKeyMismatch,
+    TResult? Function(GitError_Cancelled value)? cancelled,
+    TResult? Function(GitError_UnbornBranch value)? unbornBranch,
+    TResult? Function(GitError_Other value)? other,
+  }) {
+    return nonFastForward?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GitError_Io value)? io,
+    TResult Function(GitError_Git value)? git,
+    TResult Function(GitError_AuthenticationFailed value)? authenticationFailed,
+    TResult Function(GitError_RepositoryNotFound value)? repositoryNotFound,
+    TResult Function(GitError_NotFound value)? notFound,
+    TResult Function(GitError_NetworkUnreachable value)? networkUnreachable,
+    TResult Function(GitError_Locked value)? locked,
+    TResult Function(GitError_DetachedHead value)? detachedHead,
+    TResult Function(GitError_MergeConflict value)? mergeConflict,
+    TResult Function(GitError_StashConflict value)? stashConflict,
+    TResult Function(GitError_NonFastForward value)? nonFastForward,
+    TResult Function(GitError_CertificateInvalid value)? certificateInvalid,
+    TResult Function(GitError_UnknownHostKey value)? unknownHostKey,
+    TResult Function(GitError_HostKeyMismatch value)? hostKeyMismatch,
+    TResult Function(GitError_Cancelled value)? cancelled,
+    TResult Function(GitError_UnbornBranch value)? unbornBranch,
+    TResult Function(GitError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (nonFastForward != null) {
+      return nonFastForward(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GitError_NonFastForward extends GitError {
+  const factory GitError_NonFastForward(final String field0) = _$GitError_NonFastForwardImpl;
+  const GitError_NonFastForward._() : super._();
+
+  String get field0;
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GitError_NonFastForwardImplCopyWith<_$GitError_NonFastForwardImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$GitError_CertificateInvalidImplCopyWith<$Res> {
+  factory _$$GitError_CertificateInvalidImplCopyWith(
+    _$GitError_CertificateInvalidImpl value,
+    $Res Function(_$GitError_CertificateInvalidImpl) then,
+  ) = __$$GitError_CertificateInvalidImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String host, CertificateError reason});
+
+  $CertificateErrorCopyWith<$Res> get reason;
+}
+
+/// @nodoc
+class __$$GitError_CertificateInvalidImplCopyWithImpl<$Res>
+    extends _$GitErrorCopyWithImpl<$Res, _$GitError_CertificateInvalidImpl>
+    implements _$$GitError_CertificateInvalidImplCopyWith<$Res> {
+  __$$GitError_CertificateInvalidImplCopyWithImpl(
+    _$GitError_CertificateInvalidImpl _value,
+    $Res Function(_$GitError_CertificateInvalidImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? host = null, Object? reason = null}) {
+    return _then(
+      _$GitError_CertificateInvalidImpl(
+        host: null == host
+            ? _value.host
+            : host // ignore: cast_nullable_to_non_nullable
+                  as String,
+        reason: null == reason
+            ? _value.reason
+            : reason // ignore: cast_nullable_to_non_nullable
+                  as CertificateError,
+      ),
+    );
+  }
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $CertificateErrorCopyWith<$Res> get reason {
+    return $CertificateErrorCopyWith<$Res>(_value.reason, (value) {
+      return _then(_value.copyWith(reason: value));
+    });
+  }
+}
+
+/// @nodoc
+
+class _$GitError_CertificateInvalidImpl extends GitError_CertificateInvalid {
+  const _$GitError_CertificateInvalidImpl({required this.host, required this.reason}) : super._();
+
+  @override
+  final String host;
+  @override
+  final CertificateError reason;
+
+  @override
+  String toString() {
+    return 'GitError.certificateInvalid(host: $host, reason: $reason)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GitError_CertificateInvalidImpl &&
+            (identical(other.host, host) || other.host == host) &&
+            (identical(other.reason, reason) || other.reason == reason));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, host, reason);
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GitError_CertificateInvalidImplCopyWith<_$GitError_CertificateInvalidImpl> get copyWith =>
+      __$$GitError_CertificateInvalidImplCopyWithImpl<_$GitError_CertificateInvalidImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String field0) io,
+    required TResult Function(String field0) git,
+    required TResult Function(String field0) authenticationFailed,
+    required TResult Function(String field0) repositoryNotFound,
+    required TResult Function(String field0) notFound,
+    required TResult Function(String field0) networkUnreachable,
+    required TResult Function(String field0) locked,
+    required TResult Function(String field0) detachedHead,
+    required TResult Function(List<String> field0) mergeConflict,
+    required TResult Function(List<String> field0) stashConflict,
+    required TResult Function(String field0) nonFastForward,
+    required TResult Function(String host, CertificateError reason) certificateInvalid,
+    required TResult Function(HostKey field0) unknownHostKey,
+    required TResult Function(HostKey field0) hostKeyMismatch,
+    required TResult Function() cancelled,
+    required TResult Function(String field0) unbornBranch,
+    required TResult Function(String field0) other,
+  }) {
+    return certificateInvalid(host, reason);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String field0)? io,
+    TResult? Function(String field0)? git,
+    TResult? Function(String field0)? authenticationFailed,
+    TResult? Function(String field0)? repositoryNotFound,
+    TResult? Function(String field0)? notFound,
+    TResult? Function(String field0)? networkUnreachable,
+    TResult? Function(String field0)? locked,
+    TResult? Function(String field0)? detachedHead,
+    TResult? Function(List<String> field0)? mergeConflict,
+    TResult? Function(List<String> field0)? stashConflict,
+    TResult? Function(String field0)? nonFastForward,
+    TResult? Function(String host, CertificateError reason)? certificateInvalid,
+    TResult? Function(HostKey field0)? unknownHostKey,
+    TResult? Function(HostKey field0)? hostKeyMismatch,
+    TResult? Function()? cancelled,
+    TResult? Function(String field0)? unbornBranch,
+    TResult? Function(String field0)? other,
+  }) {
+    return certificateInvalid?.call(host, reason);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String field0)? io,
+    TResult Function(String field0)? git,
+    TResult Function(String field0)? authenticationFailed,
+    TResult Function(String field0)? repositoryNotFound,
+    TResult Function(String field0)? notFound,
+    TResult Function(String field0)? networkUnreachable,
+    TResult Function(String field0)? locked,
+    TResult Function(String field0)? detachedHead,
+    TResult Function(List<String> field0)? mergeConflict,
+    TResult Function(List<String> field0)? stashConflict,
+    TResult Function(String field0)? nonFastForward,
+    TResult Function(String host, CertificateError reason)? certificateInvalid,
+    TResult Function(HostKey field0)? unknownHostKey,
+    TResult Function(HostKey field0)? hostKeyMismatch,
+    TResult Function()? cancelled,
+    TResult Function(String field0)? unbornBranch,
+    TResult Function(String field0)? other,
+    required TResult orElse(),
+  }) {
+    if (certificateInvalid != null) {
+      return certificateInvalid(host, reason);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GitError_Io value) io,
+    required TResult Function(GitError_Git value) git,
+    required TResult Function(GitError_AuthenticationFailed value) authenticationFailed,
+    required TResult Function(GitError_RepositoryNotFound value) repositoryNotFound,
+    required TResult Function(GitError_NotFound value) notFound,
+    required TResult Function(GitError_NetworkUnreachable value) networkUnreachable,
+    required TResult Function(GitError_Locked value) locked,
+    required TResult Function(GitError_DetachedHead value) detachedHead,
+    required TResult Function(GitError_MergeConflict value) mergeConflict,
+    required TResult Function(GitError_StashConflict value) stashConflict,
+    required TResult Function(GitError_NonFastForward value) nonFastForward,
+    required TResult Function(GitError_CertificateInvalid value) certificateInvalid,
+    required TResult Function(GitError_UnknownHostKey value) unknownHostKey,
+    required TResult Function(GitError_HostKeyMismatch value) hostKeyMismatch,
+    required TResult Function(GitError_Cancelled value) cancelled,
+    required TResult Function(GitError_UnbornBranch value) unbornBranch,
+    required TResult Function(GitError_Other value) other,
+  }) {
+    return certificateInvalid(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GitError_Io value)? io,
+    TResult? Function(GitError_Git value)? git,
+    TResult? Function(GitError_AuthenticationFailed value)? authenticationFailed,
+    TResult? Function(GitError_RepositoryNotFound value)? repositoryNotFound,
+    TResult? Function(GitError_NotFound value)? notFound,
+    TResult? Function(GitError_NetworkUnreachable value)? networkUnreachable,
+    TResult? Function(GitError_Locked value)? locked,
+    TResult? Function(GitError_DetachedHead value)? detachedHead,
+    TResult? Function(GitError_MergeConflict value)? mergeConflict,
+    TResult? Function(GitError_StashConflict value)? stashConflict,
+    TResult? Function(GitError_NonFastForward value)? nonFastForward,
+    TResult? Function(GitError_CertificateInvalid value)? certificateInvalid,
+    TResult? Function(GitError_UnknownHostKey value)? unknownHostKey,
+    TResult? Function(GitError_HostKeyMismatch value)? hostKeyMismatch,
+    TResult? Function(GitError_Cancelled value)? cancelled,
+    TResult? Function(GitError_UnbornBranch value)? unbornBranch,
+    TResult? Function(GitError_Other value)? other,
+  }) {
+    return certificateInvalid?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GitError_Io value)? io,
+    TResult Function(GitError_Git value)? git,
+    TResult Function(GitError_AuthenticationFailed value)? authenticationFailed,
+    TResult Function(GitError_RepositoryNotFound value)? repositoryNotFound,
+    TResult Function(GitError_NotFound value)? notFound,
+    TResult Function(GitError_NetworkUnreachable value)? networkUnreachable,
+    TResult Function(GitError_Locked value)? locked,
+    TResult Function(GitError_DetachedHead value)? detachedHead,
+    TResult Function(GitError_MergeConflict value)? mergeConflict,
+    TResult Function(GitError_StashConflict value)? stashConflict,
+    TResult Function(GitError_NonFastForward value)? nonFastForward,
+    TResult Function(GitError_CertificateInvalid value)? certificateInvalid,
+    TResult Function(GitError_UnknownHostKey value)? unknownHostKey,
+    TResult Function(GitError_HostKeyMismatch value)? hostKeyMismatch,
+    TResult Function(GitError_Cancelled value)? cancelled,
+    TResult Function(GitError_UnbornBranch value)? unbornBranch,
+    TResult Function(GitError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (certificateInvalid != null) {
+      return certificateInvalid(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GitError_CertificateInvalid extends GitError {
+  const factory GitError_CertificateInvalid({
+    required final String host,
+    required final CertificateError reason,
+  }) = _$GitError_CertificateInvalidImpl;
+  const GitError_CertificateInvalid._() : super._();
+
+  String get host;
+
+  CertificateError get reason;
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GitError_CertificateInvalidImplCopyWith<_$GitError_CertificateInvalidImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$GitError_UnknownHostKeyImplCopyWith<$Res> {
+  factory _$$GitError_UnknownHostKeyImplCopyWith(
+    _$GitError_UnknownHostKeyImpl value,
+    $Res Function(_$GitError_UnknownHostKeyImpl) then,
+  ) = __$$GitError_UnknownHostKeyImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({HostKey field0});
+}
+
+/// @nodoc
+class __$$GitError_UnknownHostKeyImplCopyWithImpl<$Res>
+    extends _$GitErrorCopyWithImpl<$Res, _$GitError_UnknownHostKeyImpl>
+    implements _$$GitError_UnknownHostKeyImplCopyWith<$Res> {
+  __$$GitError_UnknownHostKeyImplCopyWithImpl(
+    _$GitError_UnknownHostKeyImpl _value,
+    $Res Function(_$GitError_UnknownHostKeyImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field0 = null}) {
+    return _then(
+      _$GitError_UnknownHostKeyImpl(
+        null == field0
+            ? _value.field0
+            : field0 // ignore: cast_nullable_to_non_nullable
+                  as HostKey,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$GitError_UnknownHostKeyImpl extends GitError_UnknownHostKey {
+  const _$GitError_UnknownHostKeyImpl(this.field0) : super._();
+
+  @override
+  final HostKey field0;
+
+  @override
+  String toString() {
+    return 'GitError.unknownHostKey(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GitError_UnknownHostKeyImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GitError_UnknownHostKeyImplCopyWith<_$GitError_UnknownHostKeyImpl> get copyWith =>
+      __$$GitError_UnknownHostKeyImplCopyWithImpl<_$GitError_UnknownHostKeyImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String field0) io,
+    required TResult Function(String field0) git,
+    required TResult Function(String field0) authenticationFailed,
+    required TResult Function(String field0) repositoryNotFound,
+    required TResult Function(String field0) notFound,
+    required TResult Function(String field0) networkUnreachable,
+    required TResult Function(String field0) locked,
+    required TResult Function(String field0) detachedHead,
+    required TResult Function(List<String> field0) mergeConflict,
+    required TResult Function(List<String> field0) stashConflict,
+    required TResult Function(String field0) nonFastForward,
+    required TResult Function(String host, CertificateError reason) certificateInvalid,
+    required TResult Function(HostKey field0) unknownHostKey,
+    required TResult Function(HostKey field0) hostKeyMismatch,
+    required TResult Function() cancelled,
+    required TResult Function(String field0) unbornBranch,
+    required TResult Function(String field0) other,
+  }) {
+    return unknownHostKey(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String field0)? io,
+    TResult? Function(String field0)? git,
+    TResult? Function(String field0)? authenticationFailed,
+    TResult? Function(String field0)? repositoryNotFound,
+    TResult? Function(String field0)? notFound,
+    TResult? Function(String field0)? networkUnreachable,
+    TResult? Function(String field0)? locked,
+    TResult? Function(String field0)? detachedHead,
+    TResult? Function(List<String> field0)? mergeConflict,
+    TResult? Function(List<String> field0)? stashConflict,
+    TResult? Function(String field0)? nonFastForward,
+    TResult? Function(String host, CertificateError reason)? certificateInvalid,
+    TResult? Function(HostKey field0)? unknownHostKey,
+    TResult? Function(HostKey field0)? hostKeyMismatch,
+    TResult? Function()? cancelled,
+    TResult? Function(String field0)? unbornBranch,
+    TResult? Function(String field0)? other,
+  }) {
+    return unknownHostKey?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String field0)? io,
+    TResult Function(String field0)? git,
+    TResult Function(String field0)? authenticationFailed,
+    TResult Function(String field0)? repositoryNotFound,
+    TResult Function(String field0)? notFound,
+    TResult Function(String field0)? networkUnreachable,
+    TResult Function(String field0)? locked,
+    TResult Function(String field0)? detachedHead,
+    TResult Function(List<String> field0)? mergeConflict,
+    TResult Function(List<String> field0)? stashConflict,
+    TResult Function(String field0)? nonFastForward,
+    TResult Function(String host, CertificateError reason)? certificateInvalid,
+    TResult Function(HostKey field0)? unknownHostKey,
+    TResult Function(HostKey field0)? hostKeyMismatch,
+    TResult Function()? cancelled,
+    TResult Function(String field0)? unbornBranch,
+    TResult Function(String field0)? other,
+    required TResult orElse(),
+  }) {
+    if (unknownHostKey != null) {
+      return unknownHostKey(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GitError_Io value) io,
+    required TResult Function(GitError_Git value) git,
+    required TResult Function(GitError_AuthenticationFailed value) authenticationFailed,
+    required TResult Function(GitError_RepositoryNotFound value) repositoryNotFound,
+    required TResult Function(GitError_NotFound value) notFound,
+    required TResult Function(GitError_NetworkUnreachable value) networkUnreachable,
+    required TResult Function(GitError_Locked value) locked,
+    required TResult Function(GitError_DetachedHead value) detachedHead,
+    required TResult Function(GitError_MergeConflict value) mergeConflict,
+    required TResult Function(GitError_StashConflict value) stashConflict,
+    required TResult Function(GitError_NonFastForward value) nonFastForward,
+    required TResult Function(GitError_CertificateInvalid value) certificateInvalid,
+    required TResult Function(GitError_UnknownHostKey value) unknownHostKey,
+    required TResult Function(GitError_HostKeyMismatch value) hostKeyMismatch,
+    required TResult Function(GitError_Cancelled value) cancelled,
+    required TResult Function(GitError_UnbornBranch value) unbornBranch,
+    required TResult Function(GitError_Other value) other,
+  }) {
+    return unknownHostKey(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GitError_Io value)? io,
+    TResult? Function(GitError_Git value)? git,
+    TResult? Function(GitError_AuthenticationFailed value)? authenticationFailed,
+    TResult? Function(GitError_RepositoryNotFound value)? repositoryNotFound,
+    TResult? Function(GitError_NotFound value)? notFound,
+    TResult? Function(GitError_NetworkUnreachable value)? networkUnreachable,
+    TResult? Function(GitError_Locked value)? locked,
+    TResult? Function(GitError_DetachedHead value)? detachedHead,
+    TResult? Function(GitError_MergeConflict value)? mergeConflict,
+    TResult? Function(GitError_StashConflict value)? stashConflict,
+    TResult? Function(GitError_NonFastForward value)? nonFastForward,
+    TResult? Function(GitError_CertificateInvalid value)? certificateInvalid,
+    TResult? Function(GitError_UnknownHostKey value)? unknownHostKey,
+    TResult? Function(GitError_HostKeyMismatch value)? hostKeyMismatch,
+    TResult? Function(GitError_Cancelled value)? cancelled,
+    TResult? Function(GitError_UnbornBranch value)? unbornBranch,
+    TResult? Function(GitError_Other value)? other,
+  }) {
+    return unknownHostKey?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GitError_Io value)? io,
+    TResult Function(GitError_Git value)? git,
+    TResult Function(GitError_AuthenticationFailed value)? authenticationFailed,
+    TResult Function(GitError_RepositoryNotFound value)? repositoryNotFound,
+    TResult Function(GitError_NotFound value)? notFound,
+    TResult Function(GitError_NetworkUnreachable value)? networkUnreachable,
+    TResult Function(GitError_Locked value)? locked,
+    TResult Function(GitError_DetachedHead value)? detachedHead,
+    TResult Function(GitError_MergeConflict value)? mergeConflict,
+    TResult Function(GitError_StashConflict value)? stashConflict,
+    TResult Function(GitError_NonFastForward value)? nonFastForward,
+    TResult Function(GitError_CertificateInvalid value)? certificateInvalid,
+    TResult Function(GitError_UnknownHostKey value)? unknownHostKey,
+    TResult Function(GitError_HostKeyMismatch value)? hostKeyMismatch,
+    TResult Function(GitError_Cancelled value)? cancelled,
+    TResult Function(GitError_UnbornBranch value)? unbornBranch,
+    TResult Function(GitError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (unknownHostKey != null) {
+      return unknownHostKey(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GitError_UnknownHostKey extends GitError {
+  const factory GitError_UnknownHostKey(final HostKey field0) = _$GitError_UnknownHostKeyImpl;
+  const GitError_UnknownHostKey._() : super._();
+
+  HostKey get field0;
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GitError_UnknownHostKeyImplCopyWith<_$GitError_UnknownHostKeyImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$GitError_HostKeyMismatchImplCopyWith<$Res> {
+  factory _$$GitError_HostKeyMismatchImplCopyWith(
+    _$GitError_HostKeyMismatchImpl value,
+    $Res Function(_$GitError_HostKeyMismatchImpl) then,
+  ) = __$$GitError_HostKeyMismatchImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({HostKey field0});
+}
+
+/// @nodoc
+class __$$GitError_HostKeyMismatchImplCopyWithImpl<$Res>
+    extends _$GitErrorCopyWithImpl<$Res, _$GitError_HostKeyMismatchImpl>
+    implements _$$GitError_HostKeyMismatchImplCopyWith<$Res> {
+  __$$GitError_HostKeyMismatchImplCopyWithImpl(
+    _$GitError_HostKeyMismatchImpl _value,
+    $Res Function(_$GitError_HostKeyMismatchImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field0 = null}) {
+    return _then(
+      _$GitError_HostKeyMismatchImpl(
+        null == field0
+            ? _value.field0
+            : field0 // ignore: cast_nullable_to_non_nullable
+                  as HostKey,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$GitError_HostKeyMismatchImpl extends GitError_HostKeyMismatch {
+  const _$GitError_HostKeyMismatchImpl(this.field0) : super._();
+
+  @override
+  final HostKey field0;
+
+  @override
+  String toString() {
+    return 'GitError.hostKeyMismatch(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GitError_HostKeyMismatchImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GitError_HostKeyMismatchImplCopyWith<_$GitError_HostKeyMismatchImpl> get copyWith =>
+      __$$GitError_HostKeyMismatchImplCopyWithImpl<_$GitError_HostKeyMismatchImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String field0) io,
+    required TResult Function(String field0) git,
+    required TResult Function(String field0) authenticationFailed,
+    required TResult Function(String field0) repositoryNotFound,
+    required TResult Function(String field0) notFound,
+    required TResult Function(String field0) networkUnreachable,
+    required TResult Function(String field0) locked,
+    required TResult Function(String field0) detachedHead,
+    required TResult Function(List<String> field0) mergeConflict,
+    required TResult Function(List<String> field0) stashConflict,
+    required TResult Function(String field0) nonFastForward,
+    required TResult Function(String host, CertificateError reason) certificateInvalid,
+    required TResult Function(HostKey field0) unknownHostKey,
+    required TResult Function(HostKey field0) hostKeyMismatch,
+    required TResult Function() cancelled,
+    required TResult Function(String field0) unbornBranch,
+    required TResult Function(String field0) other,
+  }) {
+    return hostKeyMismatch(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String field0)? io,
+    TResult? Function(String field0)? git,
+    TResult? Function(String field0)? authenticationFailed,
+    TResult? Function(String field0)? repositoryNotFound,
+    TResult? Function(String field0)? notFound,
+    TResult? Function(String field0)? networkUnreachable,
+    TResult? Function(String field0)? locked,
+    TResult? Function(String field0)? detachedHead,
+    TResult? Function(List<String> field0)? mergeConflict,
+    TResult? Function(List<String> field0)? stashConflict,
+    TResult? Function(String field0)? nonFastForward,
+    TResult? Function(String host, CertificateError reason)? certificateInvalid,
+    TResult? Function(HostKey field0)? unknownHostKey,
+    TResult? Function(HostKey field0)? hostKeyMismatch,
+    TResult? Function()? cancelled,
+    TResult? Function(String field0)? unbornBranch,
+    TResult? Function(String field0)? other,
+  }) {
+    return hostKeyMismatch?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String field0)? io,
+    TResult Function(String field0)? git,
+    TResult Function(String field0)? authenticationFailed,
+    TResult Function(String field0)? repositoryNotFound,
+    TResult Function(String field0)? notFound,
+    TResult Function(String field0)? networkUnreachable,
+    TResult Function(String field0)? locked,
+    TResult Function(String field0)? detachedHead,
+    TResult Function(List<String> field0)? mergeConflict,
+    TResult Function(List<String> field0)? stashConflict,
+    TResult Function(String field0)? nonFastForward,
+    TResult Function(String host, CertificateError reason)? certificateInvalid,
+    TResult Function(HostKey field0)? unknownHostKey,
+    TResult Function(HostKey field0)? hostKeyMismatch,
+    TResult Function()? cancelled,
+    TResult Function(String field0)? unbornBranch,
+    TResult Function(String field0)? other,
+    required TResult orElse(),
+  }) {
+    if (hostKeyMismatch != null) {
+      return hostKeyMismatch(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GitError_Io value) io,
+    required TResult Function(GitError_Git value) git,
+    required TResult Function(GitError_AuthenticationFailed value) authenticationFailed,
+    required TResult Function(GitError_RepositoryNotFound value) repositoryNotFound,
+    required TResult Function(GitError_NotFound value) notFound,
+    required TResult Function(GitError_NetworkUnreachable value) networkUnreachable,
+    required TResult Function(GitError_Locked value) locked,
+    required TResult Function(GitError_DetachedHead value) detachedHead,
+    required TResult Function(GitError_MergeConflict value) mergeConflict,
+    required TResult Function(GitError_StashConflict value) stashConflict,
+    required TResult Function(GitError_NonFastForward value) nonFastForward,
+    required TResult Function(GitError_CertificateInvalid value) certificateInvalid,
+    required TResult Function(GitError_UnknownHostKey value) unknownHostKey,
+    required TResult Function(GitError_HostKeyMismatch value) hostKeyMismatch,
+    required TResult Function(GitError_Cancelled value) cancelled,
+    required TResult Function(GitError_UnbornBranch value) unbornBranch,
+    required TResult Function(GitError_Other value) other,
+  }) {
+    return hostKeyMismatch(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GitError_Io value)? io,
+    TResult? Function(GitError_Git value)? git,
+    TResult? Function(GitError_AuthenticationFailed value)? authenticationFailed,
+    TResult? Function(GitError_RepositoryNotFound value)? repositoryNotFound,
+    TResult? Function(GitError_NotFound value)? notFound,
+    TResult? Function(GitError_NetworkUnreachable value)? networkUnreachable,
+    TResult? Function(GitError_Locked value)? locked,
+    TResult? Function(GitError_DetachedHead value)? detachedHead,
+    TResult? Function(GitError_MergeConflict value)? mergeConflict,
+    TResult? Function(GitError_StashConflict value)? stashConflict,
+    TResult? Function(GitError_NonFastForward value)? nonFastForward,
+    TResult? Function(GitError_CertificateInvalid value)? certificateInvalid,
+    TResult? Function(GitError_UnknownHostKey value)? unknownHostKey,
+    TResult? Function(GitError_HostKeyMismatch value)? hostKeyMismatch,
+    TResult? Function(GitError_Cancelled value)? cancelled,
+    TResult? Function(GitError_UnbornBranch value)? unbornBranch,
+    TResult? Function(GitError_Other value)? other,
+  }) {
+    return hostKeyMismatch?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GitError_Io value)? io,
+    TResult Function(GitError_Git value)? git,
+    TResult Function(GitError_AuthenticationFailed value)? authenticationFailed,
+    TResult Function(GitError_RepositoryNotFound value)? repositoryNotFound,
+    TResult Function(GitError_NotFound value)? notFound,
+    TResult Function(GitError_NetworkUnreachable value)? networkUnreachable,
+    TResult Function(GitError_Locked value)? locked,
+    TResult Function(GitError_DetachedHead value)? detachedHead,
+    TResult Function(GitError_MergeConflict value)? mergeConflict,
+    TResult Function(GitError_StashConflict value)? stashConflict,
+    TResult Function(GitError_NonFastForward value)? nonFastForward,
+    TResult Function(GitError_CertificateInvalid value)? certificateInvalid,
+    TResult Function(GitError_UnknownHostKey value)? unknownHostKey,
+    TResult Function(GitError_HostKeyMismatch value)? hostKeyMismatch,
+    TResult Function(GitError_Cancelled value)? cancelled,
+    TResult Function(GitError_UnbornBranch value)? unbornBranch,
+    TResult Function(GitError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (hostKeyMismatch != null) {
+      return hostKeyMismatch(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GitError_HostKeyMismatch extends GitError {
+  const factory GitError_HostKeyMismatch(final HostKey field0) = _$GitError_HostKeyMismatchImpl;
+  const GitError_HostKeyMismatch._() : super._();
+
+  HostKey get field0;
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GitError_HostKeyMismatchImplCopyWith<_$GitError_HostKeyMismatchImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$GitError_CancelledImplCopyWith<$Res> {
+  factory _$$GitError_CancelledImplCopyWith(
+    _$GitError_CancelledImpl value,
+    $Res Function(_$GitError_CancelledImpl) then,
+  ) = __$$GitError_CancelledImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$GitError_CancelledImplCopyWithImpl<$Res>
+    extends _$GitErrorCopyWithImpl<$Res, _$GitError_CancelledImpl>
+    implements _$$GitError_CancelledImplCopyWith<$Res> {
+  __$$GitError_CancelledImplCopyWithImpl(
+    _$GitError_CancelledImpl _value,
+    $Res Function(_$GitError_CancelledImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$GitError_CancelledImpl extends GitError_Cancelled {
+  const _$GitError_CancelledImpl() : super._();
+
+  @override
+  String toString() {
+    return 'GitError.cancelled()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType && other is _$GitError_CancelledImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String field0) io,
+    required TResult Function(String field0) git,
+    required TResult Function(String field0) authenticationFailed,
+    required TResult Function(String field0) repositoryNotFound,
+    required TResult Function(String field0) notFound,
+    required TResult Function(String field0) networkUnreachable,
+    required TResult Function(String field0) locked,
+    required TResult Function(String field0) detachedHead,
+    required TResult Function(List<String> field0) mergeConflict,
+    required TResult Function(List<String> field0) stashConflict,
+    required TResult Function(String field0) nonFastForward,
+    required TResult Function(String host, CertificateError reason) certificateInvalid,
+    required TResult Function(HostKey field0) unknownHostKey,
+    required TResult Function(HostKey field0) hostKeyMismatch,
+    required TResult Function() cancelled,
+    required TResult Function(String field0) unbornBranch,
+    required TResult Function(String field0) other,
+  }) {
+    return cancelled();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String field0)? io,
+    TResult? Function(String field0)? git,
+    TResult? Function(String field0)? authenticationFailed,
+    TResult? Function(String field0)? repositoryNotFound,
+    TResult? Function(String field0)? notFound,
+    TResult? Function(String field0)? networkUnreachable,
+    TResult? Function(String field0)? locked,
+    TResult? Function(String field0)? detachedHead,
+    TResult? Function(List<String> field0)? mergeConflict,
+    TResult? Function(List<String> field0)? stashConflict,
+    TResult? Function(String field0)? nonFastForward,
+    TResult? Function(String host, CertificateError reason)? certificateInvalid,
+    TResult? Function(HostKey field0)? unknownHostKey,
+    TResult? Function(HostKey field0)? hostKeyMismatch,
+    TResult? Function()? cancelled,
+    TResult? Function(String field0)? unbornBranch,
+    TResult? Function(String field0)? other,
+  }) {
+    return cancelled?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String field0)? io,
+    TResult Function(String field0)? git,
+    TResult Function(String field0)? authenticationFailed,
+    TResult Function(String field0)? repositoryNotFound,
+    TResult Function(String field0)? notFound,
+    TResult Function(String field0)? networkUnreachable,
+    TResult Function(String field0)? locked,
+    TResult Function(String field0)? detachedHead,
+    TResult Function(List<String> field0)? mergeConflict,
+    TResult Function(List<String> field0)? stashConflict,
+    TResult Function(String field0)? nonFastForward,
+    TResult Function(String host, CertificateError reason)? certificateInvalid,
+    TResult Function(HostKey field0)? unknownHostKey,
+    TResult Function(HostKey field0)? hostKeyMismatch,
+    TResult Function()? cancelled,
+    TResult Function(String field0)? unbornBranch,
+    TResult Function(String field0)? other,
+    required TResult orElse(),
+  }) {
+    if (cancelled != null) {
+      return cancelled();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GitError_Io value) io,
+    required TResult Function(GitError_Git value) git,
+    required TResult Function(GitError_AuthenticationFailed value) authenticationFailed,
+    required TResult Function(GitError_RepositoryNotFound value) repositoryNotFound,
+    required TResult Function(GitError_NotFound value) notFound,
+    required TResult Function(GitError_NetworkUnreachable value) networkUnreachable,
+    required TResult Function(GitError_Locked value) locked,
+    required TResult Function(GitError_DetachedHead value) detachedHead,
+    required TResult Function(GitError_MergeConflict value) mergeConflict,
+    required TResult Function(GitError_StashConflict value) stashConflict,
+    required TResult Function(GitError_NonFastForward value) nonFastForward,
+    required TResult Function(GitError_CertificateInvalid value) certificateInvalid,
+    required TResult Function(GitError_UnknownHostKey value) unknownHostKey,
+    required TResult Function(GitError_HostKeyMismatch value) hostKeyMismatch,
+    required TResult Function(GitError_Cancelled value) cancelled,
+    required TResult Function(GitError_UnbornBranch value) unbornBranch,
+    required TResult Function(GitError_Other value) other,
+  }) {
+    return cancelled(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GitError_Io value)? io,
+    TResult? Function(GitError_Git value)? git,
+    TResult? Function(GitError_AuthenticationFailed value)? authenticationFailed,
+    TResult? Function(GitError_RepositoryNotFound value)? repositoryNotFound,
+    TResult? Function(GitError_NotFound value)? notFound,
+    TResult? Function(GitError_NetworkUnreachable value)? networkUnreachable,
+    TResult? Function(GitError_Locked value)? locked,
+    TResult? Function(GitError_DetachedHead value)? detachedHead,
+    TResult? Function(GitError_MergeConflict value)? mergeConflict,
+    TResult? Function(GitError_StashConflict value)? stashConflict,
+    TResult? Function(GitError_NonFastForward value)? nonFastForward,
+    TResult? Function(GitError_CertificateInvalid value)? certificateInvalid,
+    TResult? Function(GitError_UnknownHostKey value)? unknownHostKey,
+    TResult? Function(GitError_HostKeyMismatch value)? hostKeyMismatch,
+    TResult? Function(GitError_Cancelled value)? cancelled,
+    TResult? Function(GitError_UnbornBranch value)? unbornBranch,
+    TResult? Function(GitError_Other value)? other,
+  }) {
+    return cancelled?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GitError_Io value)? io,
+    TResult Function(GitError_Git value)? git,
+    TResult Function(GitError_AuthenticationFailed value)? authenticationFailed,
+    TResult Function(GitError_RepositoryNotFound value)? repositoryNotFound,
+    TResult Function(GitError_NotFound value)? notFound,
+    TResult Function(GitError_NetworkUnreachable value)? networkUnreachable,
+    TResult Function(GitError_Locked value)? locked,
+    TResult Function(GitError_DetachedHead value)? detachedHead,
+    TResult Function(GitError_MergeConflict value)? mergeConflict,
+    TResult Function(GitError_StashConflict value)? stashConflict,
+    TResult Function(GitError_NonFastForward value)? nonFastForward,
+    TResult Function(GitError_CertificateInvalid value)? certificateInvalid,
+    TResult Function(GitError_UnknownHostKey value)? unknownHostKey,
+    TResult Function(GitError_HostKeyMismatch value)? hostKeyMismatch,
+    TResult Function(GitError_Cancelled value)? cancelled,
+    TResult Function(GitError_UnbornBranch value)? unbornBranch,
+    TResult Function(GitError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (cancelled != null) {
+      return cancelled(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GitError_Cancelled extends GitError {
+  const factory GitError_Cancelled() = _$GitError_CancelledImpl;
+  const GitError_Cancelled._() : super._();
+}
+
+/// @nodoc
+abstract class _$$GitError_UnbornBranchImplCopyWith<$Res> {
+  factory _$$GitError_UnbornBranchImplCopyWith(
+    _$GitError_UnbornBranchImpl value,
+    $Res Function(_$GitError_UnbornBranchImpl) then,
+  ) = __$$GitError_UnbornBranchImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class __$$GitError_UnbornBranchImplCopyWithImpl<$Res>
+    extends _$GitErrorCopyWithImpl<$Res, _$GitError_UnbornBranchImpl>
+    implements _$$GitError_UnbornBranchImplCopyWith<$Res> {
+  __$$GitError_UnbornBranchImplCopyWithImpl(
+    _$GitError_UnbornBranchImpl _value,
+    $Res Function(_$GitError_UnbornBranchImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field0 = null}) {
+    return _then(
+      _$GitError_UnbornBranchImpl(
+        null == field0
+            ? _value.field0
+            : field0 // ignore: cast_nullable_to_non_nullable
+                  as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$GitError_UnbornBranchImpl extends GitError_UnbornBranch {
+  const _$GitError_UnbornBranchImpl(this.field0) : super._();
+
+  @override
+  final String field0;
+
+  @override
+  String toString() {
+    return 'GitError.unbornBranch(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GitError_UnbornBranchImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GitError_UnbornBranchImplCopyWith<_$GitError_UnbornBranchImpl> get copyWith =>
+      __$$GitError_UnbornBranchImplCopyWithImpl<_$GitError_UnbornBranchImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String field0) io,
+    required TResult Function(String field0) git,
+    required TResult Function(String field0) authenticationFailed,
+    required TResult Function(String field0) repositoryNotFound,
+    required TResult Function(String field0) notFound,
+    required TResult Function(String field0) networkUnreachable,
+    required TResult Function(String field0) locked,
+    required TResult Function(String field0) detachedHead,
+    required TResult Function(List<String> field0) mergeConflict,
+    required TResult Function(List<String> field0) stashConflict,
+    required TResult Function(String field0) nonFastForward,
+    required TResult Function(String host, CertificateError reason) certificateInvalid,
+    required TResult Function(HostKey field0) unknownHostKey,
+    required TResult Function(HostKey field0) hostKeyMismatch,
+    required TResult Function() cancelled,
+    required TResult Function(String field0) unbornBranch,
+    required TResult Function(String field0) other,
+  }) {
+    return unbornBranch(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String field0)? io,
+    TResult? Function(String field0)? git,
+    TResult? Function(String field0)? authenticationFailed,
+    TResult? Function(String field0)? repositoryNotFound,
+    TResult? Function(String field0)? notFound,
+    TResult? Function(String field0)? networkUnreachable,
+    TResult? Function(String field0)? locked,
+    TResult? Function(String field0)? detachedHead,
+    TResult? Function(List<String> field0)? mergeConflict,
+    TResult? Function(List<String> field0)? stashConflict,
+    TResult? Function(String field0)? nonFastForward,
+    TResult? Function(String host, CertificateError reason)? certificateInvalid,
+    TResult? Function(HostKey field0)? unknownHostKey,
+    TResult? Function(HostKey field0)? hostKeyMismatch,
+    TResult? Function()? cancelled,
+    TResult? Function(String field0)? unbornBranch,
+    TResult? Function(String field0)? other,
+  }) {
+    return unbornBranch?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String field0)? io,
+    TResult Function(String field0)? git,
+    TResult Function(String field0)? authenticationFailed,
+    TResult Function(String field0)? repositoryNotFound,
+    TResult Function(String field0)? notFound,
+    TResult Function(String field0)? networkUnreachable,
+    TResult Function(String field0)? locked,
+    TResult Function(String field0)? detachedHead,
+    TResult Function(List<String> field0)? mergeConflict,
+    TResult Function(List<String> field0)? stashConflict,
+    TResult Function(String field0)? nonFastForward,
+    TResult Function(String host, CertificateError reason)? certificateInvalid,
+    TResult Function(HostKey field0)? unknownHostKey,
+    TResult Function(HostKey field0)? hostKeyMismatch,
+    TResult Function()? cancelled,
+    TResult Function(String field0)? unbornBranch,
+    TResult Function(String field0)? other,
+    required TResult orElse(),
+  }) {
+    if (unbornBranch != null) {
+      return unbornBranch(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GitError_Io value) io,
+    required TResult Function(GitError_Git value) git,
+    required TResult Function(GitError_AuthenticationFailed value) authenticationFailed,
+    required TResult Function(GitError_RepositoryNotFound value) repositoryNotFound,
+    required TResult Function(GitError_NotFound value) notFound,
+    required TResult Function(GitError_NetworkUnreachable value) networkUnreachable,
+    required TResult Function(GitError_Locked value) locked,
+    required TResult Function(GitError_DetachedHead value) detachedHead,
+    required TResult Function(GitError_MergeConflict value) mergeConflict,
+    required TResult Function(GitError_StashConflict value) stashConflict,
+    required TResult Function(GitError_NonFastForward value) nonFastForward,
+    required TResult Function(GitError_CertificateInvalid value) certificateInvalid,
+    required TResult Function(GitError_UnknownHostKey value) unknownHostKey,
+    required TResult Function(GitError_HostKeyMismatch value) hostKeyMismatch,
+    required TResult Function(GitError_Cancelled value) cancelled,
+    required TResult Function(GitError_UnbornBranch value) unbornBranch,
+    required TResult Function(GitError_Other value) other,
+  }) {
+    return unbornBranch(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GitError_Io value)? io,
+    TResult? Function(GitError_Git value)? git,
+    TResult? Function(GitError_AuthenticationFailed value)? authenticationFailed,
+    TResult? Function(GitError_RepositoryNotFound value)? repositoryNotFound,
+    TResult? Function(GitError_NotFound value)? notFound,
+    TResult? Function(GitError_NetworkUnreachable value)? networkUnreachable,
+    TResult? Function(GitError_Locked value)? locked,
+    TResult? Function(GitError_DetachedHead value)? detachedHead,
+    TResult? Function(GitError_MergeConflict value)? mergeConflict,
+    TResult? Function(GitError_StashConflict value)? stashConflict,
+    TResult? Function(GitError_NonFastForward value)? nonFastForward,
+    TResult? Function(GitError_CertificateInvalid value)? certificateInvalid,
+    TResult? Function(GitError_UnknownHostKey value)? unknownHostKey,
+    TResult? Function(GitError_HostKeyMismatch value)? hostKeyMismatch,
+    TResult? Function(GitError_Cancelled value)? cancelled,
+    TResult? Function(GitError_UnbornBranch value)? unbornBranch,
+    TResult? Function(GitError_Other value)? other,
+  }) {
+    return unbornBranch?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GitError_Io value)? io,
+    TResult Function(GitError_Git value)? git,
+    TResult Function(GitError_AuthenticationFailed value)? authenticationFailed,
+    TResult Function(GitError_RepositoryNotFound value)? repositoryNotFound,
+    TResult Function(GitError_NotFound value)? notFound,
+    TResult Function(GitError_NetworkUnreachable value)? networkUnreachable,
+    TResult Function(GitError_Locked value)? locked,
+    TResult Function(GitError_DetachedHead value)? detachedHead,
+    TResult Function(GitError_MergeConflict value)? mergeConflict,
+    TResult Function(GitError_StashConflict value)? stashConflict,
+    TResult Function(GitError_NonFastForward value)? nonFastForward,
+    TResult Function(GitError_CertificateInvalid value)? certificateInvalid,
+    TResult Function(GitError_UnknownHostKey value)? unknownHostKey,
+    TResult Function(GitError_HostKeyMismatch value)? hostKeyMismatch,
+    TResult Function(GitError_Cancelled value)? cancelled,
+    TResult Function(GitError_UnbornBranch value)? unbornBranch,
+    TResult Function(GitError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (unbornBranch != null) {
+      return unbornBranch(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GitError_UnbornBranch extends GitError {
+  const factory GitError_UnbornBranch(final String field0) = _$GitError_UnbornBranchImpl;
+  const GitError_UnbornBranch._() : super._();
+
+  String get field0;
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GitError_UnbornBranchImplCopyWith<_$GitError_UnbornBranchImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$GitError_OtherImplCopyWith<$Res> {
+  factory _$$GitError_OtherImplCopyWith(
+    _$GitError_OtherImpl value,
+    $Res Function(_$GitError_OtherImpl) then,
+  ) = __$$GitError_OtherImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class __$$GitError_OtherImplCopyWithImpl<$Res>
+    extends _$GitErrorCopyWithImpl<$Res, _$GitError_OtherImpl>
+    implements _$$GitError_OtherImplCopyWith<$Res> {
+  __$$GitError_OtherImplCopyWithImpl(
+    _$GitError_OtherImpl _value,
+    $Res Function(_$GitError_OtherImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field0 = null}) {
+    return _then(
+      _$GitError_OtherImpl(
+        null == field0
+            ? _value.field0
+            : field0 // ignore: cast_nullable_to_non_nullable
+                  as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$GitError_OtherImpl extends GitError_Other {
+  const _$GitError_OtherImpl(this.field0) : super._();
+
+  @override
+  final String field0;
+
+  @override
+  String toString() {
+    return 'GitError.other(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GitError_OtherImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GitError_OtherImplCopyWith<_$GitError_OtherImpl> get copyWith =>
+      __$$GitError_OtherImplCopyWithImpl<_$GitError_OtherImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String field0) io,
+    required TResult Function(String field0) git,
+    required TResult Function(String field0) authenticationFailed,
+    required TResult Function(String field0) repositoryNotFound,
+    required TResult Function(String field0) notFound,
+    required TResult Function(String field0) networkUnreachable,
+    required TResult Function(String field0) locked,
+    required TResult Function(String field0) detachedHead,
+    required TResult Function(List<String> field0) mergeConflict,
+    required TResult Function(List<String> field0) stashConflict,
+    required TResult Function(String field0) nonFastForward,
+    required TResult Function(String host, CertificateError reason) certificateInvalid,
+    required TResult Function(HostKey field0) unknownHostKey,
+    required TResult Function(HostKey field0) hostKeyMismatch,
+    required TResult Function() cancelled,
+    required TResult Function(String field0) unbornBranch,
+    required TResult Function(String field0) other,
+  }) {
+    return other(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String field0)? io,
+    TResult? Function(String field0)? git,
+    TResult? Function(String field0)? authenticationFailed,
+    TResult? Function(String field0)? repositoryNotFound,
+    TResult? Function(String field0)? notFound,
+    TResult? Function(String field0)? networkUnreachable,
+    TResult? Function(String field0)? locked,
+    TResult? Function(String field0)? detachedHead,
+    TResult? Function(List<String> field0)? mergeConflict,
+    TResult? Function(List<String> field0)? stashConflict,
+    TResult? Function(String field0)? nonFastForward,
+    TResult? Function(String host, CertificateError reason)? certificateInvalid,
+    TResult? Function(HostKey field0)? unknownHostKey,
+    TResult? Function(HostKey field0)? hostKeyMismatch,
+    TResult? Function()? cancelled,
+    TResult? Function(String field0)? unbornBranch,
+    TResult? Function(String field0)? other,
+  }) {
+    return other?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String field0)? io,
+    TResult Function(String field0)? git,
+    TResult Function(String field0)? authenticationFailed,
+    TResult Function(String field0)? repositoryNotFound,
+    TResult Function(String field0)? notFound,
+    TResult Function(String field0)? networkUnreachable,
+    TResult Function(String field0)? locked,
+    TResult Function(String field0)? detachedHead,
+    TResult Function(List<String> field0)? mergeConflict,
+    TResult Function(List<String> field0)? stashConflict,
+    TResult Function(String field0)? nonFastForward,
+    TResult Function(String host, CertificateError reason)? certificateInvalid,
+    TResult Function(HostKey field0)? unknownHostKey,
+    TResult Function(HostKey field0)? hostKeyMismatch,
+    TResult Function()? cancelled,
+    TResult Function(String field0)? unbornBranch,
+    TResult Function(String field0)? other,
+    required TResult orElse(),
+  }) {
+    if (other != null) {
+      return other(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GitError_Io value) io,
+    required TResult Function(GitError_Git value) git,
+    required TResult Function(GitError_AuthenticationFailed value) authenticationFailed,
+    required TResult Function(GitError_RepositoryNotFound value) repositoryNotFound,
+    required TResult Function(GitError_NotFound value) notFound,
+    required TResult Function(GitError_NetworkUnreachable value) networkUnreachable,
+    required TResult Function(GitError_Locked value) locked,
+    required TResult Function(GitError_DetachedHead value) detachedHead,
+    required TResult Function(GitError_MergeConflict value) mergeConflict,
+    required TResult Function(GitError_StashConflict value) stashConflict,
+    required TResult Function(GitError_NonFastForward value) nonFastForward,
+    required TResult Function(GitError_CertificateInvalid value) certificateInvalid,
+    required TResult Function(GitError_UnknownHostKey value) unknownHostKey,
+    required TResult Function(GitError_HostKeyMismatch value) hostKeyMismatch,
+    required TResult Function(GitError_Cancelled value) cancelled,
+    required TResult Function(GitError_UnbornBranch value) unbornBranch,
+    required TResult Function(GitError_Other value) other,
+  }) {
+    return other(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GitError_Io value)? io,
+    TResult? Function(GitError_Git value)? git,
+    TResult? Function(GitError_AuthenticationFailed value)? authenticationFailed,
+    TResult? Function(GitError_RepositoryNotFound value)? repositoryNotFound,
+    TResult? Function(GitError_NotFound value)? notFound,
+    TResult? Function(GitError_NetworkUnreachable value)? networkUnreachable,
+    TResult? Function(GitError_Locked value)? locked,
+    TResult? Function(GitError_DetachedHead value)? detachedHead,
+    TResult? Function(GitError_MergeConflict value)? mergeConflict,
+    TResult? Function(GitError_StashConflict value)? stashConflict,
+    TResult? Function(GitError_NonFastForward value)? nonFastForward,
+    TResult? Function(GitError_CertificateInvalid value)? certificateInvalid,
+    TResult? Function(GitError_UnknownHostKey value)? unknownHostKey,
+    TResult? Function(GitError_HostKeyMismatch value)? hostKeyMismatch,
+    TResult? Function(GitError_Cancelled value)? cancelled,
+    TResult? Function(GitError_UnbornBranch value)? unbornBranch,
+    TResult? Function(GitError_Other value)? other,
+  }) {
+    return other?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GitError_Io value)? io,
+    TResult Function(GitError_Git value)? git,
+    TResult Function(GitError_AuthenticationFailed value)? authenticationFailed,
+    TResult Function(GitError_RepositoryNotFound value)? repositoryNotFound,
+    TResult Function(GitError_NotFound value)? notFound,
+    TResult Function(GitError_NetworkUnreachable value)? networkUnreachable,
+    TResult Function(GitError_Locked value)? locked,
+    TResult Function(GitError_DetachedHead value)? detachedHead,
+    TResult Function(GitError_MergeConflict value)? mergeConflict,
+    TResult Function(GitError_StashConflict value)? stashConflict,
+    TResult Function(GitError_NonFastForward value)? nonFastForward,
+    TResult Function(GitError_CertificateInvalid value)? certificateInvalid,
+    TResult Function(GitError_UnknownHostKey value)? unknownHostKey,
+    TResult Function(GitError_HostKeyMismatch value)? hostKeyMismatch,
+    TResult Function(GitError_Cancelled value)? cancelled,
+    TResult Function(GitError_UnbornBranch value)? unbornBranch,
+    TResult Function(GitError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (other != null) {
+      return other(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GitError_Other extends GitError {
+  const factory GitError_Other(final String field0) = _$GitError_OtherImpl;
+  const GitError_Other._() : super._();
+
+  String get field0;
+
+  /// Create a copy of GitError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GitError_OtherImplCopyWith<_$GitError_OtherImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$GitProgress {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt receivedObjects, BigInt totalObjects, BigInt indexedObjects, BigInt receivedBytes, BigInt totalDeltas, BigInt indexedDeltas) transfer,
+    required TResult Function(String? path, BigInt completed, BigInt total) checkout,
+    required TResult Function(BigInt current, BigInt total, BigInt bytes) pushTransfer,
+    required TResult Function(String field0) sideband,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt receivedObjects, BigInt totalObjects, BigInt indexedObjects, BigInt receivedBytes, BigInt totalDeltas, BigInt indexedDeltas)? transfer,
+    TResult? Function(String? path, BigInt completed, BigInt total)? checkout,
+    TResult? Function(BigInt current, BigInt total, BigInt bytes)? pushTransfer,
+    TResult? Function(String field0)? sideband,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt receivedObjects, BigInt totalObjects, BigInt indexedObjects, BigInt receivedBytes, BigInt totalDeltas, BigInt indexedDeltas)? transfer,
+    TResult Function(String? path, BigInt completed, BigInt total)? checkout,
+    TResult Function(BigInt current, BigInt total, BigInt bytes)? pushTransfer,
+    TResult Function(String field0)? sideband,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GitProgress_Transfer value) transfer,
+    required TResult Function(GitProgress_Checkout value) checkout,
+    required TResult Function(GitProgress_PushTransfer value) pushTransfer,
+    required TResult Function(GitProgress_Sideband value) sideband,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GitProgress_Transfer value)? transfer,
+    TResult? Function(GitProgress_Checkout value)? checkout,
+    TResult? Function(GitProgress_PushTransfer value)? pushTransfer,
+    TResult? Function(GitProgress_Sideband value)? sideband,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GitProgress_Transfer value)? transfer,
+    TResult Function(GitProgress_Checkout value)? checkout,
+    TResult Function(GitProgress_PushTransfer value)? pushTransfer,
+    TResult Function(GitProgress_Sideband value)? sideband,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $GitProgressCopyWith<$Res> {
+  factory $GitProgressCopyWith(GitProgress value, $Res Function(GitProgress) then) =
+      _$GitProgressCopyWithImpl<$Res, GitProgress>;
+}
+
+/// @nodoc
+class _$GitProgressCopyWithImpl<$Res, $Val extends GitProgress>
+    implements $GitProgressCopyWith<$Res> {
+  _$GitProgressCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of GitProgress
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$GitProgress_TransferImplCopyWith<$Res> {
+  factory _$$GitProgress_TransferImplCopyWith(
+    _$GitProgress_TransferImpl value,
+    $Res Function(_$GitProgress_TransferImpl) then,
+  ) = __$$GitProgress_TransferImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({BigInt receivedObjects, BigInt totalObjects, BigInt indexedObjects, BigInt receivedBytes, BigInt totalDeltas, BigInt indexedDeltas});
+}
+
+/// @nodoc
+class __$$GitProgress_TransferImplCopyWithImpl<$Res>
+    extends _$GitProgressCopyWithImpl<$Res, _$GitProgress_TransferImpl>
+    implements _$$GitProgress_TransferImplCopyWith<$Res> {
+  __$$GitProgress_TransferImplCopyWithImpl(
+    _$GitProgress_TransferImpl _value,
+    $Res Function(_$GitProgress_TransferImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of GitProgress
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? receivedObjects = null, Object? totalObjects = null, Object? indexedObjects = null, Object? receivedBytes = null, Object? totalDeltas = null, Object? indexedDeltas = null}) {
+    return _then(
+      _$GitProgress_TransferImpl(
+        receivedObjects: null == receivedObjects
+            ? _value.receivedObjects
+            : receivedObjects // ignore: cast_nullable_to_non_nullable
+                  as BigInt,
+        totalObjects: null == totalObjects
+            ? _value.totalObjects
+            : totalObjects // ignore: cast_nullable_to_non_nullable
+                  as BigInt,
+        indexedObjects: null == indexedObjects
+            ? _value.indexedObjects
+            : indexedObjects // ignore: cast_nullable_to_non_nullable
+                  as BigInt,
+        receivedBytes: null == receivedBytes
+            ? _value.receivedBytes
+            : receivedBytes // ignore: cast_nullable_to_non_nullable
+                  as BigInt,
+        totalDeltas: null == totalDeltas
+            ? _value.totalDeltas
+            : totalDeltas // ignore: cast_nullable_to_non_nullable
+                  as BigInt,
+        indexedDeltas: null == indexedDeltas
+            ? _value.indexedDeltas
+            : indexedDeltas // ignore: cast_nullable_to_non_nullable
+                  as BigInt,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$GitProgress_TransferImpl extends GitProgress_Transfer {
+  const _$GitProgress_TransferImpl({required this.receivedObjects, required this.totalObjects, required this.indexedObjects, required this.receivedBytes, required this.totalDeltas, required this.indexedDeltas}) : super._();
+
+  @override
+  final BigInt receivedObjects;
+  @override
+  final BigInt totalObjects;
+  @override
+  final BigInt indexedObjects;
+  @override
+  final BigInt receivedBytes;
+  @override
+  final BigInt totalDeltas;
+  @override
+  final BigInt indexedDeltas;
+
+  @override
+  String toString() {
+    return 'GitProgress.transfer(receivedObjects: $receivedObjects, totalObjects: $totalObjects, indexedObjects: $indexedObjects, receivedBytes: $receivedBytes, totalDeltas: $totalDeltas, indexedDeltas: $indexedDeltas)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GitProgress_TransferImpl &&
+            (identical(other.receivedObjects, receivedObjects) || other.receivedObjects == receivedObjects) &&
+            (identical(other.totalObjects, totalObjects) || other.totalObjects == totalObjects) &&
+            (identical(other.indexedObjects, indexedObjects) || other.indexedObjects == indexedObjects) &&
+            (identical(other.receivedBytes, receivedBytes) || other.receivedBytes == receivedBytes) &&
+            (identical(other.totalDeltas, totalDeltas) || other.totalDeltas == totalDeltas) &&
+            (identical(other.indexedDeltas, indexedDeltas) || other.indexedDeltas == indexedDeltas));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, receivedObjects, totalObjects, indexedObjects, receivedBytes, totalDeltas, indexedDeltas);
+
+  /// Create a copy of GitProgress
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GitProgress_TransferImplCopyWith<_$GitProgress_TransferImpl> get copyWith =>
+      __$$GitProgress_TransferImplCopyWithImpl<_$GitProgress_TransferImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt receivedObjects, BigInt totalObjects, BigInt indexedObjects, BigInt receivedBytes, BigInt totalDeltas, BigInt indexedDeltas) transfer,
+    required TResult Function(String? path, BigInt completed, BigInt total) checkout,
+    required TResult Function(BigInt current, BigInt total, BigInt bytes) pushTransfer,
+    required TResult Function(String field0) sideband,
+  }) {
+    return transfer(receivedObjects, totalObjects, indexedObjects, receivedBytes, totalDeltas, indexedDeltas);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt receivedObjects, BigInt totalObjects, BigInt indexedObjects, BigInt receivedBytes, BigInt totalDeltas, BigInt indexedDeltas)? transfer,
+    TResult? Function(String? path, BigInt completed, BigInt total)? checkout,
+    TResult? Function(BigInt current, BigInt total, BigInt bytes)? pushTransfer,
+    TResult? Function(String field0)? sideband,
+  }) {
+    return transfer?.call(receivedObjects, totalObjects, indexedObjects, receivedBytes, totalDeltas, indexedDeltas);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt receivedObjects, BigInt totalObjects, BigInt indexedObjects, BigInt receivedBytes, BigInt totalDeltas, BigInt indexedDeltas)? transfer,
+    TResult Function(String? path, BigInt completed, BigInt total)? checkout,
+    TResult Function(BigInt current, BigInt total, BigInt bytes)? pushTransfer,
+    TResult Function(String field0)? sideband,
+    required TResult orElse(),
+  }) {
+    if (transfer != null) {
+      return transfer(receivedObjects, totalObjects, indexedObjects, receivedBytes, totalDeltas, indexedDeltas);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GitProgress_Transfer value) transfer,
+    required TResult Function(GitProgress_Checkout value) checkout,
+    required TResult Function(GitProgress_PushTransfer value) pushTransfer,
+    required TResult Function(GitProgress_Sideband value) sideband,
+  }) {
+    return transfer(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GitProgress_Transfer value)? transfer,
+    TResult? Function(GitProgress_Checkout value)? checkout,
+    TResult? Function(GitProgress_PushTransfer value)? pushTransfer,
+    TResult? Function(GitProgress_Sideband value)? sideband,
+  }) {
+    return transfer?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GitProgress_Transfer value)? transfer,
+    TResult Function(GitProgress_Checkout value)? checkout,
+    TResult Function(GitProgress_PushTransfer value)? pushTransfer,
+    TResult Function(GitProgress_Sideband value)? sideband,
+    required TResult orElse(),
+  }) {
+    if (transfer != null) {
+      return transfer(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GitProgress_Transfer extends GitProgress {
+  const factory GitProgress_Transfer({
+    required final BigInt receivedObjects,
+    required final BigInt totalObjects,
+    required final BigInt indexedObjects,
+    required final BigInt receivedBytes,
+    required final BigInt totalDeltas,
+    required final BigInt indexedDeltas,
+  }) = _$GitProgress_TransferImpl;
+  const GitProgress_Transfer._() : super._();
+
+  BigInt get receivedObjects;
+
+  BigInt get totalObjects;
+
+  BigInt get indexedObjects;
+
+  BigInt get receivedBytes;
+
+  BigInt get totalDeltas;
+
+  BigInt get indexedDeltas;
+
+  /// Create a copy of GitProgress
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GitProgress_TransferImplCopyWith<_$GitProgress_TransferImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$GitProgress_CheckoutImplCopyWith<$Res> {
+  factory _$$GitProgress_CheckoutImplCopyWith(
+    _$GitProgress_CheckoutImpl value,
+    $Res Function(_$GitProgress_CheckoutImpl) then,
+  ) = __$$GitProgress_CheckoutImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String? path, BigInt completed, BigInt total});
+}
+
+/// @nodoc
+class __$$GitProgress_CheckoutImplCopyWithImpl<$Res>
+    extends _$GitProgressCopyWithImpl<$Res, _$GitProgress_CheckoutImpl>
+    implements _$$GitProgress_CheckoutImplCopyWith<$Res> {
+  __$$GitProgress_CheckoutImplCopyWithImpl(
+    _$GitProgress_CheckoutImpl _value,
+    $Res Function(_$GitProgress_CheckoutImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of GitProgress
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? path = freezed, Object? completed = null, Object? total = null}) {
+    return _then(
+      _$GitProgress_CheckoutImpl(
+        path: freezed == path
+            ? _value.path
+            : path // ignore: cast_nullable_to_non_nullable
+                  as String?,
+        completed: null == completed
+            ? _value.completed
+            : completed // ignore: cast_nullable_to_non_nullable
+                  as BigInt,
+        total: null == total
+            ? _value.total
+            : total // ignore: cast_nullable_to_non_nullable
+                  as BigInt,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$GitProgress_CheckoutImpl extends GitProgress_Checkout {
+  const _$GitProgress_CheckoutImpl({this.path, required this.completed, required this.total}) : super._();
+
+  @override
+  final String? path;
+  @override
+  final BigInt completed;
+  @override
+  final BigInt total;
+
+  @override
+  String toString() {
+    return 'GitProgress.checkout(path: $path, completed: $completed, total: $total)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GitProgress_CheckoutImpl &&
+            (identical(other.path, path) || other.path == path) &&
+            (identical(other.completed, completed) || other.completed == completed) &&
+            (identical(other.total, total) || other.total == total));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, path, completed, total);
+
+  /// Create a copy of GitProgress
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GitProgress_CheckoutImplCopyWith<_$GitProgress_CheckoutImpl> get copyWith =>
+      __$$GitProgress_CheckoutImplCopyWithImpl<_$GitProgress_CheckoutImpl>(
+        this,
         _$identity,
       );
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(String field0) io,
-    required TResult Function(String field0) git,
-    required TResult Function(String field0) auth,
-    required TResult Function(String field0) other,
+    required TResult Function(BigInt receivedObjects, BigInt totalObjects, BigInt indexedObjects, BigInt receivedBytes, BigInt totalDeltas, BigInt indexedDeltas) transfer,
+    required TResult Function(String? path, BigInt completed, BigInt total) checkout,
+    required TResult Function(BigInt current, BigInt total, BigInt bytes) pushTransfer,
+    required TResult Function(String field0) sideband,
   }) {
-    return other(field0);
+    return checkout(path, completed, total);
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(String field0)? io,
-    TResult? Function(String field0)? git,
-    TResult? Function(String field0)? auth,
-    TResult? Function(String field0)? other,
+    TResult? Function(BigInt receivedObjects, BigInt totalObjects, BigInt indexedObjects, BigInt receivedBytes, BigInt totalDeltas, BigInt indexedDeltas)? transfer,
+    TResult? Function(String? path, BigInt completed, BigInt total)? checkout,
+    TResult? Function(BigInt current, BigInt total, BigInt bytes)? pushTransfer,
+    TResult? Function(String field0)? sideband,
   }) {
-    return other?.call(field0);
+    return checkout?.call(path, completed, total);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(String field0)? io,
-    TResult Function(String field0)? git,
-    TResult Function(String field0)? auth,
-    TResult Function(String field0)? other,
+    TResult Function(BigInt receivedObjects, BigInt totalObjects, BigInt indexedObjects, BigInt receivedBytes, BigInt totalDeltas, BigInt indexedDeltas)? transfer,
+    TResult Function(String? path, BigInt completed, BigInt total)? checkout,
+    TResult Function(BigInt current, BigInt total, BigInt bytes)? pushTransfer,
+    TResult Function(String field0)? sideband,
     required TResult orElse(),
   }) {
-    if (other != null) {
-      return other(field0);
+    if (checkout != null) {
+      return checkout(path, completed, total);
     }
     return orElse();
   }
@@ -694,52 +10075,396 @@ class _$GitError_OtherImpl extends GitError_Other {
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(GitError_Io value) io,
-    required TResult Function(GitError_Git value) git,
-    required TResult Function(GitError_Auth value) auth,
-    required TResult Function(GitError_Other value) other,
+    required TResult Function(GitProgress_Transfer value) transfer,
+    required TResult Function(GitProgress_Checkout value) checkout,
+    required TResult Function(GitProgress_PushTransfer value) pushTransfer,
+    required TResult Function(GitProgress_Sideband value) sideband,
   }) {
-    return other(this);
+    return checkout(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(GitError_Io value)? io,
-    TResult? Function(GitError_Git value)? git,
-    TResult? Function(GitError_Auth value)? auth,
-    TResult? Function(GitError_Other value)? other,
+    TResult? Function(GitProgress_Transfer value)? transfer,
+    TResult? Function(GitProgress_Checkout value)? checkout,
+    TResult? Function(GitProgress_PushTransfer value)? pushTransfer,
+    TResult? Function(GitProgress_Sideband value)? sideband,
   }) {
-    return other?.call(this);
+    return checkout?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(GitError_Io value)? io,
-    TResult Function(GitError_Git value)? git,
-    TResult Function(GitError_Auth value)? auth,
-    TResult Function(GitError_Other value)? other,
+    TResult Function(GitProgress_Transfer value)? transfer,
+    TResult Function(GitProgress_Checkout value)? checkout,
+    TResult Function(GitProgress_PushTransfer value)? pushTransfer,
+    TResult Function(GitProgress_Sideband value)? sideband,
     required TResult orElse(),
   }) {
-    if (other != null) {
-      return other(this);
+    if (checkout != null) {
+      return checkout(this);
     }
     return orElse();
   }
 }
 
-abstract class GitError_Other extends GitError {
-  const factory GitError_Other(final String field0) = _$GitError_OtherImpl;
-  const GitError_Other._() : super._();
+abstract class GitProgress_Checkout extends GitProgress {
+  const factory GitProgress_Checkout({
+    final String? path,
+    required final BigInt completed,
+    required final BigInt total,
+  }) = _$GitProgress_CheckoutImpl;
+  const GitProgress_Checkout._() : super._();
+
+  String? get path;
+
+  BigInt get completed;
+
+  BigInt get total;
+
+  /// Create a copy of GitProgress
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GitProgress_CheckoutImplCopyWith<_$GitProgress_CheckoutImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$GitProgress_PushTransferImplCopyWith<$Res> {
+  factory _$$GitProgress_PushTransferImplCopyWith(
+    _$GitProgress_PushTransferImpl value,
+    $Res Function(_$GitProgress_PushTransferImpl) then,
+  ) = __$$GitProgress_PushTransferImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({BigInt current, BigInt total, BigInt bytes});
+}
+
+/// @nodoc
+class __$$GitProgress_PushTransferImplCopyWithImpl<$Res>
+    extends _$GitProgressCopyWithImpl<$Res, _$GitProgress_PushTransferImpl>
+    implements _$$GitProgress_PushTransferImplCopyWith<$Res> {
+  __$$GitProgress_PushTransferImplCopyWithImpl(
+    _$GitProgress_PushTransferImpl _value,
+    $Res Function(_$GitProgress_PushTransferImpl) _then,
+  ) : super(_value, _then);
 
+  /// Create a copy of GitProgress
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
   @override
-  String get field0;
+  $Res call({Object? current = null, Object? total = null, Object? bytes = null}) {
+    return _then(
+      _$GitProgress_PushTransferImpl(
+        current: null == current
+            ? _value.current
+            : current // ignore: cast_nullable_to_non_nullable
+                  as BigInt,
+        total: null == total
+            ? _value.total
+            : total // ignore: cast_nullable_to_non_nullable
+                  as BigInt,
+        bytes: null == bytes
+            ? _value.bytes
+            : bytes // ignore: cast_nullable_to_non_nullable
+                  as BigInt,
+      ),
+    );
+  }
+}
 
-  /// Create a copy of GitError
+/// @nodoc
+
+class _$GitProgress_PushTransferImpl extends GitProgress_PushTransfer {
+  const _$GitProgress_PushTransferImpl({required this.current, required this.total, required this.bytes}) : super._();
+
+  @override
+  final BigInt current;
+  @override
+  final BigInt total;
+  @override
+  final BigInt bytes;
+
+  @override
+  String toString() {
+    return 'GitProgress.pushTransfer(current: $current, total: $total, bytes: $bytes)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GitProgress_PushTransferImpl &&
+            (identical(other.current, current) || other.current == current) &&
+            (identical(other.total, total) || other.total == total) &&
+            (identical(other.bytes, bytes) || other.bytes == bytes));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, current, total, bytes);
+
+  /// Create a copy of GitProgress
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GitProgress_PushTransferImplCopyWith<_$GitProgress_PushTransferImpl> get copyWith =>
+      __$$GitProgress_PushTransferImplCopyWithImpl<_$GitProgress_PushTransferImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt receivedObjects, BigInt totalObjects, BigInt indexedObjects, BigInt receivedBytes, BigInt totalDeltas, BigInt indexedDeltas) transfer,
+    required TResult Function(String? path, BigInt completed, BigInt total) checkout,
+    required TResult Function(BigInt current, BigInt total, BigInt bytes) pushTransfer,
+    required TResult Function(String field0) sideband,
+  }) {
+    return pushTransfer(current, total, bytes);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt receivedObjects, BigInt totalObjects, BigInt indexedObjects, BigInt receivedBytes, BigInt totalDeltas, BigInt indexedDeltas)? transfer,
+    TResult? Function(String? path, BigInt completed, BigInt total)? checkout,
+    TResult? Function(BigInt current, BigInt total, BigInt bytes)? pushTransfer,
+    TResult? Function(String field0)? sideband,
+  }) {
+    return pushTransfer?.call(current, total, bytes);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt receivedObjects, BigInt totalObjects, BigInt indexedObjects, BigInt receivedBytes, BigInt totalDeltas, BigInt indexedDeltas)? transfer,
+    TResult Function(String? path, BigInt completed, BigInt total)? checkout,
+    TResult Function(BigInt current, BigInt total, BigInt bytes)? pushTransfer,
+    TResult Function(String field0)? sideband,
+    required TResult orElse(),
+  }) {
+    if (pushTransfer != null) {
+      return pushTransfer(current, total, bytes);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GitProgress_Transfer value) transfer,
+    required TResult Function(GitProgress_Checkout value) checkout,
+    required TResult Function(GitProgress_PushTransfer value) pushTransfer,
+    required TResult Function(GitProgress_Sideband value) sideband,
+  }) {
+    return pushTransfer(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GitProgress_Transfer value)? transfer,
+    TResult? Function(GitProgress_Checkout value)? checkout,
+    TResult? Function(GitProgress_PushTransfer value)? pushTransfer,
+    TResult? Function(GitProgress_Sideband value)? sideband,
+  }) {
+    return pushTransfer?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GitProgress_Transfer value)? transfer,
+    TResult Function(GitProgress_Checkout value)? checkout,
+    TResult Function(GitProgress_PushTransfer value)? pushTransfer,
+    TResult Function(GitProgress_Sideband value)? sideband,
+    required TResult orElse(),
+  }) {
+    if (pushTransfer != null) {
+      return pushTransfer(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GitProgress_PushTransfer extends GitProgress {
+  const factory GitProgress_PushTransfer({
+    required final BigInt current,
+    required final BigInt total,
+    required final BigInt bytes,
+  }) = _$GitProgress_PushTransferImpl;
+  const GitProgress_PushTransfer._() : super._();
+
+  BigInt get current;
+
+  BigInt get total;
+
+  BigInt get bytes;
+
+  /// Create a copy of GitProgress
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$GitProgress_PushTransferImplCopyWith<_$GitProgress_PushTransferImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$GitProgress_SidebandImplCopyWith<$Res> {
+  factory _$$GitProgress_SidebandImplCopyWith(
+    _$GitProgress_SidebandImpl value,
+    $Res Function(_$GitProgress_SidebandImpl) then,
+  ) = __$$GitProgress_SidebandImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class __$$GitProgress_SidebandImplCopyWithImpl<$Res>
+    extends _$GitProgressCopyWithImpl<$Res, _$GitProgress_SidebandImpl>
+    implements _$$GitProgress_SidebandImplCopyWith<$Res> {
+  __$$GitProgress_SidebandImplCopyWithImpl(
+    _$GitProgress_SidebandImpl _value,
+    $Res Function(_$GitProgress_SidebandImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of GitProgress
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field0 = null}) {
+    return _then(
+      _$GitProgress_SidebandImpl(
+        null == field0
+            ? _value.field0
+            : field0 // ignore: cast_nullable_to_non_nullable
+                  as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$GitProgress_SidebandImpl extends GitProgress_Sideband {
+  const _$GitProgress_SidebandImpl(this.field0) : super._();
+
+  @override
+  final String field0;
+
+  @override
+  String toString() {
+    return 'GitProgress.sideband(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$GitProgress_SidebandImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of GitProgress
   /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GitProgress_SidebandImplCopyWith<_$GitProgress_SidebandImpl> get copyWith =>
+      __$$GitProgress_SidebandImplCopyWithImpl<_$GitProgress_SidebandImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt receivedObjects, BigInt totalObjects, BigInt indexedObjects, BigInt receivedBytes, BigInt totalDeltas, BigInt indexedDeltas) transfer,
+    required TResult Function(String? path, BigInt completed, BigInt total) checkout,
+    required TResult Function(BigInt current, BigInt total, BigInt bytes) pushTransfer,
+    required TResult Function(String field0) sideband,
+  }) {
+    return sideband(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt receivedObjects, BigInt totalObjects, BigInt indexedObjects, BigInt receivedBytes, BigInt totalDeltas, BigInt indexedDeltas)? transfer,
+    TResult? Function(String? path, BigInt completed, BigInt total)? checkout,
+    TResult? Function(BigInt current, BigInt total, BigInt bytes)? pushTransfer,
+    TResult? Function(String field0)? sideband,
+  }) {
+    return sideband?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt receivedObjects, BigInt totalObjects, BigInt indexedObjects, BigInt receivedBytes, BigInt totalDeltas, BigInt indexedDeltas)? transfer,
+    TResult Function(String? path, BigInt completed, BigInt total)? checkout,
+    TResult Function(BigInt current, BigInt total, BigInt bytes)? pushTransfer,
+    TResult Function(String field0)? sideband,
+    required TResult orElse(),
+  }) {
+    if (sideband != null) {
+      return sideband(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GitProgress_Transfer value) transfer,
+    required TResult Function(GitProgress_Checkout value) checkout,
+    required TResult Function(GitProgress_PushTransfer value) pushTransfer,
+    required TResult Function(GitProgress_Sideband value) sideband,
+  }) {
+    return sideband(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GitProgress_Transfer value)? transfer,
+    TResult? Function(GitProgress_Checkout value)? checkout,
+    TResult? Function(GitProgress_PushTransfer value)? pushTransfer,
+    TResult? Function(GitProgress_Sideband value)? sideband,
+  }) {
+    return sideband?.call(this);
+  }
+
   @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GitProgress_Transfer value)? transfer,
+    TResult Function(GitProgress_Checkout value)? checkout,
+    TResult Function(GitProgress_PushTransfer value)? pushTransfer,
+    TResult Function(GitProgress_Sideband value)? sideband,
+    required TResult orElse(),
+  }) {
+    if (sideband != null) {
+      return sideband(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GitProgress_Sideband extends GitProgress {
+  const factory GitProgress_Sideband(final String field0) = _$GitProgress_SidebandImpl;
+  const GitProgress_Sideband._() : super._();
+
+  String get field0;
+
+  /// Create a copy of GitProgress
+  /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
-  _$$GitError_OtherImplCopyWith<_$GitError_OtherImpl> get copyWith =>
+  _$$GitProgress_SidebandImplCopyWith<_$GitProgress_SidebandImpl> get copyWith =>
       throw _privateConstructorUsedError;
 }
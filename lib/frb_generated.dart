@@ -66,7 +66,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => 380905477;
+  int get rustContentHash => 2110218451;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -79,15 +79,19 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 abstract class RustLibApi extends BaseApi {
   Future<int> crateApiAdd({required int left, required int right});
 
+  Future<bool> crateApiCancelOperation({required BigInt handle});
+
+  Future<void> crateApiClearCredentialCallback();
+
+  Future<void> crateApiCloseCalendar({required String calendarDir});
+
+  Future<EventDto> crateApiConvertEventToZone({
+    required EventDto event,
+    required String displayZone,
+  });
+
   Future<String> crateApiCreateEvent({
-    required String title,
-    required String description,
-    required String startDate,
-    String? endDate,
-    String? startTime,
-    String? endTime,
-    required bool isAllDay,
-    required String recurrence,
+    required EventInput input,
     required String calendarDir,
   });
 
@@ -101,15 +105,24 @@ abstract class RustLibApi extends BaseApi {
     required String date,
   });
 
-  Future<List<EventDto>> crateApiGenerateInstances({
+  Future<String> crateApiFormatRecurrenceRule({required RecurrenceRule rule});
+
+  Future<InstancesResult> crateApiGenerateInstances({
+    required List<EventDto> events,
+    required String startDate,
+    required String endDate,
+  });
+
+  Future<InstancesResult> crateApiGenerateInstancesInZone({
     required List<EventDto> events,
     required String startDate,
     required String endDate,
+    required String displayZone,
   });
 
-  Future<List<EventDto>> crateApiGetAllEvents({required String calendarDir});
+  Future<EventsResult> crateApiGetAllEvents({required String calendarDir});
 
-  Future<List<EventDto>> crateApiGetEventsInRange({
+  Future<EventsResult> crateApiGetEventsInRange({
     required String startDate,
     required String endDate,
     required String calendarDir,
@@ -136,23 +149,46 @@ abstract class RustLibApi extends BaseApi {
     String? sshKeyPath,
   });
 
+  Stream<GitProgress> crateApiGitCloneWithProgress({
+    required String url,
+    required String path,
+    String? username,
+    String? password,
+    String? sshKeyPath,
+    BigInt? operation,
+  });
+
   Future<String> crateApiGitCommit({
     required String path,
     required String message,
+    GitIdentity? author,
   });
 
   Future<String> crateApiGitCurrentBranch({required String path});
 
   Future<String> crateApiGitDiff({required String path});
 
-  Future<String> crateApiGitFetch({
+  Future<SyncResult> crateApiGitFetch({
     required String path,
     required String remote,
     String? username,
     String? password,
     String? sshKeyPath,
+    String? branch,
   });
 
+  Stream<GitProgress> crateApiGitFetchWithProgress({
+    required String path,
+    required String remote,
+    String? username,
+    String? password,
+    String? sshKeyPath,
+    String? branch,
+    BigInt? operation,
+  });
+
+  Future<GitIdentity?> crateApiGitGetIdentity({required String path});
+
   Future<bool> crateApiGitHasLocalChanges({required String path});
 
   Future<String> crateApiGitInit({required String path});
@@ -161,20 +197,51 @@ abstract class RustLibApi extends BaseApi {
 
   Future<String> crateApiGitMergeAbort({required String path});
 
+  Future<String> crateApiGitMergePreferLocal({required String path});
+
   Future<String> crateApiGitMergePreferRemote({required String path});
 
-  Future<String> crateApiGitPull({
+  Future<EventMergeReport> crateApiGitMergeResolveEvents({
+    required String path,
+  });
+
+  Future<List<String>> crateApiGitMergeResolvePaths({
+    required String path,
+    required List<PathResolution> resolutions,
+  });
+
+  Future<SyncResult> crateApiGitPull({
+    required String path,
+    String? username,
+    String? password,
+    String? sshKeyPath,
+    String? branch,
+  });
+
+  Stream<GitProgress> crateApiGitPullWithProgress({
+    required String path,
+    String? username,
+    String? password,
+    String? sshKeyPath,
+    String? branch,
+    BigInt? operation,
+  });
+
+  Future<SyncResult> crateApiGitPush({
     required String path,
     String? username,
     String? password,
     String? sshKeyPath,
+    String? branch,
   });
 
-  Future<String> crateApiGitPush({
+  Stream<GitProgress> crateApiGitPushWithProgress({
     required String path,
     String? username,
     String? password,
     String? sshKeyPath,
+    String? branch,
+    BigInt? operation,
   });
 
   Future<String> crateApiGitRemoveRemote({
@@ -182,24 +249,108 @@ abstract class RustLibApi extends BaseApi {
     required String name,
   });
 
+  Future<String> crateApiGitSetIdentity({
+    required String path,
+    required GitIdentity identity,
+  });
+
   Future<String> crateApiGitStash({required String path});
 
   Future<List<StatusEntry>> crateApiGitStatus({required String path});
 
+  Future<SyncReport> crateApiGitSync({
+    required String path,
+    String? username,
+    String? password,
+    String? sshKeyPath,
+    String? branch,
+  });
+
+  Stream<GitProgress> crateApiGitSyncWithProgress({
+    required String path,
+    String? username,
+    String? password,
+    String? sshKeyPath,
+    String? branch,
+    BigInt? operation,
+  });
+
+  Future<IcsImportOptions> crateApiIcsImportOptionsDefault();
+
+  Future<IcsImportReport> crateApiImportIcs({
+    required String icsText,
+    required String calendarDir,
+    required IcsImportOptions options,
+  });
+
   Future<void> crateApiInitApp();
 
+  Future<List<HostKey>> crateApiListKnownHosts();
+
+  Future<List<String>> crateApiListTimeZones();
+
+  Future<List<String>> crateApiMigrateEventIds({required String calendarDir});
+
+  Future<void> crateApiOpenCalendar({required String calendarDir});
+
+  Future<void> crateApiOverrideOccurrence({
+    required String id,
+    required OccurrenceOverride changes,
+    required String calendarDir,
+  });
+
+  Future<RecurrenceRule> crateApiParseRecurrenceRule({required String text});
+
+  Future<bool> crateApiRemoveHostKey({required String host});
+
+  Future<void> crateApiSetCredentialCallback({
+    required FutureOr<GitCredentials?> Function(CredentialRequest) callback,
+  });
+
+  Future<void> crateApiSetKnownHostsPath({required String path});
+
   Future<void> crateApiSetSslCaCerts({required List<String> pemCerts});
 
+  Future<void> crateApiSetTrustStorePath({required String path});
+
+  Future<void> crateApiSkipOccurrence({
+    required String id,
+    required String date,
+    required String calendarDir,
+  });
+
+  Future<String> crateApiSplitSeriesFrom({
+    required String id,
+    required String date,
+    required String calendarDir,
+  });
+
+  Future<BigInt> crateApiStartOperation();
+
+  Future<List<TrustedCertificate>> crateApiTrustAddCa({
+    required String host,
+    required String pem,
+  });
+
+  Future<void> crateApiTrustHostKey({required HostKey key});
+
+  Future<List<TrustedCertificate>> crateApiTrustList({String? host});
+
+  Future<List<TrustedCertificate>> crateApiTrustPinCertificate({
+    required String host,
+    required String pem,
+  });
+
+  Future<bool> crateApiTrustRemove({
+    required String host,
+    required String fingerprint,
+  });
+
+  Future<void> crateApiUnwatchCalendar({required String calendarDir});
+
   Future<void> crateApiUpdateEvent({
     required String id,
-    required String title,
-    required String description,
-    required String startDate,
-    String? endDate,
-    String? startTime,
-    String? endTime,
-    required bool isAllDay,
-    required String recurrence,
+    required EventInput input,
     required String calendarDir,
   });
 
@@ -210,6 +361,8 @@ abstract class RustLibApi extends BaseApi {
     String? startTime,
     String? endTime,
   });
+
+  Stream<CalendarChange> crateApiWatchCalendar({required String calendarDir});
 }
 
 class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
@@ -250,30 +403,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       const TaskConstMeta(debugName: "add", argNames: ["left", "right"]);
 
   @override
-  Future<String> crateApiCreateEvent({
-    required String title,
-    required String description,
-    required String startDate,
-    String? endDate,
-    String? startTime,
-    String? endTime,
-    required bool isAllDay,
-    required String recurrence,
-    required String calendarDir,
-  }) {
+  Future<bool> crateApiCancelOperation({required BigInt handle}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(title, serializer);
-          sse_encode_String(description, serializer);
-          sse_encode_String(startDate, serializer);
-          sse_encode_opt_String(endDate, serializer);
-          sse_encode_opt_String(startTime, serializer);
-          sse_encode_opt_String(endTime, serializer);
-          sse_encode_bool(isAllDay, serializer);
-          sse_encode_String(recurrence, serializer);
-          sse_encode_String(calendarDir, serializer);
+          sse_encode_u_64(handle, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -282,52 +417,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiCreateEventConstMeta,
-        argValues: [
-          title,
-          description,
-          startDate,
-          endDate,
-          startTime,
-          endTime,
-          isAllDay,
-          recurrence,
-          calendarDir,
-        ],
+        constMeta: kCrateApiCancelOperationConstMeta,
+        argValues: [handle],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCreateEventConstMeta => const TaskConstMeta(
-    debugName: "create_event",
-    argNames: [
-      "title",
-      "description",
-      "startDate",
-      "endDate",
-      "startTime",
-      "endTime",
-      "isAllDay",
-      "recurrence",
-      "calendarDir",
-    ],
-  );
+  TaskConstMeta get kCrateApiCancelOperationConstMeta =>
+      const TaskConstMeta(debugName: "cancel_operation", argNames: ["handle"]);
 
   @override
-  Future<void> crateApiDeleteEvent({
-    required String id,
-    required String calendarDir,
-  }) {
+  Future<void> crateApiClearCredentialCallback() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(id, serializer);
-          sse_encode_String(calendarDir, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -337,31 +445,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiDeleteEventConstMeta,
-        argValues: [id, calendarDir],
+        constMeta: kCrateApiClearCredentialCallbackConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDeleteEventConstMeta => const TaskConstMeta(
-    debugName: "delete_event",
-    argNames: ["id", "calendarDir"],
-  );
+  TaskConstMeta get kCrateApiClearCredentialCallbackConstMeta =>
+      const TaskConstMeta(debugName: "clear_credential_callback", argNames: []);
 
   @override
-  Future<bool> crateApiEventOccursOn({
-    required EventDto event,
-    required String date,
-  }) {
+  Future<void> crateApiCloseCalendar({required String calendarDir}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_event_dto(event, serializer);
-          sse_encode_String(date, serializer);
+          sse_encode_String(calendarDir, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -370,34 +472,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiEventOccursOnConstMeta,
-        argValues: [event, date],
+        constMeta: kCrateApiCloseCalendarConstMeta,
+        argValues: [calendarDir],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiEventOccursOnConstMeta => const TaskConstMeta(
-    debugName: "event_occurs_on",
-    argNames: ["event", "date"],
+  TaskConstMeta get kCrateApiCloseCalendarConstMeta => const TaskConstMeta(
+    debugName: "close_calendar",
+    argNames: ["calendarDir"],
   );
 
   @override
-  Future<List<EventDto>> crateApiGenerateInstances({
-    required List<EventDto> events,
-    required String startDate,
-    required String endDate,
+  Future<EventDto> crateApiConvertEventToZone({
+    required EventDto event,
+    required String displayZone,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_event_dto(events, serializer);
-          sse_encode_String(startDate, serializer);
-          sse_encode_String(endDate, serializer);
+          sse_encode_box_autoadd_event_dto(event, serializer);
+          sse_encode_String(displayZone, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -406,27 +506,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_event_dto,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_event_dto,
+          decodeErrorData: sse_decode_event_error,
         ),
-        constMeta: kCrateApiGenerateInstancesConstMeta,
-        argValues: [events, startDate, endDate],
+        constMeta: kCrateApiConvertEventToZoneConstMeta,
+        argValues: [event, displayZone],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGenerateInstancesConstMeta => const TaskConstMeta(
-    debugName: "generate_instances",
-    argNames: ["events", "startDate", "endDate"],
+  TaskConstMeta get kCrateApiConvertEventToZoneConstMeta => const TaskConstMeta(
+    debugName: "convert_event_to_zone",
+    argNames: ["event", "displayZone"],
   );
 
   @override
-  Future<List<EventDto>> crateApiGetAllEvents({required String calendarDir}) {
+  Future<String> crateApiCreateEvent({
+    required EventInput input,
+    required String calendarDir,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_event_input(input, serializer);
           sse_encode_String(calendarDir, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
@@ -436,33 +540,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_event_dto,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_event_error,
         ),
-        constMeta: kCrateApiGetAllEventsConstMeta,
-        argValues: [calendarDir],
+        constMeta: kCrateApiCreateEventConstMeta,
+        argValues: [input, calendarDir],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGetAllEventsConstMeta => const TaskConstMeta(
-    debugName: "get_all_events",
-    argNames: ["calendarDir"],
+  TaskConstMeta get kCrateApiCreateEventConstMeta => const TaskConstMeta(
+    debugName: "create_event",
+    argNames: ["input", "calendarDir"],
   );
 
   @override
-  Future<List<EventDto>> crateApiGetEventsInRange({
-    required String startDate,
-    required String endDate,
+  Future<void> crateApiDeleteEvent({
+    required String id,
     required String calendarDir,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(startDate, serializer);
-          sse_encode_String(endDate, serializer);
+          sse_encode_String(id, serializer);
           sse_encode_String(calendarDir, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
@@ -472,28 +574,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_event_dto,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_event_error,
         ),
-        constMeta: kCrateApiGetEventsInRangeConstMeta,
-        argValues: [startDate, endDate, calendarDir],
+        constMeta: kCrateApiDeleteEventConstMeta,
+        argValues: [id, calendarDir],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGetEventsInRangeConstMeta => const TaskConstMeta(
-    debugName: "get_events_in_range",
-    argNames: ["startDate", "endDate", "calendarDir"],
+  TaskConstMeta get kCrateApiDeleteEventConstMeta => const TaskConstMeta(
+    debugName: "delete_event",
+    argNames: ["id", "calendarDir"],
   );
 
   @override
-  Future<String> crateApiGitAddAll({required String path}) {
+  Future<bool> crateApiEventOccursOn({
+    required EventDto event,
+    required String date,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(path, serializer);
+          sse_encode_box_autoadd_event_dto(event, serializer);
+          sse_encode_String(date, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -502,32 +608,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_git_error,
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_event_error,
         ),
-        constMeta: kCrateApiGitAddAllConstMeta,
-        argValues: [path],
+        constMeta: kCrateApiEventOccursOnConstMeta,
+        argValues: [event, date],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGitAddAllConstMeta =>
-      const TaskConstMeta(debugName: "git_add_all", argNames: ["path"]);
+  TaskConstMeta get kCrateApiEventOccursOnConstMeta => const TaskConstMeta(
+    debugName: "event_occurs_on",
+    argNames: ["event", "date"],
+  );
 
   @override
-  Future<String> crateApiGitAddRemote({
-    required String path,
-    required String name,
-    required String url,
-  }) {
+  Future<String> crateApiFormatRecurrenceRule({required RecurrenceRule rule}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(path, serializer);
-          sse_encode_String(name, serializer);
-          sse_encode_String(url, serializer);
+          sse_encode_box_autoadd_recurrence_rule(rule, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -537,32 +639,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_git_error,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiGitAddRemoteConstMeta,
-        argValues: [path, name, url],
+        constMeta: kCrateApiFormatRecurrenceRuleConstMeta,
+        argValues: [rule],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGitAddRemoteConstMeta => const TaskConstMeta(
-    debugName: "git_add_remote",
-    argNames: ["path", "name", "url"],
-  );
+  TaskConstMeta get kCrateApiFormatRecurrenceRuleConstMeta =>
+      const TaskConstMeta(
+        debugName: "format_recurrence_rule",
+        argNames: ["rule"],
+      );
 
   @override
-  Future<String> crateApiGitCheckout({
-    required String path,
-    required String branch,
+  Future<InstancesResult> crateApiGenerateInstances({
+    required List<EventDto> events,
+    required String startDate,
+    required String endDate,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(path, serializer);
-          sse_encode_String(branch, serializer);
-          pdeCallFfi(
+          sse_encode_list_event_dto(events, serializer);
+          sse_encode_String(startDate, serializer);
+          sse_encode_String(endDate, serializer);
+          pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
             funcId: 10,
@@ -570,38 +675,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_git_error,
+          decodeSuccessData: sse_decode_instances_result,
+          decodeErrorData: sse_decode_event_error,
         ),
-        constMeta: kCrateApiGitCheckoutConstMeta,
-        argValues: [path, branch],
+        constMeta: kCrateApiGenerateInstancesConstMeta,
+        argValues: [events, startDate, endDate],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGitCheckoutConstMeta => const TaskConstMeta(
-    debugName: "git_checkout",
-    argNames: ["path", "branch"],
+  TaskConstMeta get kCrateApiGenerateInstancesConstMeta => const TaskConstMeta(
+    debugName: "generate_instances",
+    argNames: ["events", "startDate", "endDate"],
   );
 
   @override
-  Future<String> crateApiGitClone({
-    required String url,
-    required String path,
-    String? username,
-    String? password,
-    String? sshKeyPath,
+  Future<InstancesResult> crateApiGenerateInstancesInZone({
+    required List<EventDto> events,
+    required String startDate,
+    required String endDate,
+    required String displayZone,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(url, serializer);
-          sse_encode_String(path, serializer);
-          sse_encode_opt_String(username, serializer);
-          sse_encode_opt_String(password, serializer);
-          sse_encode_opt_String(sshKeyPath, serializer);
+          sse_encode_list_event_dto(events, serializer);
+          sse_encode_String(startDate, serializer);
+          sse_encode_String(endDate, serializer);
+          sse_encode_String(displayZone, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -610,32 +713,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_git_error,
+          decodeSuccessData: sse_decode_instances_result,
+          decodeErrorData: sse_decode_event_error,
         ),
-        constMeta: kCrateApiGitCloneConstMeta,
-        argValues: [url, path, username, password, sshKeyPath],
+        constMeta: kCrateApiGenerateInstancesInZoneConstMeta,
+        argValues: [events, startDate, endDate, displayZone],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGitCloneConstMeta => const TaskConstMeta(
-    debugName: "git_clone",
-    argNames: ["url", "path", "username", "password", "sshKeyPath"],
-  );
+  TaskConstMeta get kCrateApiGenerateInstancesInZoneConstMeta =>
+      const TaskConstMeta(
+        debugName: "generate_instances_in_zone",
+        argNames: ["events", "startDate", "endDate", "displayZone"],
+      );
 
   @override
-  Future<String> crateApiGitCommit({
-    required String path,
-    required String message,
-  }) {
+  Future<EventsResult> crateApiGetAllEvents({required String calendarDir}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(path, serializer);
-          sse_encode_String(message, serializer);
+          sse_encode_String(calendarDir, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -644,28 +744,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_git_error,
+          decodeSuccessData: sse_decode_events_result,
+          decodeErrorData: sse_decode_event_error,
         ),
-        constMeta: kCrateApiGitCommitConstMeta,
-        argValues: [path, message],
+        constMeta: kCrateApiGetAllEventsConstMeta,
+        argValues: [calendarDir],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGitCommitConstMeta => const TaskConstMeta(
-    debugName: "git_commit",
-    argNames: ["path", "message"],
+  TaskConstMeta get kCrateApiGetAllEventsConstMeta => const TaskConstMeta(
+    debugName: "get_all_events",
+    argNames: ["calendarDir"],
   );
 
   @override
-  Future<String> crateApiGitCurrentBranch({required String path}) {
+  Future<EventsResult> crateApiGetEventsInRange({
+    required String startDate,
+    required String endDate,
+    required String calendarDir,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(path, serializer);
+          sse_encode_String(startDate, serializer);
+          sse_encode_String(endDate, serializer);
+          sse_encode_String(calendarDir, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -674,21 +780,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_git_error,
+          decodeSuccessData: sse_decode_events_result,
+          decodeErrorData: sse_decode_event_error,
         ),
-        constMeta: kCrateApiGitCurrentBranchConstMeta,
-        argValues: [path],
+        constMeta: kCrateApiGetEventsInRangeConstMeta,
+        argValues: [startDate, endDate, calendarDir],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGitCurrentBranchConstMeta =>
-      const TaskConstMeta(debugName: "git_current_branch", argNames: ["path"]);
+  TaskConstMeta get kCrateApiGetEventsInRangeConstMeta => const TaskConstMeta(
+    debugName: "get_events_in_range",
+    argNames: ["startDate", "endDate", "calendarDir"],
+  );
 
   @override
-  Future<String> crateApiGitDiff({required String path}) {
+  Future<String> crateApiGitAddAll({required String path}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -705,33 +813,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_git_error,
         ),
-        constMeta: kCrateApiGitDiffConstMeta,
+        constMeta: kCrateApiGitAddAllConstMeta,
         argValues: [path],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGitDiffConstMeta =>
-      const TaskConstMeta(debugName: "git_diff", argNames: ["path"]);
+  TaskConstMeta get kCrateApiGitAddAllConstMeta =>
+      const TaskConstMeta(debugName: "git_add_all", argNames: ["path"]);
 
   @override
-  Future<String> crateApiGitFetch({
+  Future<String> crateApiGitAddRemote({
     required String path,
-    required String remote,
-    String? username,
-    String? password,
-    String? sshKeyPath,
+    required String name,
+    required String url,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(path, serializer);
-          sse_encode_String(remote, serializer);
-          sse_encode_opt_String(username, serializer);
-          sse_encode_opt_String(password, serializer);
-          sse_encode_opt_String(sshKeyPath, serializer);
+          sse_encode_String(name, serializer);
+          sse_encode_String(url, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -743,25 +847,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_git_error,
         ),
-        constMeta: kCrateApiGitFetchConstMeta,
-        argValues: [path, remote, username, password, sshKeyPath],
+        constMeta: kCrateApiGitAddRemoteConstMeta,
+        argValues: [path, name, url],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGitFetchConstMeta => const TaskConstMeta(
-    debugName: "git_fetch",
-    argNames: ["path", "remote", "username", "password", "sshKeyPath"],
+  TaskConstMeta get kCrateApiGitAddRemoteConstMeta => const TaskConstMeta(
+    debugName: "git_add_remote",
+    argNames: ["path", "name", "url"],
   );
 
   @override
-  Future<bool> crateApiGitHasLocalChanges({required String path}) {
+  Future<String> crateApiGitCheckout({
+    required String path,
+    required String branch,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(path, serializer);
+          sse_encode_String(branch, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -770,28 +878,38 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_git_error,
         ),
-        constMeta: kCrateApiGitHasLocalChangesConstMeta,
-        argValues: [path],
+        constMeta: kCrateApiGitCheckoutConstMeta,
+        argValues: [path, branch],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGitHasLocalChangesConstMeta => const TaskConstMeta(
-    debugName: "git_has_local_changes",
-    argNames: ["path"],
+  TaskConstMeta get kCrateApiGitCheckoutConstMeta => const TaskConstMeta(
+    debugName: "git_checkout",
+    argNames: ["path", "branch"],
   );
 
   @override
-  Future<String> crateApiGitInit({required String path}) {
+  Future<String> crateApiGitClone({
+    required String url,
+    required String path,
+    String? username,
+    String? password,
+    String? sshKeyPath,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(url, serializer);
           sse_encode_String(path, serializer);
+          sse_encode_opt_String(username, serializer);
+          sse_encode_opt_String(password, serializer);
+          sse_encode_opt_String(sshKeyPath, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -803,46 +921,120 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_git_error,
         ),
-        constMeta: kCrateApiGitInitConstMeta,
-        argValues: [path],
+        constMeta: kCrateApiGitCloneConstMeta,
+        argValues: [url, path, username, password, sshKeyPath],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGitInitConstMeta =>
-      const TaskConstMeta(debugName: "git_init", argNames: ["path"]);
+  TaskConstMeta get kCrateApiGitCloneConstMeta => const TaskConstMeta(
+    debugName: "git_clone",
+    argNames: ["url", "path", "username", "password", "sshKeyPath"],
+  );
 
   @override
-  Future<List<String>> crateApiGitListBranches({required String path}) {
+  Stream<GitProgress> crateApiGitCloneWithProgress({
+    required String url,
+    required String path,
+    String? username,
+    String? password,
+    String? sshKeyPath,
+    BigInt? operation,
+  }) {
+    final sink = RustStreamSink<GitProgress>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(url, serializer);
+            sse_encode_String(path, serializer);
+            sse_encode_opt_String(username, serializer);
+            sse_encode_opt_String(password, serializer);
+            sse_encode_opt_String(sshKeyPath, serializer);
+            sse_encode_opt_box_autoadd_u_64(operation, serializer);
+            sse_encode_StreamSink_git_progress_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 18,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_String,
+            decodeErrorData: sse_decode_git_error,
+          ),
+          constMeta: kCrateApiGitCloneWithProgressConstMeta,
+          argValues: [
+            url,
+            path,
+            username,
+            password,
+            sshKeyPath,
+            operation,
+            sink,
+          ],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiGitCloneWithProgressConstMeta =>
+      const TaskConstMeta(
+        debugName: "git_clone_with_progress",
+        argNames: [
+          "url",
+          "path",
+          "username",
+          "password",
+          "sshKeyPath",
+          "operation",
+          "sink",
+        ],
+      );
+
+  @override
+  Future<String> crateApiGitCommit({
+    required String path,
+    required String message,
+    GitIdentity? author,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(path, serializer);
+          sse_encode_String(message, serializer);
+          sse_encode_opt_box_autoadd_git_identity(author, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 18,
+            funcId: 19,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_String,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_git_error,
         ),
-        constMeta: kCrateApiGitListBranchesConstMeta,
-        argValues: [path],
+        constMeta: kCrateApiGitCommitConstMeta,
+        argValues: [path, message, author],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGitListBranchesConstMeta =>
-      const TaskConstMeta(debugName: "git_list_branches", argNames: ["path"]);
+  TaskConstMeta get kCrateApiGitCommitConstMeta => const TaskConstMeta(
+    debugName: "git_commit",
+    argNames: ["path", "message", "author"],
+  );
 
   @override
-  Future<String> crateApiGitMergeAbort({required String path}) {
+  Future<String> crateApiGitCurrentBranch({required String path}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -851,7 +1043,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 19,
+            funcId: 20,
             port: port_,
           );
         },
@@ -859,18 +1051,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_git_error,
         ),
-        constMeta: kCrateApiGitMergeAbortConstMeta,
+        constMeta: kCrateApiGitCurrentBranchConstMeta,
         argValues: [path],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGitMergeAbortConstMeta =>
-      const TaskConstMeta(debugName: "git_merge_abort", argNames: ["path"]);
+  TaskConstMeta get kCrateApiGitCurrentBranchConstMeta =>
+      const TaskConstMeta(debugName: "git_current_branch", argNames: ["path"]);
 
   @override
-  Future<String> crateApiGitMergePreferRemote({required String path}) {
+  Future<String> crateApiGitDiff({required String path}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -879,7 +1071,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 20,
+            funcId: 21,
             port: port_,
           );
         },
@@ -887,131 +1079,193 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_git_error,
         ),
-        constMeta: kCrateApiGitMergePreferRemoteConstMeta,
+        constMeta: kCrateApiGitDiffConstMeta,
         argValues: [path],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGitMergePreferRemoteConstMeta =>
-      const TaskConstMeta(
-        debugName: "git_merge_prefer_remote",
-        argNames: ["path"],
-      );
+  TaskConstMeta get kCrateApiGitDiffConstMeta =>
+      const TaskConstMeta(debugName: "git_diff", argNames: ["path"]);
 
   @override
-  Future<String> crateApiGitPull({
+  Future<SyncResult> crateApiGitFetch({
     required String path,
+    required String remote,
     String? username,
     String? password,
     String? sshKeyPath,
+    String? branch,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(path, serializer);
+          sse_encode_String(remote, serializer);
           sse_encode_opt_String(username, serializer);
           sse_encode_opt_String(password, serializer);
           sse_encode_opt_String(sshKeyPath, serializer);
+          sse_encode_opt_String(branch, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 21,
+            funcId: 22,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_sync_result,
           decodeErrorData: sse_decode_git_error,
         ),
-        constMeta: kCrateApiGitPullConstMeta,
-        argValues: [path, username, password, sshKeyPath],
+        constMeta: kCrateApiGitFetchConstMeta,
+        argValues: [path, remote, username, password, sshKeyPath, branch],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGitPullConstMeta => const TaskConstMeta(
-    debugName: "git_pull",
-    argNames: ["path", "username", "password", "sshKeyPath"],
+  TaskConstMeta get kCrateApiGitFetchConstMeta => const TaskConstMeta(
+    debugName: "git_fetch",
+    argNames: [
+      "path",
+      "remote",
+      "username",
+      "password",
+      "sshKeyPath",
+      "branch",
+    ],
   );
 
   @override
-  Future<String> crateApiGitPush({
+  Stream<GitProgress> crateApiGitFetchWithProgress({
     required String path,
+    required String remote,
     String? username,
     String? password,
     String? sshKeyPath,
+    String? branch,
+    BigInt? operation,
   }) {
+    final sink = RustStreamSink<GitProgress>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(path, serializer);
+            sse_encode_String(remote, serializer);
+            sse_encode_opt_String(username, serializer);
+            sse_encode_opt_String(password, serializer);
+            sse_encode_opt_String(sshKeyPath, serializer);
+            sse_encode_opt_String(branch, serializer);
+            sse_encode_opt_box_autoadd_u_64(operation, serializer);
+            sse_encode_StreamSink_git_progress_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 23,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_sync_result,
+            decodeErrorData: sse_decode_git_error,
+          ),
+          constMeta: kCrateApiGitFetchWithProgressConstMeta,
+          argValues: [
+            path,
+            remote,
+            username,
+            password,
+            sshKeyPath,
+            branch,
+            operation,
+            sink,
+          ],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiGitFetchWithProgressConstMeta =>
+      const TaskConstMeta(
+        debugName: "git_fetch_with_progress",
+        argNames: [
+          "path",
+          "remote",
+          "username",
+          "password",
+          "sshKeyPath",
+          "branch",
+          "operation",
+          "sink",
+        ],
+      );
+
+  @override
+  Future<GitIdentity?> crateApiGitGetIdentity({required String path}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(path, serializer);
-          sse_encode_opt_String(username, serializer);
-          sse_encode_opt_String(password, serializer);
-          sse_encode_opt_String(sshKeyPath, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 22,
+            funcId: 24,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_opt_box_autoadd_git_identity,
           decodeErrorData: sse_decode_git_error,
         ),
-        constMeta: kCrateApiGitPushConstMeta,
-        argValues: [path, username, password, sshKeyPath],
+        constMeta: kCrateApiGitGetIdentityConstMeta,
+        argValues: [path],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGitPushConstMeta => const TaskConstMeta(
-    debugName: "git_push",
-    argNames: ["path", "username", "password", "sshKeyPath"],
-  );
+  TaskConstMeta get kCrateApiGitGetIdentityConstMeta =>
+      const TaskConstMeta(debugName: "git_get_identity", argNames: ["path"]);
 
   @override
-  Future<String> crateApiGitRemoveRemote({
-    required String path,
-    required String name,
-  }) {
+  Future<bool> crateApiGitHasLocalChanges({required String path}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(path, serializer);
-          sse_encode_String(name, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 23,
+            funcId: 25,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_git_error,
         ),
-        constMeta: kCrateApiGitRemoveRemoteConstMeta,
-        argValues: [path, name],
+        constMeta: kCrateApiGitHasLocalChangesConstMeta,
+        argValues: [path],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGitRemoveRemoteConstMeta => const TaskConstMeta(
-    debugName: "git_remove_remote",
-    argNames: ["path", "name"],
+  TaskConstMeta get kCrateApiGitHasLocalChangesConstMeta => const TaskConstMeta(
+    debugName: "git_has_local_changes",
+    argNames: ["path"],
   );
 
   @override
-  Future<String> crateApiGitStash({required String path}) {
+  Future<String> crateApiGitInit({required String path}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -1020,7 +1274,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 24,
+            funcId: 26,
             port: port_,
           );
         },
@@ -1028,18 +1282,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_git_error,
         ),
-        constMeta: kCrateApiGitStashConstMeta,
+        constMeta: kCrateApiGitInitConstMeta,
         argValues: [path],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGitStashConstMeta =>
-      const TaskConstMeta(debugName: "git_stash", argNames: ["path"]);
+  TaskConstMeta get kCrateApiGitInitConstMeta =>
+      const TaskConstMeta(debugName: "git_init", argNames: ["path"]);
 
   @override
-  Future<List<StatusEntry>> crateApiGitStatus({required String path}) {
+  Future<List<String>> crateApiGitListBranches({required String path}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -1048,585 +1302,4366 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 25,
+            funcId: 27,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_status_entry,
+          decodeSuccessData: sse_decode_list_String,
           decodeErrorData: sse_decode_git_error,
         ),
-        constMeta: kCrateApiGitStatusConstMeta,
+        constMeta: kCrateApiGitListBranchesConstMeta,
         argValues: [path],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGitStatusConstMeta =>
-      const TaskConstMeta(debugName: "git_status", argNames: ["path"]);
+  TaskConstMeta get kCrateApiGitListBranchesConstMeta =>
+      const TaskConstMeta(debugName: "git_list_branches", argNames: ["path"]);
 
   @override
-  Future<void> crateApiInitApp() {
+  Future<String> crateApiGitMergeAbort({required String path}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 26,
+            funcId: 28,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_git_error,
         ),
-        constMeta: kCrateApiInitAppConstMeta,
-        argValues: [],
+        constMeta: kCrateApiGitMergeAbortConstMeta,
+        argValues: [path],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
+  TaskConstMeta get kCrateApiGitMergeAbortConstMeta =>
+      const TaskConstMeta(debugName: "git_merge_abort", argNames: ["path"]);
 
   @override
-  Future<void> crateApiSetSslCaCerts({required List<String> pemCerts}) {
+  Future<String> crateApiGitMergePreferLocal({required String path}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_String(pemCerts, serializer);
+          sse_encode_String(path, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 27,
+            funcId: 29,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_git_error,
         ),
-        constMeta: kCrateApiSetSslCaCertsConstMeta,
-        argValues: [pemCerts],
+        constMeta: kCrateApiGitMergePreferLocalConstMeta,
+        argValues: [path],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSetSslCaCertsConstMeta => const TaskConstMeta(
-    debugName: "set_ssl_ca_certs",
-    argNames: ["pemCerts"],
-  );
+  TaskConstMeta get kCrateApiGitMergePreferLocalConstMeta =>
+      const TaskConstMeta(
+        debugName: "git_merge_prefer_local",
+        argNames: ["path"],
+      );
 
   @override
-  Future<void> crateApiUpdateEvent({
-    required String id,
-    required String title,
-    required String description,
-    required String startDate,
-    String? endDate,
-    String? startTime,
-    String? endTime,
-    required bool isAllDay,
-    required String recurrence,
-    required String calendarDir,
-  }) {
+  Future<String> crateApiGitMergePreferRemote({required String path}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(id, serializer);
-          sse_encode_String(title, serializer);
-          sse_encode_String(description, serializer);
-          sse_encode_String(startDate, serializer);
-          sse_encode_opt_String(endDate, serializer);
-          sse_encode_opt_String(startTime, serializer);
-          sse_encode_opt_String(endTime, serializer);
-          sse_encode_bool(isAllDay, serializer);
-          sse_encode_String(recurrence, serializer);
-          sse_encode_String(calendarDir, serializer);
+          sse_encode_String(path, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 28,
+            funcId: 30,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_git_error,
         ),
-        constMeta: kCrateApiUpdateEventConstMeta,
-        argValues: [
-          id,
-          title,
-          description,
-          startDate,
-          endDate,
-          startTime,
-          endTime,
-          isAllDay,
-          recurrence,
-          calendarDir,
-        ],
+        constMeta: kCrateApiGitMergePreferRemoteConstMeta,
+        argValues: [path],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiUpdateEventConstMeta => const TaskConstMeta(
-    debugName: "update_event",
-    argNames: [
-      "id",
-      "title",
-      "description",
-      "startDate",
-      "endDate",
-      "startTime",
-      "endTime",
-      "isAllDay",
-      "recurrence",
-      "calendarDir",
-    ],
-  );
+  TaskConstMeta get kCrateApiGitMergePreferRemoteConstMeta =>
+      const TaskConstMeta(
+        debugName: "git_merge_prefer_remote",
+        argNames: ["path"],
+      );
 
   @override
-  Future<void> crateApiValidateEvent({
-    required String title,
-    required String startDate,
-    String? endDate,
-    String? startTime,
-    String? endTime,
+  Future<EventMergeReport> crateApiGitMergeResolveEvents({
+    required String path,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(title, serializer);
-          sse_encode_String(startDate, serializer);
-          sse_encode_opt_String(endDate, serializer);
-          sse_encode_opt_String(startTime, serializer);
-          sse_encode_opt_String(endTime, serializer);
+          sse_encode_String(path, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 29,
+            funcId: 31,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_event_merge_report,
+          decodeErrorData: sse_decode_git_error,
         ),
-        constMeta: kCrateApiValidateEventConstMeta,
-        argValues: [title, startDate, endDate, startTime, endTime],
+        constMeta: kCrateApiGitMergeResolveEventsConstMeta,
+        argValues: [path],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiValidateEventConstMeta => const TaskConstMeta(
-    debugName: "validate_event",
-    argNames: ["title", "startDate", "endDate", "startTime", "endTime"],
-  );
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
-  }
-
-  @protected
-  EventDto dco_decode_box_autoadd_event_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_event_dto(raw);
-  }
+  TaskConstMeta get kCrateApiGitMergeResolveEventsConstMeta =>
+      const TaskConstMeta(
+        debugName: "git_merge_resolve_events",
+        argNames: ["path"],
+      );
 
-  @protected
-  EventDto dco_decode_event_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 10)
-      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
-    return EventDto(
-      id: dco_decode_String(arr[0]),
-      title: dco_decode_String(arr[1]),
-      description: dco_decode_String(arr[2]),
-      startDate: dco_decode_String(arr[3]),
-      endDate: dco_decode_opt_String(arr[4]),
-      startTime: dco_decode_opt_String(arr[5]),
-      endTime: dco_decode_opt_String(arr[6]),
-      isAllDay: dco_decode_bool(arr[7]),
-      recurrence: dco_decode_String(arr[8]),
-      isRecurringInstance: dco_decode_bool(arr[9]),
+  @override
+  Future<List<String>> crateApiGitMergeResolvePaths({
+    required String path,
+    required List<PathResolution> resolutions,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_list_path_resolution(resolutions, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 32,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_git_error,
+        ),
+        constMeta: kCrateApiGitMergeResolvePathsConstMeta,
+        argValues: [path, resolutions],
+        apiImpl: this,
+      ),
     );
   }
 
-  @protected
-  GitError dco_decode_git_error(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
-      case 0:
-        return GitError_Io(dco_decode_String(raw[1]));
-      case 1:
-        return GitError_Git(dco_decode_String(raw[1]));
-      case 2:
-        return GitError_Auth(dco_decode_String(raw[1]));
-      case 3:
-        return GitError_Other(dco_decode_String(raw[1]));
-      default:
-        throw Exception("unreachable");
-    }
-  }
+  TaskConstMeta get kCrateApiGitMergeResolvePathsConstMeta =>
+      const TaskConstMeta(
+        debugName: "git_merge_resolve_paths",
+        argNames: ["path", "resolutions"],
+      );
 
-  @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  @override
+  Future<SyncResult> crateApiGitPull({
+    required String path,
+    String? username,
+    String? password,
+    String? sshKeyPath,
+    String? branch,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_opt_String(username, serializer);
+          sse_encode_opt_String(password, serializer);
+          sse_encode_opt_String(sshKeyPath, serializer);
+          sse_encode_opt_String(branch, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 33,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_sync_result,
+          decodeErrorData: sse_decode_git_error,
+        ),
+        constMeta: kCrateApiGitPullConstMeta,
+        argValues: [path, username, password, sshKeyPath, branch],
+        apiImpl: this,
+      ),
+    );
   }
 
-  @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
-  }
+  TaskConstMeta get kCrateApiGitPullConstMeta => const TaskConstMeta(
+    debugName: "git_pull",
+    argNames: ["path", "username", "password", "sshKeyPath", "branch"],
+  );
 
-  @protected
-  List<EventDto> dco_decode_list_event_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_event_dto).toList();
+  @override
+  Stream<GitProgress> crateApiGitPullWithProgress({
+    required String path,
+    String? username,
+    String? password,
+    String? sshKeyPath,
+    String? branch,
+    BigInt? operation,
+  }) {
+    final sink = RustStreamSink<GitProgress>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(path, serializer);
+            sse_encode_opt_String(username, serializer);
+            sse_encode_opt_String(password, serializer);
+            sse_encode_opt_String(sshKeyPath, serializer);
+            sse_encode_opt_String(branch, serializer);
+            sse_encode_opt_box_autoadd_u_64(operation, serializer);
+            sse_encode_StreamSink_git_progress_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 34,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_sync_result,
+            decodeErrorData: sse_decode_git_error,
+          ),
+          constMeta: kCrateApiGitPullWithProgressConstMeta,
+          argValues: [
+            path,
+            username,
+            password,
+            sshKeyPath,
+            branch,
+            operation,
+            sink,
+          ],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
   }
 
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
+  TaskConstMeta get kCrateApiGitPullWithProgressConstMeta =>
+      const TaskConstMeta(
+        debugName: "git_pull_with_progress",
+        argNames: [
+          "path",
+          "username",
+          "password",
+          "sshKeyPath",
+          "branch",
+          "operation",
+          "sink",
+        ],
+      );
 
-  @protected
-  List<StatusEntry> dco_decode_list_status_entry(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_status_entry).toList();
+  @override
+  Future<SyncResult> crateApiGitPush({
+    required String path,
+    String? username,
+    String? password,
+    String? sshKeyPath,
+    String? branch,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_opt_String(username, serializer);
+          sse_encode_opt_String(password, serializer);
+          sse_encode_opt_String(sshKeyPath, serializer);
+          sse_encode_opt_String(branch, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 35,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_sync_result,
+          decodeErrorData: sse_decode_git_error,
+        ),
+        constMeta: kCrateApiGitPushConstMeta,
+        argValues: [path, username, password, sshKeyPath, branch],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGitPushConstMeta => const TaskConstMeta(
+    debugName: "git_push",
+    argNames: ["path", "username", "password", "sshKeyPath", "branch"],
+  );
+
+  @override
+  Stream<GitProgress> crateApiGitPushWithProgress({
+    required String path,
+    String? username,
+    String? password,
+    String? sshKeyPath,
+    String? branch,
+    BigInt? operation,
+  }) {
+    final sink = RustStreamSink<GitProgress>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(path, serializer);
+            sse_encode_opt_String(username, serializer);
+            sse_encode_opt_String(password, serializer);
+            sse_encode_opt_String(sshKeyPath, serializer);
+            sse_encode_opt_String(branch, serializer);
+            sse_encode_opt_box_autoadd_u_64(operation, serializer);
+            sse_encode_StreamSink_git_progress_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 36,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_sync_result,
+            decodeErrorData: sse_decode_git_error,
+          ),
+          constMeta: kCrateApiGitPushWithProgressConstMeta,
+          argValues: [
+            path,
+            username,
+            password,
+            sshKeyPath,
+            branch,
+            operation,
+            sink,
+          ],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiGitPushWithProgressConstMeta =>
+      const TaskConstMeta(
+        debugName: "git_push_with_progress",
+        argNames: [
+          "path",
+          "username",
+          "password",
+          "sshKeyPath",
+          "branch",
+          "operation",
+          "sink",
+        ],
+      );
+
+  @override
+  Future<String> crateApiGitRemoveRemote({
+    required String path,
+    required String name,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_String(name, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 37,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_git_error,
+        ),
+        constMeta: kCrateApiGitRemoveRemoteConstMeta,
+        argValues: [path, name],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGitRemoveRemoteConstMeta => const TaskConstMeta(
+    debugName: "git_remove_remote",
+    argNames: ["path", "name"],
+  );
+
+  @override
+  Future<String> crateApiGitSetIdentity({
+    required String path,
+    required GitIdentity identity,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_box_autoadd_git_identity(identity, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 38,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_git_error,
+        ),
+        constMeta: kCrateApiGitSetIdentityConstMeta,
+        argValues: [path, identity],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGitSetIdentityConstMeta => const TaskConstMeta(
+    debugName: "git_set_identity",
+    argNames: ["path", "identity"],
+  );
+
+  @override
+  Future<String> crateApiGitStash({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 39,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_git_error,
+        ),
+        constMeta: kCrateApiGitStashConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGitStashConstMeta =>
+      const TaskConstMeta(debugName: "git_stash", argNames: ["path"]);
+
+  @override
+  Future<List<StatusEntry>> crateApiGitStatus({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 40,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_status_entry,
+          decodeErrorData: sse_decode_git_error,
+        ),
+        constMeta: kCrateApiGitStatusConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGitStatusConstMeta =>
+      const TaskConstMeta(debugName: "git_status", argNames: ["path"]);
+
+  @override
+  Future<SyncReport> crateApiGitSync({
+    required String path,
+    String? username,
+    String? password,
+    String? sshKeyPath,
+    String? branch,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_opt_String(username, serializer);
+          sse_encode_opt_String(password, serializer);
+          sse_encode_opt_String(sshKeyPath, serializer);
+          sse_encode_opt_String(branch, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 41,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_sync_report,
+          decodeErrorData: sse_decode_git_error,
+        ),
+        constMeta: kCrateApiGitSyncConstMeta,
+        argValues: [path, username, password, sshKeyPath, branch],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGitSyncConstMeta => const TaskConstMeta(
+    debugName: "git_sync",
+    argNames: ["path", "username", "password", "sshKeyPath", "branch"],
+  );
+
+  @override
+  Stream<GitProgress> crateApiGitSyncWithProgress({
+    required String path,
+    String? username,
+    String? password,
+    String? sshKeyPath,
+    String? branch,
+    BigInt? operation,
+  }) {
+    final sink = RustStreamSink<GitProgress>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(path, serializer);
+            sse_encode_opt_String(username, serializer);
+            sse_encode_opt_String(password, serializer);
+            sse_encode_opt_String(sshKeyPath, serializer);
+            sse_encode_opt_String(branch, serializer);
+            sse_encode_opt_box_autoadd_u_64(operation, serializer);
+            sse_encode_StreamSink_git_progress_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 42,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_sync_report,
+            decodeErrorData: sse_decode_git_error,
+          ),
+          constMeta: kCrateApiGitSyncWithProgressConstMeta,
+          argValues: [
+            path,
+            username,
+            password,
+            sshKeyPath,
+            branch,
+            operation,
+            sink,
+          ],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiGitSyncWithProgressConstMeta =>
+      const TaskConstMeta(
+        debugName: "git_sync_with_progress",
+        argNames: [
+          "path",
+          "username",
+          "password",
+          "sshKeyPath",
+          "branch",
+          "operation",
+          "sink",
+        ],
+      );
+
+  @override
+  Future<IcsImportOptions> crateApiIcsImportOptionsDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 43,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_ics_import_options,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiIcsImportOptionsDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiIcsImportOptionsDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "ics_import_options_default",
+        argNames: [],
+      );
+
+  @override
+  Future<IcsImportReport> crateApiImportIcs({
+    required String icsText,
+    required String calendarDir,
+    required IcsImportOptions options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(icsText, serializer);
+          sse_encode_String(calendarDir, serializer);
+          sse_encode_box_autoadd_ics_import_options(options, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 44,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_ics_import_report,
+          decodeErrorData: sse_decode_event_error,
+        ),
+        constMeta: kCrateApiImportIcsConstMeta,
+        argValues: [icsText, calendarDir, options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiImportIcsConstMeta => const TaskConstMeta(
+    debugName: "import_ics",
+    argNames: ["icsText", "calendarDir", "options"],
+  );
+
+  @override
+  Future<void> crateApiInitApp() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 45,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiInitAppConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInitAppConstMeta =>
+      const TaskConstMeta(debugName: "init_app", argNames: []);
+
+  @override
+  Future<List<HostKey>> crateApiListKnownHosts() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 46,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_host_key,
+          decodeErrorData: sse_decode_git_error,
+        ),
+        constMeta: kCrateApiListKnownHostsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiListKnownHostsConstMeta =>
+      const TaskConstMeta(debugName: "list_known_hosts", argNames: []);
+
+  @override
+  Future<List<String>> crateApiListTimeZones() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 47,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiListTimeZonesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiListTimeZonesConstMeta =>
+      const TaskConstMeta(debugName: "list_time_zones", argNames: []);
+
+  @override
+  Future<List<String>> crateApiMigrateEventIds({required String calendarDir}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(calendarDir, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 48,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_event_error,
+        ),
+        constMeta: kCrateApiMigrateEventIdsConstMeta,
+        argValues: [calendarDir],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMigrateEventIdsConstMeta => const TaskConstMeta(
+    debugName: "migrate_event_ids",
+    argNames: ["calendarDir"],
+  );
+
+  @override
+  Future<void> crateApiOpenCalendar({required String calendarDir}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(calendarDir, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 49,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_event_error,
+        ),
+        constMeta: kCrateApiOpenCalendarConstMeta,
+        argValues: [calendarDir],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiOpenCalendarConstMeta => const TaskConstMeta(
+    debugName: "open_calendar",
+    argNames: ["calendarDir"],
+  );
+
+  @override
+  Future<void> crateApiOverrideOccurrence({
+    required String id,
+    required OccurrenceOverride changes,
+    required String calendarDir,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(id, serializer);
+          sse_encode_box_autoadd_occurrence_override(changes, serializer);
+          sse_encode_String(calendarDir, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 50,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_event_error,
+        ),
+        constMeta: kCrateApiOverrideOccurrenceConstMeta,
+        argValues: [id, changes, calendarDir],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiOverrideOccurrenceConstMeta => const TaskConstMeta(
+    debugName: "override_occurrence",
+    argNames: ["id", "changes", "calendarDir"],
+  );
+
+  @override
+  Future<RecurrenceRule> crateApiParseRecurrenceRule({required String text}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(text, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 51,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_recurrence_rule,
+          decodeErrorData: sse_decode_event_error,
+        ),
+        constMeta: kCrateApiParseRecurrenceRuleConstMeta,
+        argValues: [text],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiParseRecurrenceRuleConstMeta =>
+      const TaskConstMeta(
+        debugName: "parse_recurrence_rule",
+        argNames: ["text"],
+      );
+
+  @override
+  Future<bool> crateApiRemoveHostKey({required String host}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(host, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 52,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_git_error,
+        ),
+        constMeta: kCrateApiRemoveHostKeyConstMeta,
+        argValues: [host],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRemoveHostKeyConstMeta =>
+      const TaskConstMeta(debugName: "remove_host_key", argNames: ["host"]);
+
+  @override
+  Future<void> crateApiSetCredentialCallback({
+    required FutureOr<GitCredentials?> Function(CredentialRequest) callback,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_DartFn_Inputs_credential_request_Output_opt_box_autoadd_git_credentials_AnyhowException(
+            callback,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 53,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSetCredentialCallbackConstMeta,
+        argValues: [callback],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetCredentialCallbackConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_credential_callback",
+        argNames: ["callback"],
+      );
+
+  @override
+  Future<void> crateApiSetKnownHostsPath({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 54,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_git_error,
+        ),
+        constMeta: kCrateApiSetKnownHostsPathConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetKnownHostsPathConstMeta => const TaskConstMeta(
+    debugName: "set_known_hosts_path",
+    argNames: ["path"],
+  );
+
+  @override
+  Future<void> crateApiSetSslCaCerts({required List<String> pemCerts}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_String(pemCerts, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 55,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_git_error,
+        ),
+        constMeta: kCrateApiSetSslCaCertsConstMeta,
+        argValues: [pemCerts],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetSslCaCertsConstMeta => const TaskConstMeta(
+    debugName: "set_ssl_ca_certs",
+    argNames: ["pemCerts"],
+  );
+
+  @override
+  Future<void> crateApiSetTrustStorePath({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 56,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_git_error,
+        ),
+        constMeta: kCrateApiSetTrustStorePathConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetTrustStorePathConstMeta => const TaskConstMeta(
+    debugName: "set_trust_store_path",
+    argNames: ["path"],
+  );
+
+  @override
+  Future<void> crateApiSkipOccurrence({
+    required String id,
+    required String date,
+    required String calendarDir,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(id, serializer);
+          sse_encode_String(date, serializer);
+          sse_encode_String(calendarDir, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 57,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_event_error,
+        ),
+        constMeta: kCrateApiSkipOccurrenceConstMeta,
+        argValues: [id, date, calendarDir],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSkipOccurrenceConstMeta => const TaskConstMeta(
+    debugName: "skip_occurrence",
+    argNames: ["id", "date", "calendarDir"],
+  );
+
+  @override
+  Future<String> crateApiSplitSeriesFrom({
+    required String id,
+    required String date,
+    required String calendarDir,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(id, serializer);
+          sse_encode_String(date, serializer);
+          sse_encode_String(calendarDir, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 58,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_event_error,
+        ),
+        constMeta: kCrateApiSplitSeriesFromConstMeta,
+        argValues: [id, date, calendarDir],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSplitSeriesFromConstMeta => const TaskConstMeta(
+    debugName: "split_series_from",
+    argNames: ["id", "date", "calendarDir"],
+  );
+
+  @override
+  Future<BigInt> crateApiStartOperation() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 59,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiStartOperationConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiStartOperationConstMeta =>
+      const TaskConstMeta(debugName: "start_operation", argNames: []);
+
+  @override
+  Future<List<TrustedCertificate>> crateApiTrustAddCa({
+    required String host,
+    required String pem,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(host, serializer);
+          sse_encode_String(pem, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 60,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_trusted_certificate,
+          decodeErrorData: sse_decode_git_error,
+        ),
+        constMeta: kCrateApiTrustAddCaConstMeta,
+        argValues: [host, pem],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTrustAddCaConstMeta =>
+      const TaskConstMeta(debugName: "trust_add_ca", argNames: ["host", "pem"]);
+
+  @override
+  Future<void> crateApiTrustHostKey({required HostKey key}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_host_key(key, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 61,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_git_error,
+        ),
+        constMeta: kCrateApiTrustHostKeyConstMeta,
+        argValues: [key],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTrustHostKeyConstMeta =>
+      const TaskConstMeta(debugName: "trust_host_key", argNames: ["key"]);
+
+  @override
+  Future<List<TrustedCertificate>> crateApiTrustList({String? host}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(host, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 62,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_trusted_certificate,
+          decodeErrorData: sse_decode_git_error,
+        ),
+        constMeta: kCrateApiTrustListConstMeta,
+        argValues: [host],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTrustListConstMeta =>
+      const TaskConstMeta(debugName: "trust_list", argNames: ["host"]);
+
+  @override
+  Future<List<TrustedCertificate>> crateApiTrustPinCertificate({
+    required String host,
+    required String pem,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(host, serializer);
+          sse_encode_String(pem, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 63,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_trusted_certificate,
+          decodeErrorData: sse_decode_git_error,
+        ),
+        constMeta: kCrateApiTrustPinCertificateConstMeta,
+        argValues: [host, pem],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTrustPinCertificateConstMeta =>
+      const TaskConstMeta(
+        debugName: "trust_pin_certificate",
+        argNames: ["host", "pem"],
+      );
+
+  @override
+  Future<bool> crateApiTrustRemove({
+    required String host,
+    required String fingerprint,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(host, serializer);
+          sse_encode_String(fingerprint, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 64,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_git_error,
+        ),
+        constMeta: kCrateApiTrustRemoveConstMeta,
+        argValues: [host, fingerprint],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTrustRemoveConstMeta => const TaskConstMeta(
+    debugName: "trust_remove",
+    argNames: ["host", "fingerprint"],
+  );
+
+  @override
+  Future<void> crateApiUnwatchCalendar({required String calendarDir}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(calendarDir, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 65,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiUnwatchCalendarConstMeta,
+        argValues: [calendarDir],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUnwatchCalendarConstMeta => const TaskConstMeta(
+    debugName: "unwatch_calendar",
+    argNames: ["calendarDir"],
+  );
+
+  @override
+  Future<void> crateApiUpdateEvent({
+    required String id,
+    required EventInput input,
+    required String calendarDir,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(id, serializer);
+          sse_encode_box_autoadd_event_input(input, serializer);
+          sse_encode_String(calendarDir, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 66,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_event_error,
+        ),
+        constMeta: kCrateApiUpdateEventConstMeta,
+        argValues: [id, input, calendarDir],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUpdateEventConstMeta => const TaskConstMeta(
+    debugName: "update_event",
+    argNames: ["id", "input", "calendarDir"],
+  );
+
+  @override
+  Future<void> crateApiValidateEvent({
+    required String title,
+    required String startDate,
+    String? endDate,
+    String? startTime,
+    String? endTime,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(title, serializer);
+          sse_encode_String(startDate, serializer);
+          sse_encode_opt_String(endDate, serializer);
+          sse_encode_opt_String(startTime, serializer);
+          sse_encode_opt_String(endTime, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 67,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_event_error,
+        ),
+        constMeta: kCrateApiValidateEventConstMeta,
+        argValues: [title, startDate, endDate, startTime, endTime],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiValidateEventConstMeta => const TaskConstMeta(
+    debugName: "validate_event",
+    argNames: ["title", "startDate", "endDate", "startTime", "endTime"],
+  );
+
+  @override
+  Stream<CalendarChange> crateApiWatchCalendar({required String calendarDir}) {
+    final sink = RustStreamSink<CalendarChange>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(calendarDir, serializer);
+            sse_encode_StreamSink_calendar_change_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 68,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_event_error,
+          ),
+          constMeta: kCrateApiWatchCalendarConstMeta,
+          argValues: [calendarDir, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiWatchCalendarConstMeta => const TaskConstMeta(
+    debugName: "watch_calendar",
+    argNames: ["calendarDir", "sink"],
+  );
+
+  Future<void> Function(int, dynamic)
+  encode_DartFn_Inputs_credential_request_Output_opt_box_autoadd_git_credentials_AnyhowException(
+    FutureOr<GitCredentials?> Function(CredentialRequest) raw,
+  ) {
+    return (callId, rawArg0) async {
+      final arg0 = dco_decode_credential_request(rawArg0);
+
+      Box<GitCredentials?>? rawOutput;
+      Box<AnyhowException>? rawError;
+      try {
+        rawOutput = Box(await raw(arg0));
+      } catch (e, s) {
+        rawError = Box(AnyhowException("$e\n\n$s"));
+      }
+
+      final serializer = SseSerializer(generalizedFrbRustBinding);
+      assert((rawOutput != null) ^ (rawError != null));
+      if (rawOutput != null) {
+        serializer.buffer.putUint8(0);
+        sse_encode_opt_box_autoadd_git_credentials(rawOutput.value, serializer);
+      } else {
+        serializer.buffer.putUint8(1);
+        sse_encode_AnyhowException(rawError!.value, serializer);
+      }
+      final output = serializer.intoRaw();
+
+      generalizedFrbRustBinding.dartFnDeliverOutput(
+        callId: callId,
+        ptr: output.ptr,
+        rustVecLen: output.rustVecLen,
+        dataLen: output.dataLen,
+      );
+    };
+  }
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  FutureOr<GitCredentials?> Function(CredentialRequest)
+  dco_decode_DartFn_Inputs_credential_request_Output_opt_box_autoadd_git_credentials_AnyhowException(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError('');
+  }
+
+  @protected
+  Object dco_decode_DartOpaque(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return decodeDartOpaque(raw, generalizedFrbRustBinding);
+  }
+
+  @protected
+  RustStreamSink<CalendarChange> dco_decode_StreamSink_calendar_change_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<GitProgress> dco_decode_StreamSink_git_progress_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  CertificateError dco_decode_box_autoadd_certificate_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_certificate_error(raw);
+  }
+
+  @protected
+  EventDto dco_decode_box_autoadd_event_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_event_dto(raw);
+  }
+
+  @protected
+  EventInput dco_decode_box_autoadd_event_input(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_event_input(raw);
+  }
+
+  @protected
+  GitCredentials dco_decode_box_autoadd_git_credentials(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_git_credentials(raw);
+  }
+
+  @protected
+  GitIdentity dco_decode_box_autoadd_git_identity(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_git_identity(raw);
+  }
+
+  @protected
+  HostKey dco_decode_box_autoadd_host_key(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_host_key(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  IcsImportOptions dco_decode_box_autoadd_ics_import_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_ics_import_options(raw);
+  }
+
+  @protected
+  OccurrenceOverride dco_decode_box_autoadd_occurrence_override(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_occurrence_override(raw);
+  }
+
+  @protected
+  RecurrenceRule dco_decode_box_autoadd_recurrence_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_recurrence_rule(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_u_64(raw);
+  }
+
+  @protected
+  CalendarChange dco_decode_calendar_change(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return CalendarChange_EventAdded(
+          dco_decode_box_autoadd_event_dto(raw[1]),
+        );
+      case 1:
+        return CalendarChange_EventChanged(
+          dco_decode_box_autoadd_event_dto(raw[1]),
+        );
+      case 2:
+        return CalendarChange_EventRemoved(
+          dco_decode_box_autoadd_event_dto(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  CertificateError dco_decode_certificate_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return CertificateError_Expired();
+      case 1:
+        return CertificateError_NotYetValid();
+      case 2:
+        return CertificateError_UnknownIssuer();
+      case 3:
+        return CertificateError_NameMismatch();
+      case 4:
+        return CertificateError_PinMismatch();
+      case 5:
+        return CertificateError_InvalidSignature();
+      case 6:
+        return CertificateError_Malformed(dco_decode_String(raw[1]));
+      case 7:
+        return CertificateError_Other(dco_decode_String(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  CredentialRequest dco_decode_credential_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return CredentialRequest(
+      url: dco_decode_String(arr[0]),
+      usernameFromUrl: dco_decode_opt_String(arr[1]),
+      allowPassword: dco_decode_bool(arr[2]),
+      allowSshKey: dco_decode_bool(arr[3]),
+      attempt: dco_decode_u_32(arr[4]),
+    );
+  }
+
+  @protected
+  EventDiagnostic dco_decode_event_diagnostic(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return EventDiagnostic(
+      id: dco_decode_String(arr[0]),
+      reason: dco_decode_event_error(arr[1]),
+    );
+  }
+
+  @protected
+  EventDto dco_decode_event_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 15)
+      throw Exception('unexpected arr length: expect 15 but see ${arr.length}');
+    return EventDto(
+      id: dco_decode_String(arr[0]),
+      title: dco_decode_String(arr[1]),
+      description: dco_decode_String(arr[2]),
+      startDate: dco_decode_String(arr[3]),
+      endDate: dco_decode_opt_String(arr[4]),
+      startTime: dco_decode_opt_String(arr[5]),
+      endTime: dco_decode_opt_String(arr[6]),
+      isAllDay: dco_decode_bool(arr[7]),
+      timeZone: dco_decode_opt_String(arr[8]),
+      recurrence: dco_decode_String(arr[9]),
+      rrule: dco_decode_opt_box_autoadd_recurrence_rule(arr[10]),
+      excludedDates: dco_decode_list_String(arr[11]),
+      overrides: dco_decode_list_occurrence_override(arr[12]),
+      occurrenceDate: dco_decode_opt_String(arr[13]),
+      isRecurringInstance: dco_decode_bool(arr[14]),
+    );
+  }
+
+  @protected
+  EventError dco_decode_event_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return EventError_InvalidDate(
+          field: dco_decode_String(raw[1]),
+          value: dco_decode_String(raw[2]),
+        );
+      case 1:
+        return EventError_InvalidTime(
+          field: dco_decode_String(raw[1]),
+          value: dco_decode_String(raw[2]),
+        );
+      case 2:
+        return EventError_MissingField(dco_decode_String(raw[1]));
+      case 3:
+        return EventError_InvalidRange(field: dco_decode_String(raw[1]));
+      case 4:
+        return EventError_InvalidRecurrence(
+          value: dco_decode_String(raw[1]),
+          reason: dco_decode_String(raw[2]),
+        );
+      case 5:
+        return EventError_NoOccurrence(
+          id: dco_decode_String(raw[1]),
+          date: dco_decode_String(raw[2]),
+        );
+      case 6:
+        return EventError_UnknownTimeZone(dco_decode_String(raw[1]));
+      case 7:
+        return EventError_TitleInvalid(dco_decode_String(raw[1]));
+      case 8:
+        return EventError_NotFound(dco_decode_String(raw[1]));
+      case 9:
+        return EventError_DuplicateId(dco_decode_String(raw[1]));
+      case 10:
+        return EventError_Storage(dco_decode_String(raw[1]));
+      case 11:
+        return EventError_ParseFailure(
+          file: dco_decode_String(raw[1]),
+          message: dco_decode_String(raw[2]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  EventFieldConflict dco_decode_event_field_conflict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return EventFieldConflict(
+      path: dco_decode_String(arr[0]),
+      eventId: dco_decode_String(arr[1]),
+      field: dco_decode_String(arr[2]),
+      baseValue: dco_decode_opt_String(arr[3]),
+      localValue: dco_decode_opt_String(arr[4]),
+      remoteValue: dco_decode_opt_String(arr[5]),
+    );
+  }
+
+  @protected
+  EventInput dco_decode_event_input(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return EventInput(
+      title: dco_decode_String(arr[0]),
+      description: dco_decode_String(arr[1]),
+      startDate: dco_decode_String(arr[2]),
+      endDate: dco_decode_opt_String(arr[3]),
+      startTime: dco_decode_opt_String(arr[4]),
+      endTime: dco_decode_opt_String(arr[5]),
+      isAllDay: dco_decode_bool(arr[6]),
+      recurrence: dco_decode_String(arr[7]),
+      rrule: dco_decode_opt_box_autoadd_recurrence_rule(arr[8]),
+      timeZone: dco_decode_opt_String(arr[9]),
+    );
+  }
+
+  @protected
+  EventMergeReport dco_decode_event_merge_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return EventMergeReport(
+      mergedPaths: dco_decode_list_String(arr[0]),
+      unresolvedPaths: dco_decode_list_String(arr[1]),
+      conflicts: dco_decode_list_event_field_conflict(arr[2]),
+      committed: dco_decode_bool(arr[3]),
+    );
+  }
+
+  @protected
+  EventsResult dco_decode_events_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return EventsResult(
+      events: dco_decode_list_event_dto(arr[0]),
+      unreadable: dco_decode_list_event_error(arr[1]),
+    );
+  }
+
+  @protected
+  Frequency dco_decode_frequency(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Frequency.values[raw as int];
+  }
+
+  @protected
+  GitCredentials dco_decode_git_credentials(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return GitCredentials_UserPassword(
+          username: dco_decode_String(raw[1]),
+          password: dco_decode_String(raw[2]),
+        );
+      case 1:
+        return GitCredentials_Token(
+          username: dco_decode_opt_String(raw[1]),
+          token: dco_decode_String(raw[2]),
+        );
+      case 2:
+        return GitCredentials_SshKeyFile(
+          username: dco_decode_opt_String(raw[1]),
+          privateKeyPath: dco_decode_String(raw[2]),
+          publicKeyPath: dco_decode_opt_String(raw[3]),
+          passphrase: dco_decode_opt_String(raw[4]),
+        );
+      case 3:
+        return GitCredentials_SshKeyMemory(
+          username: dco_decode_opt_String(raw[1]),
+          privateKey: dco_decode_String(raw[2]),
+          publicKey: dco_decode_opt_String(raw[3]),
+          passphrase: dco_decode_opt_String(raw[4]),
+        );
+      case 4:
+        return GitCredentials_SshAgent(username: dco_decode_opt_String(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  GitError dco_decode_git_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return GitError_Io(dco_decode_String(raw[1]));
+      case 1:
+        return GitError_Git(dco_decode_String(raw[1]));
+      case 2:
+        return GitError_AuthenticationFailed(dco_decode_String(raw[1]));
+      case 3:
+        return GitError_RepositoryNotFound(dco_decode_String(raw[1]));
+      case 4:
+        return GitError_NotFound(dco_decode_String(raw[1]));
+      case 5:
+        return GitError_NetworkUnreachable(dco_decode_String(raw[1]));
+      case 6:
+        return GitError_Locked(dco_decode_String(raw[1]));
+      case 7:
+        return GitError_DetachedHead(dco_decode_String(raw[1]));
+      case 8:
+        return GitError_MergeConflict(dco_decode_list_String(raw[1]));
+      case 9:
+        return GitError_StashConflict(dco_decode_list_String(raw[1]));
+      case 10:
+        return GitError_NonFastForward(dco_decode_String(raw[1]));
+      case 11:
+        return GitError_CertificateInvalid(
+          host: dco_decode_String(raw[1]),
+          reason: dco_decode_box_autoadd_certificate_error(raw[2]),
+        );
+      case 12:
+        return GitError_UnknownHostKey(dco_decode_box_autoadd_host_key(raw[1]));
+      case 13:
+        return GitError_HostKeyMismatch(
+          dco_decode_box_autoadd_host_key(raw[1]),
+        );
+      case 14:
+        return GitError_Cancelled();
+      case 15:
+        return GitError_UnbornBranch(dco_decode_String(raw[1]));
+      case 16:
+        return GitError_Other(dco_decode_String(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  GitIdentity dco_decode_git_identity(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return GitIdentity(
+      name: dco_decode_String(arr[0]),
+      email: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  GitProgress dco_decode_git_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return GitProgress_Transfer(
+          receivedObjects: dco_decode_usize(raw[1]),
+          totalObjects: dco_decode_usize(raw[2]),
+          indexedObjects: dco_decode_usize(raw[3]),
+          receivedBytes: dco_decode_usize(raw[4]),
+          totalDeltas: dco_decode_usize(raw[5]),
+          indexedDeltas: dco_decode_usize(raw[6]),
+        );
+      case 1:
+        return GitProgress_Checkout(
+          path: dco_decode_opt_String(raw[1]),
+          completed: dco_decode_usize(raw[2]),
+          total: dco_decode_usize(raw[3]),
+        );
+      case 2:
+        return GitProgress_PushTransfer(
+          current: dco_decode_usize(raw[1]),
+          total: dco_decode_usize(raw[2]),
+          bytes: dco_decode_usize(raw[3]),
+        );
+      case 3:
+        return GitProgress_Sideband(dco_decode_String(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  HostKey dco_decode_host_key(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return HostKey(
+      host: dco_decode_String(arr[0]),
+      keyType: dco_decode_String(arr[1]),
+      key: dco_decode_String(arr[2]),
+      fingerprint: dco_decode_String(arr[3]),
+    );
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  IcsImportIssue dco_decode_ics_import_issue(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return IcsImportIssue(
+      uid: dco_decode_opt_String(arr[0]),
+      summary: dco_decode_String(arr[1]),
+      reason: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  IcsImportOptions dco_decode_ics_import_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return IcsImportOptions(replaceExisting: dco_decode_bool(arr[0]));
+  }
+
+  @protected
+  IcsImportReport dco_decode_ics_import_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return IcsImportReport(
+      imported: dco_decode_list_String(arr[0]),
+      skipped: dco_decode_list_ics_import_issue(arr[1]),
+      unsupported: dco_decode_list_ics_import_issue(arr[2]),
+    );
+  }
+
+  @protected
+  InstancesResult dco_decode_instances_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return InstancesResult(
+      instances: dco_decode_list_event_dto(arr[0]),
+      diagnostics: dco_decode_list_event_diagnostic(arr[1]),
+    );
+  }
+
+  @protected
+  PlatformInt64 dco_decode_isize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64(raw);
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<EventDiagnostic> dco_decode_list_event_diagnostic(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_event_diagnostic).toList();
+  }
+
+  @protected
+  List<EventDto> dco_decode_list_event_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_event_dto).toList();
+  }
+
+  @protected
+  List<EventError> dco_decode_list_event_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_event_error).toList();
+  }
+
+  @protected
+  List<EventFieldConflict> dco_decode_list_event_field_conflict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_event_field_conflict).toList();
+  }
+
+  @protected
+  List<HostKey> dco_decode_list_host_key(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_host_key).toList();
+  }
+
+  @protected
+  List<IcsImportIssue> dco_decode_list_ics_import_issue(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_ics_import_issue).toList();
+  }
+
+  @protected
+  List<OccurrenceOverride> dco_decode_list_occurrence_override(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_occurrence_override).toList();
+  }
+
+  @protected
+  List<PathResolution> dco_decode_list_path_resolution(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_path_resolution).toList();
+  }
+
+  @protected
+  Int32List dco_decode_list_prim_i_32_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Int32List;
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<StatusEntry> dco_decode_list_status_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_status_entry).toList();
+  }
+
+  @protected
+  List<TrustedCertificate> dco_decode_list_trusted_certificate(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_trusted_certificate).toList();
+  }
+
+  @protected
+  List<WeekdayRule> dco_decode_list_weekday_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_weekday_rule).toList();
+  }
+
+  @protected
+  MergeSide dco_decode_merge_side(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MergeSide.values[raw as int];
+  }
+
+  @protected
+  OccurrenceOverride dco_decode_occurrence_override(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return OccurrenceOverride(
+      occurrenceDate: dco_decode_String(arr[0]),
+      title: dco_decode_opt_String(arr[1]),
+      description: dco_decode_opt_String(arr[2]),
+      startDate: dco_decode_opt_String(arr[3]),
+      endDate: dco_decode_opt_String(arr[4]),
+      startTime: dco_decode_opt_String(arr[5]),
+      endTime: dco_decode_opt_String(arr[6]),
+      isAllDay: dco_decode_opt_box_autoadd_bool(arr[7]),
+    );
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_bool(raw);
+  }
+
+  @protected
+  GitCredentials? dco_decode_opt_box_autoadd_git_credentials(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_git_credentials(raw);
+  }
+
+  @protected
+  GitIdentity? dco_decode_opt_box_autoadd_git_identity(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_git_identity(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_i_32(raw);
+  }
+
+  @protected
+  RecurrenceRule? dco_decode_opt_box_autoadd_recurrence_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_recurrence_rule(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  }
+
+  @protected
+  PathResolution dco_decode_path_resolution(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return PathResolution(
+      path: dco_decode_String(arr[0]),
+      side: dco_decode_merge_side(arr[1]),
+    );
+  }
+
+  @protected
+  RecurrenceRule dco_decode_recurrence_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return RecurrenceRule(
+      frequency: dco_decode_frequency(arr[0]),
+      interval: dco_decode_u_32(arr[1]),
+      byDay: dco_decode_list_weekday_rule(arr[2]),
+      byMonthDay: dco_decode_list_prim_i_32_strict(arr[3]),
+      bySetPos: dco_decode_list_prim_i_32_strict(arr[4]),
+      count: dco_decode_opt_box_autoadd_u_32(arr[5]),
+      until: dco_decode_opt_String(arr[6]),
+    );
+  }
+
+  @protected
+  StatusEntry dco_decode_status_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return StatusEntry(
+      path: dco_decode_String(arr[0]),
+      status: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  SyncOutcome dco_decode_sync_outcome(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SyncOutcome.values[raw as int];
+  }
+
+  @protected
+  SyncReport dco_decode_sync_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return SyncReport(
+      localCommit: dco_decode_opt_String(arr[0]),
+      pull: dco_decode_sync_result(arr[1]),
+      push: dco_decode_sync_result(arr[2]),
+      pushAttempts: dco_decode_u_32(arr[3]),
+    );
+  }
+
+  @protected
+  SyncResult dco_decode_sync_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return SyncResult(
+      outcome: dco_decode_sync_outcome(arr[0]),
+      oldCommit: dco_decode_opt_String(arr[1]),
+      newCommit: dco_decode_opt_String(arr[2]),
+      addedPaths: dco_decode_list_String(arr[3]),
+      modifiedPaths: dco_decode_list_String(arr[4]),
+      deletedPaths: dco_decode_list_String(arr[5]),
+      affectedEventIds: dco_decode_list_String(arr[6]),
+    );
+  }
+
+  @protected
+  TrustKind dco_decode_trust_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return TrustKind.values[raw as int];
+  }
+
+  @protected
+  TrustedCertificate dco_decode_trusted_certificate(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return TrustedCertificate(
+      host: dco_decode_String(arr[0]),
+      kind: dco_decode_trust_kind(arr[1]),
+      fingerprint: dco_decode_String(arr[2]),
+      subject: dco_decode_String(arr[3]),
+      notAfter: dco_decode_String(arr[4]),
+    );
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  Weekday dco_decode_weekday(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Weekday.values[raw as int];
+  }
+
+  @protected
+  WeekdayRule dco_decode_weekday_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return WeekdayRule(
+      weekday: dco_decode_weekday(arr[0]),
+      ordinal: dco_decode_opt_box_autoadd_i_32(arr[1]),
+    );
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  Object sse_decode_DartOpaque(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_isize(deserializer);
+    return decodeDartOpaque(inner, generalizedFrbRustBinding);
+  }
+
+  @protected
+  RustStreamSink<CalendarChange> sse_decode_StreamSink_calendar_change_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<GitProgress> sse_decode_StreamSink_git_progress_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_bool(deserializer));
+  }
+
+  @protected
+  CertificateError sse_decode_box_autoadd_certificate_error(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_certificate_error(deserializer));
+  }
+
+  @protected
+  EventDto sse_decode_box_autoadd_event_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_event_dto(deserializer));
+  }
+
+  @protected
+  EventInput sse_decode_box_autoadd_event_input(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_event_input(deserializer));
+  }
+
+  @protected
+  GitCredentials sse_decode_box_autoadd_git_credentials(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_git_credentials(deserializer));
+  }
+
+  @protected
+  GitIdentity sse_decode_box_autoadd_git_identity(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_git_identity(deserializer));
+  }
+
+  @protected
+  HostKey sse_decode_box_autoadd_host_key(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_host_key(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  IcsImportOptions sse_decode_box_autoadd_ics_import_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_ics_import_options(deserializer));
+  }
+
+  @protected
+  OccurrenceOverride sse_decode_box_autoadd_occurrence_override(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_occurrence_override(deserializer));
+  }
+
+  @protected
+  RecurrenceRule sse_decode_box_autoadd_recurrence_rule(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_recurrence_rule(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_64(deserializer));
+  }
+
+  @protected
+  CalendarChange sse_decode_calendar_change(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 = sse_decode_box_autoadd_event_dto(deserializer);
+        return CalendarChange_EventAdded(var_field0);
+      case 1:
+        var var_field0 = sse_decode_box_autoadd_event_dto(deserializer);
+        return CalendarChange_EventChanged(var_field0);
+      case 2:
+        var var_field0 = sse_decode_box_autoadd_event_dto(deserializer);
+        return CalendarChange_EventRemoved(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  CertificateError sse_decode_certificate_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return CertificateError_Expired();
+      case 1:
+        return CertificateError_NotYetValid();
+      case 2:
+        return CertificateError_UnknownIssuer();
+      case 3:
+        return CertificateError_NameMismatch();
+      case 4:
+        return CertificateError_PinMismatch();
+      case 5:
+        return CertificateError_InvalidSignature();
+      case 6:
+        var var_field0 = sse_decode_String(deserializer);
+        return CertificateError_Malformed(var_field0);
+      case 7:
+        var var_field0 = sse_decode_String(deserializer);
+        return CertificateError_Other(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  CredentialRequest sse_decode_credential_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_url = sse_decode_String(deserializer);
+    var var_usernameFromUrl = sse_decode_opt_String(deserializer);
+    var var_allowPassword = sse_decode_bool(deserializer);
+    var var_allowSshKey = sse_decode_bool(deserializer);
+    var var_attempt = sse_decode_u_32(deserializer);
+    return CredentialRequest(
+      url: var_url,
+      usernameFromUrl: var_usernameFromUrl,
+      allowPassword: var_allowPassword,
+      allowSshKey: var_allowSshKey,
+      attempt: var_attempt,
+    );
+  }
+
+  @protected
+  EventDiagnostic sse_decode_event_diagnostic(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_reason = sse_decode_event_error(deserializer);
+    return EventDiagnostic(id: var_id, reason: var_reason);
+  }
+
+  @protected
+  EventDto sse_decode_event_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_description = sse_decode_String(deserializer);
+    var var_startDate = sse_decode_String(deserializer);
+    var var_endDate = sse_decode_opt_String(deserializer);
+    var var_startTime = sse_decode_opt_String(deserializer);
+    var var_endTime = sse_decode_opt_String(deserializer);
+    var var_isAllDay = sse_decode_bool(deserializer);
+    var var_timeZone = sse_decode_opt_String(deserializer);
+    var var_recurrence = sse_decode_String(deserializer);
+    var var_rrule = sse_decode_opt_box_autoadd_recurrence_rule(deserializer);
+    var var_excludedDates = sse_decode_list_String(deserializer);
+    var var_overrides = sse_decode_list_occurrence_override(deserializer);
+    var var_occurrenceDate = sse_decode_opt_String(deserializer);
+    var var_isRecurringInstance = sse_decode_bool(deserializer);
+    return EventDto(
+      id: var_id,
+      title: var_title,
+      description: var_description,
+      startDate: var_startDate,
+      endDate: var_endDate,
+      startTime: var_startTime,
+      endTime: var_endTime,
+      isAllDay: var_isAllDay,
+      timeZone: var_timeZone,
+      recurrence: var_recurrence,
+      rrule: var_rrule,
+      excludedDates: var_excludedDates,
+      overrides: var_overrides,
+      occurrenceDate: var_occurrenceDate,
+      isRecurringInstance: var_isRecurringInstance,
+    );
+  }
+
+  @protected
+  EventError sse_decode_event_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field = sse_decode_String(deserializer);
+        var var_value = sse_decode_String(deserializer);
+        return EventError_InvalidDate(field: var_field, value: var_value);
+      case 1:
+        var var_field = sse_decode_String(deserializer);
+        var var_value = sse_decode_String(deserializer);
+        return EventError_InvalidTime(field: var_field, value: var_value);
+      case 2:
+        var var_field0 = sse_decode_String(deserializer);
+        return EventError_MissingField(var_field0);
+      case 3:
+        var var_field = sse_decode_String(deserializer);
+        return EventError_InvalidRange(field: var_field);
+      case 4:
+        var var_value = sse_decode_String(deserializer);
+        var var_reason = sse_decode_String(deserializer);
+        return EventError_InvalidRecurrence(
+          value: var_value,
+          reason: var_reason,
+        );
+      case 5:
+        var var_id = sse_decode_String(deserializer);
+        var var_date = sse_decode_String(deserializer);
+        return EventError_NoOccurrence(id: var_id, date: var_date);
+      case 6:
+        var var_field0 = sse_decode_String(deserializer);
+        return EventError_UnknownTimeZone(var_field0);
+      case 7:
+        var var_field0 = sse_decode_String(deserializer);
+        return EventError_TitleInvalid(var_field0);
+      case 8:
+        var var_field0 = sse_decode_String(deserializer);
+        return EventError_NotFound(var_field0);
+      case 9:
+        var var_field0 = sse_decode_String(deserializer);
+        return EventError_DuplicateId(var_field0);
+      case 10:
+        var var_field0 = sse_decode_String(deserializer);
+        return EventError_Storage(var_field0);
+      case 11:
+        var var_file = sse_decode_String(deserializer);
+        var var_message = sse_decode_String(deserializer);
+        return EventError_ParseFailure(file: var_file, message: var_message);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  EventFieldConflict sse_decode_event_field_conflict(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    var var_eventId = sse_decode_String(deserializer);
+    var var_field = sse_decode_String(deserializer);
+    var var_baseValue = sse_decode_opt_String(deserializer);
+    var var_localValue = sse_decode_opt_String(deserializer);
+    var var_remoteValue = sse_decode_opt_String(deserializer);
+    return EventFieldConflict(
+      path: var_path,
+      eventId: var_eventId,
+      field: var_field,
+      baseValue: var_baseValue,
+      localValue: var_localValue,
+      remoteValue: var_remoteValue,
+    );
+  }
+
+  @protected
+  EventInput sse_decode_event_input(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_title = sse_decode_String(deserializer);
+    var var_description = sse_decode_String(deserializer);
+    var var_startDate = sse_decode_String(deserializer);
+    var var_endDate = sse_decode_opt_String(deserializer);
+    var var_startTime = sse_decode_opt_String(deserializer);
+    var var_endTime = sse_decode_opt_String(deserializer);
+    var var_isAllDay = sse_decode_bool(deserializer);
+    var var_recurrence = sse_decode_String(deserializer);
+    var var_rrule = sse_decode_opt_box_autoadd_recurrence_rule(deserializer);
+    var var_timeZone = sse_decode_opt_String(deserializer);
+    return EventInput(
+      title: var_title,
+      description: var_description,
+      startDate: var_startDate,
+      endDate: var_endDate,
+      startTime: var_startTime,
+      endTime: var_endTime,
+      isAllDay: var_isAllDay,
+      recurrence: var_recurrence,
+      rrule: var_rrule,
+      timeZone: var_timeZone,
+    );
+  }
+
+  @protected
+  EventMergeReport sse_decode_event_merge_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_mergedPaths = sse_decode_list_String(deserializer);
+    var var_unresolvedPaths = sse_decode_list_String(deserializer);
+    var var_conflicts = sse_decode_list_event_field_conflict(deserializer);
+    var var_committed = sse_decode_bool(deserializer);
+    return EventMergeReport(
+      mergedPaths: var_mergedPaths,
+      unresolvedPaths: var_unresolvedPaths,
+      conflicts: var_conflicts,
+      committed: var_committed,
+    );
+  }
+
+  @protected
+  EventsResult sse_decode_events_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_events = sse_decode_list_event_dto(deserializer);
+    var var_unreadable = sse_decode_list_event_error(deserializer);
+    return EventsResult(events: var_events, unreadable: var_unreadable);
+  }
+
+  @protected
+  Frequency sse_decode_frequency(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return Frequency.values[inner];
+  }
+
+  @protected
+  GitCredentials sse_decode_git_credentials(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_username = sse_decode_String(deserializer);
+        var var_password = sse_decode_String(deserializer);
+        return GitCredentials_UserPassword(
+          username: var_username,
+          password: var_password,
+        );
+      case 1:
+        var var_username = sse_decode_opt_String(deserializer);
+        var var_token = sse_decode_String(deserializer);
+        return GitCredentials_Token(username: var_username, token: var_token);
+      case 2:
+        var var_username = sse_decode_opt_String(deserializer);
+        var var_privateKeyPath = sse_decode_String(deserializer);
+        var var_publicKeyPath = sse_decode_opt_String(deserializer);
+        var var_passphrase = sse_decode_opt_String(deserializer);
+        return GitCredentials_SshKeyFile(
+          username: var_username,
+          privateKeyPath: var_privateKeyPath,
+          publicKeyPath: var_publicKeyPath,
+          passphrase: var_passphrase,
+        );
+      case 3:
+        var var_username = sse_decode_opt_String(deserializer);
+        var var_privateKey = sse_decode_String(deserializer);
+        var var_publicKey = sse_decode_opt_String(deserializer);
+        var var_passphrase = sse_decode_opt_String(deserializer);
+        return GitCredentials_SshKeyMemory(
+          username: var_username,
+          privateKey: var_privateKey,
+          publicKey: var_publicKey,
+          passphrase: var_passphrase,
+        );
+      case 4:
+        var var_username = sse_decode_opt_String(deserializer);
+        return GitCredentials_SshAgent(username: var_username);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  GitError sse_decode_git_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 = sse_decode_String(deserializer);
+        return GitError_Io(var_field0);
+      case 1:
+        var var_field0 = sse_decode_String(deserializer);
+        return GitError_Git(var_field0);
+      case 2:
+        var var_field0 = sse_decode_String(deserializer);
+        return GitError_AuthenticationFailed(var_field0);
+      case 3:
+        var var_field0 = sse_decode_String(deserializer);
+        return GitError_RepositoryNotFound(var_field0);
+      case 4:
+        var var_field0 = sse_decode_String(deserializer);
+        return GitError_NotFound(var_field0);
+      case 5:
+        var var_field0 = sse_decode_String(deserializer);
+        return GitError_NetworkUnreachable(var_field0);
+      case 6:
+        var var_field0 = sse_decode_String(deserializer);
+        return GitError_Locked(var_field0);
+      case 7:
+        var var_field0 = sse_decode_String(deserializer);
+        return GitError_DetachedHead(var_field0);
+      case 8:
+        var var_field0 = sse_decode_list_String(deserializer);
+        return GitError_MergeConflict(var_field0);
+      case 9:
+        var var_field0 = sse_decode_list_String(deserializer);
+        return GitError_StashConflict(var_field0);
+      case 10:
+        var var_field0 = sse_decode_String(deserializer);
+        return GitError_NonFastForward(var_field0);
+      case 11:
+        var var_host = sse_decode_String(deserializer);
+        var var_reason = sse_decode_box_autoadd_certificate_error(deserializer);
+        return GitError_CertificateInvalid(host: var_host, reason: var_reason);
+      case 12:
+        var var_field0 = sse_decode_box_autoadd_host_key(deserializer);
+        return GitError_UnknownHostKey(var_field0);
+      case 13:
+        var var_field0 = sse_decode_box_autoadd_host_key(deserializer);
+        return GitError_HostKeyMismatch(var_field0);
+      case 14:
+        return GitError_Cancelled();
+      case 15:
+        var var_field0 = sse_decode_String(deserializer);
+        return GitError_UnbornBranch(var_field0);
+      case 16:
+        var var_field0 = sse_decode_String(deserializer);
+        return GitError_Other(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  GitIdentity sse_decode_git_identity(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_email = sse_decode_String(deserializer);
+    return GitIdentity(name: var_name, email: var_email);
+  }
+
+  @protected
+  GitProgress sse_decode_git_progress(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_receivedObjects = sse_decode_usize(deserializer);
+        var var_totalObjects = sse_decode_usize(deserializer);
+        var var_indexedObjects = sse_decode_usize(deserializer);
+        var var_receivedBytes = sse_decode_usize(deserializer);
+        var var_totalDeltas = sse_decode_usize(deserializer);
+        var var_indexedDeltas = sse_decode_usize(deserializer);
+        return GitProgress_Transfer(
+          receivedObjects: var_receivedObjects,
+          totalObjects: var_totalObjects,
+          indexedObjects: var_indexedObjects,
+          receivedBytes: var_receivedBytes,
+          totalDeltas: var_totalDeltas,
+          indexedDeltas: var_indexedDeltas,
+        );
+      case 1:
+        var var_path = sse_decode_opt_String(deserializer);
+        var var_completed = sse_decode_usize(deserializer);
+        var var_total = sse_decode_usize(deserializer);
+        return GitProgress_Checkout(
+          path: var_path,
+          completed: var_completed,
+          total: var_total,
+        );
+      case 2:
+        var var_current = sse_decode_usize(deserializer);
+        var var_total = sse_decode_usize(deserializer);
+        var var_bytes = sse_decode_usize(deserializer);
+        return GitProgress_PushTransfer(
+          current: var_current,
+          total: var_total,
+          bytes: var_bytes,
+        );
+      case 3:
+        var var_field0 = sse_decode_String(deserializer);
+        return GitProgress_Sideband(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  HostKey sse_decode_host_key(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_host = sse_decode_String(deserializer);
+    var var_keyType = sse_decode_String(deserializer);
+    var var_key = sse_decode_String(deserializer);
+    var var_fingerprint = sse_decode_String(deserializer);
+    return HostKey(
+      host: var_host,
+      keyType: var_keyType,
+      key: var_key,
+      fingerprint: var_fingerprint,
+    );
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  IcsImportIssue sse_decode_ics_import_issue(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_uid = sse_decode_opt_String(deserializer);
+    var var_summary = sse_decode_String(deserializer);
+    var var_reason = sse_decode_String(deserializer);
+    return IcsImportIssue(
+      uid: var_uid,
+      summary: var_summary,
+      reason: var_reason,
+    );
+  }
+
+  @protected
+  IcsImportOptions sse_decode_ics_import_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_replaceExisting = sse_decode_bool(deserializer);
+    return IcsImportOptions(replaceExisting: var_replaceExisting);
+  }
+
+  @protected
+  IcsImportReport sse_decode_ics_import_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_imported = sse_decode_list_String(deserializer);
+    var var_skipped = sse_decode_list_ics_import_issue(deserializer);
+    var var_unsupported = sse_decode_list_ics_import_issue(deserializer);
+    return IcsImportReport(
+      imported: var_imported,
+      skipped: var_skipped,
+      unsupported: var_unsupported,
+    );
+  }
+
+  @protected
+  InstancesResult sse_decode_instances_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_instances = sse_decode_list_event_dto(deserializer);
+    var var_diagnostics = sse_decode_list_event_diagnostic(deserializer);
+    return InstancesResult(
+      instances: var_instances,
+      diagnostics: var_diagnostics,
+    );
+  }
+
+  @protected
+  PlatformInt64 sse_decode_isize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getPlatformInt64();
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<EventDiagnostic> sse_decode_list_event_diagnostic(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <EventDiagnostic>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_event_diagnostic(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<EventDto> sse_decode_list_event_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <EventDto>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_event_dto(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<EventError> sse_decode_list_event_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <EventError>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_event_error(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<EventFieldConflict> sse_decode_list_event_field_conflict(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <EventFieldConflict>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_event_field_conflict(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<HostKey> sse_decode_list_host_key(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <HostKey>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_host_key(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<IcsImportIssue> sse_decode_list_ics_import_issue(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <IcsImportIssue>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_ics_import_issue(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<OccurrenceOverride> sse_decode_list_occurrence_override(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <OccurrenceOverride>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_occurrence_override(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PathResolution> sse_decode_list_path_resolution(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PathResolution>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_path_resolution(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Int32List sse_decode_list_prim_i_32_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getInt32List(len_);
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<StatusEntry> sse_decode_list_status_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <StatusEntry>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_status_entry(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TrustedCertificate> sse_decode_list_trusted_certificate(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TrustedCertificate>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_trusted_certificate(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<WeekdayRule> sse_decode_list_weekday_rule(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <WeekdayRule>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_weekday_rule(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  MergeSide sse_decode_merge_side(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return MergeSide.values[inner];
+  }
+
+  @protected
+  OccurrenceOverride sse_decode_occurrence_override(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_occurrenceDate = sse_decode_String(deserializer);
+    var var_title = sse_decode_opt_String(deserializer);
+    var var_description = sse_decode_opt_String(deserializer);
+    var var_startDate = sse_decode_opt_String(deserializer);
+    var var_endDate = sse_decode_opt_String(deserializer);
+    var var_startTime = sse_decode_opt_String(deserializer);
+    var var_endTime = sse_decode_opt_String(deserializer);
+    var var_isAllDay = sse_decode_opt_box_autoadd_bool(deserializer);
+    return OccurrenceOverride(
+      occurrenceDate: var_occurrenceDate,
+      title: var_title,
+      description: var_description,
+      startDate: var_startDate,
+      endDate: var_endDate,
+      startTime: var_startTime,
+      endTime: var_endTime,
+      isAllDay: var_isAllDay,
+    );
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_bool(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  GitCredentials? sse_decode_opt_box_autoadd_git_credentials(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_git_credentials(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  GitIdentity? sse_decode_opt_box_autoadd_git_identity(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_git_identity(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_i_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  RecurrenceRule? sse_decode_opt_box_autoadd_recurrence_rule(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_recurrence_rule(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PathResolution sse_decode_path_resolution(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    var var_side = sse_decode_merge_side(deserializer);
+    return PathResolution(path: var_path, side: var_side);
+  }
+
+  @protected
+  RecurrenceRule sse_decode_recurrence_rule(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_frequency = sse_decode_frequency(deserializer);
+    var var_interval = sse_decode_u_32(deserializer);
+    var var_byDay = sse_decode_list_weekday_rule(deserializer);
+    var var_byMonthDay = sse_decode_list_prim_i_32_strict(deserializer);
+    var var_bySetPos = sse_decode_list_prim_i_32_strict(deserializer);
+    var var_count = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_until = sse_decode_opt_String(deserializer);
+    return RecurrenceRule(
+      frequency: var_frequency,
+      interval: var_interval,
+      byDay: var_byDay,
+      byMonthDay: var_byMonthDay,
+      bySetPos: var_bySetPos,
+      count: var_count,
+      until: var_until,
+    );
+  }
+
+  @protected
+  StatusEntry sse_decode_status_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    var var_status = sse_decode_String(deserializer);
+    return StatusEntry(path: var_path, status: var_status);
+  }
+
+  @protected
+  SyncOutcome sse_decode_sync_outcome(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SyncOutcome.values[inner];
+  }
+
+  @protected
+  SyncReport sse_decode_sync_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_localCommit = sse_decode_opt_String(deserializer);
+    var var_pull = sse_decode_sync_result(deserializer);
+    var var_push = sse_decode_sync_result(deserializer);
+    var var_pushAttempts = sse_decode_u_32(deserializer);
+    return SyncReport(
+      localCommit: var_localCommit,
+      pull: var_pull,
+      push: var_push,
+      pushAttempts: var_pushAttempts,
+    );
+  }
+
+  @protected
+  SyncResult sse_decode_sync_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_outcome = sse_decode_sync_outcome(deserializer);
+    var var_oldCommit = sse_decode_opt_String(deserializer);
+    var var_newCommit = sse_decode_opt_String(deserializer);
+    var var_addedPaths = sse_decode_list_String(deserializer);
+    var var_modifiedPaths = sse_decode_list_String(deserializer);
+    var var_deletedPaths = sse_decode_list_String(deserializer);
+    var var_affectedEventIds = sse_decode_list_String(deserializer);
+    return SyncResult(
+      outcome: var_outcome,
+      oldCommit: var_oldCommit,
+      newCommit: var_newCommit,
+      addedPaths: var_addedPaths,
+      modifiedPaths: var_modifiedPaths,
+      deletedPaths: var_deletedPaths,
+      affectedEventIds: var_affectedEventIds,
+    );
+  }
+
+  @protected
+  TrustKind sse_decode_trust_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return TrustKind.values[inner];
+  }
+
+  @protected
+  TrustedCertificate sse_decode_trusted_certificate(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_host = sse_decode_String(deserializer);
+    var var_kind = sse_decode_trust_kind(deserializer);
+    var var_fingerprint = sse_decode_String(deserializer);
+    var var_subject = sse_decode_String(deserializer);
+    var var_notAfter = sse_decode_String(deserializer);
+    return TrustedCertificate(
+      host: var_host,
+      kind: var_kind,
+      fingerprint: var_fingerprint,
+      subject: var_subject,
+      notAfter: var_notAfter,
+    );
+  }
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
+  }
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
+  }
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+  }
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  Weekday sse_decode_weekday(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return Weekday.values[inner];
+  }
+
+  @protected
+  WeekdayRule sse_decode_weekday_rule(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_weekday = sse_decode_weekday(deserializer);
+    var var_ordinal = sse_decode_opt_box_autoadd_i_32(deserializer);
+    return WeekdayRule(weekday: var_weekday, ordinal: var_ordinal);
+  }
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
+  }
+
+  @protected
+  void
+  sse_encode_DartFn_Inputs_credential_request_Output_opt_box_autoadd_git_credentials_AnyhowException(
+    FutureOr<GitCredentials?> Function(CredentialRequest) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_DartOpaque(
+      encode_DartFn_Inputs_credential_request_Output_opt_box_autoadd_git_credentials_AnyhowException(
+        self,
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_DartOpaque(Object self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_isize(
+      PlatformPointerUtil.ptrToPlatformInt64(
+        encodeDartOpaque(
+          self,
+          portManager.dartHandlerPort,
+          generalizedFrbRustBinding,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_calendar_change_Sse(
+    RustStreamSink<CalendarChange> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_calendar_change,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_git_progress_Sse(
+    RustStreamSink<GitProgress> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_git_progress,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+  }
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_certificate_error(
+    CertificateError self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_certificate_error(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_event_dto(
+    EventDto self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_event_dto(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_event_input(
+    EventInput self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_event_input(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_git_credentials(
+    GitCredentials self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_git_credentials(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_git_identity(
+    GitIdentity self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_git_identity(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_host_key(HostKey self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_host_key(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_ics_import_options(
+    IcsImportOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_ics_import_options(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_occurrence_override(
+    OccurrenceOverride self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_occurrence_override(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_recurrence_rule(
+    RecurrenceRule self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_recurrence_rule(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_calendar_change(
+    CalendarChange self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case CalendarChange_EventAdded(field0: final field0):
+        sse_encode_i_32(0, serializer);
+        sse_encode_box_autoadd_event_dto(field0, serializer);
+      case CalendarChange_EventChanged(field0: final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_box_autoadd_event_dto(field0, serializer);
+      case CalendarChange_EventRemoved(field0: final field0):
+        sse_encode_i_32(2, serializer);
+        sse_encode_box_autoadd_event_dto(field0, serializer);
+    }
   }
 
   @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
+  void sse_encode_certificate_error(
+    CertificateError self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case CertificateError_Expired():
+        sse_encode_i_32(0, serializer);
+      case CertificateError_NotYetValid():
+        sse_encode_i_32(1, serializer);
+      case CertificateError_UnknownIssuer():
+        sse_encode_i_32(2, serializer);
+      case CertificateError_NameMismatch():
+        sse_encode_i_32(3, serializer);
+      case CertificateError_PinMismatch():
+        sse_encode_i_32(4, serializer);
+      case CertificateError_InvalidSignature():
+        sse_encode_i_32(5, serializer);
+      case CertificateError_Malformed(field0: final field0):
+        sse_encode_i_32(6, serializer);
+        sse_encode_String(field0, serializer);
+      case CertificateError_Other(field0: final field0):
+        sse_encode_i_32(7, serializer);
+        sse_encode_String(field0, serializer);
+    }
   }
 
   @protected
-  StatusEntry dco_decode_status_entry(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return StatusEntry(
-      path: dco_decode_String(arr[0]),
-      status: dco_decode_String(arr[1]),
-    );
+  void sse_encode_credential_request(
+    CredentialRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.url, serializer);
+    sse_encode_opt_String(self.usernameFromUrl, serializer);
+    sse_encode_bool(self.allowPassword, serializer);
+    sse_encode_bool(self.allowSshKey, serializer);
+    sse_encode_u_32(self.attempt, serializer);
+  }
+
+  @protected
+  void sse_encode_event_diagnostic(
+    EventDiagnostic self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_event_error(self.reason, serializer);
+  }
+
+  @protected
+  void sse_encode_event_dto(EventDto self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_String(self.description, serializer);
+    sse_encode_String(self.startDate, serializer);
+    sse_encode_opt_String(self.endDate, serializer);
+    sse_encode_opt_String(self.startTime, serializer);
+    sse_encode_opt_String(self.endTime, serializer);
+    sse_encode_bool(self.isAllDay, serializer);
+    sse_encode_opt_String(self.timeZone, serializer);
+    sse_encode_String(self.recurrence, serializer);
+    sse_encode_opt_box_autoadd_recurrence_rule(self.rrule, serializer);
+    sse_encode_list_String(self.excludedDates, serializer);
+    sse_encode_list_occurrence_override(self.overrides, serializer);
+    sse_encode_opt_String(self.occurrenceDate, serializer);
+    sse_encode_bool(self.isRecurringInstance, serializer);
+  }
+
+  @protected
+  void sse_encode_event_error(EventError self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case EventError_InvalidDate(field: final field, value: final value):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(field, serializer);
+        sse_encode_String(value, serializer);
+      case EventError_InvalidTime(field: final field, value: final value):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(field, serializer);
+        sse_encode_String(value, serializer);
+      case EventError_MissingField(field0: final field0):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(field0, serializer);
+      case EventError_InvalidRange(field: final field):
+        sse_encode_i_32(3, serializer);
+        sse_encode_String(field, serializer);
+      case EventError_InvalidRecurrence(
+        value: final value,
+        reason: final reason,
+      ):
+        sse_encode_i_32(4, serializer);
+        sse_encode_String(value, serializer);
+        sse_encode_String(reason, serializer);
+      case EventError_NoOccurrence(id: final id, date: final date):
+        sse_encode_i_32(5, serializer);
+        sse_encode_String(id, serializer);
+        sse_encode_String(date, serializer);
+      case EventError_UnknownTimeZone(field0: final field0):
+        sse_encode_i_32(6, serializer);
+        sse_encode_String(field0, serializer);
+      case EventError_TitleInvalid(field0: final field0):
+        sse_encode_i_32(7, serializer);
+        sse_encode_String(field0, serializer);
+      case EventError_NotFound(field0: final field0):
+        sse_encode_i_32(8, serializer);
+        sse_encode_String(field0, serializer);
+      case EventError_DuplicateId(field0: final field0):
+        sse_encode_i_32(9, serializer);
+        sse_encode_String(field0, serializer);
+      case EventError_Storage(field0: final field0):
+        sse_encode_i_32(10, serializer);
+        sse_encode_String(field0, serializer);
+      case EventError_ParseFailure(file: final file, message: final message):
+        sse_encode_i_32(11, serializer);
+        sse_encode_String(file, serializer);
+        sse_encode_String(message, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_event_field_conflict(
+    EventFieldConflict self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.path, serializer);
+    sse_encode_String(self.eventId, serializer);
+    sse_encode_String(self.field, serializer);
+    sse_encode_opt_String(self.baseValue, serializer);
+    sse_encode_opt_String(self.localValue, serializer);
+    sse_encode_opt_String(self.remoteValue, serializer);
+  }
+
+  @protected
+  void sse_encode_event_input(EventInput self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.title, serializer);
+    sse_encode_String(self.description, serializer);
+    sse_encode_String(self.startDate, serializer);
+    sse_encode_opt_String(self.endDate, serializer);
+    sse_encode_opt_String(self.startTime, serializer);
+    sse_encode_opt_String(self.endTime, serializer);
+    sse_encode_bool(self.isAllDay, serializer);
+    sse_encode_String(self.recurrence, serializer);
+    sse_encode_opt_box_autoadd_recurrence_rule(self.rrule, serializer);
+    sse_encode_opt_String(self.timeZone, serializer);
+  }
+
+  @protected
+  void sse_encode_event_merge_report(
+    EventMergeReport self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_String(self.mergedPaths, serializer);
+    sse_encode_list_String(self.unresolvedPaths, serializer);
+    sse_encode_list_event_field_conflict(self.conflicts, serializer);
+    sse_encode_bool(self.committed, serializer);
+  }
+
+  @protected
+  void sse_encode_events_result(EventsResult self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_event_dto(self.events, serializer);
+    sse_encode_list_event_error(self.unreadable, serializer);
+  }
+
+  @protected
+  void sse_encode_frequency(Frequency self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_git_credentials(
+    GitCredentials self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case GitCredentials_UserPassword(
+        username: final username,
+        password: final password,
+      ):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(username, serializer);
+        sse_encode_String(password, serializer);
+      case GitCredentials_Token(username: final username, token: final token):
+        sse_encode_i_32(1, serializer);
+        sse_encode_opt_String(username, serializer);
+        sse_encode_String(token, serializer);
+      case GitCredentials_SshKeyFile(
+        username: final username,
+        privateKeyPath: final privateKeyPath,
+        publicKeyPath: final publicKeyPath,
+        passphrase: final passphrase,
+      ):
+        sse_encode_i_32(2, serializer);
+        sse_encode_opt_String(username, serializer);
+        sse_encode_String(privateKeyPath, serializer);
+        sse_encode_opt_String(publicKeyPath, serializer);
+        sse_encode_opt_String(passphrase, serializer);
+      case GitCredentials_SshKeyMemory(
+        username: final username,
+        privateKey: final privateKey,
+        publicKey: final publicKey,
+        passphrase: final passphrase,
+      ):
+        sse_encode_i_32(3, serializer);
+        sse_encode_opt_String(username, serializer);
+        sse_encode_String(privateKey, serializer);
+        sse_encode_opt_String(publicKey, serializer);
+        sse_encode_opt_String(passphrase, serializer);
+      case GitCredentials_SshAgent(username: final username):
+        sse_encode_i_32(4, serializer);
+        sse_encode_opt_String(username, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_git_error(GitError self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case GitError_Io(field0: final field0):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(field0, serializer);
+      case GitError_Git(field0: final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(field0, serializer);
+      case GitError_AuthenticationFailed(field0: final field0):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(field0, serializer);
+      case GitError_RepositoryNotFound(field0: final field0):
+        sse_encode_i_32(3, serializer);
+        sse_encode_String(field0, serializer);
+      case GitError_NotFound(field0: final field0):
+        sse_encode_i_32(4, serializer);
+        sse_encode_String(field0, serializer);
+      case GitError_NetworkUnreachable(field0: final field0):
+        sse_encode_i_32(5, serializer);
+        sse_encode_String(field0, serializer);
+      case GitError_Locked(field0: final field0):
+        sse_encode_i_32(6, serializer);
+        sse_encode_String(field0, serializer);
+      case GitError_DetachedHead(field0: final field0):
+        sse_encode_i_32(7, serializer);
+        sse_encode_String(field0, serializer);
+      case GitError_MergeConflict(field0: final field0):
+        sse_encode_i_32(8, serializer);
+        sse_encode_list_String(field0, serializer);
+      case GitError_StashConflict(field0: final field0):
+        sse_encode_i_32(9, serializer);
+        sse_encode_list_String(field0, serializer);
+      case GitError_NonFastForward(field0: final field0):
+        sse_encode_i_32(10, serializer);
+        sse_encode_String(field0, serializer);
+      case GitError_CertificateInvalid(host: final host, reason: final reason):
+        sse_encode_i_32(11, serializer);
+        sse_encode_String(host, serializer);
+        sse_encode_box_autoadd_certificate_error(reason, serializer);
+      case GitError_UnknownHostKey(field0: final field0):
+        sse_encode_i_32(12, serializer);
+        sse_encode_box_autoadd_host_key(field0, serializer);
+      case GitError_HostKeyMismatch(field0: final field0):
+        sse_encode_i_32(13, serializer);
+        sse_encode_box_autoadd_host_key(field0, serializer);
+      case GitError_Cancelled():
+        sse_encode_i_32(14, serializer);
+      case GitError_UnbornBranch(field0: final field0):
+        sse_encode_i_32(15, serializer);
+        sse_encode_String(field0, serializer);
+      case GitError_Other(field0: final field0):
+        sse_encode_i_32(16, serializer);
+        sse_encode_String(field0, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_git_identity(GitIdentity self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.email, serializer);
+  }
+
+  @protected
+  void sse_encode_git_progress(GitProgress self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case GitProgress_Transfer(
+        receivedObjects: final receivedObjects,
+        totalObjects: final totalObjects,
+        indexedObjects: final indexedObjects,
+        receivedBytes: final receivedBytes,
+        totalDeltas: final totalDeltas,
+        indexedDeltas: final indexedDeltas,
+      ):
+        sse_encode_i_32(0, serializer);
+        sse_encode_usize(receivedObjects, serializer);
+        sse_encode_usize(totalObjects, serializer);
+        sse_encode_usize(indexedObjects, serializer);
+        sse_encode_usize(receivedBytes, serializer);
+        sse_encode_usize(totalDeltas, serializer);
+        sse_encode_usize(indexedDeltas, serializer);
+      case GitProgress_Checkout(
+        path: final path,
+        completed: final completed,
+        total: final total,
+      ):
+        sse_encode_i_32(1, serializer);
+        sse_encode_opt_String(path, serializer);
+        sse_encode_usize(completed, serializer);
+        sse_encode_usize(total, serializer);
+      case GitProgress_PushTransfer(
+        current: final current,
+        total: final total,
+        bytes: final bytes,
+      ):
+        sse_encode_i_32(2, serializer);
+        sse_encode_usize(current, serializer);
+        sse_encode_usize(total, serializer);
+        sse_encode_usize(bytes, serializer);
+      case GitProgress_Sideband(field0: final field0):
+        sse_encode_i_32(3, serializer);
+        sse_encode_String(field0, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_host_key(HostKey self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.host, serializer);
+    sse_encode_String(self.keyType, serializer);
+    sse_encode_String(self.key, serializer);
+    sse_encode_String(self.fingerprint, serializer);
+  }
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putInt32(self);
+  }
+
+  @protected
+  void sse_encode_ics_import_issue(
+    IcsImportIssue self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.uid, serializer);
+    sse_encode_String(self.summary, serializer);
+    sse_encode_String(self.reason, serializer);
+  }
+
+  @protected
+  void sse_encode_ics_import_options(
+    IcsImportOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.replaceExisting, serializer);
+  }
+
+  @protected
+  void sse_encode_ics_import_report(
+    IcsImportReport self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_String(self.imported, serializer);
+    sse_encode_list_ics_import_issue(self.skipped, serializer);
+    sse_encode_list_ics_import_issue(self.unsupported, serializer);
+  }
+
+  @protected
+  void sse_encode_instances_result(
+    InstancesResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_event_dto(self.instances, serializer);
+    sse_encode_list_event_diagnostic(self.diagnostics, serializer);
+  }
+
+  @protected
+  void sse_encode_isize(PlatformInt64 self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putPlatformInt64(self);
+  }
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_event_diagnostic(
+    List<EventDiagnostic> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_event_diagnostic(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_event_dto(
+    List<EventDto> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_event_dto(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_event_error(
+    List<EventError> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_event_error(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_event_field_conflict(
+    List<EventFieldConflict> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_event_field_conflict(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_host_key(List<HostKey> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_host_key(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_ics_import_issue(
+    List<IcsImportIssue> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_ics_import_issue(item, serializer);
+    }
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_list_occurrence_override(
+    List<OccurrenceOverride> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_occurrence_override(item, serializer);
+    }
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  void sse_encode_list_path_resolution(
+    List<PathResolution> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_path_resolution(item, serializer);
+    }
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void sse_encode_list_prim_i_32_strict(
+    Int32List self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putInt32List(self);
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
   }
 
   @protected
-  EventDto sse_decode_box_autoadd_event_dto(SseDeserializer deserializer) {
+  void sse_encode_list_status_entry(
+    List<StatusEntry> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_event_dto(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_status_entry(item, serializer);
+    }
   }
 
   @protected
-  EventDto sse_decode_event_dto(SseDeserializer deserializer) {
+  void sse_encode_list_trusted_certificate(
+    List<TrustedCertificate> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_String(deserializer);
-    var var_title = sse_decode_String(deserializer);
-    var var_description = sse_decode_String(deserializer);
-    var var_startDate = sse_decode_String(deserializer);
-    var var_endDate = sse_decode_opt_String(deserializer);
-    var var_startTime = sse_decode_opt_String(deserializer);
-    var var_endTime = sse_decode_opt_String(deserializer);
-    var var_isAllDay = sse_decode_bool(deserializer);
-    var var_recurrence = sse_decode_String(deserializer);
-    var var_isRecurringInstance = sse_decode_bool(deserializer);
-    return EventDto(
-      id: var_id,
-      title: var_title,
-      description: var_description,
-      startDate: var_startDate,
-      endDate: var_endDate,
-      startTime: var_startTime,
-      endTime: var_endTime,
-      isAllDay: var_isAllDay,
-      recurrence: var_recurrence,
-      isRecurringInstance: var_isRecurringInstance,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_trusted_certificate(item, serializer);
+    }
   }
 
   @protected
-  GitError sse_decode_git_error(SseDeserializer deserializer) {
+  void sse_encode_list_weekday_rule(
+    List<WeekdayRule> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        var var_field0 = sse_decode_String(deserializer);
-        return GitError_Io(var_field0);
-      case 1:
-        var var_field0 = sse_decode_String(deserializer);
-        return GitError_Git(var_field0);
-      case 2:
-        var var_field0 = sse_decode_String(deserializer);
-        return GitError_Auth(var_field0);
-      case 3:
-        var var_field0 = sse_decode_String(deserializer);
-        return GitError_Other(var_field0);
-      default:
-        throw UnimplementedError('');
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_weekday_rule(item, serializer);
     }
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_merge_side(MergeSide self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+  void sse_encode_occurrence_override(
+    OccurrenceOverride self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
-    }
-    return ans_;
+    sse_encode_String(self.occurrenceDate, serializer);
+    sse_encode_opt_String(self.title, serializer);
+    sse_encode_opt_String(self.description, serializer);
+    sse_encode_opt_String(self.startDate, serializer);
+    sse_encode_opt_String(self.endDate, serializer);
+    sse_encode_opt_String(self.startTime, serializer);
+    sse_encode_opt_String(self.endTime, serializer);
+    sse_encode_opt_box_autoadd_bool(self.isAllDay, serializer);
   }
 
   @protected
-  List<EventDto> sse_decode_list_event_dto(SseDeserializer deserializer) {
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <EventDto>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_event_dto(deserializer));
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
     }
-    return ans_;
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_bool(self, serializer);
+    }
   }
 
   @protected
-  List<StatusEntry> sse_decode_list_status_entry(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_git_credentials(
+    GitCredentials? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <StatusEntry>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_status_entry(deserializer));
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_git_credentials(self, serializer);
     }
-    return ans_;
   }
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_git_identity(
+    GitIdentity? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_git_identity(self, serializer);
     }
   }
 
   @protected
-  StatusEntry sse_decode_status_entry(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_path = sse_decode_String(deserializer);
-    var var_status = sse_decode_String(deserializer);
-    return StatusEntry(path: var_path, status: var_status);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_i_32(self, serializer);
+    }
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_recurrence_rule(
+    RecurrenceRule? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_recurrence_rule(self, serializer);
+    }
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_64(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_path_resolution(
+    PathResolution self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    sse_encode_String(self.path, serializer);
+    sse_encode_merge_side(self.side, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_event_dto(
-    EventDto self,
+  void sse_encode_recurrence_rule(
+    RecurrenceRule self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_event_dto(self, serializer);
+    sse_encode_frequency(self.frequency, serializer);
+    sse_encode_u_32(self.interval, serializer);
+    sse_encode_list_weekday_rule(self.byDay, serializer);
+    sse_encode_list_prim_i_32_strict(self.byMonthDay, serializer);
+    sse_encode_list_prim_i_32_strict(self.bySetPos, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.count, serializer);
+    sse_encode_opt_String(self.until, serializer);
   }
 
   @protected
-  void sse_encode_event_dto(EventDto self, SseSerializer serializer) {
+  void sse_encode_status_entry(StatusEntry self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.id, serializer);
-    sse_encode_String(self.title, serializer);
-    sse_encode_String(self.description, serializer);
-    sse_encode_String(self.startDate, serializer);
-    sse_encode_opt_String(self.endDate, serializer);
-    sse_encode_opt_String(self.startTime, serializer);
-    sse_encode_opt_String(self.endTime, serializer);
-    sse_encode_bool(self.isAllDay, serializer);
-    sse_encode_String(self.recurrence, serializer);
-    sse_encode_bool(self.isRecurringInstance, serializer);
+    sse_encode_String(self.path, serializer);
+    sse_encode_String(self.status, serializer);
   }
 
   @protected
-  void sse_encode_git_error(GitError self, SseSerializer serializer) {
+  void sse_encode_sync_outcome(SyncOutcome self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    switch (self) {
-      case GitError_Io(field0: final field0):
-        sse_encode_i_32(0, serializer);
-        sse_encode_String(field0, serializer);
-      case GitError_Git(field0: final field0):
-        sse_encode_i_32(1, serializer);
-        sse_encode_String(field0, serializer);
-      case GitError_Auth(field0: final field0):
-        sse_encode_i_32(2, serializer);
-        sse_encode_String(field0, serializer);
-      case GitError_Other(field0: final field0):
-        sse_encode_i_32(3, serializer);
-        sse_encode_String(field0, serializer);
-    }
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
+  void sse_encode_sync_report(SyncReport self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
+    sse_encode_opt_String(self.localCommit, serializer);
+    sse_encode_sync_result(self.pull, serializer);
+    sse_encode_sync_result(self.push, serializer);
+    sse_encode_u_32(self.pushAttempts, serializer);
   }
 
   @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+  void sse_encode_sync_result(SyncResult self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_String(item, serializer);
-    }
+    sse_encode_sync_outcome(self.outcome, serializer);
+    sse_encode_opt_String(self.oldCommit, serializer);
+    sse_encode_opt_String(self.newCommit, serializer);
+    sse_encode_list_String(self.addedPaths, serializer);
+    sse_encode_list_String(self.modifiedPaths, serializer);
+    sse_encode_list_String(self.deletedPaths, serializer);
+    sse_encode_list_String(self.affectedEventIds, serializer);
   }
 
   @protected
-  void sse_encode_list_event_dto(
-    List<EventDto> self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_trust_kind(TrustKind self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_event_dto(item, serializer);
-    }
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
+  void sse_encode_trusted_certificate(
+    TrustedCertificate self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+    sse_encode_String(self.host, serializer);
+    sse_encode_trust_kind(self.kind, serializer);
+    sse_encode_String(self.fingerprint, serializer);
+    sse_encode_String(self.subject, serializer);
+    sse_encode_String(self.notAfter, serializer);
   }
 
   @protected
-  void sse_encode_list_status_entry(
-    List<StatusEntry> self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_status_entry(item, serializer);
-    }
+    serializer.buffer.putUint32(self);
   }
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putBigUint64(self);
+  }
 
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
-    }
+  @protected
+  void sse_encode_u_8(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self);
   }
 
   @protected
-  void sse_encode_status_entry(StatusEntry self, SseSerializer serializer) {
+  void sse_encode_unit(void self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.path, serializer);
-    sse_encode_String(self.status, serializer);
   }
 
   @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
+  void sse_encode_usize(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
+    serializer.buffer.putBigUint64(self);
   }
 
   @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
+  void sse_encode_weekday(Weekday self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_weekday_rule(WeekdayRule self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_weekday(self.weekday, serializer);
+    sse_encode_opt_box_autoadd_i_32(self.ordinal, serializer);
   }
 }
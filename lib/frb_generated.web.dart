@@ -20,117 +20,781 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     required super.portManager,
   });
 
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw);
+
+  @protected
+  FutureOr<GitCredentials?> Function(CredentialRequest)
+  dco_decode_DartFn_Inputs_credential_request_Output_opt_box_autoadd_git_credentials_AnyhowException(
+    dynamic raw,
+  );
+
+  @protected
+  Object dco_decode_DartOpaque(dynamic raw);
+
+  @protected
+  RustStreamSink<CalendarChange> dco_decode_StreamSink_calendar_change_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<GitProgress> dco_decode_StreamSink_git_progress_Sse(
+    dynamic raw,
+  );
+
   @protected
   String dco_decode_String(dynamic raw);
 
   @protected
   bool dco_decode_bool(dynamic raw);
 
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw);
+
+  @protected
+  CertificateError dco_decode_box_autoadd_certificate_error(dynamic raw);
+
   @protected
   EventDto dco_decode_box_autoadd_event_dto(dynamic raw);
 
+  @protected
+  EventInput dco_decode_box_autoadd_event_input(dynamic raw);
+
+  @protected
+  GitCredentials dco_decode_box_autoadd_git_credentials(dynamic raw);
+
+  @protected
+  GitIdentity dco_decode_box_autoadd_git_identity(dynamic raw);
+
+  @protected
+  HostKey dco_decode_box_autoadd_host_key(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_i_32(dynamic raw);
+
+  @protected
+  IcsImportOptions dco_decode_box_autoadd_ics_import_options(dynamic raw);
+
+  @protected
+  OccurrenceOverride dco_decode_box_autoadd_occurrence_override(dynamic raw);
+
+  @protected
+  RecurrenceRule dco_decode_box_autoadd_recurrence_rule(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  CalendarChange dco_decode_calendar_change(dynamic raw);
+
+  @protected
+  CertificateError dco_decode_certificate_error(dynamic raw);
+
+  @protected
+  CredentialRequest dco_decode_credential_request(dynamic raw);
+
+  @protected
+  EventDiagnostic dco_decode_event_diagnostic(dynamic raw);
+
   @protected
   EventDto dco_decode_event_dto(dynamic raw);
 
+  @protected
+  EventError dco_decode_event_error(dynamic raw);
+
+  @protected
+  EventFieldConflict dco_decode_event_field_conflict(dynamic raw);
+
+  @protected
+  EventInput dco_decode_event_input(dynamic raw);
+
+  @protected
+  EventMergeReport dco_decode_event_merge_report(dynamic raw);
+
+  @protected
+  EventsResult dco_decode_events_result(dynamic raw);
+
+  @protected
+  Frequency dco_decode_frequency(dynamic raw);
+
+  @protected
+  GitCredentials dco_decode_git_credentials(dynamic raw);
+
   @protected
   GitError dco_decode_git_error(dynamic raw);
 
+  @protected
+  GitIdentity dco_decode_git_identity(dynamic raw);
+
+  @protected
+  GitProgress dco_decode_git_progress(dynamic raw);
+
+  @protected
+  HostKey dco_decode_host_key(dynamic raw);
+
   @protected
   int dco_decode_i_32(dynamic raw);
 
+  @protected
+  IcsImportIssue dco_decode_ics_import_issue(dynamic raw);
+
+  @protected
+  IcsImportOptions dco_decode_ics_import_options(dynamic raw);
+
+  @protected
+  IcsImportReport dco_decode_ics_import_report(dynamic raw);
+
+  @protected
+  InstancesResult dco_decode_instances_result(dynamic raw);
+
+  @protected
+  PlatformInt64 dco_decode_isize(dynamic raw);
+
   @protected
   List<String> dco_decode_list_String(dynamic raw);
 
+  @protected
+  List<EventDiagnostic> dco_decode_list_event_diagnostic(dynamic raw);
+
   @protected
   List<EventDto> dco_decode_list_event_dto(dynamic raw);
 
+  @protected
+  List<EventError> dco_decode_list_event_error(dynamic raw);
+
+  @protected
+  List<EventFieldConflict> dco_decode_list_event_field_conflict(dynamic raw);
+
+  @protected
+  List<HostKey> dco_decode_list_host_key(dynamic raw);
+
+  @protected
+  List<IcsImportIssue> dco_decode_list_ics_import_issue(dynamic raw);
+
+  @protected
+  List<OccurrenceOverride> dco_decode_list_occurrence_override(dynamic raw);
+
+  @protected
+  List<PathResolution> dco_decode_list_path_resolution(dynamic raw);
+
+  @protected
+  Int32List dco_decode_list_prim_i_32_strict(dynamic raw);
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
   @protected
   List<StatusEntry> dco_decode_list_status_entry(dynamic raw);
 
+  @protected
+  List<TrustedCertificate> dco_decode_list_trusted_certificate(dynamic raw);
+
+  @protected
+  List<WeekdayRule> dco_decode_list_weekday_rule(dynamic raw);
+
+  @protected
+  MergeSide dco_decode_merge_side(dynamic raw);
+
+  @protected
+  OccurrenceOverride dco_decode_occurrence_override(dynamic raw);
+
   @protected
   String? dco_decode_opt_String(dynamic raw);
 
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
+
+  @protected
+  GitCredentials? dco_decode_opt_box_autoadd_git_credentials(dynamic raw);
+
+  @protected
+  GitIdentity? dco_decode_opt_box_autoadd_git_identity(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_i_32(dynamic raw);
+
+  @protected
+  RecurrenceRule? dco_decode_opt_box_autoadd_recurrence_rule(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  PathResolution dco_decode_path_resolution(dynamic raw);
+
+  @protected
+  RecurrenceRule dco_decode_recurrence_rule(dynamic raw);
+
   @protected
   StatusEntry dco_decode_status_entry(dynamic raw);
 
+  @protected
+  SyncOutcome dco_decode_sync_outcome(dynamic raw);
+
+  @protected
+  SyncReport dco_decode_sync_report(dynamic raw);
+
+  @protected
+  SyncResult dco_decode_sync_result(dynamic raw);
+
+  @protected
+  TrustKind dco_decode_trust_kind(dynamic raw);
+
+  @protected
+  TrustedCertificate dco_decode_trusted_certificate(dynamic raw);
+
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw);
+
   @protected
   int dco_decode_u_8(dynamic raw);
 
   @protected
   void dco_decode_unit(dynamic raw);
 
+  @protected
+  BigInt dco_decode_usize(dynamic raw);
+
+  @protected
+  Weekday dco_decode_weekday(dynamic raw);
+
+  @protected
+  WeekdayRule dco_decode_weekday_rule(dynamic raw);
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+  @protected
+  Object sse_decode_DartOpaque(SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<CalendarChange> sse_decode_StreamSink_calendar_change_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<GitProgress> sse_decode_StreamSink_git_progress_Sse(
+    SseDeserializer deserializer,
+  );
+
   @protected
   String sse_decode_String(SseDeserializer deserializer);
 
   @protected
   bool sse_decode_bool(SseDeserializer deserializer);
 
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
+
+  @protected
+  CertificateError sse_decode_box_autoadd_certificate_error(
+    SseDeserializer deserializer,
+  );
+
   @protected
   EventDto sse_decode_box_autoadd_event_dto(SseDeserializer deserializer);
 
+  @protected
+  EventInput sse_decode_box_autoadd_event_input(SseDeserializer deserializer);
+
+  @protected
+  GitCredentials sse_decode_box_autoadd_git_credentials(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  GitIdentity sse_decode_box_autoadd_git_identity(SseDeserializer deserializer);
+
+  @protected
+  HostKey sse_decode_box_autoadd_host_key(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer);
+
+  @protected
+  IcsImportOptions sse_decode_box_autoadd_ics_import_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  OccurrenceOverride sse_decode_box_autoadd_occurrence_override(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RecurrenceRule sse_decode_box_autoadd_recurrence_rule(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  CalendarChange sse_decode_calendar_change(SseDeserializer deserializer);
+
+  @protected
+  CertificateError sse_decode_certificate_error(SseDeserializer deserializer);
+
+  @protected
+  CredentialRequest sse_decode_credential_request(SseDeserializer deserializer);
+
+  @protected
+  EventDiagnostic sse_decode_event_diagnostic(SseDeserializer deserializer);
+
   @protected
   EventDto sse_decode_event_dto(SseDeserializer deserializer);
 
+  @protected
+  EventError sse_decode_event_error(SseDeserializer deserializer);
+
+  @protected
+  EventFieldConflict sse_decode_event_field_conflict(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  EventInput sse_decode_event_input(SseDeserializer deserializer);
+
+  @protected
+  EventMergeReport sse_decode_event_merge_report(SseDeserializer deserializer);
+
+  @protected
+  EventsResult sse_decode_events_result(SseDeserializer deserializer);
+
+  @protected
+  Frequency sse_decode_frequency(SseDeserializer deserializer);
+
+  @protected
+  GitCredentials sse_decode_git_credentials(SseDeserializer deserializer);
+
   @protected
   GitError sse_decode_git_error(SseDeserializer deserializer);
 
+  @protected
+  GitIdentity sse_decode_git_identity(SseDeserializer deserializer);
+
+  @protected
+  GitProgress sse_decode_git_progress(SseDeserializer deserializer);
+
+  @protected
+  HostKey sse_decode_host_key(SseDeserializer deserializer);
+
   @protected
   int sse_decode_i_32(SseDeserializer deserializer);
 
+  @protected
+  IcsImportIssue sse_decode_ics_import_issue(SseDeserializer deserializer);
+
+  @protected
+  IcsImportOptions sse_decode_ics_import_options(SseDeserializer deserializer);
+
+  @protected
+  IcsImportReport sse_decode_ics_import_report(SseDeserializer deserializer);
+
+  @protected
+  InstancesResult sse_decode_instances_result(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_isize(SseDeserializer deserializer);
+
   @protected
   List<String> sse_decode_list_String(SseDeserializer deserializer);
 
+  @protected
+  List<EventDiagnostic> sse_decode_list_event_diagnostic(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<EventDto> sse_decode_list_event_dto(SseDeserializer deserializer);
 
+  @protected
+  List<EventError> sse_decode_list_event_error(SseDeserializer deserializer);
+
+  @protected
+  List<EventFieldConflict> sse_decode_list_event_field_conflict(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<HostKey> sse_decode_list_host_key(SseDeserializer deserializer);
+
+  @protected
+  List<IcsImportIssue> sse_decode_list_ics_import_issue(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<OccurrenceOverride> sse_decode_list_occurrence_override(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<PathResolution> sse_decode_list_path_resolution(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Int32List sse_decode_list_prim_i_32_strict(SseDeserializer deserializer);
+
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
   @protected
   List<StatusEntry> sse_decode_list_status_entry(SseDeserializer deserializer);
 
+  @protected
+  List<TrustedCertificate> sse_decode_list_trusted_certificate(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<WeekdayRule> sse_decode_list_weekday_rule(SseDeserializer deserializer);
+
+  @protected
+  MergeSide sse_decode_merge_side(SseDeserializer deserializer);
+
+  @protected
+  OccurrenceOverride sse_decode_occurrence_override(
+    SseDeserializer deserializer,
+  );
+
   @protected
   String? sse_decode_opt_String(SseDeserializer deserializer);
 
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
+
+  @protected
+  GitCredentials? sse_decode_opt_box_autoadd_git_credentials(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  GitIdentity? sse_decode_opt_box_autoadd_git_identity(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer);
+
+  @protected
+  RecurrenceRule? sse_decode_opt_box_autoadd_recurrence_rule(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  PathResolution sse_decode_path_resolution(SseDeserializer deserializer);
+
+  @protected
+  RecurrenceRule sse_decode_recurrence_rule(SseDeserializer deserializer);
+
   @protected
   StatusEntry sse_decode_status_entry(SseDeserializer deserializer);
 
+  @protected
+  SyncOutcome sse_decode_sync_outcome(SseDeserializer deserializer);
+
+  @protected
+  SyncReport sse_decode_sync_report(SseDeserializer deserializer);
+
+  @protected
+  SyncResult sse_decode_sync_result(SseDeserializer deserializer);
+
+  @protected
+  TrustKind sse_decode_trust_kind(SseDeserializer deserializer);
+
+  @protected
+  TrustedCertificate sse_decode_trusted_certificate(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
+
   @protected
   int sse_decode_u_8(SseDeserializer deserializer);
 
   @protected
   void sse_decode_unit(SseDeserializer deserializer);
 
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer);
+
+  @protected
+  Weekday sse_decode_weekday(SseDeserializer deserializer);
+
+  @protected
+  WeekdayRule sse_decode_weekday_rule(SseDeserializer deserializer);
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_DartFn_Inputs_credential_request_Output_opt_box_autoadd_git_credentials_AnyhowException(
+    FutureOr<GitCredentials?> Function(CredentialRequest) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_DartOpaque(Object self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_StreamSink_calendar_change_Sse(
+    RustStreamSink<CalendarChange> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_git_progress_Sse(
+    RustStreamSink<GitProgress> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_String(String self, SseSerializer serializer);
 
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_certificate_error(
+    CertificateError self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_event_dto(
     EventDto self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_event_input(
+    EventInput self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_git_credentials(
+    GitCredentials self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_git_identity(
+    GitIdentity self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_host_key(HostKey self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_ics_import_options(
+    IcsImportOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_occurrence_override(
+    OccurrenceOverride self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_recurrence_rule(
+    RecurrenceRule self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_calendar_change(
+    CalendarChange self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_certificate_error(
+    CertificateError self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_credential_request(
+    CredentialRequest self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_event_diagnostic(
+    EventDiagnostic self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_event_dto(EventDto self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_event_error(EventError self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_event_field_conflict(
+    EventFieldConflict self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_event_input(EventInput self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_event_merge_report(
+    EventMergeReport self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_events_result(EventsResult self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_frequency(Frequency self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_git_credentials(
+    GitCredentials self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_git_error(GitError self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_git_identity(GitIdentity self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_git_progress(GitProgress self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_host_key(HostKey self, SseSerializer serializer);
+
   @protected
   void sse_encode_i_32(int self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_ics_import_issue(
+    IcsImportIssue self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_ics_import_options(
+    IcsImportOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_ics_import_report(
+    IcsImportReport self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_instances_result(
+    InstancesResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_isize(PlatformInt64 self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_event_diagnostic(
+    List<EventDiagnostic> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_event_dto(List<EventDto> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_event_error(
+    List<EventError> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_event_field_conflict(
+    List<EventFieldConflict> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_host_key(List<HostKey> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_ics_import_issue(
+    List<IcsImportIssue> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_occurrence_override(
+    List<OccurrenceOverride> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_path_resolution(
+    List<PathResolution> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_i_32_strict(
+    Int32List self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_prim_u_8_strict(
     Uint8List self,
@@ -143,17 +807,113 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_trusted_certificate(
+    List<TrustedCertificate> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_weekday_rule(
+    List<WeekdayRule> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_merge_side(MergeSide self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_occurrence_override(
+    OccurrenceOverride self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_git_credentials(
+    GitCredentials? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_git_identity(
+    GitIdentity? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_recurrence_rule(
+    RecurrenceRule? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_path_resolution(
+    PathResolution self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_recurrence_rule(
+    RecurrenceRule self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_status_entry(StatusEntry self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_sync_outcome(SyncOutcome self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_sync_report(SyncReport self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_sync_result(SyncResult self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_trust_kind(TrustKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_trusted_certificate(
+    TrustedCertificate self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_8(int self, SseSerializer serializer);
 
   @protected
   void sse_encode_unit(void self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_usize(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_weekday(Weekday self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_weekday_rule(WeekdayRule self, SseSerializer serializer);
 }
 
 // Section: wire_class
@@ -19,10 +19,15 @@ class Event {
   final DateTime? endDate;
   final String? startTime; // HH:MM format or null for all-day
   final String? endTime; // HH:MM format or null
+  final String? timeZone; // IANA zone of the times, null when floating
   final String description;
   final String recurrence; // 'none', 'daily', 'weekly', 'monthly', 'yearly'
   final String?
   filename; // Title-based filename for display/identification only
+  final String? id; // Stable id assigned by the calendar, null until saved
+  final rcal_api.RecurrenceRule? rrule; // Set when repeating by a full rule
+  final List<String> excludedDates; // Skipped occurrences, YYYY-MM-DD
+  final List<rcal_api.OccurrenceOverride> overrides; // Edited occurrences
 
   Event({
     required this.title,
@@ -30,9 +35,14 @@ class Event {
     this.endDate,
     this.startTime,
     this.endTime,
+    this.timeZone,
     this.description = '',
     this.recurrence = 'none',
     this.filename,
+    this.id,
+    this.rrule,
+    this.excludedDates = const [],
+    this.overrides = const [],
   }) {
     // Validate using rcal API
     final validationError = Event.validate(
@@ -125,6 +135,7 @@ class Event {
     return rcal_api.EventDto(
       id:
           id ??
+          this.id ??
           title
               .replaceAll(RegExp(r'[^\w\s-]'), '')
               .replaceAll(' ', '-')
@@ -136,7 +147,11 @@ class Event {
       startTime: startTime,
       endTime: endTime,
       isAllDay: isAllDay,
+      timeZone: timeZone,
       recurrence: recurrence,
+      rrule: rrule,
+      excludedDates: excludedDates,
+      overrides: overrides,
       isRecurringInstance: false,
     );
   }
@@ -154,8 +169,13 @@ class Event {
       endDate: endDate,
       startTime: dto.startTime,
       endTime: dto.endTime,
+      timeZone: dto.timeZone,
       description: dto.description,
       recurrence: dto.recurrence,
+      id: dto.id,
+      rrule: dto.rrule,
+      excludedDates: dto.excludedDates,
+      overrides: dto.overrides,
     );
   }
 
@@ -173,14 +193,16 @@ class Event {
     final eventDto = event.toEventDto();
 
     try {
-      final dtos = await rcal_api.generateInstances(
+      final result = await rcal_api.generateInstances(
         events: [eventDto],
         startDate: startDateStr,
         endDate: endDateStr,
       );
+      // An event rcal can't expand falls back like a failed call
+      if (result.diagnostics.isNotEmpty) return [event];
 
       // Convert back to Event objects
-      return dtos.map((dto) => Event.fromEventDto(dto)).toList();
+      return result.instances.map((dto) => Event.fromEventDto(dto)).toList();
     } catch (e) {
       // If API fails, fall back to returning just the base event
       return [event];
@@ -454,9 +476,14 @@ class Event {
     DateTime? endDate,
     String? startTime,
     String? endTime,
+    String? timeZone,
     String? description,
     String? recurrence,
     String? filename,
+    String? id,
+    rcal_api.RecurrenceRule? rrule,
+    List<String>? excludedDates,
+    List<rcal_api.OccurrenceOverride>? overrides,
   }) {
     return Event(
       title: title ?? this.title,
@@ -464,9 +491,14 @@ class Event {
       endDate: endDate ?? this.endDate,
       startTime: startTime ?? this.startTime,
       endTime: endTime ?? this.endTime,
+      timeZone: timeZone ?? this.timeZone,
       description: description ?? this.description,
       recurrence: recurrence ?? this.recurrence,
       filename: filename ?? this.filename,
+      id: id ?? this.id,
+      rrule: rrule ?? this.rrule,
+      excludedDates: excludedDates ?? this.excludedDates,
+      overrides: overrides ?? this.overrides,
     );
   }
 
@@ -478,7 +510,10 @@ class Event {
   @override
   bool operator ==(Object other) {
     if (identical(this, other)) return true;
-    // Note: filename is NOT included in equality since it's now display-only
+    // Note: filename, id, rrule and the occurrence exceptions are NOT
+    // included in equality; filename is display-only, id is only known once
+    // the event is saved, recurrence already summarises rrule and the
+    // exceptions are edited one occurrence at a time
     // The persistence key is (title, startDate)
     return other is Event &&
         other.title == title &&
@@ -486,6 +521,7 @@ class Event {
         other.endDate == endDate &&
         other.startTime == startTime &&
         other.endTime == endTime &&
+        other.timeZone == timeZone &&
         other.description == description &&
         other.recurrence == recurrence;
   }
@@ -497,6 +533,7 @@ class Event {
       (endDate?.hashCode ?? 0) ^
       (startTime?.hashCode ?? 0) ^
       (endTime?.hashCode ?? 0) ^
+      (timeZone?.hashCode ?? 0) ^
       description.hashCode ^
       recurrence.hashCode;
 }
@@ -625,11 +625,14 @@ class EventProvider extends ChangeNotifier {
       final filenames = <String>[];
 
       for (final event in events) {
-        // Save event to storage - rcal-lib uses (title, startDate) as key
-        await _storage.addEvent(event);
+        // Save event to storage - rcal-lib assigns the event its id
+        final id = await _storage.addEvent(event);
 
         // The filename is derived from title, compute it locally
-        final eventWithFilename = event.copyWith(filename: event.fileName);
+        final eventWithFilename = event.copyWith(
+          filename: event.fileName,
+          id: id,
+        );
 
         // Track that this event was successfully saved to Rust BEFORE adding to Dart
         // This ensures we can rollback only what was actually persisted
@@ -725,11 +728,12 @@ class EventProvider extends ChangeNotifier {
               e.startDate.day == newEvent.startDate.day,
         );
         if (index != -1) {
-          // Update event in storage - rcal-lib uses (title, startDate) as key
-          await _storage.updateEvent(_allEvents[index], newEvent);
+          // Update event in storage - rcal-lib finds it by the old event's id
+          final id = await _storage.updateEvent(_allEvents[index], newEvent);
           // The filename is derived from title, compute it locally
           final newEventWithFilename = newEvent.copyWith(
             filename: newEvent.fileName,
+            id: id,
           );
           _allEvents[index] = newEventWithFilename;
         }
@@ -939,12 +943,15 @@ class EventProvider extends ChangeNotifier {
 
   Future<void> _performAddEvent(Event event) async {
     try {
-      // Save event to storage - rcal-lib uses (title, startDate) as key
-      await _storage.addEvent(event);
+      // Save event to storage - rcal-lib assigns the event its id
+      final id = await _storage.addEvent(event);
 
       // The filename is derived from title, compute it locally
       // No need to get it from storage since it's title-based
-      final eventWithFilename = event.copyWith(filename: event.fileName);
+      final eventWithFilename = event.copyWith(
+        filename: event.fileName,
+        id: id,
+      );
       _allEvents.add(eventWithFilename);
       await computeEventDatesAsync();
       if (!Platform.isLinux) {
@@ -997,14 +1004,14 @@ class EventProvider extends ChangeNotifier {
 
   Future<void> _performUpdateEvent(Event oldEvent, Event newEvent) async {
     try {
-      // Update event in storage - rcal-lib uses (title, startDate) as key
-      // Same title+date will replace the existing event
-      await _storage.updateEvent(oldEvent, newEvent);
+      // Update event in storage - rcal-lib finds it by the old event's id
+      final id = await _storage.updateEvent(oldEvent, newEvent);
 
       final index = _allEvents.indexWhere((e) => e == oldEvent);
       // The filename is derived from title, compute it locally
       final newEventWithFilename = newEvent.copyWith(
         filename: newEvent.fileName,
+        id: id,
       );
       if (index != -1) {
         _allEvents[index] = newEventWithFilename;
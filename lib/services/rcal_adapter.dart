@@ -1,5 +1,6 @@
 // ignore_for_file: unused_import, invalid_use_of_internal_member, unused_element
 
+import 'dart:developer';
 import 'dart:io';
 import 'package:path/path.dart' as path;
 import 'package:path_provider/path_provider.dart';
@@ -101,8 +102,13 @@ class RcalAdapter {
         endDate: endDate,
         startTime: dto.startTime,
         endTime: dto.endTime,
+        timeZone: dto.timeZone,
         description: dto.description,
         recurrence: dto.recurrence,
+        id: dto.id,
+        rrule: dto.rrule,
+        excludedDates: dto.excludedDates,
+        overrides: dto.overrides,
         // Filename is now title-based, not ID-based
         // Store the filename for display/identification purposes
         filename: _generateTitleBasedFilename(dto.title),
@@ -136,7 +142,7 @@ class RcalAdapter {
     final titleBasedFilename = _generateTitleBasedFilename(event.title);
 
     return EventDto(
-      id: id ?? titleBasedFilename.replaceAll('.md', ''),
+      id: id ?? event.id ?? titleBasedFilename.replaceAll('.md', ''),
       title: event.title,
       description: event.description,
       startDate: _dateToString(event.startDate),
@@ -144,18 +150,48 @@ class RcalAdapter {
       startTime: event.startTime,
       endTime: event.endTime,
       isAllDay: event.isAllDay,
+      timeZone: event.timeZone,
       recurrence: event.recurrence,
+      rrule: event.rrule,
+      excludedDates: event.excludedDates,
+      overrides: event.overrides,
       isRecurringInstance: false,
     );
   }
 
-  /// Loads all events from the specified calendar directory.
+  /// Converts an Event model to the EventInput that creates or updates it.
+  EventInput _eventToInput(Event event) {
+    return EventInput(
+      title: event.title,
+      description: event.description,
+      startDate: _dateToString(event.startDate),
+      endDate: event.endDate != null ? _dateToString(event.endDate!) : null,
+      startTime: event.startTime,
+      endTime: event.endTime,
+      isAllDay: event.isAllDay,
+      recurrence: event.recurrence,
+      rrule: event.rrule,
+      timeZone: event.timeZone,
+    );
+  }
+
+  /// Converts the readable events of an [EventsResult], logging the files
+  /// that could not be read.
+  List<Event> _readableEvents(EventsResult result) {
+    for (final error in result.unreadable) {
+      log('Skipped unreadable event file: $error');
+    }
+    return result.events.map(_dtoToEvent).toList();
+  }
+
+  /// Loads all events from the specified calendar directory. Event files
+  /// that can't be read are logged and left out.
   ///
   /// Throws [RcalException] if the operation fails.
   Future<List<Event>> loadEvents(String calendarDir) async {
     try {
-      final dtos = await api.crateApiGetAllEvents(calendarDir: calendarDir);
-      return dtos.map(_dtoToEvent).toList();
+      final result = await api.crateApiGetAllEvents(calendarDir: calendarDir);
+      return _readableEvents(result);
     } catch (e) {
       throw RcalException('Failed to load events: $e');
     }
@@ -176,84 +212,75 @@ class RcalAdapter {
     String calendarDir,
   ) async {
     try {
-      final dtos = await api.crateApiGetEventsInRange(
+      final result = await api.crateApiGetEventsInRange(
         startDate: _dateToString(start),
         endDate: _dateToString(end),
         calendarDir: calendarDir,
       );
-      return dtos.map(_dtoToEvent).toList();
+      return _readableEvents(result);
     } catch (e) {
       throw RcalException('Failed to get events in range: $e');
     }
   }
 
-  /// Saves an event to the specified calendar directory.
+  /// Saves a new event to the specified calendar directory.
   ///
-  /// The rcal-lib now uses (title, start_date) as the persistence key.
-  /// If an event with the same title and start_date already exists, it will be replaced.
-  /// The filename is generated from the title: {sanitized_title}.md
+  /// Every saved event gets its own file, even when another event has the
+  /// same title, and a stable id that later updates and deletes refer to.
   ///
-  /// Returns the filename of the saved event.
+  /// Returns the id of the saved event.
   ///
   /// Throws [RcalException] if the operation fails.
   Future<String> saveEvent(Event event, String calendarDir) async {
     try {
-      // The persistence key is (title, start_date) - no need to check for existing ID
-      // Just create/update the event - rcal-lib handles the rest based on title+date
-
-      // Create the event - rcal-lib will use (title, start_date) as key
-      await api.crateApiCreateEvent(
-        title: event.title,
-        description: event.description,
-        startDate: _dateToString(event.startDate),
-        endDate: event.endDate != null ? _dateToString(event.endDate!) : null,
-        startTime: event.startTime,
-        endTime: event.endTime,
-        isAllDay: event.isAllDay,
-        recurrence: event.recurrence,
+      return await api.crateApiCreateEvent(
+        input: _eventToInput(event),
         calendarDir: calendarDir,
       );
-
-      // Return title-based filename for identification
-      return _generateTitleBasedFilename(event.title);
     } catch (e) {
       throw RcalException('Failed to save event: $e');
     }
   }
 
-  /// Deletes an event from the specified calendar directory.
+  /// Replaces the event with the given [id] in the specified calendar directory.
   ///
-  /// The rcal-lib uses title as the deletion key via delete_by_title_from_path.
-  /// This method sends just the title to the Rust API.
+  /// Throws [RcalException] if the operation fails, including if the event
+  /// is not found in storage.
+  Future<void> replaceEvent(String id, Event event, String calendarDir) async {
+    try {
+      await api.crateApiUpdateEvent(
+        id: id,
+        input: _eventToInput(event),
+        calendarDir: calendarDir,
+      );
+    } on EventError_NotFound catch (e) {
+      throw RcalException(
+        'Event $id not found in storage. '
+        'This may indicate a state mismatch between Dart and Rust. '
+        'Original error: $e',
+      );
+    } catch (e) {
+      throw RcalException('Failed to update event: $e');
+    }
+  }
+
+  /// Deletes an event from the specified calendar directory.
   ///
-  /// [title] - The title of the event to delete.
-  /// [startDate] - The start date of the event to delete (as DateTime).
+  /// [id] - The id of the event to delete.
   /// [calendarDir] - The path to the calendar directory.
   ///
   /// Throws [RcalException] if the operation fails, including if the event
   /// is not found in storage.
-  Future<void> deleteEvent(
-    String title,
-    DateTime startDate,
-    String calendarDir,
-  ) async {
-    // Send just the title - rcal-lib's delete_by_title_from_path uses title
-    final dateStr = _dateToString(startDate);
-
+  Future<void> deleteEvent(String id, String calendarDir) async {
     try {
-      await api.crateApiDeleteEvent(id: title, calendarDir: calendarDir);
-    } on RcalException {
-      rethrow;
+      await api.crateApiDeleteEvent(id: id, calendarDir: calendarDir);
+    } on EventError_NotFound catch (e) {
+      throw RcalException(
+        'Event $id not found in storage. '
+        'This may indicate a state mismatch between Dart and Rust. '
+        'Original error: $e',
+      );
     } catch (e) {
-      final errorStr = e.toString();
-      // Check if this is a "not found" error from Rust
-      if (errorStr.contains("not found")) {
-        throw RcalException(
-          'Event with title \'$title\' and start date \'$dateStr\' not found in storage. '
-          'This may indicate a state mismatch between Dart and Rust. '
-          'Original error: $e',
-        );
-      }
       throw RcalException('Failed to delete event: $e');
     }
   }
@@ -265,11 +292,17 @@ class RcalAdapter {
   /// Loads all events from the calendar directory.
   ///
   /// This is a convenience wrapper around [loadEvents] that automatically
-  /// determines the calendar directory.
+  /// determines the calendar directory. Event files written before events had
+  /// stable ids are given one first.
   ///
   /// Throws [RcalException] if the operation fails.
   Future<List<Event>> loadAllEvents() async {
     final calendarDir = await getCalendarDirectory();
+    try {
+      await api.crateApiMigrateEventIds(calendarDir: calendarDir);
+    } catch (e) {
+      throw RcalException('Failed to migrate event ids: $e');
+    }
     return loadEvents(calendarDir);
   }
 
@@ -277,7 +310,7 @@ class RcalAdapter {
   ///
   /// Convenience wrapper that calls [saveEvent] to create a new event.
   ///
-  /// Returns the filename of the added event.
+  /// Returns the id of the added event.
   ///
   /// Throws [RcalException] if the operation fails.
   Future<String> addEvent(Event event) async {
@@ -287,30 +320,37 @@ class RcalAdapter {
 
   /// Updates an existing event in the calendar.
   ///
-  /// Since rcal-lib now uses (title, start_date) as the persistence key,
-  /// updates are handled by creating a new event with the same key.
-  /// The oldEvent is no longer needed for determining the ID.
+  /// The event file is found by the id of [oldEvent], so renaming or moving
+  /// the event keeps it a single event.
   ///
-  /// Returns the filename of the updated event.
+  /// Returns the id of the updated event.
   ///
-  /// Throws [RcalException] if the operation fails.
+  /// Throws [RcalException] if the operation fails or [oldEvent] was never saved.
   Future<String> updateEvent(Event oldEvent, Event newEvent) async {
+    final id = _requireId(oldEvent);
     final calendarDir = await getCalendarDirectory();
-    // Just save the new event - rcal-lib uses (title, start_date) as key
-    // Same title+date will replace the existing event
-    return saveEvent(newEvent, calendarDir);
+    await replaceEvent(id, newEvent, calendarDir);
+    return id;
   }
 
   /// Deletes an event from the calendar.
   ///
-  /// Convenience wrapper that uses (title, start_date) for deletion.
-  /// This is the primary deletion method since rcal-lib now uses title+date as key.
+  /// Convenience wrapper that deletes the event by its id.
   ///
-  /// Throws [RcalException] if the operation fails.
+  /// Throws [RcalException] if the operation fails or [event] was never saved.
   Future<void> deleteEventByEvent(Event event) async {
+    final id = _requireId(event);
     final calendarDir = await getCalendarDirectory();
-    // Use title and start_date as the deletion key
-    await deleteEvent(event.title, event.startDate, calendarDir);
+    await deleteEvent(id, calendarDir);
+  }
+
+  /// Returns the id of a saved event.
+  String _requireId(Event event) {
+    final id = event.id;
+    if (id == null) {
+      throw RcalException('Event \'${event.title}\' has not been saved');
+    }
+    return id;
   }
 
   /// Generates instances for recurring events within a date range.
@@ -322,7 +362,8 @@ class RcalAdapter {
   /// [start] - The start date of the range (inclusive).
   /// [end] - The end date of the range (inclusive).
   ///
-  /// Returns all events including expanded recurring instances.
+  /// Returns all events including expanded recurring instances. Events whose
+  /// recurrence can't be expanded are logged and left out.
   ///
   /// Throws [RcalException] if the operation fails.
   Future<List<Event>> generateInstances(
@@ -333,12 +374,15 @@ class RcalAdapter {
     try {
       // Convert events to DTOs
       final dtos = events.map((e) => _eventToDto(e)).toList();
-      final instanceDtos = await api.crateApiGenerateInstances(
+      final result = await api.crateApiGenerateInstances(
         events: dtos,
         startDate: _dateToString(start),
         endDate: _dateToString(end),
       );
-      return instanceDtos.map(_dtoToEvent).toList();
+      for (final diagnostic in result.diagnostics) {
+        log('Skipped event ${diagnostic.id}: ${diagnostic.reason}');
+      }
+      return result.instances.map(_dtoToEvent).toList();
     } catch (e) {
       throw RcalException('Failed to generate instances: $e');
     }
@@ -233,14 +233,11 @@ class SyncService {
         password: password,
         sshKeyPath: sshKeyPath,
       );
-      log('DEBUG pullSync: Rust git_pull returned result: "$result"');
-      if (result.contains('Non-fast-forward')) {
-        log('Pull sync detected non-fast-forward merge, treating as conflict');
-        throw SyncConflictException(
-          "Merge conflict detected during pull. Please resolve manually.",
-        );
-      }
-      log('Pull sync completed successfully: $result');
+      log('DEBUG pullSync: Rust git_pull returned ${result.outcome.name}');
+      log(
+        'Pull sync completed successfully: ${result.outcome.name}, '
+        '${result.affectedEventIds.length} events changed',
+      );
     } catch (e) {
       log('Pull sync failed: $e');
       if (e.toString().toLowerCase().contains('conflict')) {
@@ -1,14 +1,27 @@
-use chrono::{NaiveDate, NaiveTime};
+use base64::prelude::{Engine as _, BASE64_STANDARD, BASE64_STANDARD_NO_PAD};
+use chrono::{
+    DateTime, Datelike, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone,
+};
+use chrono_tz::Tz;
+use flutter_rust_bridge::DartFnFuture;
 use git2::{Delta, Repository};
+use notify::{RecommendedWatcher, RecursiveMode};
+use notify_debouncer_mini::{new_debouncer, DebounceEventResult, Debouncer};
 use rcal_lib::core::EventService;
 use rcal_lib::models::{CalendarEvent, Recurrence};
 use rcal_lib::storage::FileEventRepository;
 use rcal_lib::validation::{is_valid_date_range, is_valid_time_range, is_valid_title};
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
-use std::process::Command;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock, PoisonError};
+use std::time::{Duration, SystemTime};
 use x509_parser::prelude::*;
 
+use crate::frb_generated::StreamSink;
+
 #[flutter_rust_bridge::frb]
 #[derive(Debug)]
 pub struct StatusEntry {
@@ -16,15 +29,223 @@ pub struct StatusEntry {
     pub status: String,
 }
 
+/// What a sync operation did.
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SyncOutcome {
+    UpToDate,
+    FastForward,
+    Merged,
+    Pushed,
+    Fetched,
+}
+
+/// Result of pull, push or fetch, describing the commits and files that changed.
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone)]
+pub struct SyncResult {
+    pub outcome: SyncOutcome,
+    pub old_commit: Option<String>,
+    pub new_commit: Option<String>,
+    pub added_paths: Vec<String>,
+    pub modified_paths: Vec<String>,
+    pub deleted_paths: Vec<String>,
+    /// Ids of the events stored in the added, modified and deleted files
+    pub affected_event_ids: Vec<String>,
+}
+
+/// Result of git_sync.
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone)]
+pub struct SyncReport {
+    /// Id of the commit created for local changes, None when there was nothing to commit
+    pub local_commit: Option<String>,
+    /// The last pull, run before the push that succeeded
+    pub pull: SyncResult,
+    pub push: SyncResult,
+    /// Number of push attempts, including the one that succeeded
+    pub push_attempts: u32,
+}
+
+/// Name and email recorded on commits.
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone)]
+pub struct GitIdentity {
+    pub name: String,
+    pub email: String,
+}
+
 #[flutter_rust_bridge::frb]
 #[derive(Debug)]
 pub enum GitError {
     Io(String),
+    /// A libgit2 error without a more specific variant
     Git(String),
-    Auth(String),
+    /// The remote rejected the credentials, or none were available
+    AuthenticationFailed(String),
+    /// The repository path or remote repository doesn't exist
+    RepositoryNotFound(String),
+    /// A reference, object or remote the operation needs doesn't exist
+    NotFound(String),
+    /// The remote couldn't be reached: no network, DNS failure or connection refused
+    NetworkUnreachable(String),
+    /// Another process holds a lock in the repository, e.g. index.lock
+    Locked(String),
+    /// HEAD is not on a branch; carries the commit HEAD points to.
+    DetachedHead(String),
+    /// A merge stopped on conflicts; carries the conflicted paths.
+    /// The repository is left mid-merge for git_merge_prefer_remote or git_merge_abort.
+    MergeConflict(Vec<String>),
+    /// The pull or merge succeeded, but the local changes stashed before it conflict
+    /// with the result; carries the conflicted paths. The changes are kept in the stash.
+    StashConflict(Vec<String>),
+    /// The remote rejected a push because it has commits the local branch lacks;
+    /// carries the rejected reference. Pull (or git_sync) and push again.
+    NonFastForward(String),
+    /// The server's TLS certificate was rejected.
+    CertificateInvalid {
+        host: String,
+        reason: CertificateError,
+    },
+    /// The SSH server presented a host key that isn't in known_hosts yet.
+    /// Show the fingerprint to the user; call trust_host_key and retry to accept it.
+    UnknownHostKey(HostKey),
+    /// The SSH server presented a different key than the one pinned for its host.
+    /// Either the server was reinstalled or the connection is being intercepted.
+    HostKeyMismatch(HostKey),
+    /// The operation was stopped through cancel_operation.
+    Cancelled,
+    /// HEAD points to a branch without commits and no remote default exists.
+    UnbornBranch(String),
     Other(String),
 }
 
+/// Why a server's TLS certificate was rejected.
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone, PartialEq)]
+pub enum CertificateError {
+    Expired,
+    NotYetValid,
+    /// Not issued by webpki-roots or a CA registered through set_ssl_ca_certs
+    UnknownIssuer,
+    /// Not valid for the host name or IP address that was connected to
+    NameMismatch,
+    /// The host has pinned certificates and this isn't one of them
+    PinMismatch,
+    InvalidSignature,
+    Malformed(String),
+    Other(String),
+}
+
+/// Credentials for a remote. User names left empty default to the one in the
+/// remote URL, then to "git" for SSH and "x-access-token" for tokens.
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone)]
+pub enum GitCredentials {
+    UserPassword {
+        username: String,
+        password: String,
+    },
+    /// Personal access token, sent as the HTTPS password
+    Token {
+        username: Option<String>,
+        token: String,
+    },
+    SshKeyFile {
+        username: Option<String>,
+        private_key_path: String,
+        public_key_path: Option<String>,
+        passphrase: Option<String>,
+    },
+    /// PEM/OpenSSH key text, for apps that keep keys in secure storage instead of files
+    SshKeyMemory {
+        username: Option<String>,
+        private_key: String,
+        public_key: Option<String>,
+        passphrase: Option<String>,
+    },
+    SshAgent {
+        username: Option<String>,
+    },
+}
+
+/// What a remote asks credentials for, passed to the set_credential_callback callback.
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone)]
+pub struct CredentialRequest {
+    pub url: String,
+    pub username_from_url: Option<String>,
+    pub allow_password: bool,
+    pub allow_ssh_key: bool,
+    /// 1 on the first request of an operation; higher once earlier credentials were rejected
+    pub attempt: u32,
+}
+
+/// How a certificate in the trust store is trusted.
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TrustKind {
+    /// A CA whose certificates are trusted for the host
+    CertificateAuthority,
+    /// A server certificate the host must present; any other is rejected
+    PinnedLeaf,
+}
+
+/// A certificate in the trust store.
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrustedCertificate {
+    pub host: String,
+    pub kind: TrustKind,
+    /// SHA-256 of the DER encoding, e.g. "SHA256:47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU"
+    pub fingerprint: String,
+    pub subject: String,
+    /// RFC 3339 end of the validity period
+    pub not_after: String,
+}
+
+/// An SSH host key as stored in known_hosts.
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone, PartialEq)]
+pub struct HostKey {
+    pub host: String,
+    /// Key type as written in known_hosts, e.g. "ssh-ed25519"
+    pub key_type: String,
+    /// Base64 encoded public key
+    pub key: String,
+    /// OpenSSH style fingerprint, e.g. "SHA256:uNiVztksCsDhcc0u9e8BujQXVUpKZIDTMczCvj3tD2s"
+    pub fingerprint: String,
+}
+
+/// Progress of a long-running network operation, taken from git2's callbacks.
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone)]
+pub enum GitProgress {
+    /// Objects and bytes received while fetching or cloning
+    Transfer {
+        received_objects: usize,
+        total_objects: usize,
+        indexed_objects: usize,
+        received_bytes: usize,
+        total_deltas: usize,
+        indexed_deltas: usize,
+    },
+    /// Files written to the working directory
+    Checkout {
+        path: Option<String>,
+        completed: usize,
+        total: usize,
+    },
+    /// Objects sent while pushing
+    PushTransfer {
+        current: usize,
+        total: usize,
+        bytes: usize,
+    },
+    /// Text the server sends, e.g. "Counting objects: 42% (21/50)"
+    Sideband(String),
+}
+
 impl From<std::io::Error> for GitError {
     fn from(err: std::io::Error) -> Self {
         GitError::Io(err.to_string())
@@ -33,7 +254,20 @@ impl From<std::io::Error> for GitError {
 
 impl From<git2::Error> for GitError {
     fn from(err: git2::Error) -> Self {
-        GitError::Git(err.to_string())
+        use git2::{ErrorClass, ErrorCode};
+        // Conflicts and certificate errors need the repository or the session to
+        // name their paths and host: see conflict_error and RemoteSession::error
+        let message = err.message().to_string();
+        match (err.code(), err.class()) {
+            (ErrorCode::Auth, _) => GitError::AuthenticationFailed(message),
+            (ErrorCode::NotFastForward, _) => GitError::NonFastForward(message),
+            (ErrorCode::Locked, _) => GitError::Locked(message),
+            // Also local remotes without a repository
+            (ErrorCode::NotFound, ErrorClass::Repository) => GitError::RepositoryNotFound(message),
+            (ErrorCode::NotFound, _) => GitError::NotFound(message),
+            (_, ErrorClass::Net) => GitError::NetworkUnreachable(message),
+            _ => GitError::Git(err.to_string()),
+        }
     }
 }
 
@@ -42,7 +276,7 @@ impl From<git2::Error> for GitError {
 // ============================================================================
 
 #[flutter_rust_bridge::frb]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct EventDto {
     pub id: String,
     pub title: String,
@@ -52,10 +286,244 @@ pub struct EventDto {
     pub start_time: Option<String>,
     pub end_time: Option<String>,
     pub is_all_day: bool,
+    /// IANA time zone of the times, e.g. "Europe/Berlin"; None for floating times
+    /// that read the same in every zone. All-day events are always floating
+    pub time_zone: Option<String>,
     pub recurrence: String,
+    /// The full rule when the event repeats by one; [recurrence] then holds its frequency
+    pub rrule: Option<RecurrenceRule>,
+    /// Dates whose occurrence is skipped (EXDATE), YYYY-MM-DD
+    pub excluded_dates: Vec<String>,
+    pub overrides: Vec<OccurrenceOverride>,
+    /// For generated instances, the date the occurrence falls on by the recurrence,
+    /// also when an override moved it; skip_occurrence and override_occurrence take it
+    pub occurrence_date: Option<String>,
     pub is_recurring_instance: bool,
 }
 
+/// The fields of an event to create or update. [recurrence] is none, daily, weekly,
+/// monthly, yearly or RRULE text; [rrule] takes its place when given. [time_zone]
+/// is an IANA name, or None or "floating" for times that read the same in every zone.
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventInput {
+    pub title: String,
+    pub description: String,
+    pub start_date: String,
+    pub end_date: Option<String>,
+    pub start_time: Option<String>,
+    pub end_time: Option<String>,
+    pub is_all_day: bool,
+    pub recurrence: String,
+    pub rrule: Option<RecurrenceRule>,
+    pub time_zone: Option<String>,
+}
+
+/// Changes to one occurrence of a recurring event (RECURRENCE-ID). Fields left None
+/// keep the series' values; moving [start_date] moves the end date with it.
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone, PartialEq)]
+pub struct OccurrenceOverride {
+    /// The date the occurrence falls on by the recurrence
+    pub occurrence_date: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub start_date: Option<String>,
+    pub end_date: Option<String>,
+    pub start_time: Option<String>,
+    pub end_time: Option<String>,
+    pub is_all_day: Option<bool>,
+}
+
+/// How often a recurrence rule repeats (RRULE FREQ).
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+/// A BYDAY entry: a weekday, optionally the nth of the month or year, such as the
+/// first Monday (1MO) or the last Friday (-1FR).
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeekdayRule {
+    pub weekday: Weekday,
+    pub ordinal: Option<i32>,
+}
+
+/// An RFC 5545 recurrence rule (RRULE). The event's start date is always the
+/// first occurrence; the rule picks the ones after it.
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecurrenceRule {
+    pub frequency: Frequency,
+    /// Repeats every [interval] days, weeks, months or years
+    pub interval: u32,
+    pub by_day: Vec<WeekdayRule>,
+    /// Days of the month, negative counting from the end (-1 is the last day)
+    pub by_month_day: Vec<i32>,
+    /// Picks the nth of the dates each period yields, negative counting from the end
+    pub by_set_pos: Vec<i32>,
+    /// Total number of occurrences
+    pub count: Option<u32>,
+    /// Last date an occurrence may start on (YYYY-MM-DD, inclusive)
+    pub until: Option<String>,
+}
+
+/// Errors from the calendar event functions. [field] names the input an error
+/// refers to ("title", "start_date", "end_time", ...) so forms can highlight it.
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone, PartialEq)]
+pub enum EventError {
+    /// Not a YYYY-MM-DD date
+    InvalidDate { field: String, value: String },
+    /// Not an HH:MM time
+    InvalidTime { field: String, value: String },
+    /// A field the event needs is missing, e.g. the start time of a timed event
+    MissingField(String),
+    /// The end lies before the start; [field] is the end field
+    InvalidRange { field: String },
+    /// Not a known recurrence or a supported RRULE
+    InvalidRecurrence { value: String, reason: String },
+    /// The event has no occurrence on this date to skip, override or split at
+    NoOccurrence { id: String, date: String },
+    /// Not an IANA time zone name
+    UnknownTimeZone(String),
+    /// Empty or longer than rcal-lib allows
+    TitleInvalid(String),
+    /// No event with this id exists in the calendar
+    NotFound(String),
+    /// More than one event file carries this id
+    DuplicateId(String),
+    /// Reading or writing the calendar directory failed
+    Storage(String),
+    /// An event file couldn't be parsed
+    ParseFailure { file: String, message: String },
+}
+
+impl From<std::io::Error> for EventError {
+    fn from(err: std::io::Error) -> Self {
+        EventError::Storage(err.to_string())
+    }
+}
+
+// Helper function for rcal-lib storage errors, which come as boxed errors
+fn storage_error(err: impl std::fmt::Display) -> EventError {
+    EventError::Storage(err.to_string())
+}
+
+/// Which side of a conflicted merge to keep.
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MergeSide {
+    Local,
+    Remote,
+}
+
+/// The side chosen for one conflicted file.
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone)]
+pub struct PathResolution {
+    pub path: String,
+    pub side: MergeSide,
+}
+
+/// A field that both sides of a merge changed to different values.
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone)]
+pub struct EventFieldConflict {
+    pub path: String,
+    pub event_id: String,
+    pub field: String,
+    pub base_value: Option<String>,
+    pub local_value: Option<String>,
+    pub remote_value: Option<String>,
+}
+
+/// Outcome of merging conflicted event files field by field.
+#[flutter_rust_bridge::frb]
+#[derive(Debug)]
+pub struct EventMergeReport {
+    /// Event files merged without contested fields and staged
+    pub merged_paths: Vec<String>,
+    /// Files still conflicted in the index
+    pub unresolved_paths: Vec<String>,
+    pub conflicts: Vec<EventFieldConflict>,
+    /// True when nothing was left unresolved and the merge commit was written
+    pub committed: bool,
+}
+
+/// An event left out of a result, and why.
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventDiagnostic {
+    pub id: String,
+    pub reason: EventError,
+}
+
+/// Occurrences expanded from a list of events. Events that couldn't be expanded
+/// are reported in [diagnostics] instead of being dropped without a trace.
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone, PartialEq)]
+pub struct InstancesResult {
+    pub instances: Vec<EventDto>,
+    pub diagnostics: Vec<EventDiagnostic>,
+}
+
+/// Events read from a calendar directory. Event files that couldn't be parsed are
+/// reported in [unreadable] as ParseFailure instead of being left out without a trace.
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventsResult {
+    pub events: Vec<EventDto>,
+    pub unreadable: Vec<EventError>,
+}
+
+/// Options for import_ics.
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone, Default)]
+pub struct IcsImportOptions {
+    /// Replace events already in the calendar whose id is an imported UID,
+    /// instead of skipping them
+    pub replace_existing: bool,
+}
+
+/// A calendar item import_ics left out, and why.
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone, PartialEq)]
+pub struct IcsImportIssue {
+    pub uid: Option<String>,
+    pub summary: String,
+    pub reason: String,
+}
+
+/// Outcome of import_ics.
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone, PartialEq)]
+pub struct IcsImportReport {
+    /// Ids of the events written; an event's id is its UID when it has one
+    pub imported: Vec<String>,
+    /// Duplicates, cancelled events, events already in the calendar and malformed items
+    pub skipped: Vec<IcsImportIssue>,
+    /// Items using what events here can't hold, such as to-dos or hourly rules
+    pub unsupported: Vec<IcsImportIssue>,
+}
+
 /// Converts a CalendarEvent to an EventDto
 fn event_to_dto(event: &CalendarEvent) -> EventDto {
     EventDto {
@@ -71,76 +539,183 @@ fn event_to_dto(event: &CalendarEvent) -> EventDto {
         },
         end_time: event.end_time.map(|t| t.format("%H:%M").to_string()),
         is_all_day: event.is_all_day,
+        time_zone: None,
         recurrence: event.recurrence.to_storage_string().to_string(),
+        rrule: None,
+        excluded_dates: Vec::new(),
+        overrides: Vec::new(),
+        occurrence_date: None,
         is_recurring_instance: event.is_recurring_instance,
     }
 }
 
-/// Parses a date string in YYYY-MM-DD format
-fn parse_date(date_str: &str) -> Result<NaiveDate, String> {
-    NaiveDate::parse_from_str(date_str, "%Y-%m-%d")
-        .map_err(|e| format!("Invalid date format '{}': {}", date_str, e))
+/// An event as the wrapper stores it: rcal-lib's CalendarEvent plus what its model
+/// has no field for, kept in extra lines of the same Markdown file.
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Debug, Clone)]
+pub(crate) struct EventRecord {
+    pub event: CalendarEvent,
+    pub rule: Option<RecurrenceRule>,
+    pub excluded_dates: Vec<NaiveDate>,
+    pub overrides: Vec<OccurrenceOverride>,
+    /// None for floating times
+    pub time_zone: Option<Tz>,
+}
+
+impl EventRecord {
+    /// Whether single occurrences are skipped or changed, which rcal-lib can't expand
+    fn has_exceptions(&self) -> bool {
+        !self.excluded_dates.is_empty() || !self.overrides.is_empty()
+    }
+}
+
+impl From<CalendarEvent> for EventRecord {
+    fn from(event: CalendarEvent) -> Self {
+        EventRecord {
+            event,
+            rule: None,
+            excluded_dates: Vec::new(),
+            overrides: Vec::new(),
+            time_zone: None,
+        }
+    }
+}
+
+/// Converts an EventRecord to an EventDto
+fn record_to_dto(record: &EventRecord) -> EventDto {
+    EventDto {
+        rrule: record.rule.clone(),
+        excluded_dates: record
+            .excluded_dates
+            .iter()
+            .map(|d| d.format("%Y-%m-%d").to_string())
+            .collect(),
+        overrides: record.overrides.clone(),
+        time_zone: record.time_zone.map(|zone| zone.name().to_string()),
+        ..event_to_dto(&record.event)
+    }
+}
+
+/// Parses the date string of [field] in YYYY-MM-DD format
+fn parse_date(field: &str, date_str: &str) -> Result<NaiveDate, EventError> {
+    NaiveDate::parse_from_str(date_str, "%Y-%m-%d").map_err(|_| EventError::InvalidDate {
+        field: field.to_string(),
+        value: date_str.to_string(),
+    })
+}
+
+/// Parses the time string of [field] in HH:MM format
+fn parse_time(field: &str, time_str: &str) -> Result<NaiveTime, EventError> {
+    NaiveTime::parse_from_str(time_str, "%H:%M").map_err(|_| EventError::InvalidTime {
+        field: field.to_string(),
+        value: time_str.to_string(),
+    })
+}
+
+/// Parses a recurrence string to Recurrence enum; anything but an empty string,
+/// "none" or one of rcal-lib's recurrences is an error rather than no recurrence
+fn parse_recurrence(recurrence: &str) -> Result<Recurrence, EventError> {
+    let parsed = Recurrence::from_storage_string(recurrence);
+    let name = recurrence.trim();
+    if parsed == Recurrence::None && !name.is_empty() && !name.eq_ignore_ascii_case("none") {
+        return Err(invalid_recurrence(
+            recurrence,
+            "expected none, daily, weekly, monthly, yearly or an RRULE",
+        ));
+    }
+    Ok(parsed)
 }
 
-/// Parses a time string in HH:MM format
-fn parse_time(time_str: &str) -> Result<NaiveTime, String> {
-    NaiveTime::parse_from_str(time_str, "%H:%M")
-        .map_err(|e| format!("Invalid time format '{}': {}", time_str, e))
+// Helper function to tell RRULE text from rcal-lib's recurrence names
+fn is_rrule_text(recurrence: &str) -> bool {
+    let text = recurrence.trim_start().to_ascii_uppercase();
+    text.starts_with("RRULE:") || text.starts_with("FREQ=")
 }
 
-/// Parses a recurrence string to Recurrence enum
-fn parse_recurrence(recurrence: &str) -> Recurrence {
-    Recurrence::from_storage_string(recurrence)
+// Helper function to tell whether an occurrence starting on [occurrence_start] and
+// lasting [span] overlaps [start]..=[end]
+fn occurrence_overlaps(
+    occurrence_start: NaiveDate,
+    span: chrono::Duration,
+    start: NaiveDate,
+    end: NaiveDate,
+) -> bool {
+    occurrence_start <= end
+        && occurrence_start
+            .checked_add_signed(span)
+            .is_none_or(|occurrence_end| occurrence_end >= start)
 }
 
-/// Checks if an event occurs within a date range (inclusive)
-fn event_occurs_in_range(event: &CalendarEvent, start: NaiveDate, end: NaiveDate) -> bool {
-    // For recurring events, check if any instance falls in range
+/// The start dates of a record's occurrences up to [until] (inclusive), in order,
+/// beginning with the event's own start date.
+fn occurrence_starts(record: &EventRecord, until: NaiveDate) -> Vec<NaiveDate> {
+    let event = &record.event;
+    if event.start_date > until {
+        return Vec::new();
+    }
+    if let Some(rule) = &record.rule {
+        return expand_rule(rule, event.start_date, until);
+    }
+    let mut starts = vec![event.start_date];
     if event.recurrence != Recurrence::None {
-        // Check if the base event is in range
-        if event.start_date >= start && event.start_date <= end {
-            return true;
-        }
-        // Generate a few instances to check (simple check for start date + 1 year)
-        let instances = FileEventRepository::generate_recurring_instances(event, end);
-        for instance in instances {
-            if instance.start_date >= start && instance.start_date <= end {
-                return true;
-            }
-        }
-        false
-    } else {
-        // For non-recurring events, check if the event's date range overlaps
-        let event_end = event.effective_end_date();
-        event_end >= start && event.start_date <= end
+        starts.extend(
+            FileEventRepository::generate_recurring_instances(event, until)
+                .iter()
+                .map(|instance| instance.start_date),
+        );
     }
+    starts
 }
 
-/// Creates a CalendarEvent from the input parameters
+/// Checks if an event occurs within a date range (inclusive): some occurrence,
+/// running from its start date through effective_end_date(), overlaps the range.
+fn event_occurs_in_range(record: &EventRecord, start: NaiveDate, end: NaiveDate) -> bool {
+    if record.has_exceptions() {
+        return !record_instances(record, start, end).is_empty();
+    }
+    // Every occurrence lasts as long as the base event, as in CalendarEvent::occurs_on
+    let event = &record.event;
+    let span = event.effective_end_date() - event.start_date;
+    // Occurrences starting after the range can't overlap it, so expanding up to
+    // [end] covers every candidate, including ones that start before the range
+    occurrence_starts(record, end)
+        .into_iter()
+        .any(|occurrence_start| occurrence_overlaps(occurrence_start, span, start, end))
+}
+
+/// Creates an event from [input], with a new id unless [existing_id] is given.
 fn create_calendar_event(
-    title: String,
-    description: String,
-    start_date: String,
-    end_date: Option<String>,
-    start_time: Option<String>,
-    end_time: Option<String>,
-    is_all_day: bool,
-    recurrence: String,
+    input: EventInput,
     existing_id: Option<String>,
-) -> Result<CalendarEvent, String> {
-    let start = parse_date(&start_date)?;
+) -> Result<EventRecord, EventError> {
+    let EventInput {
+        title,
+        description,
+        start_date,
+        end_date,
+        start_time,
+        end_time,
+        is_all_day,
+        recurrence,
+        rrule,
+        time_zone,
+    } = input;
+    if !is_valid_title(&title) {
+        return Err(EventError::TitleInvalid(title));
+    }
+    let start = parse_date("start_date", &start_date)?;
     // If end_date is provided but equals start_date, treat it as None (no end date specified)
     let end = match end_date {
         Some(ref d) if d == &start_date => None,
-        _ => end_date.map(|d| parse_date(&d)).transpose()?,
+        _ => end_date.map(|d| parse_date("end_date", &d)).transpose()?,
     };
 
     let start_t = if is_all_day {
         NaiveTime::from_hms_opt(0, 0, 0).unwrap()
     } else {
         match start_time {
-            Some(t) => parse_time(&t)?,
-            None => return Err("Start time is required for non-all-day events".to_string()),
+            Some(t) => parse_time("start_time", &t)?,
+            None => return Err(EventError::MissingField("start_time".to_string())),
         }
     };
 
@@ -148,14 +723,35 @@ fn create_calendar_event(
         None
     } else {
         match end_time {
-            Some(t) => Some(parse_time(&t)?),
+            Some(t) => Some(parse_time("end_time", &t)?),
             None => None,
         }
     };
 
-    let recurrence_enum = parse_recurrence(&recurrence);
+    let rule = match rrule {
+        Some(rule) => {
+            validate_rule(&rule)
+                .map_err(|reason| invalid_recurrence(&format_rrule(&rule), reason))?;
+            Some(rule)
+        }
+        None if is_rrule_text(&recurrence) => Some(parse_rrule(&recurrence)?),
+        None => None,
+    };
+    if let Some(until) = rule.as_ref().and_then(|r| r.until.as_deref()) {
+        if parse_date("until", until)? < start {
+            return Err(EventError::InvalidRange {
+                field: "until".to_string(),
+            });
+        }
+    }
+    let recurrence_enum = match &rule {
+        Some(rule) => rule_recurrence(rule),
+        None => parse_recurrence(&recurrence)?,
+    };
+    // All-day events cover the same dates everywhere
+    let zone = parse_time_zone(time_zone.as_deref())?.filter(|_| !is_all_day);
 
-    Ok(CalendarEvent {
+    let event = CalendarEvent {
         id: existing_id.unwrap_or_else(|| uuid::Uuid::new_v4().to_string()),
         title,
         description,
@@ -167,934 +763,5207 @@ fn create_calendar_event(
         recurrence: recurrence_enum,
         is_recurring_instance: false,
         base_date: None,
+    };
+    Ok(EventRecord {
+        rule,
+        time_zone: zone,
+        ..EventRecord::from(event)
     })
 }
 
-// Helper function for credentials
-fn get_credentials(
-    url: &str,
-    username: &Option<String>,
-    password: &Option<String>,
-    ssh_key_path: &Option<String>,
-) -> Result<git2::Cred, git2::Error> {
-    if url.starts_with("http") || url.starts_with("https") {
-        if let (Some(user), Some(pass)) = (username, password) {
-            git2::Cred::userpass_plaintext(user, pass)
-        } else {
-            Err(git2::Error::from_str("Credentials required for HTTPS"))
-        }
-    } else {
-        if let Some(key_path) = ssh_key_path {
-            git2::Cred::ssh_key("git", None, Path::new(key_path), None)
-        } else {
-            git2::Cred::ssh_key_from_agent("git")
+impl GitCredentials {
+    // Helper function to turn the credentials into what libgit2 asked for, if they fit
+    fn to_cred(
+        &self,
+        username_from_url: Option<&str>,
+        allowed: git2::CredentialType,
+    ) -> Result<Option<git2::Cred>, GitError> {
+        let ssh_user = |username: &Option<String>| {
+            username
+                .as_deref()
+                .or(username_from_url)
+                .unwrap_or("git")
+                .to_string()
+        };
+        let password = allowed.contains(git2::CredentialType::USER_PASS_PLAINTEXT);
+        let ssh_key = allowed.contains(git2::CredentialType::SSH_KEY)
+            || allowed.contains(git2::CredentialType::SSH_MEMORY);
+        let cred = match self {
+            GitCredentials::UserPassword {
+                username,
+                password: secret,
+            } if password => git2::Cred::userpass_plaintext(username, secret)?,
+            GitCredentials::Token { username, token } if password => {
+                let user = username
+                    .as_deref()
+                    .or(username_from_url)
+                    .unwrap_or("x-access-token");
+                git2::Cred::userpass_plaintext(user, token)?
+            }
+            GitCredentials::SshKeyFile {
+                username,
+                private_key_path,
+                public_key_path,
+                passphrase,
+            } if ssh_key => git2::Cred::ssh_key(
+                &ssh_user(username),
+                public_key_path.as_deref().map(Path::new),
+                Path::new(private_key_path),
+                passphrase.as_deref(),
+            )?,
+            GitCredentials::SshKeyMemory {
+                username,
+                private_key,
+                public_key,
+                passphrase,
+            } if ssh_key => git2::Cred::ssh_key_from_memory(
+                &ssh_user(username),
+                public_key.as_deref(),
+                private_key,
+                passphrase.as_deref(),
+            )?,
+            GitCredentials::SshAgent { username } if ssh_key => {
+                git2::Cred::ssh_key_from_agent(&ssh_user(username))?
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(cred))
+    }
+
+    fn ssh_username(&self) -> Option<&str> {
+        match self {
+            GitCredentials::SshKeyFile { username, .. }
+            | GitCredentials::SshKeyMemory { username, .. }
+            | GitCredentials::SshAgent { username } => username.as_deref(),
+            _ => None,
         }
     }
 }
 
-// Helper function to extract branch name from repo or remote default
-pub(crate) fn extract_branch_name(_repo: &Repository) -> String {
-    "main".to_string()
+// Helper function for the credentials given as plain arguments: user and password
+// for HTTPS, then the key file or the agent for SSH
+fn legacy_credentials(
+    username: Option<String>,
+    password: Option<String>,
+    ssh_key_path: Option<String>,
+) -> Vec<GitCredentials> {
+    let mut credentials = Vec::new();
+    if let (Some(username), Some(password)) = (username, password) {
+        credentials.push(GitCredentials::UserPassword { username, password });
+    }
+    credentials.push(match ssh_key_path {
+        Some(private_key_path) => GitCredentials::SshKeyFile {
+            username: None,
+            private_key_path,
+            public_key_path: None,
+            passphrase: None,
+        },
+        None => GitCredentials::SshAgent { username: None },
+    });
+    credentials
 }
 
-// Helper function for certificate validation
-fn validate_certificate(hostname: &str, cert_data: &[u8]) -> Result<(), String> {
-    // Check hostname using x509-parser
-    let (_, cert) = x509_parser::parse_x509_certificate(cert_data)
-        .map_err(|e| format!("Failed to parse certificate for hostname check: {}", e))?;
-    let sans = cert.subject_alternative_name();
-    if let Ok(Some(sans)) = sans {
-        for san in &sans.value.general_names {
-            if let GeneralName::DNSName(dns) = san {
-                if *dns == hostname {
-                    return Ok(());
-                }
-            }
-        }
-    }
-    // Also check common name
-    if let Some(cn) = cert.subject().iter_common_name().next() {
-        if let Ok(cn_str) = cn.as_str() {
-            if cn_str == hostname {
-                return Ok(());
-            }
-        }
-    }
-    Err(format!(
-        "Hostname '{}' does not match certificate",
-        hostname
-    ))
+pub(crate) type CredentialCallback =
+    Arc<dyn Fn(CredentialRequest) -> DartFnFuture<Option<GitCredentials>> + Send + Sync>;
+
+/// How often one operation asks the credential callback before giving up.
+const MAX_CREDENTIAL_PROMPTS: u32 = 3;
+
+fn credential_callback() -> &'static Mutex<Option<CredentialCallback>> {
+    static CALLBACK: OnceLock<Mutex<Option<CredentialCallback>>> = OnceLock::new();
+    CALLBACK.get_or_init(|| Mutex::new(None))
 }
 
-// Define the API struct
-#[flutter_rust_bridge::frb(init)]
-pub fn init_app() {
-    // Default utilities - feel free to customize
-    flutter_rust_bridge::setup_default_user_utils();
+/// Registers a callback that supplies credentials when those passed to an operation
+/// are missing or rejected, e.g. read lazily from secure storage. It is asked again,
+/// with a higher attempt, each time the remote rejects what it returned; returning
+/// None gives up.
+#[flutter_rust_bridge::frb]
+pub fn set_credential_callback(
+    callback: impl Fn(CredentialRequest) -> DartFnFuture<Option<GitCredentials>> + Send + Sync + 'static,
+) {
+    *credential_callback()
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner) = Some(Arc::new(callback));
 }
 
-// Placeholder for Git operations
+/// Removes the callback registered with set_credential_callback.
 #[flutter_rust_bridge::frb]
-pub fn add(left: i32, right: i32) -> i32 {
-    left + right
+pub fn clear_credential_callback() {
+    *credential_callback()
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner) = None;
 }
 
-// Git operations
-fn git_init_impl(path: String) -> Result<String, GitError> {
-    Repository::init(&path)?;
-    Ok("Initialized empty Git repository".to_string())
+// Helper function to open the repository at [path], reporting a missing one as RepositoryNotFound
+fn open_repository(path: &str) -> Result<Repository, GitError> {
+    Repository::open(path).map_err(|e| match e.code() {
+        git2::ErrorCode::NotFound => GitError::RepositoryNotFound(e.message().to_string()),
+        _ => e.into(),
+    })
 }
 
-#[flutter_rust_bridge::frb]
-pub fn git_init(path: String) -> Result<String, GitError> {
-    git_init_impl(path)
+/// Local branch to sync and the remote branch it tracks.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct SyncBranch {
+    pub local: String,
+    pub upstream: String,
 }
 
-#[flutter_rust_bridge::frb]
-pub fn git_clone(
-    url: String,
-    path: String,
-    username: Option<String>,
-    password: Option<String>,
-    ssh_key_path: Option<String>,
-) -> Result<String, GitError> {
-    let mut callbacks = git2::RemoteCallbacks::new();
-    let username = username.clone();
-    let password = password.clone();
-    let ssh_key_path = ssh_key_path.clone();
-    let _url_clone = url.clone();
-    callbacks
-        .credentials(move |url, _, _| get_credentials(url, &username, &password, &ssh_key_path));
-    callbacks.certificate_check(move |cert, hostname| {
-        if let Some(x509) = cert.as_x509() {
-            match validate_certificate(hostname, x509.data()) {
-                Ok(()) => Ok(git2::CertificateCheckStatus::CertificateOk),
-                Err(e) => Err(git2::Error::from_str(&e)),
+/// Resolves the branch used by pull, push and fetch.
+/// An explicit override wins; otherwise HEAD is followed, then the upstream
+/// tracking config of that branch. When the current branch has no commits yet
+/// the remote's default HEAD is followed: `refs/remotes/<remote>/HEAD` if a clone
+/// left one, else what the remote reports when [session] is given to connect.
+pub(crate) fn resolve_sync_branch(
+    repo: &Repository,
+    remote_name: &str,
+    branch_override: Option<&str>,
+    session: Option<&RemoteSession>,
+) -> Result<SyncBranch, GitError> {
+    if let Some(branch) = branch_override {
+        let branch = branch.strip_prefix("refs/heads/").unwrap_or(branch);
+        return Ok(SyncBranch {
+            local: branch.to_string(),
+            upstream: branch.to_string(),
+        });
+    }
+
+    let head_target = match repo.head() {
+        Ok(head) => {
+            if !head.is_branch() {
+                let oid = head.target().map(|o| o.to_string()).unwrap_or_default();
+                return Err(GitError::DetachedHead(oid));
             }
-        } else {
-            Ok(git2::CertificateCheckStatus::CertificateOk)
+            head.name().map(|n| n.to_string())
         }
-    });
-    let mut fetch_options = git2::FetchOptions::new();
-    fetch_options.remote_callbacks(callbacks);
-    let mut builder = git2::build::RepoBuilder::new();
-    builder.fetch_options(fetch_options);
-    builder.clone(&url, Path::new(&path))?;
-    Ok("Repository cloned successfully".to_string())
-}
+        Err(e) if e.code() == git2::ErrorCode::UnbornBranch => None,
+        Err(e) => return Err(e.into()),
+    };
+    let born = head_target.is_some();
+    // For an unborn branch HEAD is still a symbolic ref naming the branch
+    let head_target = match head_target {
+        Some(name) => name,
+        None => repo
+            .find_reference("HEAD")?
+            .symbolic_target()
+            .map(|t| t.to_string())
+            .ok_or_else(|| GitError::DetachedHead(String::new()))?,
+    };
+    let local = head_target
+        .strip_prefix("refs/heads/")
+        .unwrap_or(&head_target)
+        .to_string();
 
-#[flutter_rust_bridge::frb]
-pub fn git_current_branch(path: String) -> Result<String, GitError> {
-    let repo = Repository::open(&path)?;
-    let head_ref = repo.head()?;
-    let branch_name = head_ref
-        .name()
-        .and_then(|n| n.strip_prefix("refs/heads/"))
-        .unwrap_or("unknown");
-    Ok(branch_name.to_string())
-}
+    if let Some(upstream) = upstream_branch_name(repo, &local, remote_name) {
+        return Ok(SyncBranch { local, upstream });
+    }
+    if born {
+        return Ok(SyncBranch {
+            upstream: local.clone(),
+            local,
+        });
+    }
 
-#[flutter_rust_bridge::frb]
-pub fn git_list_branches(path: String) -> Result<Vec<String>, GitError> {
-    let repo = Repository::open(&path)?;
-    let branches = repo.branches(None)?;
-    let mut branch_names = Vec::new();
-    for branch_result in branches {
-        let (branch, _) = branch_result?;
-        if let Some(name) = branch.name()? {
-            branch_names.push(name.to_string());
-        }
+    // Nothing committed yet: follow whatever the remote considers its default branch
+    let remote_head = format!("refs/remotes/{}/HEAD", remote_name);
+    let mut default_branch = repo
+        .find_reference(&remote_head)
+        .ok()
+        .and_then(|r| r.symbolic_target().map(|t| t.to_string()))
+        .and_then(|t| {
+            t.strip_prefix(&format!("refs/remotes/{}/", remote_name))
+                .map(|b| b.to_string())
+        });
+    if let (None, Some(session)) = (&default_branch, session) {
+        default_branch = remote_default_branch(repo, remote_name, session)?;
+    }
+    match default_branch {
+        Some(branch) => Ok(SyncBranch {
+            local: branch.clone(),
+            upstream: branch,
+        }),
+        None => Err(GitError::UnbornBranch(local)),
     }
-    Ok(branch_names)
 }
 
-// Helper function to check if there are local uncommitted changes
-fn has_local_changes(repo: &Repository) -> Result<bool, GitError> {
-    let statuses = repo.statuses(None)?;
-    Ok(statuses.iter().any(|entry| {
-        let status = entry.status();
-        status.contains(git2::Status::WT_MODIFIED)
-            || status.contains(git2::Status::WT_DELETED)
-            || status.contains(git2::Status::WT_NEW)
-            || status.contains(git2::Status::INDEX_MODIFIED)
-            || status.contains(git2::Status::INDEX_DELETED)
-            || status.contains(git2::Status::INDEX_NEW)
-    }))
+// Helper function to ask [remote_name] which branch its HEAD points to. A remote
+// that doesn't say is followed to its only branch; None when it has no single branch
+fn remote_default_branch(
+    repo: &Repository,
+    remote_name: &str,
+    session: &RemoteSession,
+) -> Result<Option<String>, GitError> {
+    let mut remote = repo.find_remote(remote_name)?;
+    let connection = remote
+        .connect_auth(git2::Direction::Fetch, Some(session.callbacks()), None)
+        .map_err(|e| session.error(e))?;
+    if let Ok(head) = connection.default_branch() {
+        if let Some(branch) = head.as_str().and_then(|h| h.strip_prefix("refs/heads/")) {
+            return Ok(Some(branch.to_string()));
+        }
+    }
+    let branches: Vec<&str> = connection
+        .list()?
+        .iter()
+        .filter_map(|head| head.name().strip_prefix("refs/heads/"))
+        .collect();
+    Ok(match branches.as_slice() {
+        [branch] => Some(branch.to_string()),
+        _ => None,
+    })
+}
+
+// Reads branch.<name>.merge, but only when it tracks the remote being synced
+fn upstream_branch_name(repo: &Repository, local: &str, remote_name: &str) -> Option<String> {
+    let config = repo.config().ok()?;
+    let remote = config
+        .get_string(&format!("branch.{}.remote", local))
+        .ok()?;
+    if remote != remote_name {
+        return None;
+    }
+    let merge = config.get_string(&format!("branch.{}.merge", local)).ok()?;
+    Some(
+        merge
+            .strip_prefix("refs/heads/")
+            .unwrap_or(&merge)
+            .to_string(),
+    )
+}
+
+/// How far a server certificate could be verified locally.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum CertificateTrust {
+    /// Chains to a trusted CA and is valid for the host
+    Verified,
+    /// Valid for the host, but issued by a CA this side doesn't know. libgit2 only
+    /// hands over the leaf, so this is also the result for chains through an
+    /// intermediate, unless the intermediate is registered; libgit2's own validation,
+    /// which sees the whole chain, then decides against the platform's CA store.
+    IssuerUnknown,
+}
+
+static SUPPORTED_SIG_ALGS: &[&webpki::SignatureAlgorithm] = &[
+    &webpki::ECDSA_P256_SHA256,
+    &webpki::ECDSA_P256_SHA384,
+    &webpki::ECDSA_P384_SHA256,
+    &webpki::ECDSA_P384_SHA384,
+    &webpki::ED25519,
+    &webpki::RSA_PKCS1_2048_8192_SHA256,
+    &webpki::RSA_PKCS1_2048_8192_SHA384,
+    &webpki::RSA_PKCS1_2048_8192_SHA512,
+    &webpki::RSA_PKCS1_3072_8192_SHA384,
+    &webpki::RSA_PSS_2048_8192_SHA256_LEGACY_KEY,
+    &webpki::RSA_PSS_2048_8192_SHA384_LEGACY_KEY,
+    &webpki::RSA_PSS_2048_8192_SHA512_LEGACY_KEY,
+];
+
+// Helper function to validate a server certificate at [now] (seconds since the epoch):
+// chain and expiry against webpki-roots plus [extra_cas] (DER), then the host name,
+// which may match a wildcard DNS SAN or an IP SAN
+pub(crate) fn validate_certificate(
+    hostname: &str,
+    cert_der: &[u8],
+    extra_cas: &[Vec<u8>],
+    now: u64,
+) -> Result<CertificateTrust, CertificateError> {
+    let cert = webpki::EndEntityCert::try_from(cert_der)
+        .map_err(|e| CertificateError::Malformed(format!("{:?}", e)))?;
+    let mut anchors: Vec<webpki::TrustAnchor> = webpki_roots::TLS_SERVER_ROOTS
+        .0
+        .iter()
+        .map(|a| webpki::TrustAnchor {
+            subject: a.subject,
+            spki: a.spki,
+            name_constraints: a.name_constraints,
+        })
+        .collect();
+    anchors.extend(
+        extra_cas
+            .iter()
+            .filter_map(|der| webpki::TrustAnchor::try_from_cert_der(der).ok()),
+    );
+    // Registered CAs may also be intermediates of a chain to a public root
+    let intermediates: Vec<&[u8]> = extra_cas.iter().map(|der| der.as_slice()).collect();
+    let trust = match cert.verify_is_valid_tls_server_cert(
+        SUPPORTED_SIG_ALGS,
+        &webpki::TlsServerTrustAnchors(&anchors),
+        &intermediates,
+        webpki::Time::from_seconds_since_unix_epoch(now),
+    ) {
+        Ok(()) => CertificateTrust::Verified,
+        Err(webpki::Error::UnknownIssuer) => CertificateTrust::IssuerUnknown,
+        Err(e) => return Err(certificate_error(e)),
+    };
+    verify_certificate_name(hostname, &cert, cert_der)?;
+    Ok(trust)
+}
+
+fn certificate_error(err: webpki::Error) -> CertificateError {
+    match err {
+        webpki::Error::CertExpired => CertificateError::Expired,
+        webpki::Error::CertNotValidYet => CertificateError::NotYetValid,
+        webpki::Error::UnknownIssuer => CertificateError::UnknownIssuer,
+        webpki::Error::CertNotValidForName => CertificateError::NameMismatch,
+        webpki::Error::InvalidSignatureForPublicKey
+        | webpki::Error::SignatureAlgorithmMismatch
+        | webpki::Error::UnsupportedSignatureAlgorithm
+        | webpki::Error::UnsupportedSignatureAlgorithmForPublicKey => {
+            CertificateError::InvalidSignature
+        }
+        webpki::Error::BadDer | webpki::Error::BadDerTime => {
+            CertificateError::Malformed(format!("{:?}", err))
+        }
+        other => CertificateError::Other(format!("{:?}", other)),
+    }
+}
+
+// Helper function to match the host against the certificate's SANs; the common name
+// is not consulted, as browsers and webpki no longer do
+fn verify_certificate_name(
+    hostname: &str,
+    cert: &webpki::EndEntityCert,
+    cert_der: &[u8],
+) -> Result<(), CertificateError> {
+    let host = hostname.trim_start_matches('[').trim_end_matches(']');
+    if let Ok(ip) = host.parse::<std::net::IpAddr>() {
+        // webpki 0.22 only matches DNS names, so IP SANs are read with x509-parser
+        let (_, parsed) = x509_parser::parse_x509_certificate(cert_der)
+            .map_err(|e| CertificateError::Malformed(e.to_string()))?;
+        let octets = match ip {
+            std::net::IpAddr::V4(v4) => v4.octets().to_vec(),
+            std::net::IpAddr::V6(v6) => v6.octets().to_vec(),
+        };
+        let matches = parsed
+            .subject_alternative_name()
+            .ok()
+            .flatten()
+            .is_some_and(|sans| {
+                sans.value
+                    .general_names
+                    .iter()
+                    .any(|name| matches!(name, GeneralName::IPAddress(bytes) if *bytes == octets))
+            });
+        return if matches {
+            Ok(())
+        } else {
+            Err(CertificateError::NameMismatch)
+        };
+    }
+    let name =
+        webpki::DnsNameRef::try_from_ascii_str(host).map_err(|_| CertificateError::NameMismatch)?;
+    cert.verify_is_valid_for_dns_name(name)
+        .map_err(|_| CertificateError::NameMismatch)
+}
+
+// CAs registered through set_ssl_ca_certs for every host, DER encoded
+fn registered_ca_certs() -> &'static Mutex<Vec<Vec<u8>>> {
+    static CERTS: OnceLock<Mutex<Vec<Vec<u8>>>> = OnceLock::new();
+    CERTS.get_or_init(|| Mutex::new(Vec::new()))
+}
+
+// Helper function to decode the CERTIFICATE blocks of a PEM text
+pub(crate) fn pem_certificates(pem: &str) -> Result<Vec<Vec<u8>>, GitError> {
+    let mut certs = Vec::new();
+    let mut body: Option<String> = None;
+    for line in pem.lines().map(str::trim) {
+        if line == "-----BEGIN CERTIFICATE-----" {
+            body = Some(String::new());
+        } else if line == "-----END CERTIFICATE-----" {
+            let text = body.take().unwrap_or_default();
+            let der = BASE64_STANDARD
+                .decode(&text)
+                .map_err(|_| GitError::Other("Invalid PEM certificate".to_string()))?;
+            certs.push(der);
+        } else if let Some(text) = body.as_mut() {
+            text.push_str(line);
+        }
+    }
+    Ok(certs)
+}
+
+// Helper function to PEM encode one DER certificate
+fn pem_certificate(der: &[u8]) -> String {
+    let mut pem = "-----BEGIN CERTIFICATE-----\n".to_string();
+    let encoded = BASE64_STANDARD.encode(der);
+    for chunk in encoded.as_bytes().chunks(64) {
+        pem.push_str(&String::from_utf8_lossy(chunk));
+        pem.push('\n');
+    }
+    pem.push_str("-----END CERTIFICATE-----\n");
+    pem
+}
+
+fn unix_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// What a network operation reports progress to and checks for cancellation.
+#[derive(Clone, Copy, Default)]
+pub(crate) struct OperationHooks<'a> {
+    pub progress: Option<&'a dyn Fn(GitProgress)>,
+    pub cancel: Option<&'a AtomicBool>,
+}
+
+impl OperationHooks<'_> {
+    fn report(&self, event: GitProgress) {
+        if let Some(progress) = self.progress {
+            progress(event);
+        }
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancel.is_some_and(|c| c.load(Ordering::SeqCst))
+    }
+}
+
+// Helper function to forward transfer, push and sideband progress to the hooks.
+// Returning false from a callback makes libgit2 stop the transfer once cancelled.
+fn attach_hooks<'a>(callbacks: &mut git2::RemoteCallbacks<'a>, hooks: OperationHooks<'a>) {
+    callbacks.transfer_progress(move |stats| {
+        hooks.report(GitProgress::Transfer {
+            received_objects: stats.received_objects(),
+            total_objects: stats.total_objects(),
+            indexed_objects: stats.indexed_objects(),
+            received_bytes: stats.received_bytes(),
+            total_deltas: stats.total_deltas(),
+            indexed_deltas: stats.indexed_deltas(),
+        });
+        !hooks.is_cancelled()
+    });
+    callbacks.push_transfer_progress(move |current, total, bytes| {
+        hooks.report(GitProgress::PushTransfer {
+            current,
+            total,
+            bytes,
+        });
+    });
+    callbacks.sideband_progress(move |data| {
+        hooks.report(GitProgress::Sideband(
+            String::from_utf8_lossy(data).to_string(),
+        ));
+        !hooks.is_cancelled()
+    });
+    // Last chance to stop a push before anything is sent
+    callbacks.push_negotiation(move |_| {
+        if hooks.is_cancelled() {
+            Err(git2::Error::from_str("Operation cancelled"))
+        } else {
+            Ok(())
+        }
+    });
+}
+
+// Helper function to report files written by a checkout and stop it once cancelled
+fn attach_checkout_hooks<'a>(
+    builder: &mut git2::build::CheckoutBuilder<'a>,
+    hooks: OperationHooks<'a>,
+) {
+    builder.progress(move |path, completed, total| {
+        hooks.report(GitProgress::Checkout {
+            path: path.map(|p| p.to_string_lossy().to_string()),
+            completed,
+            total,
+        });
+    });
+    builder.notify_on(git2::CheckoutNotificationType::UPDATED);
+    builder.notify(move |_, _, _, _, _| !hooks.is_cancelled());
+}
+
+// Cancellation tokens of registered operations, keyed by handle
+fn operation_tokens() -> &'static Mutex<HashMap<u64, Arc<AtomicBool>>> {
+    static TOKENS: OnceLock<Mutex<HashMap<u64, Arc<AtomicBool>>>> = OnceLock::new();
+    TOKENS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+static NEXT_OPERATION: AtomicU64 = AtomicU64::new(1);
+
+// Helper function to run an operation under the token registered for its handle.
+// The handle is released afterwards, and a failure after cancel_operation becomes Cancelled.
+fn run_cancellable<T>(
+    operation: Option<u64>,
+    run: impl FnOnce(Option<&AtomicBool>) -> Result<T, GitError>,
+) -> Result<T, GitError> {
+    let token = match operation {
+        Some(handle) => Some(
+            operation_tokens()
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .get(&handle)
+                .cloned()
+                .ok_or_else(|| GitError::Other(format!("Unknown operation handle {}", handle)))?,
+        ),
+        None => None,
+    };
+    let result = run(token.as_deref());
+    if let Some(handle) = operation {
+        operation_tokens()
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&handle);
+    }
+    match result {
+        Err(_) if token.is_some_and(|t| t.load(Ordering::SeqCst)) => Err(GitError::Cancelled),
+        other => other,
+    }
 }
 
+/// Registers a cancellable operation and returns its handle.
+/// Pass the handle to a *_with_progress function, then cancel_operation to abort it.
 #[flutter_rust_bridge::frb]
-pub fn git_has_local_changes(path: String) -> Result<bool, GitError> {
-    let repo = Repository::open(&path)?;
-    has_local_changes(&repo)
+pub fn start_operation() -> u64 {
+    let handle = NEXT_OPERATION.fetch_add(1, Ordering::SeqCst);
+    operation_tokens()
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(handle, Arc::new(AtomicBool::new(false)));
+    handle
 }
 
-/// Pull from remote repository, handling local uncommitted changes by stashing them.
-/// If pull succeeds, attempts to restore stashed changes.
-/// If stash pop fails due to conflicts, drops the stash to prefer remote changes.
-fn git_pull_impl(
-    path: String,
-    username: Option<String>,
-    password: Option<String>,
-    ssh_key_path: Option<String>,
-) -> Result<String, GitError> {
-    let mut repo = Repository::open(&path)?;
-    let has_changes = has_local_changes(&repo)?;
-    let mut stashed = false;
-    let old_tree = repo.head()?.peel_to_tree()?;
-    let old_oid = old_tree.id();
-    drop(old_tree);
-    let mut new_tree: Option<git2::Tree> = None;
+/// Asks the operation behind [handle] to stop at its next progress callback.
+/// Returns false if the handle is unknown or the operation already finished.
+#[flutter_rust_bridge::frb]
+pub fn cancel_operation(handle: u64) -> bool {
+    match operation_tokens()
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(&handle)
+    {
+        Some(token) => {
+            token.store(true, Ordering::SeqCst);
+            true
+        }
+        None => false,
+    }
+}
 
-    // Stash local changes to allow pull to update working directory
-    // Handle gracefully - if stash fails (e.g., "nothing to stash"), just proceed without stashing
-    if has_changes {
-        let signature = git2::Signature::now("App", "app@example.com")?;
-        match repo.stash_save(&signature, "Stashed by app during pull", None) {
-            Ok(_) => {
-                stashed = true;
+// ============================================================================
+// Remote Session and SSH Host Keys
+// ============================================================================
+
+/// Credentials, server verification and hooks shared by clone, fetch, pull and push.
+/// Every network operation builds its callbacks here so they all authenticate
+/// and verify servers the same way.
+pub(crate) struct RemoteSession<'a> {
+    credentials: Vec<GitCredentials>,
+    callback: Option<CredentialCallback>,
+    // How many of the credentials were offered, and how often the callback was asked;
+    // libgit2 asks again after each rejection
+    offered: std::cell::Cell<usize>,
+    prompts: std::cell::Cell<u32>,
+    hooks: OperationHooks<'a>,
+    // Why the last certificate check failed; libgit2 only passes on a generic error
+    rejection: std::cell::RefCell<Option<GitError>>,
+    // Set when a certificate was left to libgit2's validation, and used if that fails
+    unverified: std::cell::RefCell<Option<GitError>>,
+    // The host whose certificate was checked last, for TLS errors libgit2 reports without it
+    host: std::cell::RefCell<Option<String>>,
+}
+
+impl<'a> RemoteSession<'a> {
+    pub(crate) fn new(
+        username: Option<String>,
+        password: Option<String>,
+        ssh_key_path: Option<String>,
+        hooks: OperationHooks<'a>,
+    ) -> Self {
+        let callback = credential_callback()
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+        RemoteSession {
+            credentials: legacy_credentials(username, password, ssh_key_path),
+            callback: None,
+            offered: std::cell::Cell::new(0),
+            prompts: std::cell::Cell::new(0),
+            hooks,
+            rejection: std::cell::RefCell::new(None),
+            unverified: std::cell::RefCell::new(None),
+            host: std::cell::RefCell::new(None),
+        }
+        .with_credential_callback(callback)
+    }
+
+    /// Replaces the callback registered with set_credential_callback for this session.
+    pub(crate) fn with_credential_callback(mut self, callback: Option<CredentialCallback>) -> Self {
+        self.callback = callback;
+        self
+    }
+
+    // Offers the credentials passed to the operation in order, then asks the callback
+    pub(crate) fn next_credential(
+        &self,
+        url: &str,
+        username_from_url: Option<&str>,
+        allowed: git2::CredentialType,
+    ) -> Result<git2::Cred, GitError> {
+        // SSH asks for the user name alone before it asks for a key
+        if allowed == git2::CredentialType::USERNAME {
+            let user = self
+                .credentials
+                .iter()
+                .find_map(GitCredentials::ssh_username)
+                .unwrap_or("git");
+            return Ok(git2::Cred::username(user)?);
+        }
+        while self.offered.get() < self.credentials.len() {
+            let candidate = &self.credentials[self.offered.get()];
+            self.offered.set(self.offered.get() + 1);
+            if let Some(cred) = candidate.to_cred(username_from_url, allowed)? {
+                return Ok(cred);
             }
-            Err(_) => {
-                // Don't error out - just proceed without stashing
-                stashed = false;
+        }
+        if let Some(callback) = &self.callback {
+            while self.prompts.get() < MAX_CREDENTIAL_PROMPTS {
+                self.prompts.set(self.prompts.get() + 1);
+                let request = CredentialRequest {
+                    url: url.to_string(),
+                    username_from_url: username_from_url.map(str::to_string),
+                    allow_password: allowed.contains(git2::CredentialType::USER_PASS_PLAINTEXT),
+                    allow_ssh_key: allowed.contains(git2::CredentialType::SSH_KEY)
+                        || allowed.contains(git2::CredentialType::SSH_MEMORY),
+                    attempt: self.prompts.get(),
+                };
+                // Operations run on a worker thread, so waiting for Dart doesn't block the UI.
+                // That thread may belong to an async runtime, which can't start another
+                match futures::executor::block_on(callback(request)) {
+                    Some(credentials) => {
+                        if let Some(cred) = credentials.to_cred(username_from_url, allowed)? {
+                            return Ok(cred);
+                        }
+                    }
+                    None => break,
+                }
             }
         }
+        Err(GitError::AuthenticationFailed(format!(
+            "No accepted credentials for {}",
+            url
+        )))
     }
 
-    let result = (|| {
-        let branch_name = extract_branch_name(&repo);
-        let mut remote = repo.find_remote("origin")?;
+    pub(crate) fn callbacks(&self) -> git2::RemoteCallbacks<'_> {
         let mut callbacks = git2::RemoteCallbacks::new();
-        let username = username.clone();
-        let password = password.clone();
-        let ssh_key_path = ssh_key_path.clone();
-        callbacks.credentials(move |url, _, _| {
-            get_credentials(url, &username, &password, &ssh_key_path)
+        callbacks.credentials(move |url, username_from_url, allowed| {
+            self.next_credential(url, username_from_url, allowed)
+                .map_err(|e| {
+                    let error = git2::Error::from_str(&format!("{:?}", e));
+                    *self.rejection.borrow_mut() = Some(e);
+                    error
+                })
         });
         callbacks.certificate_check(move |cert, hostname| {
-            if let Some(x509) = cert.as_x509() {
-                match validate_certificate(hostname, x509.data()) {
-                    Ok(()) => Ok(git2::CertificateCheckStatus::CertificateOk),
-                    Err(e) => Err(git2::Error::from_str(&e)),
+            *self.host.borrow_mut() = Some(hostname.to_string());
+            let checked = if let Some(x509) = cert.as_x509() {
+                let process_cas = registered_ca_certs()
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .clone();
+                let certificate_error = |reason| GitError::CertificateInvalid {
+                    host: hostname.to_string(),
+                    reason,
+                };
+                let verdict = trust_store()
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .verify(hostname, x509.data(), &process_cas, unix_now());
+                match verdict {
+                    Ok(CertificateTrust::Verified) => {
+                        Ok(git2::CertificateCheckStatus::CertificateOk)
+                    }
+                    Ok(CertificateTrust::IssuerUnknown) => {
+                        *self.unverified.borrow_mut() =
+                            Some(certificate_error(CertificateError::UnknownIssuer));
+                        Ok(git2::CertificateCheckStatus::CertificatePassthrough)
+                    }
+                    Err(reason) => Err(certificate_error(reason)),
                 }
+            } else if let Some(hostkey) = cert.as_hostkey() {
+                presented_host_key(hostname, hostkey)
+                    .and_then(|key| {
+                        known_hosts_store()
+                            .lock()
+                            .unwrap_or_else(PoisonError::into_inner)
+                            .verify(&key)
+                    })
+                    .map(|()| git2::CertificateCheckStatus::CertificateOk)
             } else {
-                Ok(git2::CertificateCheckStatus::CertificateOk)
+                Err(GitError::Other(format!(
+                    "Unsupported certificate type from {}",
+                    hostname
+                )))
+            };
+            match checked {
+                Ok(status) => Ok(status),
+                Err(e) => {
+                    let error = git2::Error::from_str(&format!("{:?}", e));
+                    *self.rejection.borrow_mut() = Some(e);
+                    Err(error)
+                }
             }
         });
-        let mut fetch_options = git2::FetchOptions::new();
-        fetch_options.remote_callbacks(callbacks);
-        remote.fetch(&[branch_name.as_str()], Some(&mut fetch_options), None)?;
-        let fetch_head = repo.find_reference("FETCH_HEAD")?;
-        let fetch_commit = repo.reference_to_annotated_commit(&fetch_head)?;
-        let analysis = repo.merge_analysis(&[&fetch_commit])?;
-        if analysis.0.is_up_to_date() {
-            // Checkout HEAD to ensure working directory matches HEAD
-            repo.checkout_head(None)?;
+        attach_hooks(&mut callbacks, self.hooks);
+        callbacks
+    }
 
-            Ok("Already up to date".to_string())
-        } else if analysis.0.is_fast_forward() {
-            let refname = format!("refs/heads/{}", branch_name);
-            let mut reference = if let Ok(r) = repo.find_reference(&refname) {
-                r
-            } else {
-                repo.reference(&refname, fetch_commit.id(), true, "Creating branch")?
+    /// Converts a failed git2 call, preferring the reason a server was rejected.
+    pub(crate) fn error(&self, err: git2::Error) -> GitError {
+        if let Some(rejection) = self.rejection.borrow_mut().take() {
+            return rejection;
+        }
+        // A rejected passthrough comes back as a generic SSL error
+        let rejected =
+            err.code() == git2::ErrorCode::Certificate || err.class() == git2::ErrorClass::Ssl;
+        if let Some(unverified) = self.unverified.borrow_mut().take() {
+            if rejected {
+                return unverified;
+            }
+        }
+        match (self.host.borrow().as_ref(), err.class()) {
+            (Some(host), _) if rejected => GitError::CertificateInvalid {
+                host: host.clone(),
+                reason: CertificateError::Other(err.message().to_string()),
+            },
+            // Talking to a remote, OS errors come from the socket: refused,
+            // unresolvable or timed out
+            (_, git2::ErrorClass::Os) => GitError::NetworkUnreachable(err.message().to_string()),
+            // The local transport opens the remote path as a repository
+            (_, git2::ErrorClass::Repository) => {
+                GitError::RepositoryNotFound(err.message().to_string())
+            }
+            _ => err.into(),
+        }
+    }
+}
+
+// Helper function to read the host key an SSH server presented
+fn presented_host_key(host: &str, hostkey: &git2::cert::CertHostkey) -> Result<HostKey, GitError> {
+    let (key, key_type) = match (hostkey.hostkey(), hostkey.hostkey_type()) {
+        (Some(key), Some(key_type)) => (key, key_type),
+        _ => {
+            return Err(GitError::Other(format!(
+                "{} did not send its host key",
+                host
+            )))
+        }
+    };
+    Ok(HostKey {
+        host: host.to_string(),
+        key_type: key_type.name().to_string(),
+        key: BASE64_STANDARD.encode(key),
+        fingerprint: sha256_fingerprint(key),
+    })
+}
+
+// Helper function for an OpenSSH style fingerprint, used for host keys and certificates
+fn sha256_fingerprint(key: &[u8]) -> String {
+    let digest = ring::digest::digest(&ring::digest::SHA256, key);
+    format!("SHA256:{}", BASE64_STANDARD_NO_PAD.encode(digest))
+}
+
+/// Host keys the app trusts, kept in an OpenSSH known_hosts file once
+/// set_known_hosts_path was called and in memory before that.
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Default)]
+pub(crate) struct KnownHostsStore {
+    path: Option<PathBuf>,
+    memory: Vec<HostKey>,
+}
+
+impl KnownHostsStore {
+    pub(crate) fn with_path(path: PathBuf) -> Self {
+        KnownHostsStore {
+            path: Some(path),
+            memory: Vec::new(),
+        }
+    }
+
+    // Reads "host key-type base64-key" lines; hashed hosts and markers are skipped
+    pub(crate) fn entries(&self) -> Result<Vec<HostKey>, GitError> {
+        let path = match &self.path {
+            Some(path) => path,
+            None => return Ok(self.memory.clone()),
+        };
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e.into()),
+        };
+        let mut entries = Vec::new();
+        for line in content.lines() {
+            let mut fields = line.split_whitespace();
+            let (hosts, key_type, key) = match (fields.next(), fields.next(), fields.next()) {
+                (Some(hosts), Some(key_type), Some(key)) => (hosts, key_type, key),
+                _ => continue,
             };
-            reference.set_target(fetch_commit.id(), "Fast-forward")?;
-            repo.set_head(&refname)?;
-            repo.checkout_head(None)?;
-            new_tree = Some(repo.head()?.peel_to_tree()?);
-            Ok("Fast-forward merge completed".to_string())
+            if hosts.starts_with('#') || hosts.starts_with('|') || hosts.starts_with('@') {
+                continue;
+            }
+            let raw = match BASE64_STANDARD.decode(key) {
+                Ok(raw) => raw,
+                Err(_) => continue,
+            };
+            for host in hosts.split(',') {
+                entries.push(HostKey {
+                    host: host.to_string(),
+                    key_type: key_type.to_string(),
+                    key: key.to_string(),
+                    fingerprint: sha256_fingerprint(&raw),
+                });
+            }
+        }
+        Ok(entries)
+    }
+
+    fn save(&mut self, entries: Vec<HostKey>) -> Result<(), GitError> {
+        let path = match &self.path {
+            Some(path) => path,
+            None => {
+                self.memory = entries;
+                return Ok(());
+            }
+        };
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let content: String = entries
+            .iter()
+            .map(|e| format!("{} {} {}\n", e.host, e.key_type, e.key))
+            .collect();
+        fs::write(path, content)?;
+        Ok(())
+    }
+
+    /// Accepts keys pinned for the host; any other key is unknown (no pin yet) or a mismatch.
+    pub(crate) fn verify(&self, presented: &HostKey) -> Result<(), GitError> {
+        let entries = self.entries()?;
+        let mut pinned = entries
+            .iter()
+            .filter(|e| e.host == presented.host)
+            .peekable();
+        if pinned.peek().is_none() {
+            return Err(GitError::UnknownHostKey(presented.clone()));
+        }
+        if pinned.any(|e| e.key_type == presented.key_type && e.key == presented.key) {
+            Ok(())
         } else {
-            Err(GitError::Other(
-                "Non-fast-forward merge required".to_string(),
-            ))
+            Err(GitError::HostKeyMismatch(presented.clone()))
         }
-    })();
+    }
+
+    /// Pins [key] for its host. A host that already has a different key must be
+    /// removed first, so a changed key is never accepted by accident.
+    pub(crate) fn trust(&mut self, key: HostKey) -> Result<(), GitError> {
+        let mut entries = self.entries()?;
+        match self.verify(&key) {
+            Ok(()) => return Ok(()),
+            Err(GitError::UnknownHostKey(_)) => {}
+            Err(GitError::HostKeyMismatch(_)) => {
+                return Err(GitError::Other(format!(
+                    "{} already has a different pinned key; remove it first",
+                    key.host
+                )))
+            }
+            Err(e) => return Err(e),
+        }
+        if BASE64_STANDARD.decode(&key.key).is_err() {
+            return Err(GitError::Other("Host key is not valid base64".to_string()));
+        }
+        entries.push(key);
+        self.save(entries)
+    }
+
+    pub(crate) fn remove(&mut self, host: &str) -> Result<bool, GitError> {
+        let mut entries = self.entries()?;
+        let before = entries.len();
+        entries.retain(|e| e.host != host);
+        let removed = entries.len() != before;
+        if removed {
+            self.save(entries)?;
+        }
+        Ok(removed)
+    }
+}
+
+fn known_hosts_store() -> &'static Mutex<KnownHostsStore> {
+    static STORE: OnceLock<Mutex<KnownHostsStore>> = OnceLock::new();
+    STORE.get_or_init(|| Mutex::new(KnownHostsStore::default()))
+}
+
+/// Sets the known_hosts file SSH host keys are verified against. The app owns
+/// this file; keys trusted before it was set are written to it.
+#[flutter_rust_bridge::frb]
+pub fn set_known_hosts_path(path: String) -> Result<(), GitError> {
+    let mut store = known_hosts_store()
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    let pending = std::mem::take(&mut store.memory);
+    *store = KnownHostsStore::with_path(PathBuf::from(path));
+    for key in pending {
+        store.trust(key)?;
+    }
+    Ok(())
+}
+
+/// Trusts a host key, usually the one carried by GitError::UnknownHostKey after
+/// the user confirmed its fingerprint. Retry the operation afterwards.
+#[flutter_rust_bridge::frb]
+pub fn trust_host_key(key: HostKey) -> Result<(), GitError> {
+    known_hosts_store()
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .trust(key)
+}
+
+/// Lists the pinned host keys.
+#[flutter_rust_bridge::frb]
+pub fn list_known_hosts() -> Result<Vec<HostKey>, GitError> {
+    known_hosts_store()
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .entries()
+}
+
+/// Forgets the keys pinned for [host], e.g. after its server was reinstalled.
+/// Returns false if no key was pinned.
+#[flutter_rust_bridge::frb]
+pub fn remove_host_key(host: String) -> Result<bool, GitError> {
+    known_hosts_store()
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .remove(&host)
+}
+
+// ============================================================================
+// Certificate Trust Store
+// ============================================================================
+
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Debug, Clone)]
+struct TrustEntry {
+    host: String,
+    kind: TrustKind,
+    der: Vec<u8>,
+}
+
+impl TrustEntry {
+    fn describe(&self) -> Result<TrustedCertificate, GitError> {
+        let (_, cert) = x509_parser::parse_x509_certificate(&self.der)
+            .map_err(|e| GitError::Other(format!("Invalid certificate: {}", e)))?;
+        let not_after = chrono::DateTime::from_timestamp(cert.validity().not_after.timestamp(), 0)
+            .map(|t| t.to_rfc3339())
+            .unwrap_or_default();
+        Ok(TrustedCertificate {
+            host: self.host.clone(),
+            kind: self.kind,
+            fingerprint: sha256_fingerprint(&self.der),
+            subject: cert.subject().to_string(),
+            not_after,
+        })
+    }
+}
+
+/// CAs and pinned server certificates trusted per host, kept in a file once
+/// set_trust_store_path was called and in memory before that. The file holds
+/// "ca <host>" or "pin <host>" lines, each followed by a PEM certificate.
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Default)]
+pub(crate) struct TrustStore {
+    path: Option<PathBuf>,
+    memory: Vec<TrustEntry>,
+}
+
+impl TrustStore {
+    pub(crate) fn with_path(path: PathBuf) -> Self {
+        TrustStore {
+            path: Some(path),
+            memory: Vec::new(),
+        }
+    }
+
+    fn entries(&self) -> Result<Vec<TrustEntry>, GitError> {
+        let path = match &self.path {
+            Some(path) => path,
+            None => return Ok(self.memory.clone()),
+        };
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e.into()),
+        };
+        let mut entries = Vec::new();
+        let mut current: Option<(TrustKind, String, String)> = None;
+        for line in content.lines() {
+            if let Some((kind, host, pem)) = current.as_mut() {
+                pem.push_str(line);
+                pem.push('\n');
+                if line.trim() == "-----END CERTIFICATE-----" {
+                    for der in pem_certificates(pem)? {
+                        entries.push(TrustEntry {
+                            host: host.clone(),
+                            kind: *kind,
+                            der,
+                        });
+                    }
+                    current = None;
+                }
+                continue;
+            }
+            let kind = match line.split_whitespace().next() {
+                Some("ca") => TrustKind::CertificateAuthority,
+                Some("pin") => TrustKind::PinnedLeaf,
+                _ => continue,
+            };
+            if let Some(host) = line.split_whitespace().nth(1) {
+                current = Some((kind, host.to_string(), String::new()));
+            }
+        }
+        Ok(entries)
+    }
+
+    fn save(&mut self, entries: Vec<TrustEntry>) -> Result<(), GitError> {
+        let path = match &self.path {
+            Some(path) => path,
+            None => {
+                self.memory = entries;
+                return Ok(());
+            }
+        };
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut content = String::new();
+        for entry in &entries {
+            let kind = match entry.kind {
+                TrustKind::CertificateAuthority => "ca",
+                TrustKind::PinnedLeaf => "pin",
+            };
+            content.push_str(&format!("{} {}\n", kind, entry.host));
+            content.push_str(&pem_certificate(&entry.der));
+        }
+        fs::write(path, content)?;
+        Ok(())
+    }
+
+    /// Adds the certificates in [pem] for [host]; certificates already stored are kept once.
+    pub(crate) fn add(
+        &mut self,
+        host: &str,
+        kind: TrustKind,
+        pem: &str,
+    ) -> Result<Vec<TrustedCertificate>, GitError> {
+        let ders = pem_certificates(pem)?;
+        if ders.is_empty() {
+            return Err(GitError::Other("No PEM certificate found".to_string()));
+        }
+        let mut entries = self.entries()?;
+        let mut added = Vec::new();
+        for der in ders {
+            let valid = match kind {
+                TrustKind::CertificateAuthority => {
+                    webpki::TrustAnchor::try_from_cert_der(&der).is_ok()
+                }
+                TrustKind::PinnedLeaf => webpki::EndEntityCert::try_from(der.as_slice()).is_ok(),
+            };
+            if !valid {
+                return Err(GitError::CertificateInvalid {
+                    host: host.to_string(),
+                    reason: CertificateError::Malformed("Unusable certificate".to_string()),
+                });
+            }
+            let entry = TrustEntry {
+                host: host.to_string(),
+                kind,
+                der,
+            };
+            added.push(entry.describe()?);
+            if !entries
+                .iter()
+                .any(|e| e.host == entry.host && e.kind == entry.kind && e.der == entry.der)
+            {
+                entries.push(entry);
+            }
+        }
+        self.save(entries)?;
+        Ok(added)
+    }
+
+    pub(crate) fn list(&self, host: Option<&str>) -> Result<Vec<TrustedCertificate>, GitError> {
+        self.entries()?
+            .iter()
+            .filter(|e| host.is_none_or(|h| e.host == h))
+            .map(TrustEntry::describe)
+            .collect()
+    }
+
+    /// Removes the certificate with [fingerprint] from [host]; returns false if it wasn't stored.
+    pub(crate) fn remove(&mut self, host: &str, fingerprint: &str) -> Result<bool, GitError> {
+        let mut entries = self.entries()?;
+        let before = entries.len();
+        entries.retain(|e| !(e.host == host && sha256_fingerprint(&e.der) == fingerprint));
+        let removed = entries.len() != before;
+        if removed {
+            self.save(entries)?;
+        }
+        Ok(removed)
+    }
+
+    /// Checks a server certificate with the host's CAs and pins plus [process_cas].
+    /// A pinned certificate is trusted without a known issuer, but must still be valid
+    /// for the host and current.
+    pub(crate) fn verify(
+        &self,
+        hostname: &str,
+        cert_der: &[u8],
+        process_cas: &[Vec<u8>],
+        now: u64,
+    ) -> Result<CertificateTrust, CertificateError> {
+        let entries = self
+            .entries()
+            .map_err(|e| CertificateError::Other(format!("Trust store unreadable: {:?}", e)))?;
+        let host_entries: Vec<&TrustEntry> =
+            entries.iter().filter(|e| e.host == hostname).collect();
+        let pins: Vec<&TrustEntry> = host_entries
+            .iter()
+            .copied()
+            .filter(|e| e.kind == TrustKind::PinnedLeaf)
+            .collect();
+        let pinned = pins.iter().any(|e| e.der == cert_der);
+        if !pins.is_empty() && !pinned {
+            return Err(CertificateError::PinMismatch);
+        }
+        let mut cas = process_cas.to_vec();
+        cas.extend(
+            host_entries
+                .iter()
+                .filter(|e| e.kind == TrustKind::CertificateAuthority)
+                .map(|e| e.der.clone()),
+        );
+        match validate_certificate(hostname, cert_der, &cas, now)? {
+            CertificateTrust::IssuerUnknown if pinned => Ok(CertificateTrust::Verified),
+            trust => Ok(trust),
+        }
+    }
+}
+
+fn trust_store() -> &'static Mutex<TrustStore> {
+    static STORE: OnceLock<Mutex<TrustStore>> = OnceLock::new();
+    STORE.get_or_init(|| Mutex::new(TrustStore::default()))
+}
+
+/// Sets the file the trust store is kept in; certificates added before are written to it.
+#[flutter_rust_bridge::frb]
+pub fn set_trust_store_path(path: String) -> Result<(), GitError> {
+    let mut store = trust_store().lock().unwrap_or_else(PoisonError::into_inner);
+    let pending = std::mem::take(&mut store.memory);
+    *store = TrustStore::with_path(PathBuf::from(path));
+    let mut entries = store.entries()?;
+    entries.extend(pending);
+    store.save(entries)
+}
+
+/// Trusts the CA certificates in [pem] for [host], e.g. an internal CA of a self-hosted server.
+/// When the server's certificate was issued by an intermediate, add that intermediate.
+#[flutter_rust_bridge::frb]
+pub fn trust_add_ca(host: String, pem: String) -> Result<Vec<TrustedCertificate>, GitError> {
+    trust_store()
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .add(&host, TrustKind::CertificateAuthority, &pem)
+}
+
+/// Pins the server certificates in [pem] for [host]; from then on the host must
+/// present one of its pinned certificates.
+#[flutter_rust_bridge::frb]
+pub fn trust_pin_certificate(
+    host: String,
+    pem: String,
+) -> Result<Vec<TrustedCertificate>, GitError> {
+    trust_store()
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .add(&host, TrustKind::PinnedLeaf, &pem)
+}
+
+/// Lists the trusted CAs and pins, of [host] only if given.
+#[flutter_rust_bridge::frb]
+pub fn trust_list(host: Option<String>) -> Result<Vec<TrustedCertificate>, GitError> {
+    trust_store()
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .list(host.as_deref())
+}
+
+/// Removes a CA or pin by its fingerprint. Returns false if [host] had no such certificate.
+#[flutter_rust_bridge::frb]
+pub fn trust_remove(host: String, fingerprint: String) -> Result<bool, GitError> {
+    trust_store()
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .remove(&host, &fingerprint)
+}
+
+// Define the API struct
+#[flutter_rust_bridge::frb(init)]
+pub fn init_app() {
+    // Default utilities - feel free to customize
+    flutter_rust_bridge::setup_default_user_utils();
+}
+
+// Placeholder for Git operations
+#[flutter_rust_bridge::frb]
+pub fn add(left: i32, right: i32) -> i32 {
+    left + right
+}
+
+// Git operations
+fn git_init_impl(path: String) -> Result<String, GitError> {
+    Repository::init(&path)?;
+    Ok("Initialized empty Git repository".to_string())
+}
+
+#[flutter_rust_bridge::frb]
+pub fn git_init(path: String) -> Result<String, GitError> {
+    git_init_impl(path)
+}
+
+pub(crate) fn git_clone_impl(
+    url: String,
+    path: String,
+    username: Option<String>,
+    password: Option<String>,
+    ssh_key_path: Option<String>,
+    hooks: OperationHooks<'_>,
+) -> Result<String, GitError> {
+    let session = RemoteSession::new(username, password, ssh_key_path, hooks);
+    let mut checkout = git2::build::CheckoutBuilder::new();
+    attach_checkout_hooks(&mut checkout, hooks);
+    let mut fetch_options = git2::FetchOptions::new();
+    fetch_options.remote_callbacks(session.callbacks());
+    let mut builder = git2::build::RepoBuilder::new();
+    builder.fetch_options(fetch_options);
+    builder.with_checkout(checkout);
+
+    // A failed or cancelled clone must not leave a partial repository behind,
+    // but only a directory the clone created (or found empty) is cleaned up
+    let target = Path::new(&path);
+    let existed = target.exists();
+    let was_empty = fs::read_dir(target).is_ok_and(|mut d| d.next().is_none());
+    // Local clones may finish without calling back, so a late cancel still discards the result
+    let result = builder
+        .clone(&url, target)
+        .map_err(|e| session.error(e))
+        .and_then(|_| {
+            if hooks.is_cancelled() {
+                Err(GitError::Cancelled)
+            } else {
+                Ok(())
+            }
+        });
+    if let Err(e) = result {
+        if !existed || was_empty {
+            let _ = fs::remove_dir_all(target);
+            if existed {
+                let _ = fs::create_dir(target);
+            }
+        }
+        return Err(e);
+    }
+    Ok("Repository cloned successfully".to_string())
+}
+
+#[flutter_rust_bridge::frb]
+pub fn git_clone(
+    url: String,
+    path: String,
+    username: Option<String>,
+    password: Option<String>,
+    ssh_key_path: Option<String>,
+) -> Result<String, GitError> {
+    git_clone_impl(
+        url,
+        path,
+        username,
+        password,
+        ssh_key_path,
+        OperationHooks::default(),
+    )
+}
+
+/// Like git_clone, but streams GitProgress events to [sink] while it runs.
+/// [operation] is an optional handle from start_operation that allows cancelling the clone;
+/// a cancelled clone removes the partially written directory.
+#[allow(clippy::too_many_arguments)]
+#[flutter_rust_bridge::frb]
+pub fn git_clone_with_progress(
+    url: String,
+    path: String,
+    username: Option<String>,
+    password: Option<String>,
+    ssh_key_path: Option<String>,
+    operation: Option<u64>,
+    sink: StreamSink<GitProgress>,
+) -> Result<String, GitError> {
+    let progress = |event: GitProgress| {
+        let _ = sink.add(event);
+    };
+    run_cancellable(operation, |cancel| {
+        let hooks = OperationHooks {
+            progress: Some(&progress),
+            cancel,
+        };
+        git_clone_impl(url, path, username, password, ssh_key_path, hooks)
+    })
+}
+
+#[flutter_rust_bridge::frb]
+pub fn git_current_branch(path: String) -> Result<String, GitError> {
+    let repo = open_repository(&path)?;
+    let head_ref = repo.head()?;
+    let branch_name = head_ref
+        .name()
+        .and_then(|n| n.strip_prefix("refs/heads/"))
+        .unwrap_or("unknown");
+    Ok(branch_name.to_string())
+}
+
+#[flutter_rust_bridge::frb]
+pub fn git_list_branches(path: String) -> Result<Vec<String>, GitError> {
+    let repo = open_repository(&path)?;
+    let branches = repo.branches(None)?;
+    let mut branch_names = Vec::new();
+    for branch_result in branches {
+        let (branch, _) = branch_result?;
+        if let Some(name) = branch.name()? {
+            branch_names.push(name.to_string());
+        }
+    }
+    Ok(branch_names)
+}
+
+// Helper function to check if there are local uncommitted changes
+fn has_local_changes(repo: &Repository) -> Result<bool, GitError> {
+    let statuses = repo.statuses(None)?;
+    Ok(statuses.iter().any(|entry| {
+        let status = entry.status();
+        status.contains(git2::Status::WT_MODIFIED)
+            || status.contains(git2::Status::WT_DELETED)
+            || status.contains(git2::Status::WT_NEW)
+            || status.contains(git2::Status::INDEX_MODIFIED)
+            || status.contains(git2::Status::INDEX_DELETED)
+            || status.contains(git2::Status::INDEX_NEW)
+    }))
+}
+
+// Helper function to get the path a conflict entry refers to
+fn conflict_path(conflict: &git2::IndexConflict) -> Option<String> {
+    conflict
+        .our
+        .as_ref()
+        .or(conflict.their.as_ref())
+        .or(conflict.ancestor.as_ref())
+        .map(|entry| String::from_utf8_lossy(&entry.path).to_string())
+}
+
+// Helper function to list the paths with conflict entries in the index
+fn conflicted_paths(index: &git2::Index) -> Result<Vec<String>, GitError> {
+    let mut paths = Vec::new();
+    for conflict in index.conflicts()? {
+        if let Some(path) = conflict_path(&conflict?) {
+            paths.push(path);
+        }
+    }
+    Ok(paths)
+}
+
+// Helper function to convert an error of a commit on [repo]. libgit2 reports an
+// unmerged index without its paths, so they are read from the index
+fn conflict_error(repo: &Repository, err: git2::Error) -> GitError {
+    if matches!(
+        err.code(),
+        git2::ErrorCode::Unmerged | git2::ErrorCode::MergeConflict
+    ) {
+        if let Ok(paths) = repo
+            .index()
+            .map_err(GitError::from)
+            .and_then(|index| conflicted_paths(&index))
+        {
+            if !paths.is_empty() {
+                return GitError::MergeConflict(paths);
+            }
+        }
+    }
+    err.into()
+}
+
+// Helper function to fail early when there is no merge to resolve or abort
+fn ensure_merging(repo: &Repository) -> Result<(), GitError> {
+    if repo.state() != git2::RepositoryState::Merge {
+        return Err(GitError::Other("No merge in progress".to_string()));
+    }
+    Ok(())
+}
+
+// Helper function to resolve conflicts by taking one side's version of each file.
+// If that side deleted the file, the resolution is a deletion.
+// Only conflicts in `paths` are touched when given; returns the resolved paths.
+fn resolve_conflicts(
+    repo: &Repository,
+    side: MergeSide,
+    paths: Option<&[String]>,
+) -> Result<Vec<String>, GitError> {
+    let workdir = repo
+        .workdir()
+        .ok_or_else(|| GitError::Other("Repository has no working directory".to_string()))?
+        .to_path_buf();
+    let mut index = repo.index()?;
+    let conflicts: Vec<git2::IndexConflict> = index.conflicts()?.collect::<Result<_, _>>()?;
+    let mut kept = Vec::new();
+    let mut deleted = Vec::new();
+    for conflict in conflicts {
+        let path = match conflict_path(&conflict) {
+            Some(path) => path,
+            None => continue,
+        };
+        if paths.is_some_and(|paths| !paths.contains(&path)) {
+            continue;
+        }
+        let chosen = match side {
+            MergeSide::Local => conflict.our,
+            MergeSide::Remote => conflict.their,
+        };
+        match chosen {
+            Some(_) => kept.push(path),
+            None => deleted.push(path),
+        }
+    }
+
+    if !kept.is_empty() {
+        // Check out the chosen stage of each conflicted path from the index
+        let mut checkout = git2::build::CheckoutBuilder::new();
+        checkout.force();
+        match side {
+            MergeSide::Local => checkout.use_ours(true),
+            MergeSide::Remote => checkout.use_theirs(true),
+        };
+        for path in &kept {
+            checkout.path(path);
+        }
+        repo.checkout_index(Some(&mut index), Some(&mut checkout))?;
+    }
+    for path in &kept {
+        // Adding the path replaces its conflict entries with a stage-0 entry
+        index.add_path(Path::new(path))?;
+    }
+    for path in &deleted {
+        let full_path = workdir.join(path);
+        if full_path.exists() {
+            fs::remove_file(&full_path)?;
+        }
+        index.remove_path(Path::new(path))?;
+    }
+    index.write()?;
+    kept.extend(deleted);
+    Ok(kept)
+}
+
+// Helper function to conclude an in-progress merge by committing the index
+// with HEAD and every MERGE_HEAD as parents
+fn commit_merge(repo: &Repository, message: &str) -> Result<git2::Oid, GitError> {
+    let mut index = repo.index()?;
+    let tree_id = index.write_tree().map_err(|e| conflict_error(repo, e))?;
+    let tree = repo.find_tree(tree_id)?;
+    let signature = commit_signature(repo)?;
+    let mut parents = vec![repo.head()?.peel_to_commit()?];
+    // MERGE_HEAD lists one commit id per merged head
+    let merge_heads = fs::read_to_string(repo.path().join("MERGE_HEAD"))?;
+    for line in merge_heads.lines().filter(|l| !l.trim().is_empty()) {
+        parents.push(repo.find_commit(git2::Oid::from_str(line.trim())?)?);
+    }
+    let parent_refs: Vec<&git2::Commit> = parents.iter().collect();
+    let oid = repo.commit(
+        Some("HEAD"),
+        &signature,
+        &signature,
+        message,
+        &tree,
+        &parent_refs,
+    )?;
+    repo.cleanup_state()?;
+    Ok(oid)
+}
+
+// Helper function to read the id of the event stored in a blob, if it is an event file
+fn event_id_in_blob(repo: &Repository, path: &str, blob_id: git2::Oid) -> Option<String> {
+    if !path.ends_with(".md") {
+        return None;
+    }
+    let file_name = Path::new(path).file_name()?.to_str()?;
+    let blob = repo.find_blob(blob_id).ok()?;
+    parse_event_content(file_name, blob.content())
+        .ok()
+        .map(|record| record.event.id)
+}
+
+// Helper function to describe the files and events that changed between two commits
+fn sync_result(
+    repo: &Repository,
+    outcome: SyncOutcome,
+    old_commit: Option<git2::Oid>,
+    new_commit: Option<git2::Oid>,
+) -> Result<SyncResult, GitError> {
+    let mut result = SyncResult {
+        outcome,
+        old_commit: old_commit.map(|oid| oid.to_string()),
+        new_commit: new_commit.map(|oid| oid.to_string()),
+        added_paths: Vec::new(),
+        modified_paths: Vec::new(),
+        deleted_paths: Vec::new(),
+        affected_event_ids: Vec::new(),
+    };
+    if old_commit == new_commit {
+        return Ok(result);
+    }
+    let old_tree = old_commit
+        .map(|oid| repo.find_commit(oid).and_then(|c| c.tree()))
+        .transpose()?;
+    let new_tree = new_commit
+        .map(|oid| repo.find_commit(oid).and_then(|c| c.tree()))
+        .transpose()?;
+    let diff = repo.diff_tree_to_tree(old_tree.as_ref(), new_tree.as_ref(), None)?;
+    for delta in diff.deltas() {
+        let (file, paths) = match delta.status() {
+            Delta::Added => (delta.new_file(), &mut result.added_paths),
+            Delta::Modified => (delta.new_file(), &mut result.modified_paths),
+            Delta::Deleted => (delta.old_file(), &mut result.deleted_paths),
+            _ => continue,
+        };
+        let path = match file.path() {
+            Some(p) => p.to_string_lossy().to_string(),
+            None => continue,
+        };
+        if let Some(id) = event_id_in_blob(repo, &path, file.id()) {
+            if !result.affected_event_ids.contains(&id) {
+                result.affected_event_ids.push(id);
+            }
+        }
+        paths.push(path);
+    }
+    Ok(result)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn git_has_local_changes(path: String) -> Result<bool, GitError> {
+    let repo = open_repository(&path)?;
+    has_local_changes(&repo)
+}
+
+/// Pull from remote repository, handling local uncommitted changes by stashing them.
+/// The stash is popped once the pull is done; if it conflicts with the pulled files
+/// it is kept and GitError::StashConflict lists the paths.
+/// Diverged histories are merged with a merge commit; if the merge conflicts the
+/// repository is left mid-merge and GitError::MergeConflict lists the paths.
+pub(crate) fn git_pull_impl(
+    path: String,
+    username: Option<String>,
+    password: Option<String>,
+    ssh_key_path: Option<String>,
+    branch: Option<String>,
+    hooks: OperationHooks<'_>,
+) -> Result<SyncResult, GitError> {
+    let mut repo = open_repository(&path)?;
+    let session = RemoteSession::new(username, password, ssh_key_path, hooks);
+    let branch = resolve_sync_branch(&repo, "origin", branch.as_deref(), Some(&session))?;
+    let has_changes = has_local_changes(&repo)?;
+    let mut stash = None;
+    // None while the branch has no commits yet
+    let old_commit = repo
+        .head()
+        .ok()
+        .and_then(|head| head.peel_to_commit().ok())
+        .map(|commit| commit.id());
+
+    // Stash local changes to allow pull to update working directory
+    // Handle gracefully - if stash fails (e.g., "nothing to stash"), just proceed without stashing
+    if has_changes {
+        let signature = commit_signature(&repo)?;
+        match repo.stash_save(&signature, PULL_STASH_MESSAGE, None) {
+            Ok(oid) => {
+                stash = Some(oid);
+            }
+            Err(_) => {
+                // Don't error out - just proceed without stashing
+            }
+        }
+    }
+
+    let result = (|| {
+        let mut remote = repo.find_remote("origin")?;
+        let mut checkout = git2::build::CheckoutBuilder::new();
+        attach_checkout_hooks(&mut checkout, hooks);
+        let mut fetch_options = git2::FetchOptions::new();
+        fetch_options.remote_callbacks(session.callbacks());
+        // A FETCH_HEAD left by an earlier fetch must not pass for this one
+        let _ = fs::remove_file(repo.path().join("FETCH_HEAD"));
+        remote
+            .fetch(&[branch.upstream.as_str()], Some(&mut fetch_options), None)
+            .map_err(|e| session.error(e))?;
+        // FETCH_HEAD lists nothing when the remote has no such branch yet,
+        // e.g. a new empty repository: there is nothing to pull
+        let fetched = fs::read_to_string(repo.path().join("FETCH_HEAD")).unwrap_or_default();
+        if fetched.trim().is_empty() {
+            return Ok(SyncOutcome::UpToDate);
+        }
+        let fetch_head = repo.find_reference("FETCH_HEAD")?;
+        let fetch_commit = repo.reference_to_annotated_commit(&fetch_head)?;
+        let analysis = repo.merge_analysis(&[&fetch_commit])?;
+        if analysis.0.is_up_to_date() {
+            // Checkout HEAD to ensure working directory matches HEAD
+            repo.checkout_head(None)?;
+
+            Ok(SyncOutcome::UpToDate)
+        } else if analysis.0.is_fast_forward() {
+            // Check out the new tree before moving the branch, so a cancelled checkout
+            // can be rolled back to HEAD; local edits were stashed above, so force is safe
+            let target = repo.find_object(fetch_commit.id(), None)?;
+            if let Err(e) = repo.checkout_tree(&target, Some(checkout.force())) {
+                repo.checkout_head(Some(git2::build::CheckoutBuilder::new().force()))?;
+                return Err(e.into());
+            }
+            let refname = format!("refs/heads/{}", branch.local);
+            let mut reference = if let Ok(r) = repo.find_reference(&refname) {
+                r
+            } else {
+                repo.reference(&refname, fetch_commit.id(), true, "Creating branch")?
+            };
+            reference.set_target(fetch_commit.id(), "Fast-forward")?;
+            repo.set_head(&refname)?;
+            Ok(SyncOutcome::FastForward)
+        } else if analysis.0.is_normal() {
+            // Writes the merge result to index and workdir and records MERGE_HEAD
+            if let Err(e) = repo.merge(&[&fetch_commit], None, Some(&mut checkout)) {
+                let head = repo.head()?.peel_to_commit()?;
+                repo.reset(head.as_object(), git2::ResetType::Hard, None)?;
+                repo.cleanup_state()?;
+                return Err(e.into());
+            }
+            let index = repo.index()?;
+            if index.has_conflicts() {
+                return Err(GitError::MergeConflict(conflicted_paths(&index)?));
+            }
+            commit_merge(
+                &repo,
+                &format!("Merge branch '{}' of origin", branch.upstream),
+            )?;
+            Ok(SyncOutcome::Merged)
+        } else {
+            Err(GitError::Other(format!(
+                "Unsupported merge analysis: {:?}",
+                analysis.0
+            )))
+        }
+    })();
+
+    // Analyze the diff to see what changed
+    let result = result.and_then(|outcome| {
+        // Still None when neither side had commits
+        let new_commit = repo
+            .head()
+            .ok()
+            .and_then(|head| head.peel_to_commit().ok())
+            .map(|commit| commit.id());
+        sync_result(&repo, outcome, old_commit, new_commit)
+    });
+
+    // Handle stash after pull operation
+    let restored = match (stash, &result) {
+        (None, _) => Ok(()),
+        (Some(oid), Err(GitError::MergeConflict(_))) => {
+            // Keep the stash: popping onto a conflicted index would fail.
+            // Aborting or committing the merge puts it back (restore_pull_stash)
+            fs::write(repo.path().join(PULL_STASH_FILE), format!("{}\n", oid))
+                .map_err(GitError::from)
+        }
+        // Whether the pull succeeded or failed, the local changes go back on top;
+        // a stash that conflicts is kept
+        (Some(oid), _) => pop_stash(&mut repo, oid),
+    };
+
+    // Handle deletion of files that were deleted in remote
+    // This must happen outside the stash block to ensure deletions are processed
+    // even when there were no local changes to stash
+    if let Ok(summary) = &result {
+        for path in &summary.deleted_paths {
+            if let Some(workdir) = repo.workdir() {
+                let full_path = workdir.join(path);
+                if full_path.exists() {
+                    let _ = fs::remove_file(&full_path);
+                }
+            }
+        }
+    }
+
+    // Bring loaded calendars up to date with the files the pull touched
+    if let (Ok(summary), Some(workdir)) = (&result, repo.workdir()) {
+        let changed: Vec<PathBuf> = summary
+            .added_paths
+            .iter()
+            .chain(&summary.modified_paths)
+            .chain(&summary.deleted_paths)
+            .map(|path| workdir.join(path))
+            .collect();
+        reload_event_files(&changed);
+    }
+
+    // An error of the pull itself takes precedence over one of the stash
+    result.and_then(|summary| restored.map(|_| summary))
+}
+
+/// Pulls the current branch from origin.
+/// [branch] overrides the detected branch; pass None to follow HEAD and its upstream.
+#[flutter_rust_bridge::frb]
+pub fn git_pull(
+    path: String,
+    username: Option<String>,
+    password: Option<String>,
+    ssh_key_path: Option<String>,
+    branch: Option<String>,
+) -> Result<SyncResult, GitError> {
+    git_pull_impl(
+        path,
+        username,
+        password,
+        ssh_key_path,
+        branch,
+        OperationHooks::default(),
+    )
+}
+
+/// Like git_pull, but streams GitProgress events to [sink] while it runs.
+/// [operation] is an optional handle from start_operation that allows cancelling the pull.
+#[allow(clippy::too_many_arguments)]
+#[flutter_rust_bridge::frb]
+pub fn git_pull_with_progress(
+    path: String,
+    username: Option<String>,
+    password: Option<String>,
+    ssh_key_path: Option<String>,
+    branch: Option<String>,
+    operation: Option<u64>,
+    sink: StreamSink<GitProgress>,
+) -> Result<SyncResult, GitError> {
+    let progress = |event: GitProgress| {
+        let _ = sink.add(event);
+    };
+    run_cancellable(operation, |cancel| {
+        let hooks = OperationHooks {
+            progress: Some(&progress),
+            cancel,
+        };
+        git_pull_impl(path, username, password, ssh_key_path, branch, hooks)
+    })
+}
+
+fn git_push_impl(
+    path: String,
+    username: Option<String>,
+    password: Option<String>,
+    ssh_key_path: Option<String>,
+    branch: Option<String>,
+    hooks: OperationHooks<'_>,
+) -> Result<SyncResult, GitError> {
+    let repo = open_repository(&path)?;
+    let session = RemoteSession::new(username, password, ssh_key_path, hooks);
+    let branch = resolve_sync_branch(&repo, "origin", branch.as_deref(), Some(&session))?;
+    let mut remote = repo.find_remote("origin")?;
+    let rejection = std::cell::RefCell::new(None);
+    let mut callbacks = session.callbacks();
+    // Over smart transports a rejected ref doesn't fail push(); the server's
+    // verdict only arrives here
+    callbacks.push_update_reference(|refname, status| {
+        if let Some(status) = status {
+            *rejection.borrow_mut() = Some((refname.to_string(), status.to_string()));
+        }
+        Ok(())
+    });
+    let mut push_options = git2::PushOptions::new();
+    push_options.remote_callbacks(callbacks);
+    let tracking_ref = format!("refs/remotes/origin/{}", branch.upstream);
+    let old_commit = repo.refname_to_id(&tracking_ref).ok();
+    let new_commit = repo.refname_to_id(&format!("refs/heads/{}", branch.local))?;
+    let remote_ref = format!("refs/heads/{}", branch.upstream);
+    let refspec = format!("refs/heads/{}:{}", branch.local, remote_ref);
+    if let Err(e) = remote.push(&[refspec.as_str()], Some(&mut push_options)) {
+        // The local transport reports the rejection as an error instead
+        return Err(if e.code() == git2::ErrorCode::NotFastForward {
+            GitError::NonFastForward(remote_ref)
+        } else {
+            session.error(e)
+        });
+    }
+    if let Some((refname, status)) = rejection.borrow_mut().take() {
+        return Err(if is_non_fast_forward_status(&status) {
+            GitError::NonFastForward(refname)
+        } else {
+            GitError::Other(format!("Push of {} rejected: {}", refname, status))
+        });
+    }
+    let outcome = if old_commit == Some(new_commit) {
+        SyncOutcome::UpToDate
+    } else {
+        SyncOutcome::Pushed
+    };
+    sync_result(&repo, outcome, old_commit, Some(new_commit))
+}
+
+/// Pushes the current branch to origin.
+/// [branch] overrides the detected branch; pass None to follow HEAD and its upstream.
+#[flutter_rust_bridge::frb]
+pub fn git_push(
+    path: String,
+    username: Option<String>,
+    password: Option<String>,
+    ssh_key_path: Option<String>,
+    branch: Option<String>,
+) -> Result<SyncResult, GitError> {
+    git_push_impl(
+        path,
+        username,
+        password,
+        ssh_key_path,
+        branch,
+        OperationHooks::default(),
+    )
+}
+
+/// Like git_push, but streams GitProgress events to [sink] while it runs.
+/// [operation] is an optional handle from start_operation that allows cancelling the push.
+#[allow(clippy::too_many_arguments)]
+#[flutter_rust_bridge::frb]
+pub fn git_push_with_progress(
+    path: String,
+    username: Option<String>,
+    password: Option<String>,
+    ssh_key_path: Option<String>,
+    branch: Option<String>,
+    operation: Option<u64>,
+    sink: StreamSink<GitProgress>,
+) -> Result<SyncResult, GitError> {
+    let progress = |event: GitProgress| {
+        let _ = sink.add(event);
+    };
+    run_cancellable(operation, |cancel| {
+        let hooks = OperationHooks {
+            progress: Some(&progress),
+            cancel,
+        };
+        git_push_impl(path, username, password, ssh_key_path, branch, hooks)
+    })
+}
+
+// Helper function to tell whether a push status from the server means the
+// remote branch moved ahead, as opposed to e.g. a hook declining the push
+fn is_non_fast_forward_status(status: &str) -> bool {
+    let status = status.to_lowercase();
+    status.contains("non-fast-forward") || status.contains("fetch first")
+}
+
+// ============================================================================
+// One-call Sync
+// ============================================================================
+
+/// How often git_sync pulls and pushes again when the remote moved in between.
+const MAX_SYNC_PUSH_ATTEMPTS: u32 = 3;
+
+// Helper function to describe the staged changes, e.g. "Sync calendar: 2 added, 1 modified"
+fn sync_commit_message(repo: &Repository) -> Result<String, GitError> {
+    let head_tree = match repo.head() {
+        Ok(head) => Some(head.peel_to_tree()?),
+        Err(_) => None,
+    };
+    let diff = repo.diff_tree_to_index(head_tree.as_ref(), None, None)?;
+    let (mut added, mut modified, mut deleted) = (0, 0, 0);
+    for delta in diff.deltas() {
+        match delta.status() {
+            Delta::Added => added += 1,
+            Delta::Deleted => deleted += 1,
+            _ => modified += 1,
+        }
+    }
+    let parts: Vec<String> = [
+        (added, "added"),
+        (modified, "modified"),
+        (deleted, "deleted"),
+    ]
+    .iter()
+    .filter(|(count, _)| *count > 0)
+    .map(|(count, label)| format!("{} {}", count, label))
+    .collect();
+    Ok(format!("Sync calendar: {}", parts.join(", ")))
+}
+
+// Helper function to stage changed event files and commit them; returns None when
+// no event changed. Other files in the working tree are left unstaged
+fn commit_local_changes(repo: &Repository) -> Result<Option<String>, GitError> {
+    if !has_local_changes(repo)? {
+        return Ok(None);
+    }
+    let mut index = repo.index()?;
+    // 0 stages the path, a positive value skips it
+    let mut events_only = |path: &Path, _: &[u8]| if is_event_file(path) { 0 } else { 1 };
+    index.add_all(
+        ["*"].iter(),
+        git2::IndexAddOption::DEFAULT,
+        Some(&mut events_only),
+    )?;
+    index.update_all(["*"].iter(), Some(&mut events_only))?;
+    index.write()?;
+    let tree = repo.find_tree(index.write_tree()?)?;
+    let parent = match repo.head() {
+        Ok(head) => Some(head.peel_to_commit()?),
+        Err(_) => None,
+    };
+    if parent.as_ref().is_some_and(|p| p.tree_id() == tree.id()) {
+        return Ok(None);
+    }
+    let message = sync_commit_message(repo)?;
+    let signature = commit_signature(repo)?;
+    let parents: Vec<&git2::Commit> = parent.iter().collect();
+    let oid = repo.commit(
+        Some("HEAD"),
+        &signature,
+        &signature,
+        &message,
+        &tree,
+        &parents,
+    )?;
+    Ok(Some(oid.to_string()))
+}
+
+// Commits local changes, then pulls and pushes until the push lands. Each step
+// leaves the repository consistent: the local commit is kept if a later step
+// fails, so the next sync picks it up, and a conflicting pull stays mid-merge
+// for the merge resolution functions
+pub(crate) fn git_sync_impl(
+    path: String,
+    username: Option<String>,
+    password: Option<String>,
+    ssh_key_path: Option<String>,
+    branch: Option<String>,
+    hooks: OperationHooks<'_>,
+) -> Result<SyncReport, GitError> {
+    let local_commit = {
+        let repo = open_repository(&path)?;
+        if repo.state() != git2::RepositoryState::Clean {
+            return Err(GitError::Other(
+                "A merge is in progress; resolve or abort it before syncing".to_string(),
+            ));
+        }
+        // An unborn branch is resolved against the remote by the pull below
+        match resolve_sync_branch(&repo, "origin", branch.as_deref(), None) {
+            Ok(_) | Err(GitError::UnbornBranch(_)) => {}
+            Err(e) => return Err(e),
+        }
+        commit_local_changes(&repo)?
+    };
+
+    let mut attempt = 0;
+    loop {
+        attempt += 1;
+        if hooks.is_cancelled() {
+            return Err(GitError::Cancelled);
+        }
+        let pull = git_pull_impl(
+            path.clone(),
+            username.clone(),
+            password.clone(),
+            ssh_key_path.clone(),
+            branch.clone(),
+            hooks,
+        )?;
+        match git_push_impl(
+            path.clone(),
+            username.clone(),
+            password.clone(),
+            ssh_key_path.clone(),
+            branch.clone(),
+            hooks,
+        ) {
+            Ok(push) => {
+                return Ok(SyncReport {
+                    local_commit,
+                    pull,
+                    push,
+                    push_attempts: attempt,
+                })
+            }
+            Err(GitError::NonFastForward(_)) if attempt < MAX_SYNC_PUSH_ATTEMPTS => continue,
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Commits local event changes with a generated message, pulls (fast-forward or merge)
+/// and pushes in one call; files other than events are not committed. A push rejected
+/// because the remote moved is retried after pulling again. Fails with MergeConflict
+/// when the pull conflicts, leaving the repository mid-merge as git_pull does.
+/// [branch] overrides the detected branch; pass None to follow HEAD and its upstream.
+#[flutter_rust_bridge::frb]
+pub fn git_sync(
+    path: String,
+    username: Option<String>,
+    password: Option<String>,
+    ssh_key_path: Option<String>,
+    branch: Option<String>,
+) -> Result<SyncReport, GitError> {
+    git_sync_impl(
+        path,
+        username,
+        password,
+        ssh_key_path,
+        branch,
+        OperationHooks::default(),
+    )
+}
+
+/// Like git_sync, but streams GitProgress events to [sink] while it runs.
+/// [operation] is an optional handle from start_operation that allows cancelling the sync.
+#[allow(clippy::too_many_arguments)]
+#[flutter_rust_bridge::frb]
+pub fn git_sync_with_progress(
+    path: String,
+    username: Option<String>,
+    password: Option<String>,
+    ssh_key_path: Option<String>,
+    branch: Option<String>,
+    operation: Option<u64>,
+    sink: StreamSink<GitProgress>,
+) -> Result<SyncReport, GitError> {
+    let progress = |event: GitProgress| {
+        let _ = sink.add(event);
+    };
+    run_cancellable(operation, |cancel| {
+        let hooks = OperationHooks {
+            progress: Some(&progress),
+            cancel,
+        };
+        git_sync_impl(path, username, password, ssh_key_path, branch, hooks)
+    })
+}
+
+fn git_status_impl(path: String) -> Result<Vec<StatusEntry>, GitError> {
+    let repo = open_repository(&path)?;
+    let statuses = repo.statuses(None)?;
+    let mut entries = Vec::new();
+    for entry in statuses.iter() {
+        let path = entry.path().unwrap_or("unknown");
+        let status = format!("{:?}", entry.status());
+        entries.push(StatusEntry {
+            path: path.to_string(),
+            status,
+        });
+    }
+    Ok(entries)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn git_status(path: String) -> Result<Vec<StatusEntry>, GitError> {
+    git_status_impl(path)
+}
+fn git_add_remote_impl(path: String, name: String, url: String) -> Result<String, GitError> {
+    let repo = open_repository(&path)?;
+    repo.remote(&name, &url)?;
+    Ok("Remote added".to_string())
+}
+
+#[flutter_rust_bridge::frb]
+pub fn git_add_remote(path: String, name: String, url: String) -> Result<String, GitError> {
+    git_add_remote_impl(path, name, url)
+}
+
+fn git_remove_remote_impl(path: String, name: String) -> Result<String, GitError> {
+    let repo = open_repository(&path)?;
+    repo.remote_delete(&name)?;
+    Ok("Remote removed".to_string())
+}
+
+#[flutter_rust_bridge::frb]
+pub fn git_remove_remote(path: String, name: String) -> Result<String, GitError> {
+    git_remove_remote_impl(path, name)
+}
+
+fn git_fetch_impl(
+    path: String,
+    remote: String,
+    username: Option<String>,
+    password: Option<String>,
+    ssh_key_path: Option<String>,
+    branch: Option<String>,
+    hooks: OperationHooks<'_>,
+) -> Result<SyncResult, GitError> {
+    let repo = open_repository(&path)?;
+    let session = RemoteSession::new(username, password, ssh_key_path, hooks);
+    let branch = resolve_sync_branch(&repo, &remote, branch.as_deref(), Some(&session))?;
+    let mut remote_obj = repo.find_remote(&remote)?;
+    let mut fetch_options = git2::FetchOptions::new();
+    fetch_options.remote_callbacks(session.callbacks());
+    let tracking_ref = format!("refs/remotes/{}/{}", remote, branch.upstream);
+    let old_commit = repo.refname_to_id(&tracking_ref).ok();
+    remote_obj
+        .fetch(&[branch.upstream.as_str()], Some(&mut fetch_options), None)
+        .map_err(|e| session.error(e))?;
+    let new_commit = repo.refname_to_id(&tracking_ref).ok();
+    let outcome = if old_commit == new_commit {
+        SyncOutcome::UpToDate
+    } else {
+        SyncOutcome::Fetched
+    };
+    sync_result(&repo, outcome, old_commit, new_commit)
+}
+
+/// Fetches the current branch from [remote].
+/// [branch] overrides the detected branch; pass None to follow HEAD and its upstream.
+#[flutter_rust_bridge::frb]
+pub fn git_fetch(
+    path: String,
+    remote: String,
+    username: Option<String>,
+    password: Option<String>,
+    ssh_key_path: Option<String>,
+    branch: Option<String>,
+) -> Result<SyncResult, GitError> {
+    git_fetch_impl(
+        path,
+        remote,
+        username,
+        password,
+        ssh_key_path,
+        branch,
+        OperationHooks::default(),
+    )
+}
+
+/// Like git_fetch, but streams GitProgress events to [sink] while it runs.
+/// [operation] is an optional handle from start_operation that allows cancelling the fetch.
+#[allow(clippy::too_many_arguments)]
+#[flutter_rust_bridge::frb]
+pub fn git_fetch_with_progress(
+    path: String,
+    remote: String,
+    username: Option<String>,
+    password: Option<String>,
+    ssh_key_path: Option<String>,
+    branch: Option<String>,
+    operation: Option<u64>,
+    sink: StreamSink<GitProgress>,
+) -> Result<SyncResult, GitError> {
+    let progress = |event: GitProgress| {
+        let _ = sink.add(event);
+    };
+    run_cancellable(operation, |cancel| {
+        let hooks = OperationHooks {
+            progress: Some(&progress),
+            cancel,
+        };
+        git_fetch_impl(
+            path,
+            remote,
+            username,
+            password,
+            ssh_key_path,
+            branch,
+            hooks,
+        )
+    })
+}
+
+fn git_checkout_impl(path: String, branch: String) -> Result<String, GitError> {
+    let repo = open_repository(&path)?;
+    let head_ref_name = format!("refs/heads/{}", branch);
+    if repo.find_reference(&head_ref_name).is_ok() {
+        // Branch exists, just set head and checkout
+        repo.set_head(&head_ref_name)?;
+        repo.checkout_head(None)?;
+    } else {
+        // Create branch from FETCH_HEAD
+        let fetch_head = repo.find_reference("FETCH_HEAD")?;
+        let commit = repo.find_commit(fetch_head.target().unwrap())?;
+        repo.branch(&branch, &commit, false)?;
+        repo.set_head(&head_ref_name)?;
+        repo.checkout_head(None)?;
+    }
+    Ok("Checked out".to_string())
+}
+
+#[flutter_rust_bridge::frb]
+pub fn git_checkout(path: String, branch: String) -> Result<String, GitError> {
+    git_checkout_impl(path, branch)
+}
+
+fn git_add_all_impl(path: String) -> Result<String, GitError> {
+    let repo = open_repository(&path)?;
+    let mut index = repo.index()?;
+    index.add_all(["*"].iter(), git2::IndexAddOption::DEFAULT, None)?;
+    index.write()?;
+    Ok("Added all".to_string())
+}
+
+#[flutter_rust_bridge::frb]
+pub fn git_add_all(path: String) -> Result<String, GitError> {
+    git_add_all_impl(path)
+}
+
+fn git_commit_impl(
+    path: String,
+    message: String,
+    author: Option<GitIdentity>,
+) -> Result<String, GitError> {
+    let repo = open_repository(&path)?;
+    let mut index = repo.index()?;
+    let oid = index.write_tree().map_err(|e| conflict_error(&repo, e))?;
+    let tree = repo.find_tree(oid)?;
+    let signature = commit_signature(&repo)?;
+    let author = match author {
+        Some(identity) => git2::Signature::now(&identity.name, &identity.email)?,
+        None => signature.clone(),
+    };
+    let parent_commit = repo
+        .head()
+        .ok()
+        .and_then(|head| head.target())
+        .and_then(|oid| repo.find_commit(oid).ok());
+    let commit = if let Some(parent) = parent_commit {
+        repo.commit(
+            Some("HEAD"),
+            &author,
+            &signature,
+            &message,
+            &tree,
+            &[&parent],
+        )?
+    } else {
+        repo.commit(Some("HEAD"), &author, &signature, &message, &tree, &[])?
+    };
+    Ok(commit.to_string())
+}
+
+/// Commits the index. [author] overrides the configured identity for this commit only;
+/// the committer is always the repository's configured identity.
+#[flutter_rust_bridge::frb]
+pub fn git_commit(
+    path: String,
+    message: String,
+    author: Option<GitIdentity>,
+) -> Result<String, GitError> {
+    git_commit_impl(path, message, author)
+}
+
+// Helper function for the commit signature: user.name/user.email from the
+// repository or global config, falling back to the app's default identity
+fn commit_signature(repo: &Repository) -> Result<git2::Signature<'static>, GitError> {
+    match repo.signature() {
+        Ok(signature) => Ok(signature),
+        Err(_) => Ok(git2::Signature::now("App", "app@example.com")?),
+    }
+}
+
+fn git_set_identity_impl(path: String, identity: GitIdentity) -> Result<String, GitError> {
+    let repo = open_repository(&path)?;
+    let mut config = repo.config()?.open_level(git2::ConfigLevel::Local)?;
+    config.set_str("user.name", &identity.name)?;
+    config.set_str("user.email", &identity.email)?;
+    Ok("Identity set".to_string())
+}
+
+/// Stores the commit identity for this repository only (its local user.name/user.email).
+#[flutter_rust_bridge::frb]
+pub fn git_set_identity(path: String, identity: GitIdentity) -> Result<String, GitError> {
+    git_set_identity_impl(path, identity)
+}
+
+fn git_get_identity_impl(path: String) -> Result<Option<GitIdentity>, GitError> {
+    let repo = open_repository(&path)?;
+    let config = repo.config()?;
+    match (
+        config.get_string("user.name"),
+        config.get_string("user.email"),
+    ) {
+        (Ok(name), Ok(email)) => Ok(Some(GitIdentity { name, email })),
+        _ => Ok(None),
+    }
+}
+
+/// Returns the identity commits will be made with, or None when the app default is used.
+#[flutter_rust_bridge::frb]
+pub fn git_get_identity(path: String) -> Result<Option<GitIdentity>, GitError> {
+    git_get_identity_impl(path)
+}
+
+/// Message of the stash git_pull makes of local changes before merging.
+const PULL_STASH_MESSAGE: &str = "Stashed by app during pull";
+
+/// File in the git directory holding the id of the stash git_pull made before
+/// a merge that stopped on conflicts.
+const PULL_STASH_FILE: &str = "PULL_AUTOSTASH";
+
+// Helper function to pop the stash with id [oid] onto the working tree.
+// Like git's autostash, a stash that no longer applies is kept instead of lost
+fn pop_stash(repo: &mut Repository, oid: git2::Oid) -> Result<(), GitError> {
+    let mut position = None;
+    repo.stash_foreach(|index, _, id| {
+        if *id == oid {
+            position = Some(index);
+            return false;
+        }
+        true
+    })?;
+    // Nothing to do once the stash was popped or dropped by hand
+    let index = match position {
+        Some(index) => index,
+        None => return Ok(()),
+    };
+    // libgit2 pops a conflicting stash as conflict markers and drops it, so the
+    // conflicts are found first by merging the stash onto HEAD
+    let conflicts = {
+        let stash = repo.find_commit(oid)?;
+        let base = stash.parent(0)?.tree()?;
+        let head = repo.head()?.peel_to_tree()?;
+        conflicted_paths(&repo.merge_trees(&base, &head, &stash.tree()?, None)?)?
+    };
+    if !conflicts.is_empty() {
+        return Err(GitError::StashConflict(conflicts));
+    }
+    repo.stash_pop(index, None)?;
+    Ok(())
+}
+
+// Helper function to put back the local changes git_pull stashed before a merge
+// that stopped on conflicts, once that merge is aborted or committed
+fn restore_pull_stash(repo: &mut Repository) -> Result<(), GitError> {
+    let file = repo.path().join(PULL_STASH_FILE);
+    let oid = match fs::read_to_string(&file) {
+        Ok(oid) => git2::Oid::from_str(oid.trim())?,
+        Err(_) => return Ok(()),
+    };
+    fs::remove_file(&file)?;
+    pop_stash(repo, oid)
+}
+
+// Helper function to stage the resolved [paths] and commit the merge; anything
+// else in the working tree, such as untracked files, stays out of the commit
+fn finish_merge(repo: &Repository, paths: &[String], message: &str) -> Result<(), GitError> {
+    let mut index = repo.index()?;
+    index.update_all(paths.iter(), None)?;
+    index.write()?;
+    commit_merge(repo, message)?;
+    Ok(())
+}
+
+fn git_merge_prefer_remote_impl(path: String) -> Result<String, GitError> {
+    let mut repo = open_repository(&path)?;
+    ensure_merging(&repo)?;
+    let resolved = resolve_conflicts(&repo, MergeSide::Remote, None)?;
+    finish_merge(&repo, &resolved, "Merge resolved by preferring remote")?;
+    restore_pull_stash(&mut repo)?;
+    Ok("Merge resolved by preferring remote".to_string())
+}
+
+#[flutter_rust_bridge::frb]
+pub fn git_merge_prefer_remote(path: String) -> Result<String, GitError> {
+    git_merge_prefer_remote_impl(path)
+}
+
+fn git_merge_prefer_local_impl(path: String) -> Result<String, GitError> {
+    let mut repo = open_repository(&path)?;
+    ensure_merging(&repo)?;
+    let resolved = resolve_conflicts(&repo, MergeSide::Local, None)?;
+    finish_merge(&repo, &resolved, "Merge resolved by preferring local")?;
+    restore_pull_stash(&mut repo)?;
+    Ok("Merge resolved by preferring local".to_string())
+}
+
+/// Resolves every conflict with the local version and commits the merge.
+#[flutter_rust_bridge::frb]
+pub fn git_merge_prefer_local(path: String) -> Result<String, GitError> {
+    git_merge_prefer_local_impl(path)
+}
+
+fn git_merge_resolve_paths_impl(
+    path: String,
+    resolutions: Vec<PathResolution>,
+) -> Result<Vec<String>, GitError> {
+    let mut repo = open_repository(&path)?;
+    ensure_merging(&repo)?;
+    let mut resolved = Vec::new();
+    for side in [MergeSide::Local, MergeSide::Remote] {
+        let paths: Vec<String> = resolutions
+            .iter()
+            .filter(|r| r.side == side)
+            .map(|r| r.path.clone())
+            .collect();
+        if !paths.is_empty() {
+            resolved.extend(resolve_conflicts(&repo, side, Some(&paths))?);
+        }
+    }
+    let remaining = conflicted_paths(&repo.index()?)?;
+    if remaining.is_empty() {
+        finish_merge(&repo, &resolved, "Merge resolved per file")?;
+        restore_pull_stash(&mut repo)?;
+    }
+    Ok(remaining)
+}
+
+/// Resolves the listed conflicted files, each with its chosen side.
+/// Returns the paths that are still conflicted; once none remain the merge is committed.
+#[flutter_rust_bridge::frb]
+pub fn git_merge_resolve_paths(
+    path: String,
+    resolutions: Vec<PathResolution>,
+) -> Result<Vec<String>, GitError> {
+    git_merge_resolve_paths_impl(path, resolutions)
+}
+
+/// Aborts a merge stopped on conflicts and puts back the local changes
+/// git_pull stashed before merging.
+#[flutter_rust_bridge::frb]
+pub fn git_merge_abort(path: String) -> Result<String, GitError> {
+    git_merge_abort_impl(path)
+}
+
+fn git_stash_impl(path: String) -> Result<String, GitError> {
+    let mut repo = open_repository(&path)?;
+    let signature = commit_signature(&repo)?;
+    repo.stash_save(&signature, "Stashed by app", None)?;
+    Ok("Stashed".to_string())
+}
+
+#[flutter_rust_bridge::frb]
+pub fn git_stash(path: String) -> Result<String, GitError> {
+    git_stash_impl(path)
+}
+
+fn git_diff_impl(path: String) -> Result<String, GitError> {
+    let repo = open_repository(&path)?;
+    let head = repo.head()?;
+    let head_tree = head.peel_to_tree()?;
+    let diff = repo.diff_tree_to_workdir(Some(&head_tree), None)?;
+    let mut diff_str = String::new();
+    diff.print(git2::DiffFormat::Patch, |delta, _hunk, line| {
+        diff_str.push_str(&format!(
+            "{:?} {:?} {:?}\n",
+            delta.old_file().path(),
+            delta.new_file().path(),
+            line.origin()
+        ));
+        true
+    })?;
+    Ok(diff_str)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn git_diff(path: String) -> Result<String, GitError> {
+    git_diff_impl(path)
+}
+
+// Equivalent of `git merge --abort`: resetting hard to HEAD drops the merge
+// result, then the local edits git_pull stashed before merging are reapplied
+fn git_merge_abort_impl(path: String) -> Result<String, GitError> {
+    let mut repo = open_repository(&path)?;
+    ensure_merging(&repo)?;
+    {
+        let head = repo.head()?.peel_to_commit()?;
+        repo.reset(head.as_object(), git2::ResetType::Hard, None)?;
+    }
+    repo.cleanup_state()?;
+    restore_pull_stash(&mut repo)?;
+    Ok("Merge aborted".to_string())
+}
+
+/// Trusts the CAs in [pem_certs] for every host until the app exits, in addition
+/// to webpki-roots. Replaces the CAs registered by an earlier call; use
+/// trust_add_ca to trust a CA for one host across restarts.
+/// When a server's certificate was issued by an intermediate, register that intermediate.
+#[flutter_rust_bridge::frb]
+pub fn set_ssl_ca_certs(pem_certs: Vec<String>) -> Result<(), GitError> {
+    let mut ders = Vec::new();
+    for pem in &pem_certs {
+        ders.extend(pem_certificates(pem)?);
+    }
+    *registered_ca_certs()
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner) = ders;
+    Ok(())
+}
+
+// ============================================================================
+// Calendar Event Functions (rcal-lib wrapper)
+// ============================================================================
+
+/// Creates a new calendar event and saves it to the specified calendar directory.
+#[flutter_rust_bridge::frb]
+pub fn create_event(input: EventInput, calendar_dir: String) -> Result<String, EventError> {
+    let record = create_calendar_event(input, None)?;
+
+    let path = write_event_file(Path::new(&calendar_dir), &record, None)?;
+    reload_event_files(&[path]);
+
+    Ok(record.event.id)
+}
+
+/// Gets all events from the specified calendar directory, along with the event
+/// files that couldn't be parsed.
+#[flutter_rust_bridge::frb]
+pub fn get_all_events(calendar_dir: String) -> Result<EventsResult, EventError> {
+    with_event_index(Path::new(&calendar_dir), |index| {
+        Ok(EventsResult {
+            events: index.events().map(record_to_dto).collect(),
+            unreadable: index.unreadable(),
+        })
+    })
+}
+
+/// Gets all events within a date range from the specified calendar directory.
+/// Event files that couldn't be parsed are all reported, as their dates aren't known.
+#[flutter_rust_bridge::frb]
+pub fn get_events_in_range(
+    start_date: String,
+    end_date: String,
+    calendar_dir: String,
+) -> Result<EventsResult, EventError> {
+    let start = parse_date("start_date", &start_date)?;
+    let end = parse_date("end_date", &end_date)?;
+    if end < start {
+        return Err(EventError::InvalidRange {
+            field: "end_date".to_string(),
+        });
+    }
+
+    with_event_index(Path::new(&calendar_dir), |index| {
+        Ok(EventsResult {
+            events: index
+                .in_range(start, end)
+                .into_iter()
+                .map(record_to_dto)
+                .collect(),
+            unreadable: index.unreadable(),
+        })
+    })
+}
+
+/// Updates an existing event in the specified calendar directory. Skipped and
+/// changed occurrences the new dates or recurrence no longer have are dropped.
+#[flutter_rust_bridge::frb]
+pub fn update_event(id: String, input: EventInput, calendar_dir: String) -> Result<(), EventError> {
+    let dir = PathBuf::from(&calendar_dir);
+    let (existing, existing_record) = with_event_index(&dir, |index| {
+        let (path, record) = index.find(&id)?;
+        Ok((path.to_path_buf(), record.clone()))
+    })?;
+
+    // Create updated event with the same ID
+    let mut updated_event = create_calendar_event(input, Some(id))?;
+    // Skipped and changed occurrences stay with the series, as long as its new
+    // dates and recurrence still have them
+    let occurs = |date: &NaiveDate| check_occurrence(&updated_event, *date).is_ok();
+    let excluded_dates = existing_record
+        .excluded_dates
+        .into_iter()
+        .filter(occurs)
+        .collect();
+    let overrides = existing_record
+        .overrides
+        .into_iter()
+        .filter(|o| parse_date("occurrence_date", &o.occurrence_date).is_ok_and(|d| occurs(&d)))
+        .collect();
+    updated_event.excluded_dates = excluded_dates;
+    updated_event.overrides = overrides;
+
+    // Replace the event's own file, so same-title events are left alone and a
+    // renamed event doesn't leave its old file behind
+    let path = write_event_file(&dir, &updated_event, Some(&existing))?;
+    reload_event_files(&[existing, path]);
+
+    Ok(())
+}
+
+/// Deletes the event with the given [id] from the specified calendar directory.
+#[flutter_rust_bridge::frb]
+pub fn delete_event(id: String, calendar_dir: String) -> Result<(), EventError> {
+    let existing = with_event_index(Path::new(&calendar_dir), |index| {
+        Ok(index.find(&id)?.0.to_path_buf())
+    })?;
+    fs::remove_file(&existing)?;
+    reload_event_files(&[existing]);
+
+    Ok(())
+}
+
+/// Stores a stable id in every event file of the calendar directory.
+/// Event files written before ids were stored get the id rcal-lib derives for them
+/// written into the file, and copies that share another file's id get a fresh one.
+/// Returns the names of the rewritten files; files keep their names, so this is safe
+/// to run on every start.
+#[flutter_rust_bridge::frb]
+pub fn migrate_event_ids(calendar_dir: String) -> Result<Vec<String>, EventError> {
+    let mut seen = HashSet::new();
+    let mut migrated = Vec::new();
+
+    // Files that don't parse can't be given an id; queries report them as unreadable
+    let (files, _unreadable) = read_event_files(Path::new(&calendar_dir))?;
+    for file in files {
+        let mut record = file.record;
+        let duplicate = !seen.insert(record.event.id.clone());
+        if duplicate {
+            record.event.id = uuid::Uuid::new_v4().to_string();
+            seen.insert(record.event.id.clone());
+        }
+
+        let (_, rendered) = render_event_file(&record)?;
+        if !duplicate && id_is_stored(&record.event.id, &file.content, &rendered) {
+            continue;
+        }
+        fs::write(&file.path, rendered)?;
+        reload_event_files(std::slice::from_ref(&file.path));
+        migrated.push(file_name(&file.path));
+    }
+
+    Ok(migrated)
+}
+
+/// An event file in a calendar directory, with the event it holds.
+#[flutter_rust_bridge::frb(ignore)]
+pub(crate) struct EventFile {
+    pub path: PathBuf,
+    pub content: Vec<u8>,
+    pub record: EventRecord,
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+/// Reads each event file of [dir] on its own, so every event is tied to the file it
+/// came from. Files that don't hold an event are returned apart, as ParseFailure.
+pub(crate) fn read_event_files(
+    dir: &Path,
+) -> Result<(Vec<EventFile>, Vec<EventError>), EventError> {
+    let staging = tempfile::tempdir()?;
+    let mut files = Vec::new();
+    let mut unreadable = Vec::new();
+    for path in event_file_paths(dir)? {
+        let content = fs::read(&path)?;
+        match parse_staged_event(staging.path(), &file_name(&path), &content) {
+            Ok(record) => files.push(EventFile {
+                path,
+                content,
+                record,
+            }),
+            Err(e) => unreadable.push(e),
+        }
+    }
+    Ok((files, unreadable))
+}
+
+// Helper function to tell event files from the other files of a calendar directory
+fn is_event_file(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "md")
+}
+
+// Helper function to list the event files of [dir], sorted; a missing dir has none
+fn event_file_paths(dir: &Path) -> Result<Vec<PathBuf>, EventError> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_ok_and(|t| t.is_file()))
+        .map(|e| e.path())
+        .filter(|p| is_event_file(p))
+        .collect();
+    paths.sort();
+    Ok(paths)
+}
+
+/// Writes an event under the file name rcal-lib gives it, picking a free name when
+/// another event already uses it. [replaces] is the event's current file, which is
+/// removed if the event moves to a new name.
+fn write_event_file(
+    dir: &Path,
+    record: &EventRecord,
+    replaces: Option<&Path>,
+) -> Result<PathBuf, EventError> {
+    let (name, content) = render_event_file(record)?;
+    fs::create_dir_all(dir)?;
+
+    let stem = Path::new(&name)
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let is_free = |path: &Path| !path.exists() || replaces == Some(path);
+    let mut path = dir.join(&name);
+    let mut n = 2;
+    while !is_free(&path) {
+        path = dir.join(format!("{}_{}.md", stem, n));
+        n += 1;
+    }
+
+    fs::write(&path, content)?;
+    if let Some(old) = replaces {
+        if old != path {
+            fs::remove_file(old)?;
+        }
+    }
+    Ok(path)
+}
+
+/// Whether the id rcal-lib reports for a file is actually written in it, rather than
+/// derived at load time. Every line of the rendered event that carries the id must
+/// already be in the file.
+fn id_is_stored(id: &str, content: &[u8], rendered: &[u8]) -> bool {
+    let content = String::from_utf8_lossy(content);
+    let rendered = String::from_utf8_lossy(rendered);
+    rendered
+        .lines()
+        .filter(|line| line.contains(id))
+        .all(|line| content.lines().any(|l| l.trim() == line.trim()))
+}
+
+// ============================================================================
+// Event Index
+// ============================================================================
+
+/// What an event file looked like when it was indexed; a file whose stamp
+/// differs has changed since and is read again.
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct FileStamp {
+    modified: Option<SystemTime>,
+    len: u64,
+}
+
+impl FileStamp {
+    fn of(path: &Path) -> Option<FileStamp> {
+        fs::metadata(path)
+            .ok()
+            .map(|metadata| FileStamp::from(&metadata))
+    }
+}
+
+impl From<&fs::Metadata> for FileStamp {
+    fn from(metadata: &fs::Metadata) -> Self {
+        FileStamp {
+            modified: metadata.modified().ok(),
+            len: metadata.len(),
+        }
+    }
+}
+
+/// The events of one calendar directory, loaded once and kept in memory.
+/// Refreshing only reads the files added or changed since, by their stamps, and
+/// drops the events of removed files.
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Default)]
+pub(crate) struct EventIndex {
+    dir: PathBuf,
+    // Every event file seen, including ones that don't parse, which are only
+    // read again once they change
+    stamps: HashMap<PathBuf, FileStamp>,
+    events: BTreeMap<PathBuf, EventRecord>,
+    // Files that don't parse, with their content and why
+    unreadable: BTreeMap<PathBuf, (Vec<u8>, EventError)>,
+    by_id: HashMap<String, Vec<PathBuf>>,
+    // Single events by start date; recurring events, and events with an override
+    // that may move them, are checked one by one
+    by_start: BTreeSet<(NaiveDate, PathBuf)>,
+    recurring: BTreeSet<PathBuf>,
+    // Longest span of a single event, so range queries reach back far enough to
+    // find events that started before the range
+    max_span_days: i64,
+}
+
+impl EventIndex {
+    pub(crate) fn load(dir: &Path) -> Result<EventIndex, EventError> {
+        let mut index = EventIndex {
+            dir: dir.to_path_buf(),
+            ..EventIndex::default()
+        };
+        index.refresh()?;
+        Ok(index)
+    }
+
+    /// Reads the files added or changed since the last refresh and forgets removed ones.
+    pub(crate) fn refresh(&mut self) -> Result<(), EventError> {
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                self.clear();
+                return Ok(());
+            }
+            Err(e) => return Err(e.into()),
+        };
+        let mut present = HashSet::new();
+        let mut changed = Vec::new();
+        for entry in entries.filter_map(|e| e.ok()) {
+            let path = entry.path();
+            if !entry.file_type().is_ok_and(|t| t.is_file()) || !is_event_file(&path) {
+                continue;
+            }
+            let stamp = entry.metadata().ok().map(|m| FileStamp::from(&m));
+            if stamp.is_none() || stamp != self.stamps.get(&path).copied() {
+                changed.push(path.clone());
+            }
+            present.insert(path);
+        }
+        let removed: Vec<PathBuf> = self
+            .stamps
+            .keys()
+            .filter(|path| !present.contains(*path))
+            .cloned()
+            .collect();
+        for path in &removed {
+            self.remove(path);
+        }
+        changed.sort();
+        self.reload(&changed)
+    }
+
+    fn clear(&mut self) {
+        *self = EventIndex {
+            dir: std::mem::take(&mut self.dir),
+            ..EventIndex::default()
+        };
+    }
+
+    /// Reads the given files of this directory again whatever their stamps;
+    /// files that no longer exist are forgotten.
+    pub(crate) fn reload(&mut self, paths: &[PathBuf]) -> Result<(), EventError> {
+        if paths.is_empty() {
+            return Ok(());
+        }
+        let staging = tempfile::tempdir()?;
+        for path in paths {
+            self.remove(path);
+            // Stamp before reading, so a write that lands meanwhile is seen next time
+            let Some(stamp) = FileStamp::of(path) else {
+                continue;
+            };
+            let content = match fs::read(path) {
+                Ok(content) => content,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(e.into()),
+            };
+            self.stamps.insert(path.clone(), stamp);
+            match parse_staged_event(staging.path(), &file_name(path), &content) {
+                Ok(record) => self.insert(path.clone(), record),
+                Err(e) => {
+                    self.unreadable.insert(path.clone(), (content, e));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn insert(&mut self, path: PathBuf, record: EventRecord) {
+        let event = &record.event;
+        self.by_id
+            .entry(event.id.clone())
+            .or_default()
+            .push(path.clone());
+        if event.recurrence == Recurrence::None && record.overrides.is_empty() {
+            let span = (event.effective_end_date() - event.start_date).num_days();
+            self.max_span_days = self.max_span_days.max(span);
+            self.by_start.insert((event.start_date, path.clone()));
+        } else {
+            self.recurring.insert(path.clone());
+        }
+        self.events.insert(path, record);
+    }
+
+    fn remove(&mut self, path: &Path) {
+        self.stamps.remove(path);
+        self.unreadable.remove(path);
+        let Some(EventRecord { event, .. }) = self.events.remove(path) else {
+            return;
+        };
+        if let Some(paths) = self.by_id.get_mut(&event.id) {
+            paths.retain(|p| p != path);
+            if paths.is_empty() {
+                self.by_id.remove(&event.id);
+            }
+        }
+        self.by_start
+            .remove(&(event.start_date, path.to_path_buf()));
+        self.recurring.remove(path);
+    }
+
+    /// All events, in file name order.
+    pub(crate) fn events(&self) -> impl Iterator<Item = &EventRecord> {
+        self.events.values()
+    }
+
+    /// Why each event file that doesn't parse was left out, in file name order.
+    pub(crate) fn unreadable(&self) -> Vec<EventError> {
+        self.unreadable.values().map(|(_, e)| e.clone()).collect()
+    }
+
+    /// The event with the given id and the file holding it. An id only found in
+    /// the text of a file that doesn't parse gives that file's ParseFailure.
+    pub(crate) fn find(&self, id: &str) -> Result<(&Path, &EventRecord), EventError> {
+        match self.by_id.get(id).map(Vec::as_slice) {
+            Some([path]) => Ok((path, &self.events[path])),
+            Some([_, _, ..]) => Err(EventError::DuplicateId(id.to_string())),
+            _ => match self
+                .unreadable
+                .values()
+                .find(|(content, _)| String::from_utf8_lossy(content).contains(id))
+            {
+                Some((_, e)) => Err(e.clone()),
+                None => Err(EventError::NotFound(id.to_string())),
+            },
+        }
+    }
+
+    /// The events occurring between [start] and [end] (inclusive), in file name order.
+    pub(crate) fn in_range(&self, start: NaiveDate, end: NaiveDate) -> Vec<&EventRecord> {
+        let earliest = start
+            .checked_sub_signed(chrono::Duration::days(self.max_span_days))
+            .unwrap_or(NaiveDate::MIN);
+        let mut paths: BTreeSet<&PathBuf> = self
+            .by_start
+            .range((earliest, PathBuf::new())..)
+            .take_while(|(date, _)| *date <= end)
+            .map(|(_, path)| path)
+            .collect();
+        paths.extend(&self.recurring);
+        paths
+            .into_iter()
+            .map(|path| &self.events[path])
+            .filter(|record| event_occurs_in_range(record, start, end))
+            .collect()
+    }
+}
+
+// Indexes of the calendar directories opened so far, keyed by canonical path
+fn event_indexes() -> &'static Mutex<HashMap<PathBuf, EventIndex>> {
+    static INDEXES: OnceLock<Mutex<HashMap<PathBuf, EventIndex>>> = OnceLock::new();
+    INDEXES.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+// Helper function to key indexes by directory however the path was spelled
+fn canonical_dir(dir: &Path) -> PathBuf {
+    fs::canonicalize(dir).unwrap_or_else(|_| dir.to_path_buf())
+}
+
+// Helper function to run [f] on the refreshed index of [calendar_dir], loading it on first use
+fn with_event_index<T>(
+    calendar_dir: &Path,
+    f: impl FnOnce(&mut EventIndex) -> Result<T, EventError>,
+) -> Result<T, EventError> {
+    let mut indexes = event_indexes()
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    let index = match indexes.entry(canonical_dir(calendar_dir)) {
+        Entry::Occupied(entry) => {
+            let index = entry.into_mut();
+            index.refresh()?;
+            index
+        }
+        Entry::Vacant(entry) => {
+            let index = EventIndex::load(entry.key())?;
+            entry.insert(index)
+        }
+    };
+    f(index)
+}
+
+// Helper function to read changed event files again in the open indexes holding them,
+// for changes whose file stamps may not tell, such as a pull or a write of our own
+fn reload_event_files(paths: &[PathBuf]) {
+    let mut indexes = event_indexes()
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    for index in indexes.values_mut() {
+        let in_dir: Vec<PathBuf> = paths
+            .iter()
+            .filter(|path| is_event_file(path))
+            .filter_map(|path| {
+                let dir = canonical_dir(path.parent()?);
+                let name = path.file_name()?;
+                (dir == index.dir).then(|| dir.join(name))
+            })
+            .collect();
+        // A file that can't be read now is read again once its stamp changes
+        let _ = index.reload(&in_dir);
+    }
+}
+
+/// Loads the events of [calendar_dir] into memory, so later queries only read the
+/// files that changed. Queries open a calendar on first use; calling this at start
+/// moves the initial load out of the first query.
+#[flutter_rust_bridge::frb]
+pub fn open_calendar(calendar_dir: String) -> Result<(), EventError> {
+    with_event_index(Path::new(&calendar_dir), |_| Ok(()))
+}
+
+/// Drops the in-memory events of [calendar_dir]; the next query loads them again.
+#[flutter_rust_bridge::frb]
+pub fn close_calendar(calendar_dir: String) {
+    event_indexes()
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .remove(&canonical_dir(Path::new(&calendar_dir)));
+}
+
+// ============================================================================
+// Calendar Watcher
+// ============================================================================
+
+/// A change to a calendar's events found on disk, e.g. an edit made with rcal on
+/// the desktop or files checked out by a pull.
+#[flutter_rust_bridge::frb]
+#[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::enum_variant_names)]
+pub enum CalendarChange {
+    EventAdded(EventDto),
+    EventChanged(EventDto),
+    /// Carries the event as it was last seen
+    EventRemoved(EventDto),
+}
+
+/// How long file changes must settle before the watcher reports them, so a burst
+/// like a git checkout arrives as one batch.
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Keeps a calendar directory watched until dropped.
+pub(crate) struct CalendarWatcher {
+    _debouncer: Debouncer<RecommendedWatcher>,
+}
+
+// Helper function to snapshot a calendar's events by id
+fn calendar_snapshot(dir: &Path) -> Result<HashMap<String, EventDto>, EventError> {
+    with_event_index(dir, |index| {
+        Ok(index
+            .events()
+            .map(|record| (record.event.id.clone(), record_to_dto(record)))
+            .collect())
+    })
+}
+
+// Helper function to list the changes between two snapshots of a calendar, in id order
+fn calendar_changes(
+    before: &HashMap<String, EventDto>,
+    after: &HashMap<String, EventDto>,
+) -> Vec<CalendarChange> {
+    let ids: BTreeSet<&String> = before.keys().chain(after.keys()).collect();
+    ids.into_iter()
+        .filter_map(|id| match (before.get(id), after.get(id)) {
+            (None, Some(event)) => Some(CalendarChange::EventAdded(event.clone())),
+            (Some(old), Some(event)) if old != event => {
+                Some(CalendarChange::EventChanged(event.clone()))
+            }
+            (Some(old), None) => Some(CalendarChange::EventRemoved(old.clone())),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Watches [calendar_dir] and passes each change to its events to [emit], debounced.
+/// Changes are found by comparing the calendar's events before and after each batch
+/// of file changes, so changes made through this API are reported as well.
+pub(crate) fn watch_calendar_impl(
+    calendar_dir: &Path,
+    emit: impl Fn(CalendarChange) + Send + 'static,
+) -> Result<CalendarWatcher, EventError> {
+    let dir = canonical_dir(calendar_dir);
+    let mut last = calendar_snapshot(&dir)?;
+    let watched = dir.clone();
+    let mut debouncer = new_debouncer(WATCH_DEBOUNCE, move |result: DebounceEventResult| {
+        let Ok(events) = result else {
+            return;
+        };
+        if !events.iter().any(|event| is_event_file(&event.path)) {
+            return;
+        }
+        // An unreadable directory is tried again with the next batch
+        let Ok(current) = calendar_snapshot(&watched) else {
+            return;
+        };
+        for change in calendar_changes(&last, &current) {
+            emit(change);
+        }
+        last = current;
+    })
+    .map_err(storage_error)?;
+    debouncer
+        .watcher()
+        .watch(&dir, RecursiveMode::NonRecursive)
+        .map_err(storage_error)?;
+
+    Ok(CalendarWatcher {
+        _debouncer: debouncer,
+    })
+}
+
+// Watchers started through watch_calendar, keyed by canonical directory
+fn calendar_watchers() -> &'static Mutex<HashMap<PathBuf, CalendarWatcher>> {
+    static WATCHERS: OnceLock<Mutex<HashMap<PathBuf, CalendarWatcher>>> = OnceLock::new();
+    WATCHERS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Watches [calendar_dir] for changes to its event files, from rcal, a pull or any
+/// other tool, and streams them to [sink] as they settle. Watching a directory again
+/// replaces its earlier watcher; unwatch_calendar stops it.
+#[flutter_rust_bridge::frb]
+pub fn watch_calendar(
+    calendar_dir: String,
+    sink: StreamSink<CalendarChange>,
+) -> Result<(), EventError> {
+    let watcher = watch_calendar_impl(Path::new(&calendar_dir), move |change| {
+        let _ = sink.add(change);
+    })?;
+    calendar_watchers()
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(canonical_dir(Path::new(&calendar_dir)), watcher);
+    Ok(())
+}
+
+/// Stops watching [calendar_dir]; its stream gets no further changes.
+#[flutter_rust_bridge::frb]
+pub fn unwatch_calendar(calendar_dir: String) {
+    calendar_watchers()
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .remove(&canonical_dir(Path::new(&calendar_dir)));
+}
+
+// ============================================================================
+// Event Validation Function
+// ============================================================================
+
+/// Validates an event and returns validation errors.
+/// Returns Ok(()) if valid, Err(EventError) naming the offending field if invalid.
+/// Uses the existing validation logic from rcal-lib.
+#[flutter_rust_bridge::frb]
+pub fn validate_event(
+    title: String,
+    start_date: String,
+    end_date: Option<String>,
+    start_time: Option<String>,
+    end_time: Option<String>,
+) -> Result<(), EventError> {
+    // Validate title
+    if !is_valid_title(&title) {
+        return Err(EventError::TitleInvalid(title));
+    }
+
+    // Parse and validate start date
+    let start = parse_date("start_date", &start_date)?;
+
+    // Validate end date >= start date
+    if let Some(ref end_date_str) = end_date {
+        let end = parse_date("end_date", end_date_str)?;
+        if !is_valid_date_range(start, Some(end)) {
+            return Err(EventError::InvalidRange {
+                field: "end_date".to_string(),
+            });
+        }
+    }
+
+    // Validate time range for non-all-day events
+    if !start_time.is_none() || !end_time.is_none() {
+        let start_t = match start_time {
+            Some(t) => parse_time("start_time", &t)?,
+            None => return Err(EventError::MissingField("start_time".to_string())),
+        };
+
+        let end_t = match end_time {
+            Some(t) => Some(parse_time("end_time", &t)?),
+            None => None,
+        };
+
+        if !is_valid_time_range(start_t, end_t) {
+            return Err(EventError::InvalidRange {
+                field: "end_time".to_string(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+// ============================================================================
+// Recurrence Rules (RFC 5545 RRULE)
+// ============================================================================
+
+/// Prefix of the event file line that stores a recurrence rule. rcal-lib skips
+/// lines it doesn't know and keeps reading the rule's frequency from its own
+/// Recurrence line.
+const RRULE_LINE: &str = "- **RRULE**: ";
+
+const WEEKDAYS: [(Weekday, &str); 7] = [
+    (Weekday::Monday, "MO"),
+    (Weekday::Tuesday, "TU"),
+    (Weekday::Wednesday, "WE"),
+    (Weekday::Thursday, "TH"),
+    (Weekday::Friday, "FR"),
+    (Weekday::Saturday, "SA"),
+    (Weekday::Sunday, "SU"),
+];
+
+const FREQUENCIES: [(Frequency, &str); 4] = [
+    (Frequency::Daily, "DAILY"),
+    (Frequency::Weekly, "WEEKLY"),
+    (Frequency::Monthly, "MONTHLY"),
+    (Frequency::Yearly, "YEARLY"),
+];
+
+impl Weekday {
+    fn of(date: NaiveDate) -> Weekday {
+        WEEKDAYS[date.weekday().num_days_from_monday() as usize].0
+    }
+}
+
+// Helper function for an RRULE error naming the offending text
+fn invalid_recurrence(value: &str, reason: impl Into<String>) -> EventError {
+    EventError::InvalidRecurrence {
+        value: value.to_string(),
+        reason: reason.into(),
+    }
+}
+
+// Helper function to parse a comma separated RRULE list of non-zero numbers within ±[max]
+fn parse_rule_numbers(
+    text: &str,
+    part: &str,
+    value: &str,
+    max: i32,
+) -> Result<Vec<i32>, EventError> {
+    value
+        .split(',')
+        .map(|n| match n.trim().parse::<i32>() {
+            Ok(n) if n != 0 && n.abs() <= max => Ok(n),
+            _ => Err(invalid_recurrence(
+                text,
+                format!(
+                    "{} takes numbers from 1 to {}, or negative from the end",
+                    part, max
+                ),
+            )),
+        })
+        .collect()
+}
+
+/// Parses RRULE text such as "FREQ=WEEKLY;INTERVAL=2;BYDAY=TU", with or without
+/// the "RRULE:" prefix.
+fn parse_rrule(text: &str) -> Result<RecurrenceRule, EventError> {
+    let body = text.trim();
+    // The prefix is matched without case, as is_rrule_text matches it
+    let body = match body.get(..6) {
+        Some(prefix) if prefix.eq_ignore_ascii_case("RRULE:") => &body[6..],
+        _ => body,
+    };
+    let mut frequency = None;
+    let mut rule = RecurrenceRule {
+        frequency: Frequency::Daily,
+        interval: 1,
+        by_day: Vec::new(),
+        by_month_day: Vec::new(),
+        by_set_pos: Vec::new(),
+        count: None,
+        until: None,
+    };
+
+    for part in body.split(';').filter(|p| !p.trim().is_empty()) {
+        let (name, value) = part.split_once('=').ok_or_else(|| {
+            invalid_recurrence(text, format!("expected NAME=VALUE, got {}", part))
+        })?;
+        let name = name.trim().to_ascii_uppercase();
+        let value = value.trim();
+        match name.as_str() {
+            "FREQ" => {
+                let found = FREQUENCIES
+                    .iter()
+                    .find(|(_, code)| code.eq_ignore_ascii_case(value))
+                    .ok_or_else(|| {
+                        invalid_recurrence(text, format!("unsupported FREQ {}", value))
+                    })?;
+                frequency = Some(found.0);
+            }
+            "INTERVAL" => {
+                rule.interval = value.parse().ok().filter(|n| *n > 0).ok_or_else(|| {
+                    invalid_recurrence(text, "INTERVAL must be a positive number")
+                })?;
+            }
+            "COUNT" => {
+                rule.count =
+                    Some(value.parse().ok().filter(|n| *n > 0).ok_or_else(|| {
+                        invalid_recurrence(text, "COUNT must be a positive number")
+                    })?);
+            }
+            "UNTIL" => {
+                // A date, or a date-time of which only the date is used
+                let date = value.get(..8).unwrap_or(value);
+                let until = NaiveDate::parse_from_str(date, "%Y%m%d")
+                    .map_err(|_| invalid_recurrence(text, "UNTIL must be a YYYYMMDD date"))?;
+                rule.until = Some(until.format("%Y-%m-%d").to_string());
+            }
+            "BYDAY" => {
+                for day in value.split(',') {
+                    let day = day.trim().to_ascii_uppercase();
+                    // Split off the two-letter weekday on a character boundary
+                    let split = day.char_indices().rev().nth(1).map_or(0, |(i, _)| i);
+                    let (ordinal, code) = day.split_at(split);
+                    let weekday = WEEKDAYS
+                        .iter()
+                        .find(|(_, c)| *c == code)
+                        .map(|(w, _)| *w)
+                        .ok_or_else(|| {
+                            invalid_recurrence(text, format!("unknown BYDAY weekday {}", day))
+                        })?;
+                    let ordinal = match ordinal.trim_start_matches('+') {
+                        "" => None,
+                        n => Some(
+                            n.parse::<i32>()
+                                .ok()
+                                .filter(|n| *n != 0 && n.abs() <= 53)
+                                .ok_or_else(|| {
+                                    invalid_recurrence(
+                                        text,
+                                        format!("bad BYDAY ordinal in {}", day),
+                                    )
+                                })?,
+                        ),
+                    };
+                    rule.by_day.push(WeekdayRule { weekday, ordinal });
+                }
+            }
+            "BYMONTHDAY" => rule.by_month_day = parse_rule_numbers(text, "BYMONTHDAY", value, 31)?,
+            "BYSETPOS" => rule.by_set_pos = parse_rule_numbers(text, "BYSETPOS", value, 366)?,
+            // Weeks starting on Monday is all the expansion knows
+            "WKST" if value.eq_ignore_ascii_case("MO") => {}
+            _ => {
+                return Err(invalid_recurrence(
+                    text,
+                    format!("unsupported RRULE part {}", name),
+                ))
+            }
+        }
+    }
+
+    rule.frequency = frequency.ok_or_else(|| invalid_recurrence(text, "FREQ is required"))?;
+    validate_rule(&rule).map_err(|reason| invalid_recurrence(text, reason))?;
+    Ok(rule)
+}
+
+/// Formats a recurrence rule as RRULE text (without the "RRULE:" prefix).
+fn format_rrule(rule: &RecurrenceRule) -> String {
+    let code = |w: Weekday| {
+        WEEKDAYS
+            .iter()
+            .find(|(d, _)| *d == w)
+            .map_or("MO", |(_, c)| c)
+    };
+    let numbers = |ns: &[i32]| ns.iter().map(i32::to_string).collect::<Vec<_>>().join(",");
+    let frequency = FREQUENCIES
+        .iter()
+        .find(|(f, _)| *f == rule.frequency)
+        .map_or("DAILY", |(_, c)| c);
+
+    let mut parts = vec![format!("FREQ={}", frequency)];
+    if rule.interval > 1 {
+        parts.push(format!("INTERVAL={}", rule.interval));
+    }
+    if !rule.by_day.is_empty() {
+        let days: Vec<String> = rule
+            .by_day
+            .iter()
+            .map(|d| match d.ordinal {
+                Some(n) => format!("{}{}", n, code(d.weekday)),
+                None => code(d.weekday).to_string(),
+            })
+            .collect();
+        parts.push(format!("BYDAY={}", days.join(",")));
+    }
+    if !rule.by_month_day.is_empty() {
+        parts.push(format!("BYMONTHDAY={}", numbers(&rule.by_month_day)));
+    }
+    if !rule.by_set_pos.is_empty() {
+        parts.push(format!("BYSETPOS={}", numbers(&rule.by_set_pos)));
+    }
+    if let Some(count) = rule.count {
+        parts.push(format!("COUNT={}", count));
+    }
+    if let Some(until) = &rule.until {
+        parts.push(format!("UNTIL={}", until.replace('-', "")));
+    }
+    parts.join(";")
+}
+
+// Helper function to check the combinations RFC 5545 rules out, or that the
+// expansion doesn't support
+fn validate_rule(rule: &RecurrenceRule) -> Result<(), String> {
+    if rule.interval == 0 {
+        return Err("INTERVAL must be a positive number".to_string());
+    }
+    if rule.count == Some(0) {
+        return Err("COUNT must be a positive number".to_string());
+    }
+    if rule.count.is_some() && rule.until.is_some() {
+        return Err("COUNT and UNTIL can't both be set".to_string());
+    }
+    if let Some(until) = &rule.until {
+        NaiveDate::parse_from_str(until, "%Y-%m-%d")
+            .map_err(|_| format!("UNTIL {} is not a date", until))?;
+    }
+    let ordinals = rule.by_day.iter().filter_map(|d| d.ordinal);
+    match rule.frequency {
+        Frequency::Daily | Frequency::Weekly if ordinals.clone().next().is_some() => {
+            return Err("BYDAY ordinals need FREQ=MONTHLY or FREQ=YEARLY".to_string())
+        }
+        Frequency::Monthly if ordinals.clone().any(|n| n.abs() > 5) => {
+            return Err("a month has at most 5 of each weekday".to_string())
+        }
+        _ => {}
+    }
+    if ordinals.clone().any(|n| n == 0) {
+        return Err("BYDAY ordinals can't be 0".to_string());
+    }
+    if rule.frequency == Frequency::Weekly && !rule.by_month_day.is_empty() {
+        return Err("BYMONTHDAY can't be used with FREQ=WEEKLY".to_string());
+    }
+    if rule
+        .by_month_day
+        .iter()
+        .chain(&rule.by_set_pos)
+        .any(|n| *n == 0)
+    {
+        return Err("BYMONTHDAY and BYSETPOS can't be 0".to_string());
+    }
+    if !rule.by_set_pos.is_empty() && rule.by_day.is_empty() && rule.by_month_day.is_empty() {
+        return Err("BYSETPOS needs BYDAY or BYMONTHDAY".to_string());
+    }
+    Ok(())
+}
+
+// Helper function for the rcal-lib recurrence closest to a rule, written to the
+// Recurrence line for readers that don't know RRULEs
+fn rule_recurrence(rule: &RecurrenceRule) -> Recurrence {
+    match rule.frequency {
+        Frequency::Daily => Recurrence::Daily,
+        Frequency::Weekly => Recurrence::Weekly,
+        Frequency::Monthly => Recurrence::Monthly,
+        Frequency::Yearly => Recurrence::Yearly,
+    }
+}
+
+// Helper function for the first day of the [n]th period after the one holding [dtstart]
+fn rule_period_start(frequency: Frequency, dtstart: NaiveDate, n: u32) -> Option<NaiveDate> {
+    match frequency {
+        Frequency::Daily => dtstart.checked_add_days(chrono::Days::new(n.into())),
+        Frequency::Weekly => dtstart
+            .checked_sub_days(chrono::Days::new(
+                dtstart.weekday().num_days_from_monday().into(),
+            ))?
+            .checked_add_days(chrono::Days::new(u64::from(n) * 7)),
+        Frequency::Monthly => dtstart
+            .with_day(1)?
+            .checked_add_months(chrono::Months::new(n)),
+        Frequency::Yearly => NaiveDate::from_ymd_opt(dtstart.year().checked_add(n as i32)?, 1, 1),
+    }
+}
+
+// Helper function for the days after [period_start] in a period of [frequency]
+fn rule_period_days(frequency: Frequency, period_start: NaiveDate) -> Vec<NaiveDate> {
+    let next = match frequency {
+        Frequency::Daily => period_start.succ_opt(),
+        Frequency::Weekly => period_start.checked_add_days(chrono::Days::new(7)),
+        Frequency::Monthly => period_start.checked_add_months(chrono::Months::new(1)),
+        Frequency::Yearly => period_start.checked_add_months(chrono::Months::new(12)),
+    };
+    match next {
+        Some(next) => period_start.iter_days().take_while(|d| *d < next).collect(),
+        None => vec![period_start],
+    }
+}
+
+// Helper function for the days of the month holding [date]
+fn days_in_month(date: NaiveDate) -> u32 {
+    let first = date.with_day(1).unwrap_or(date);
+    first
+        .checked_add_months(chrono::Months::new(1))
+        .and_then(|next| next.pred_opt())
+        .map_or(31, |last| last.day())
+}
+
+// Helper function to tell whether [date] is the nth (or, negative, nth from last)
+// such weekday of its month or year
+fn matches_ordinal(date: NaiveDate, ordinal: i32, frequency: Frequency) -> bool {
+    let (position, length) = match frequency {
+        Frequency::Yearly => {
+            let year_length = if NaiveDate::from_ymd_opt(date.year(), 2, 29).is_some() {
+                366
+            } else {
+                365
+            };
+            (date.ordinal() as i32, year_length)
+        }
+        _ => (date.day() as i32, days_in_month(date) as i32),
+    };
+    if ordinal > 0 {
+        (position - 1) / 7 + 1 == ordinal
+    } else {
+        (length - position) / 7 + 1 == -ordinal
+    }
+}
+
+// Helper function for the occurrences a rule picks in one period, sorted
+fn rule_period_occurrences(
+    rule: &RecurrenceRule,
+    dtstart: NaiveDate,
+    period_start: NaiveDate,
+) -> Vec<NaiveDate> {
+    let matches = |date: &NaiveDate| {
+        let by_day = rule.by_day.is_empty()
+            || rule.by_day.iter().any(|d| {
+                d.weekday == Weekday::of(*date)
+                    && d.ordinal
+                        .is_none_or(|n| matches_ordinal(*date, n, rule.frequency))
+            });
+        let month_length = days_in_month(*date) as i32;
+        let by_month_day = rule.by_month_day.is_empty()
+            || rule.by_month_day.iter().any(|n| {
+                let day = if *n > 0 { *n } else { month_length + 1 + n };
+                day == date.day() as i32
+            });
+        // Without BYDAY or BYMONTHDAY, the period repeats the start date
+        let implied = !rule.by_day.is_empty()
+            || !rule.by_month_day.is_empty()
+            || match rule.frequency {
+                Frequency::Daily => true,
+                Frequency::Weekly => date.weekday() == dtstart.weekday(),
+                Frequency::Monthly => date.day() == dtstart.day(),
+                Frequency::Yearly => date.month() == dtstart.month() && date.day() == dtstart.day(),
+            };
+        by_day && by_month_day && implied
+    };
+    let dates: Vec<NaiveDate> = rule_period_days(rule.frequency, period_start)
+        .into_iter()
+        .filter(matches)
+        .collect();
+    if rule.by_set_pos.is_empty() {
+        return dates;
+    }
+
+    let mut picked: Vec<NaiveDate> = rule
+        .by_set_pos
+        .iter()
+        .filter_map(|pos| {
+            let index = if *pos > 0 {
+                *pos as usize - 1
+            } else {
+                dates.len().checked_sub(pos.unsigned_abs() as usize)?
+            };
+            dates.get(index).copied()
+        })
+        .collect();
+    picked.sort();
+    picked.dedup();
+    picked
+}
+
+/// Expands a rule into the start dates of its occurrences up to [bound] (inclusive),
+/// honoring COUNT and UNTIL. The event's start date always counts as the first.
+fn expand_rule(rule: &RecurrenceRule, dtstart: NaiveDate, bound: NaiveDate) -> Vec<NaiveDate> {
+    let until = rule
+        .until
+        .as_deref()
+        .and_then(|u| NaiveDate::parse_from_str(u, "%Y-%m-%d").ok());
+    let last = until.map_or(bound, |until| until.min(bound));
+    let limit = rule.count.map_or(usize::MAX, |count| count as usize);
+    let mut starts = vec![dtstart];
+
+    let mut n = 0u32;
+    while starts.len() < limit {
+        let Some(period_start) = rule_period_start(rule.frequency, dtstart, n) else {
+            break;
+        };
+        if period_start > last {
+            break;
+        }
+        for date in rule_period_occurrences(rule, dtstart, period_start) {
+            if date > last || starts.len() >= limit {
+                break;
+            }
+            if date > dtstart {
+                starts.push(date);
+            }
+        }
+        n = match n.checked_add(rule.interval) {
+            Some(next) => next,
+            None => break,
+        };
+    }
+    starts
+}
+
+/// Parses RRULE text such as "FREQ=MONTHLY;BYDAY=1MO" into a recurrence rule,
+/// with or without the "RRULE:" prefix.
+#[flutter_rust_bridge::frb]
+pub fn parse_recurrence_rule(text: String) -> Result<RecurrenceRule, EventError> {
+    parse_rrule(&text)
+}
+
+/// Formats a recurrence rule as RRULE text, as it is stored in event files.
+#[flutter_rust_bridge::frb]
+pub fn format_recurrence_rule(rule: RecurrenceRule) -> String {
+    format_rrule(&rule)
+}
+
+// ============================================================================
+// Occurrence Exceptions (EXDATE and RECURRENCE-ID)
+// ============================================================================
 
-    // Analyze the diff to see what changed
-    #[allow(unused_variables)]
-    let (deleted_paths, added_paths, modified_paths): (Vec<String>, Vec<String>, Vec<String>) =
-        if result.is_ok() {
-            if let Some(new_tree) = &new_tree {
-                let old_tree = repo.find_tree(old_oid).ok();
-                let diff = if let Some(ref old) = old_tree {
-                    repo.diff_tree_to_tree(Some(old), Some(new_tree), None)
-                } else {
-                    // If old tree not available, diff against HEAD
-                    repo.diff_tree_to_tree(None, Some(new_tree), None)
-                }
-                .ok();
-
-                if let Some(diff) = diff {
-                    let deleted: Vec<String> = diff
-                        .deltas()
-                        .filter(|d| d.status() == Delta::Deleted)
-                        .map(|d| {
-                            d.old_file().path().map_or_else(
-                                || "<unknown>".to_string(),
-                                |p| p.to_string_lossy().to_string(),
-                            )
-                        })
-                        .collect();
-                    let added: Vec<String> = diff
-                        .deltas()
-                        .filter(|d| d.status() == Delta::Added)
-                        .map(|d| {
-                            d.new_file().path().map_or_else(
-                                || "<unknown>".to_string(),
-                                |p| p.to_string_lossy().to_string(),
-                            )
-                        })
-                        .collect();
-                    let modified: Vec<String> = diff
-                        .deltas()
-                        .filter(|d| d.status() == Delta::Modified)
-                        .map(|d| {
-                            d.old_file().path().map_or_else(
-                                || "<unknown>".to_string(),
-                                |p| p.to_string_lossy().to_string(),
-                            )
-                        })
-                        .collect();
-
-                    (deleted, added, modified)
-                } else {
-                    (vec![], vec![], vec![])
-                }
-            } else {
-                (vec![], vec![], vec![])
-            }
-        } else {
-            (vec![], vec![], vec![])
-        };
+/// Prefix of the event file line listing skipped occurrences.
+const EXCEPTIONS_LINE: &str = "- **Exceptions**: ";
 
-    drop(new_tree);
+/// Prefix of an event file line changing one occurrence, e.g.
+/// "- **Override**: 2025-03-24; title=Team sync; start_time=10:00".
+const OVERRIDE_LINE: &str = "- **Override**: ";
 
-    // Handle stash after pull operation
-    if stashed {
-        if result.is_ok() {
-            // Pull succeeded: DON'T restore stash - we want remote (HEAD) to win
-            // The checkout_head() already gave us the correct files from remote
-            // Drop any stashed changes to avoid conflicts
-            let _ = repo.stash_drop(0);
-        } else {
-            // Pull failed: restore local changes via stash_pop
-            if let Err(_) = repo.stash_pop(0, None) {
-                let _ = repo.stash_drop(0);
-            }
+// Helper function to escape override values like iCalendar TEXT, so they fit on one line
+fn escape_text(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace('\n', "\\n")
+}
+
+// Helper function to split an override line at its unescaped semicolons, unescaping the parts
+fn split_escaped(text: &str) -> Vec<String> {
+    let mut parts = vec![String::new()];
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some('n') => parts.last_mut().unwrap().push('\n'),
+                Some(other) => parts.last_mut().unwrap().push(other),
+                None => {}
+            },
+            ';' => parts.push(String::new()),
+            c => parts.last_mut().unwrap().push(c),
         }
     }
+    parts
+}
 
-    // Handle deletion of files that were deleted in remote
-    // This must happen outside the stash block to ensure deletions are processed
-    // even when there were no local changes to stash
-    if result.is_ok() {
-        for path in deleted_paths {
-            if let Some(workdir) = repo.workdir() {
-                let full_path = workdir.join(&path);
-                if full_path.exists() {
-                    let _ = fs::remove_file(&full_path);
-                }
-            }
+// Helper function pairing the fields an override can set with their names in event files
+fn override_fields(o: &OccurrenceOverride) -> [(&'static str, Option<String>); 7] {
+    [
+        ("title", o.title.clone()),
+        ("description", o.description.clone()),
+        ("start_date", o.start_date.clone()),
+        ("end_date", o.end_date.clone()),
+        ("start_time", o.start_time.clone()),
+        ("end_time", o.end_time.clone()),
+        ("all_day", o.is_all_day.map(|b| b.to_string())),
+    ]
+}
+
+/// Formats an override as the text of its event file line.
+fn format_override(o: &OccurrenceOverride) -> String {
+    let mut parts = vec![o.occurrence_date.clone()];
+    for (name, value) in override_fields(o) {
+        if let Some(value) = value {
+            parts.push(format!("{}={}", name, escape_text(&value)));
         }
     }
+    parts.join("; ")
+}
 
-    result
+/// Parses the text of an override line.
+fn parse_override(text: &str) -> Result<OccurrenceOverride, EventError> {
+    let mut parts = split_escaped(text).into_iter();
+    let date = parts.next().unwrap_or_default();
+    let mut o = OccurrenceOverride {
+        occurrence_date: date.trim().to_string(),
+        title: None,
+        description: None,
+        start_date: None,
+        end_date: None,
+        start_time: None,
+        end_time: None,
+        is_all_day: None,
+    };
+    for part in parts {
+        let (name, value) = part
+            .split_once('=')
+            .ok_or_else(|| EventError::MissingField(part.trim().to_string()))?;
+        let flag = value.trim() == "true";
+        let value = Some(value.to_string());
+        match name.trim() {
+            "title" => o.title = value,
+            "description" => o.description = value,
+            "start_date" => o.start_date = value,
+            "end_date" => o.end_date = value,
+            "start_time" => o.start_time = value,
+            "end_time" => o.end_time = value,
+            "all_day" => o.is_all_day = Some(flag),
+            _ => {}
+        }
+    }
+    validate_override(&o)?;
+    Ok(o)
 }
 
-#[flutter_rust_bridge::frb]
-pub fn git_pull(
-    path: String,
-    username: Option<String>,
-    password: Option<String>,
-    ssh_key_path: Option<String>,
-) -> Result<String, GitError> {
-    git_pull_impl(path, username, password, ssh_key_path)
+// Helper function to check the dates, times and title an override sets
+fn validate_override(o: &OccurrenceOverride) -> Result<(), EventError> {
+    parse_date("occurrence_date", &o.occurrence_date)?;
+    if let Some(title) = &o.title {
+        if !is_valid_title(title) {
+            return Err(EventError::TitleInvalid(title.clone()));
+        }
+    }
+    for (field, date) in [("start_date", &o.start_date), ("end_date", &o.end_date)] {
+        if let Some(date) = date {
+            parse_date(field, date)?;
+        }
+    }
+    for (field, time) in [("start_time", &o.start_time), ("end_time", &o.end_time)] {
+        if let Some(time) = time {
+            parse_time(field, time)?;
+        }
+    }
+    Ok(())
 }
 
-fn git_push_impl(
-    path: String,
-    username: Option<String>,
-    password: Option<String>,
-    ssh_key_path: Option<String>,
-) -> Result<String, GitError> {
-    let repo = Repository::open(&path)?;
-    let branch_name = extract_branch_name(&repo);
-    let mut remote = repo.find_remote("origin")?;
-    let mut callbacks = git2::RemoteCallbacks::new();
-    let username = username.clone();
-    let password = password.clone();
-    let ssh_key_path = ssh_key_path.clone();
-    callbacks
-        .credentials(move |url, _, _| get_credentials(url, &username, &password, &ssh_key_path));
-    callbacks.certificate_check(move |cert, hostname| {
-        if let Some(x509) = cert.as_x509() {
-            match validate_certificate(hostname, x509.data()) {
-                Ok(()) => Ok(git2::CertificateCheckStatus::CertificateOk),
-                Err(e) => Err(git2::Error::from_str(&e)),
-            }
-        } else {
-            Ok(git2::CertificateCheckStatus::CertificateOk)
+/// Applies an override to an occurrence lasting [span].
+fn apply_override(instance: &mut CalendarEvent, o: &OccurrenceOverride, span: chrono::Duration) {
+    if let Some(title) = &o.title {
+        instance.title = title.clone();
+    }
+    if let Some(description) = &o.description {
+        instance.description = description.clone();
+    }
+    if let Some(start) = o
+        .start_date
+        .as_deref()
+        .and_then(|d| parse_date("start_date", d).ok())
+    {
+        instance.start_date = start;
+        instance.end_date = instance.end_date.map(|_| start + span);
+    }
+    if let Some(end) = o
+        .end_date
+        .as_deref()
+        .and_then(|d| parse_date("end_date", d).ok())
+    {
+        instance.end_date = (end != instance.start_date).then_some(end);
+    }
+    if let Some(is_all_day) = o.is_all_day {
+        instance.is_all_day = is_all_day;
+        if is_all_day {
+            instance.start_time = NaiveTime::MIN;
+            instance.end_time = None;
         }
-    });
-    let mut push_options = git2::PushOptions::new();
-    push_options.remote_callbacks(callbacks);
-    let refspec = format!("refs/heads/{}", branch_name);
-    remote.push(&[refspec.as_str()], Some(&mut push_options))?;
-    Ok("Pushed successfully".to_string())
+    }
+    if let Some(start) = o
+        .start_time
+        .as_deref()
+        .and_then(|t| parse_time("start_time", t).ok())
+    {
+        instance.start_time = start;
+    }
+    if let Some(end) = o
+        .end_time
+        .as_deref()
+        .and_then(|t| parse_time("end_time", t).ok())
+    {
+        instance.end_time = Some(end);
+    }
 }
 
-#[flutter_rust_bridge::frb]
-pub fn git_push(
-    path: String,
-    username: Option<String>,
-    password: Option<String>,
-    ssh_key_path: Option<String>,
-) -> Result<String, GitError> {
-    git_push_impl(path, username, password, ssh_key_path)
+/// The occurrence of [record] falling on [date] by its recurrence, with its override applied.
+fn occurrence_on(record: &EventRecord, date: NaiveDate) -> CalendarEvent {
+    let event = &record.event;
+    let span = event.effective_end_date() - event.start_date;
+    let mut instance = event.clone();
+    if date != event.start_date {
+        instance.start_date = date;
+        instance.end_date = event.end_date.map(|_| date + span);
+        instance.is_recurring_instance = true;
+        instance.base_date = Some(event.start_date);
+    }
+    let key = date.format("%Y-%m-%d").to_string();
+    if let Some(o) = record.overrides.iter().find(|o| o.occurrence_date == key) {
+        apply_override(&mut instance, o, span);
+    }
+    instance
 }
 
-fn git_status_impl(path: String) -> Result<Vec<StatusEntry>, GitError> {
-    let repo = Repository::open(&path)?;
-    let statuses = repo.statuses(None)?;
-    let mut entries = Vec::new();
-    for entry in statuses.iter() {
-        let path = entry.path().unwrap_or("unknown");
-        let status = format!("{:?}", entry.status());
-        entries.push(StatusEntry {
-            path: path.to_string(),
-            status,
+// Helper function to fail unless [record] has an occurrence on [date] that isn't skipped
+fn check_occurrence(record: &EventRecord, date: NaiveDate) -> Result<(), EventError> {
+    let occurs = occurrence_starts(record, date).last() == Some(&date);
+    if !occurs || record.excluded_dates.contains(&date) {
+        return Err(EventError::NoOccurrence {
+            id: record.event.id.clone(),
+            date: date.format("%Y-%m-%d").to_string(),
         });
     }
-    Ok(entries)
+    Ok(())
+}
+
+// Helper function for the rule equivalent to one of rcal-lib's recurrences from [start].
+// rcal-lib moves a monthly occurrence that a shorter month lacks to its last day, which
+// the latest of the days from the 28th on picks as well
+fn recurrence_as_rule(recurrence: Recurrence, start: NaiveDate) -> Option<RecurrenceRule> {
+    let frequency = match recurrence {
+        Recurrence::Daily => Frequency::Daily,
+        Recurrence::Weekly => Frequency::Weekly,
+        Recurrence::Monthly => Frequency::Monthly,
+        Recurrence::Yearly => Frequency::Yearly,
+        _ => return None,
+    };
+    let (by_month_day, by_set_pos) = if frequency == Frequency::Monthly && start.day() > 28 {
+        ((28..=start.day() as i32).collect(), vec![-1])
+    } else {
+        (Vec::new(), Vec::new())
+    };
+    Some(RecurrenceRule {
+        frequency,
+        interval: 1,
+        by_day: Vec::new(),
+        by_month_day,
+        by_set_pos,
+        count: None,
+        until: None,
+    })
+}
+
+/// How many years past a split its parts are checked to hold the series' occurrences.
+const SPLIT_CHECK_YEARS: u32 = 8;
+
+// Helper function to change the event [id] of [calendar_dir] and write it back to its file
+fn edit_event(
+    calendar_dir: &str,
+    id: &str,
+    change: impl FnOnce(&mut EventRecord) -> Result<(), EventError>,
+) -> Result<(), EventError> {
+    let dir = PathBuf::from(calendar_dir);
+    let (path, mut record) = with_event_index(&dir, |index| {
+        let (path, record) = index.find(id)?;
+        Ok((path.to_path_buf(), record.clone()))
+    })?;
+    change(&mut record)?;
+    let written = write_event_file(&dir, &record, Some(&path))?;
+    reload_event_files(&[path, written]);
+    Ok(())
 }
 
+/// Skips the occurrence of event [id] that falls on [date] by its recurrence (EXDATE).
+/// An override of that occurrence is dropped with it.
 #[flutter_rust_bridge::frb]
-pub fn git_status(path: String) -> Result<Vec<StatusEntry>, GitError> {
-    git_status_impl(path)
+pub fn skip_occurrence(id: String, date: String, calendar_dir: String) -> Result<(), EventError> {
+    let date = parse_date("date", &date)?;
+    edit_event(&calendar_dir, &id, |record| {
+        check_occurrence(record, date)?;
+        let key = date.format("%Y-%m-%d").to_string();
+        record.overrides.retain(|o| o.occurrence_date != key);
+        record.excluded_dates.push(date);
+        record.excluded_dates.sort();
+        Ok(())
+    })
 }
-fn git_add_remote_impl(path: String, name: String, url: String) -> Result<String, GitError> {
-    let repo = Repository::open(&path)?;
-    repo.remote(&name, &url)?;
-    Ok("Remote added".to_string())
+
+/// Changes one occurrence of event [id], such as its time or title, leaving the rest
+/// of the series alone (RECURRENCE-ID). [changes] names the occurrence by the date it
+/// falls on by the recurrence and replaces an earlier override of it.
+#[flutter_rust_bridge::frb]
+pub fn override_occurrence(
+    id: String,
+    changes: OccurrenceOverride,
+    calendar_dir: String,
+) -> Result<(), EventError> {
+    validate_override(&changes)?;
+    let date = parse_date("occurrence_date", &changes.occurrence_date)?;
+    edit_event(&calendar_dir, &id, |record| {
+        check_occurrence(record, date)?;
+        let changes = OccurrenceOverride {
+            occurrence_date: date.format("%Y-%m-%d").to_string(),
+            ..changes
+        };
+        record
+            .overrides
+            .retain(|o| o.occurrence_date != changes.occurrence_date);
+        record.overrides.push(changes);
+        record
+            .overrides
+            .sort_by(|a, b| a.occurrence_date.cmp(&b.occurrence_date));
+        Ok(())
+    })
 }
 
+/// Splits the recurring event [id] at its occurrence on [date]: the series ends the
+/// day before, and a new series with a new id carries the occurrences from [date] on,
+/// with their skips and overrides. Edit the new series to change "this and following".
+/// Returns the new series' id. A series whose occurrences the two parts can't keep
+/// where they are, such as a yearly one from Feb 29, isn't split.
 #[flutter_rust_bridge::frb]
-pub fn git_add_remote(path: String, name: String, url: String) -> Result<String, GitError> {
-    git_add_remote_impl(path, name, url)
+pub fn split_series_from(
+    id: String,
+    date: String,
+    calendar_dir: String,
+) -> Result<String, EventError> {
+    let date = parse_date("date", &date)?;
+    let dir = PathBuf::from(&calendar_dir);
+    let (path, record) = with_event_index(&dir, |index| {
+        let (path, record) = index.find(&id)?;
+        Ok((path.to_path_buf(), record.clone()))
+    })?;
+    check_occurrence(&record, date)?;
+    let event = &record.event;
+    let rule = match record
+        .rule
+        .clone()
+        .or_else(|| recurrence_as_rule(event.recurrence, event.start_date))
+    {
+        Some(rule) if date > event.start_date => rule,
+        _ => {
+            return Err(EventError::InvalidRange {
+                field: "date".to_string(),
+            })
+        }
+    };
+    let day_before = date.pred_opt().unwrap_or(date);
+    let key = date.format("%Y-%m-%d").to_string();
+
+    // The first part ends the day before; a count becomes that end date
+    let mut head = record.clone();
+    head.rule = Some(RecurrenceRule {
+        count: None,
+        until: Some(day_before.format("%Y-%m-%d").to_string()),
+        ..rule.clone()
+    });
+    head.excluded_dates.retain(|d| *d < date);
+    head.overrides.retain(|o| o.occurrence_date < key);
+
+    // The new series keeps what's left of the count
+    let horizon = date
+        .checked_add_months(chrono::Months::new(12 * SPLIT_CHECK_YEARS))
+        .unwrap_or(NaiveDate::MAX);
+    let (before, after): (Vec<NaiveDate>, Vec<NaiveDate>) = occurrence_starts(&record, horizon)
+        .into_iter()
+        .partition(|start| *start < date);
+    let span = event.effective_end_date() - event.start_date;
+    let mut tail = record.clone();
+    tail.event.id = uuid::Uuid::new_v4().to_string();
+    tail.event.start_date = date;
+    tail.event.end_date = event.end_date.map(|_| date + span);
+    tail.rule = Some(RecurrenceRule {
+        count: rule
+            .count
+            .map(|count| count.saturating_sub(before.len() as u32).max(1)),
+        ..rule
+    });
+    tail.excluded_dates.retain(|d| *d >= date);
+    tail.overrides.retain(|o| o.occurrence_date >= key);
+
+    // An rcal-lib series stays one where it can, so rcal-lib goes on expanding it
+    if record.rule.is_none() {
+        let plain = EventRecord {
+            rule: None,
+            ..tail.clone()
+        };
+        if occurrence_starts(&plain, horizon) == after {
+            tail = plain;
+        }
+    }
+    if occurrence_starts(&head, horizon) != before || occurrence_starts(&tail, horizon) != after {
+        let recurrence = match &record.rule {
+            Some(rule) => format_rrule(rule),
+            None => event.recurrence.to_storage_string().to_string(),
+        };
+        return Err(invalid_recurrence(
+            &recurrence,
+            format!("can't be split on {} without moving other occurrences", key),
+        ));
+    }
+
+    let head_path = write_event_file(&dir, &head, Some(&path))?;
+    let tail_path = write_event_file(&dir, &tail, None)?;
+    reload_event_files(&[path, head_path, tail_path]);
+    Ok(tail.event.id)
 }
 
-fn git_remove_remote_impl(path: String, name: String) -> Result<String, GitError> {
-    let repo = Repository::open(&path)?;
-    repo.remote_delete(&name)?;
-    Ok("Remote removed".to_string())
+// ============================================================================
+// Recurring Event Instance Generation
+// ============================================================================
+
+/// Converts an EventDto to an EventRecord
+fn dto_to_event(dto: &EventDto) -> Result<EventRecord, EventError> {
+    let input = EventInput {
+        title: dto.title.clone(),
+        description: dto.description.clone(),
+        start_date: dto.start_date.clone(),
+        end_date: dto.end_date.clone(),
+        start_time: dto.start_time.clone(),
+        end_time: dto.end_time.clone(),
+        is_all_day: dto.is_all_day,
+        recurrence: dto.recurrence.clone(),
+        rrule: dto.rrule.clone(),
+        time_zone: dto.time_zone.clone(),
+    };
+    let mut record = create_calendar_event(input, Some(dto.id.clone()))?;
+    record.excluded_dates = dto
+        .excluded_dates
+        .iter()
+        .map(|d| parse_date("excluded_dates", d))
+        .collect::<Result<_, _>>()?;
+    for o in &dto.overrides {
+        validate_override(o)?;
+    }
+    record.overrides = dto.overrides.clone();
+    Ok(record)
+}
+
+/// The occurrences of a record overlapping [start]..=[end], each with the date it
+/// falls on by the recurrence, the base event first. Events with a recurrence rule,
+/// skipped or changed occurrences are expanded here; the others by rcal-lib.
+fn record_instances(
+    record: &EventRecord,
+    start: NaiveDate,
+    end: NaiveDate,
+) -> Vec<(NaiveDate, CalendarEvent)> {
+    let event = &record.event;
+    if record.rule.is_none() && !record.has_exceptions() {
+        let mut service = EventService::with_events(vec![event.clone()]);
+        return service
+            .generate_instances_for_range(start, end)
+            .into_iter()
+            .map(|instance| (instance.start_date, instance))
+            .collect();
+    }
+
+    // An override may move an occurrence from after the range into it
+    let horizon = record
+        .overrides
+        .iter()
+        .filter_map(|o| parse_date("occurrence_date", &o.occurrence_date).ok())
+        .fold(end, NaiveDate::max);
+    let mut instances: Vec<(NaiveDate, CalendarEvent)> = occurrence_starts(record, horizon)
+        .into_iter()
+        .filter(|date| !record.excluded_dates.contains(date))
+        .map(|date| (date, occurrence_on(record, date)))
+        .filter(|(_, instance)| {
+            let span = instance.effective_end_date() - instance.start_date;
+            occurrence_overlaps(instance.start_date, span, start, end)
+        })
+        .collect();
+    instances.sort_by_key(|(_, instance)| instance.start_date);
+    instances
 }
 
+/// Generates instances for recurring events within a date range.
+/// Uses rcal's generate_instances_for_range logic to expand recurring events, and
+/// expands events with a recurrence rule itself. Fails if the range is malformed;
+/// events that don't convert are reported in the result's diagnostics.
 #[flutter_rust_bridge::frb]
-pub fn git_remove_remote(path: String, name: String) -> Result<String, GitError> {
-    git_remove_remote_impl(path, name)
+pub fn generate_instances(
+    events: Vec<EventDto>,
+    start_date: String,
+    end_date: String,
+) -> Result<InstancesResult, EventError> {
+    let start = parse_date("start_date", &start_date)?;
+    let end = parse_date("end_date", &end_date)?;
+    Ok(expand_events(&events, start, end, None))
 }
 
-fn git_fetch_impl(
-    path: String,
-    remote: String,
-    username: Option<String>,
-    password: Option<String>,
-    ssh_key_path: Option<String>,
-) -> Result<String, GitError> {
-    let repo = Repository::open(&path)?;
-    let branch_name = extract_branch_name(&repo);
-    let mut remote_obj = repo.find_remote(&remote)?;
-    let mut callbacks = git2::RemoteCallbacks::new();
-    let username = username.clone();
-    let password = password.clone();
-    let ssh_key_path = ssh_key_path.clone();
-    callbacks
-        .credentials(move |url, _, _| get_credentials(url, &username, &password, &ssh_key_path));
-    callbacks.certificate_check(move |cert, hostname| {
-        if let Some(x509) = cert.as_x509() {
-            match validate_certificate(hostname, x509.data()) {
-                Ok(()) => Ok(git2::CertificateCheckStatus::CertificateOk),
-                Err(e) => Err(git2::Error::from_str(&e)),
+/// Expands [events] into their instances overlapping [start]..=[end], with the times
+/// of zoned events shown in [display] and the range read in it when one is given.
+fn expand_events(
+    events: &[EventDto],
+    start: NaiveDate,
+    end: NaiveDate,
+    display: Option<Tz>,
+) -> InstancesResult {
+    // Shown in another zone, an instance may move up to a day and more either way
+    let (from, to) = match display {
+        Some(_) => (
+            start - chrono::Duration::days(2),
+            end + chrono::Duration::days(2),
+        ),
+        None => (start, end),
+    };
+
+    let mut result = InstancesResult {
+        instances: Vec::new(),
+        diagnostics: Vec::new(),
+    };
+    for dto in events {
+        // Convert the DTO to an EventRecord
+        let record = match dto_to_event(dto) {
+            Ok(record) => record,
+            Err(reason) => {
+                result.diagnostics.push(EventDiagnostic {
+                    id: dto.id.clone(),
+                    reason,
+                });
+                continue;
             }
-        } else {
-            Ok(git2::CertificateCheckStatus::CertificateOk)
+        };
+
+        // Expand it and convert its instances back to DTOs
+        let series = record_to_dto(&record);
+        for (date, instance) in record_instances(&record, from, to) {
+            let (instance, time_zone) = match (display, record.time_zone) {
+                (Some(display), Some(zone)) if !instance.is_all_day => (
+                    event_in_zone(&instance, zone, display),
+                    Some(display.name().to_string()),
+                ),
+                _ => (instance, series.time_zone.clone()),
+            };
+            let span = instance.effective_end_date() - instance.start_date;
+            if !occurrence_overlaps(instance.start_date, span, start, end) {
+                continue;
+            }
+            result.instances.push(EventDto {
+                time_zone,
+                rrule: series.rrule.clone(),
+                excluded_dates: series.excluded_dates.clone(),
+                overrides: series.overrides.clone(),
+                occurrence_date: Some(date.format("%Y-%m-%d").to_string()),
+                ..event_to_dto(&instance)
+            });
         }
-    });
-    let mut fetch_options = git2::FetchOptions::new();
-    fetch_options.remote_callbacks(callbacks);
-    remote_obj.fetch(&[branch_name.as_str()], Some(&mut fetch_options), None)?;
-    Ok("Fetched".to_string())
+    }
+    result
 }
 
-#[flutter_rust_bridge::frb]
-pub fn git_fetch(
-    path: String,
-    remote: String,
-    username: Option<String>,
-    password: Option<String>,
-    ssh_key_path: Option<String>,
-) -> Result<String, GitError> {
-    git_fetch_impl(path, remote, username, password, ssh_key_path)
+// ============================================================================
+// Event Occurrence Check
+// ============================================================================
+
+/// Converts an EventDto to an EventRecord for occurrence check
+fn dto_to_event_for_occurs_on(dto: &EventDto) -> Result<EventRecord, EventError> {
+    let mut record = dto_to_event(dto)?;
+    record.event.is_recurring_instance = dto.is_recurring_instance;
+    Ok(record)
 }
 
-fn git_checkout_impl(path: String, branch: String) -> Result<String, GitError> {
-    let repo = Repository::open(&path)?;
-    let head_ref_name = format!("refs/heads/{}", branch);
-    if repo.find_reference(&head_ref_name).is_ok() {
-        // Branch exists, just set head and checkout
-        repo.set_head(&head_ref_name)?;
-        repo.checkout_head(None)?;
+/// Checks if an event occurs on a specific date.
+/// Uses rcal's CalendarEvent::occurs_on logic, or the event's recurrence rule,
+/// skipped and changed occurrences. Fails if the event or the date is malformed.
+#[flutter_rust_bridge::frb]
+pub fn event_occurs_on(event: EventDto, date: String) -> Result<bool, EventError> {
+    let record = dto_to_event_for_occurs_on(&event)?;
+    let target_date = parse_date("date", &date)?;
+
+    Ok(if record.rule.is_some() || record.has_exceptions() {
+        event_occurs_in_range(&record, target_date, target_date)
     } else {
-        // Create branch from FETCH_HEAD
-        let fetch_head = repo.find_reference("FETCH_HEAD")?;
-        let commit = repo.find_commit(fetch_head.target().unwrap())?;
-        repo.branch(&branch, &commit, false)?;
-        repo.set_head(&head_ref_name)?;
-        repo.checkout_head(None)?;
+        record.event.occurs_on(target_date)
+    })
+}
+
+// ============================================================================
+// Time Zones
+// ============================================================================
+
+/// Prefix of the event file line naming the IANA time zone of a timed event.
+const TIME_ZONE_LINE: &str = "- **Time Zone**: ";
+
+/// Parses an IANA time zone name; None, an empty name or "floating" stand for
+/// floating times.
+fn parse_time_zone(name: Option<&str>) -> Result<Option<Tz>, EventError> {
+    match name.map(str::trim) {
+        None | Some("") => Ok(None),
+        Some(name) if name.eq_ignore_ascii_case("floating") => Ok(None),
+        Some(name) => name
+            .parse::<Tz>()
+            .map(Some)
+            .map_err(|_| EventError::UnknownTimeZone(name.to_string())),
     }
-    Ok("Checked out".to_string())
 }
 
-#[flutter_rust_bridge::frb]
-pub fn git_checkout(path: String, branch: String) -> Result<String, GitError> {
-    git_checkout_impl(path, branch)
+// Helper function for the zone events are shown in, which can't be floating
+fn parse_display_zone(name: &str) -> Result<Tz, EventError> {
+    parse_time_zone(Some(name))?.ok_or_else(|| EventError::UnknownTimeZone(name.to_string()))
 }
 
-fn git_add_all_impl(path: String) -> Result<String, GitError> {
-    let repo = Repository::open(&path)?;
-    let mut index = repo.index()?;
-    index.add_all(["*"].iter(), git2::IndexAddOption::DEFAULT, None)?;
-    index.write()?;
-    Ok("Added all".to_string())
+/// The instant a wall-clock time in [zone] stands for. A time skipped when the
+/// clocks go forward is read with the offset before the gap, so it moves forward by
+/// the gap as in RFC 5545; a time that repeats when they go back is the first one.
+fn localize(zone: Tz, local: NaiveDateTime) -> DateTime<Tz> {
+    match zone.from_local_datetime(&local) {
+        LocalResult::Single(time) => time,
+        LocalResult::Ambiguous(first, _) => first,
+        LocalResult::None => {
+            // Clocks change at most once a day, so a day earlier is before the gap
+            let offset = zone
+                .offset_from_utc_datetime(&(local - chrono::Duration::days(1)))
+                .fix();
+            zone.from_utc_datetime(&(local - offset))
+        }
+    }
+}
+
+/// An occurrence of a timed event in [zone] with its times shown in [display].
+/// Each occurrence keeps its wall-clock time in [zone] across DST changes, so its
+/// time in [display] moves when the two zones change their clocks on different days.
+fn event_in_zone(event: &CalendarEvent, zone: Tz, display: Tz) -> CalendarEvent {
+    let shift = |date: NaiveDate, time: NaiveTime| {
+        localize(zone, date.and_time(time))
+            .with_timezone(&display)
+            .naive_local()
+    };
+    let span = event.effective_end_date() - event.start_date;
+    let start = shift(event.start_date, event.start_time);
+    let mut shown = event.clone();
+    shown.start_date = start.date();
+    shown.start_time = start.time();
+    let end_date = match event.end_time {
+        Some(end_time) => {
+            let end = shift(event.effective_end_date(), end_time);
+            shown.end_time = Some(end.time());
+            end.date()
+        }
+        None => start.date() + span,
+    };
+    shown.end_date = (end_date != shown.start_date).then_some(end_date);
+    shown
 }
 
+/// Like generate_instances, with the times of events that have a time zone shown in
+/// [display_zone] and the range read in it. Floating and all-day events keep their
+/// times. Instances shown in another zone carry [display_zone] as their time zone.
 #[flutter_rust_bridge::frb]
-pub fn git_add_all(path: String) -> Result<String, GitError> {
-    git_add_all_impl(path)
+pub fn generate_instances_in_zone(
+    events: Vec<EventDto>,
+    start_date: String,
+    end_date: String,
+    display_zone: String,
+) -> Result<InstancesResult, EventError> {
+    let start = parse_date("start_date", &start_date)?;
+    let end = parse_date("end_date", &end_date)?;
+    let display = parse_display_zone(&display_zone)?;
+    Ok(expand_events(&events, start, end, Some(display)))
 }
 
-fn git_commit_impl(path: String, message: String) -> Result<String, GitError> {
-    let repo = Repository::open(&path)?;
-    let mut index = repo.index()?;
-    let oid = index.write_tree()?;
-    let tree = repo.find_tree(oid)?;
-    let signature = git2::Signature::now("App", "app@example.com")?;
-    let parent_commit = repo
-        .head()
-        .ok()
-        .and_then(|head| head.target())
-        .and_then(|oid| repo.find_commit(oid).ok());
-    let commit = if let Some(parent) = parent_commit {
-        repo.commit(
-            Some("HEAD"),
-            &signature,
-            &signature,
-            &message,
-            &tree,
-            &[&parent],
-        )?
-    } else {
-        repo.commit(Some("HEAD"), &signature, &signature, &message, &tree, &[])?
+/// Shows the times of one event or instance in [display_zone]. Floating and
+/// all-day events come back unchanged.
+#[flutter_rust_bridge::frb]
+pub fn convert_event_to_zone(
+    event: EventDto,
+    display_zone: String,
+) -> Result<EventDto, EventError> {
+    let display = parse_display_zone(&display_zone)?;
+    let record = dto_to_event_for_occurs_on(&event)?;
+    let zone = match record.time_zone {
+        Some(zone) if !record.event.is_all_day => zone,
+        _ => return Ok(event),
     };
-    Ok(commit.to_string())
+    let shown = event_to_dto(&event_in_zone(&record.event, zone, display));
+    Ok(EventDto {
+        start_date: shown.start_date,
+        end_date: shown.end_date,
+        start_time: shown.start_time,
+        end_time: shown.end_time,
+        time_zone: Some(display.name().to_string()),
+        ..event
+    })
 }
 
+/// The IANA time zone names events can use, for a zone picker.
 #[flutter_rust_bridge::frb]
-pub fn git_commit(path: String, message: String) -> Result<String, GitError> {
-    git_commit_impl(path, message)
+pub fn list_time_zones() -> Vec<String> {
+    chrono_tz::TZ_VARIANTS
+        .iter()
+        .map(|zone| zone.name().to_string())
+        .collect()
+}
+
+// ============================================================================
+// iCalendar Import (RFC 5545)
+// ============================================================================
+
+/// A content line of an iCalendar file: NAME;PARAM=VALUE:value.
+#[derive(Debug)]
+struct IcsProperty {
+    name: String,
+    params: Vec<(String, String)>,
+    value: String,
 }
 
-// Note: Using git commands for merge resolution as git2 does not provide a simple API for preferring remote changes in conflicts.
-// git2's merge and conflict resolution require manual iteration and resolution, which is complex for this use case.
-// Keeping command-based for reliability and simplicity.
-fn git_merge_prefer_remote_impl(path: String) -> Result<String, GitError> {
-    let output = Command::new("git")
-        .arg("checkout")
-        .arg("--theirs")
-        .arg(".")
-        .current_dir(&path)
-        .output()?;
-    if !output.status.success() {
-        return Err(GitError::Other(
-            String::from_utf8_lossy(&output.stderr).to_string(),
-        ));
+impl IcsProperty {
+    fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// A BEGIN:NAME ... END:NAME block, such as VCALENDAR or VEVENT.
+#[derive(Debug)]
+struct IcsComponent {
+    name: String,
+    properties: Vec<IcsProperty>,
+    components: Vec<IcsComponent>,
+}
+
+impl IcsComponent {
+    fn property(&self, name: &str) -> Option<&IcsProperty> {
+        self.properties.iter().find(|p| p.name == name)
+    }
+
+    fn properties_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a IcsProperty> {
+        self.properties.iter().filter(move |p| p.name == name)
     }
-    let output = Command::new("git")
-        .arg("add")
-        .arg(".")
-        .current_dir(&path)
-        .output()?;
-    if !output.status.success() {
-        return Err(GitError::Other(
-            String::from_utf8_lossy(&output.stderr).to_string(),
-        ));
+
+    /// The unescaped TEXT value of property [name]
+    fn text(&self, name: &str) -> Option<String> {
+        self.property(name).map(|p| unescape_ics_text(&p.value))
     }
-    let output = Command::new("git")
-        .arg("commit")
-        .arg("-m")
-        .arg("Merge resolved by preferring remote")
-        .current_dir(&path)
-        .output()?;
-    if !output.status.success() {
-        return Err(GitError::Other(
-            String::from_utf8_lossy(&output.stderr).to_string(),
-        ));
+
+    fn is_cancelled(&self) -> bool {
+        self.property("STATUS")
+            .is_some_and(|p| p.value.trim().eq_ignore_ascii_case("CANCELLED"))
     }
-    Ok("Merge resolved by preferring remote".to_string())
 }
 
-#[flutter_rust_bridge::frb]
-pub fn git_merge_prefer_remote(path: String) -> Result<String, GitError> {
-    git_merge_prefer_remote_impl(path)
+/// Why import_ics left an item out.
+enum IcsRejection {
+    Skipped(String),
+    Unsupported(String),
 }
 
-#[flutter_rust_bridge::frb]
-pub fn git_merge_abort(path: String) -> Result<String, GitError> {
-    git_merge_abort_impl(path)
+/// A DATE or DATE-TIME value; UTC times are zoned in UTC.
+#[derive(Debug, Clone, Copy)]
+enum IcsTime {
+    Date(NaiveDate),
+    Floating(NaiveDateTime),
+    Zoned(NaiveDateTime, Tz),
 }
 
-fn git_stash_impl(path: String) -> Result<String, GitError> {
-    let mut repo = Repository::open(&path)?;
-    let signature = git2::Signature::now("App", "app@example.com")?;
-    repo.stash_save(&signature, "Stashed by app", None)?;
-    Ok("Stashed".to_string())
+impl IcsTime {
+    fn zone(&self) -> Option<Tz> {
+        match self {
+            IcsTime::Zoned(_, zone) => Some(*zone),
+            _ => None,
+        }
+    }
 }
 
-#[flutter_rust_bridge::frb]
-pub fn git_stash(path: String) -> Result<String, GitError> {
-    git_stash_impl(path)
+/// Start and end of a VEVENT in the zone of the event it belongs to; all-day
+/// when it has no start time.
+struct IcsSpan {
+    start_date: NaiveDate,
+    end_date: Option<NaiveDate>,
+    start_time: Option<NaiveTime>,
+    end_time: Option<NaiveTime>,
 }
 
-fn git_diff_impl(path: String) -> Result<String, GitError> {
-    let repo = Repository::open(&path)?;
-    let head = repo.head()?;
-    let head_tree = head.peel_to_tree()?;
-    let diff = repo.diff_tree_to_workdir(Some(&head_tree), None)?;
-    let mut diff_str = String::new();
-    diff.print(git2::DiffFormat::Patch, |delta, _hunk, line| {
-        diff_str.push_str(&format!(
-            "{:?} {:?} {:?}\n",
-            delta.old_file().path(),
-            delta.new_file().path(),
-            line.origin()
-        ));
-        true
-    })?;
-    Ok(diff_str)
+// Helper function to join folded lines, which continue with a space or tab
+fn unfold_ics(text: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in text.lines() {
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(rest), Some(last)) => last.push_str(rest),
+            _ if line.trim().is_empty() => {}
+            _ => lines.push(line.to_string()),
+        }
+    }
+    lines
 }
 
-#[flutter_rust_bridge::frb]
-pub fn git_diff(path: String) -> Result<String, GitError> {
-    git_diff_impl(path)
+// Helper function to split a content line into name, parameters and value
+fn parse_ics_line(line: &str) -> Option<IcsProperty> {
+    // Split at the first ':' and the ';'s before it that aren't in a quoted parameter
+    let mut quoted = false;
+    let mut parts = vec![String::new()];
+    let mut value = None;
+    for (i, c) in line.char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            ';' if !quoted => {
+                parts.push(String::new());
+                continue;
+            }
+            ':' if !quoted => {
+                value = Some(line[i + 1..].to_string());
+                break;
+            }
+            _ => {}
+        }
+        parts.last_mut().unwrap().push(c);
+    }
+    let value = value?;
+    let mut parts = parts.into_iter();
+    let name = parts.next()?.trim().to_ascii_uppercase();
+    let params = parts
+        .filter_map(|param| {
+            let (name, value) = param.split_once('=')?;
+            Some((
+                name.trim().to_ascii_uppercase(),
+                value.trim().trim_matches('"').to_string(),
+            ))
+        })
+        .collect();
+    Some(IcsProperty {
+        name,
+        params,
+        value,
+    })
 }
 
-// Note: Using git command for merge abort as git2 does not have a direct equivalent.
-// git merge --abort safely resets the repository to pre-merge state, handling conflicts and staged changes.
-// git2's reset may not fully replicate this behavior in all cases.
-fn git_merge_abort_impl(path: String) -> Result<String, GitError> {
-    let output = Command::new("git")
-        .arg("merge")
-        .arg("--abort")
-        .current_dir(&path)
-        .output()?;
-    if !output.status.success() {
-        return Err(GitError::Other(
-            String::from_utf8_lossy(&output.stderr).to_string(),
-        ));
+/// Parses iCalendar text into its top-level components.
+fn parse_ics(text: &str) -> Result<Vec<IcsComponent>, String> {
+    let mut stack: Vec<IcsComponent> = Vec::new();
+    let mut top = Vec::new();
+    for line in unfold_ics(text) {
+        let property =
+            parse_ics_line(&line).ok_or_else(|| format!("not a content line: {}", line))?;
+        match property.name.as_str() {
+            "BEGIN" => stack.push(IcsComponent {
+                name: property.value.trim().to_ascii_uppercase(),
+                properties: Vec::new(),
+                components: Vec::new(),
+            }),
+            "END" => {
+                let name = property.value.trim().to_ascii_uppercase();
+                let component = stack
+                    .pop()
+                    .filter(|c| c.name == name)
+                    .ok_or_else(|| format!("END:{} doesn't close an open component", name))?;
+                match stack.last_mut() {
+                    Some(parent) => parent.components.push(component),
+                    None => top.push(component),
+                }
+            }
+            _ => match stack.last_mut() {
+                Some(component) => component.properties.push(property),
+                None => return Err(format!("{} outside of a component", property.name)),
+            },
+        }
+    }
+    match stack.pop() {
+        Some(open) => Err(format!("BEGIN:{} is never closed", open.name)),
+        None => Ok(top),
     }
-    Ok("Merge aborted".to_string())
 }
 
-#[flutter_rust_bridge::frb]
-pub fn set_ssl_ca_certs(pem_certs: Vec<String>) -> Result<(), GitError> {
-    let mut temp_file = tempfile::NamedTempFile::new()?;
-    for cert in pem_certs {
-        std::io::Write::write_all(&mut temp_file, cert.as_bytes())?;
-    }
-    let path = temp_file.path().to_str().unwrap();
-    // Set GIT_SSL_CAINFO environment variable for libgit2
-    std::env::set_var("GIT_SSL_CAINFO", path);
-    // Keep temp_file alive until process ends
-    std::mem::forget(temp_file);
-    Ok(())
+// Helper function to unescape a TEXT value
+fn unescape_ics_text(value: &str) -> String {
+    let mut text = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some('n') | Some('N') => text.push('\n'),
+                Some(other) => text.push(other),
+                None => {}
+            },
+            c => text.push(c),
+        }
+    }
+    text
 }
 
-// ============================================================================
-// Calendar Event Functions (rcal-lib wrapper)
-// ============================================================================
-
-/// Creates a new calendar event and saves it to the specified calendar directory.
-#[flutter_rust_bridge::frb]
-pub fn create_event(
-    title: String,
-    description: String,
-    start_date: String,
-    end_date: Option<String>,
-    start_time: Option<String>,
-    end_time: Option<String>,
-    is_all_day: bool,
-    recurrence: String,
-    calendar_dir: String,
-) -> Result<String, String> {
-    let event = create_calendar_event(
-        title,
-        description,
-        start_date,
-        end_date,
-        start_time,
-        end_time,
-        is_all_day,
-        recurrence,
-        None,
-    )?;
-
-    let path = PathBuf::from(&calendar_dir);
-    let repo = FileEventRepository::with_path(path.clone());
-    repo.save_to_path(&event, &path)
-        .map_err(|e| e.to_string())?;
-
-    Ok(event.id)
+// Helper function for a TZID, which some calendars prefix with a path of their own,
+// as in "/mozilla.org/20070129_1/Europe/Berlin"
+fn ics_time_zone(tzid: &str) -> Result<Tz, IcsRejection> {
+    let tzid = tzid.trim();
+    std::iter::once(tzid)
+        .chain(tzid.match_indices('/').map(|(i, _)| &tzid[i + 1..]))
+        .find_map(|name| name.parse::<Tz>().ok())
+        .ok_or_else(|| IcsRejection::Unsupported(format!("unknown time zone {}", tzid)))
 }
 
-/// Gets all events from the specified calendar directory.
-#[flutter_rust_bridge::frb]
-pub fn get_all_events(calendar_dir: String) -> Result<Vec<EventDto>, String> {
-    let path = PathBuf::from(&calendar_dir);
-    let repo = FileEventRepository::with_path(path.clone());
-    let events = repo.load_from_path(&path).map_err(|e| e.to_string())?;
-
-    // Filter out recurring instances - only return base events
-    let base_events: Vec<&CalendarEvent> =
-        events.iter().filter(|e| !e.is_recurring_instance).collect();
-
-    Ok(base_events.iter().map(|e| event_to_dto(e)).collect())
+/// Parses one DATE or DATE-TIME [value] of [property], read with its parameters.
+fn parse_ics_time(property: &IcsProperty, value: &str) -> Result<IcsTime, IcsRejection> {
+    let value = value.trim();
+    let malformed = || IcsRejection::Skipped(format!("bad {} {}", property.name, value));
+    if property.param("VALUE") == Some("DATE") || value.len() == 8 {
+        return NaiveDate::parse_from_str(value, "%Y%m%d")
+            .map(IcsTime::Date)
+            .map_err(|_| malformed());
+    }
+    let (local, utc) = match value.strip_suffix(['Z', 'z']) {
+        Some(local) => (local, true),
+        None => (value, false),
+    };
+    let time = NaiveDateTime::parse_from_str(local, "%Y%m%dT%H%M%S").map_err(|_| malformed())?;
+    Ok(match (utc, property.param("TZID")) {
+        (true, _) => IcsTime::Zoned(time, Tz::UTC),
+        (false, Some(tzid)) => IcsTime::Zoned(time, ics_time_zone(tzid)?),
+        (false, None) => IcsTime::Floating(time),
+    })
 }
 
-/// Gets all events within a date range from the specified calendar directory.
-#[flutter_rust_bridge::frb]
-pub fn get_events_in_range(
-    start_date: String,
-    end_date: String,
-    calendar_dir: String,
-) -> Result<Vec<EventDto>, String> {
-    let start = parse_date(&start_date)?;
-    let end = parse_date(&end_date)?;
-
-    let path = PathBuf::from(&calendar_dir);
-    let repo = FileEventRepository::with_path(path.clone());
-    let events = repo.load_from_path(&path).map_err(|e| e.to_string())?;
-
-    // Filter events that occur within the date range and are not recurring instances
-    let filtered: Vec<&CalendarEvent> = events
-        .iter()
-        .filter(|e| !e.is_recurring_instance && event_occurs_in_range(e, start, end))
-        .collect();
+// Helper function for the time of property [name], if the component has it
+fn ics_property_time(c: &IcsComponent, name: &str) -> Result<Option<IcsTime>, IcsRejection> {
+    c.property(name)
+        .map(|p| parse_ics_time(p, &p.value))
+        .transpose()
+}
 
-    Ok(filtered.iter().map(|e| event_to_dto(e)).collect())
+/// The date and wall-clock time of [time] in [zone]; floating times and times
+/// of floating events keep their own.
+fn ics_local(time: &IcsTime, zone: Option<Tz>) -> (NaiveDate, Option<NaiveTime>) {
+    let local = match (*time, zone) {
+        (IcsTime::Date(date), _) => return (date, None),
+        (IcsTime::Zoned(time, own), Some(zone)) if own != zone => {
+            localize(own, time).with_timezone(&zone).naive_local()
+        }
+        (IcsTime::Zoned(time, _), _) | (IcsTime::Floating(time), _) => time,
+    };
+    (local.date(), Some(local.time()))
 }
 
-/// Updates an existing event in the specified calendar directory.
-#[flutter_rust_bridge::frb]
-pub fn update_event(
-    id: String,
-    title: String,
-    description: String,
-    start_date: String,
-    end_date: Option<String>,
-    start_time: Option<String>,
-    end_time: Option<String>,
-    is_all_day: bool,
-    recurrence: String,
-    calendar_dir: String,
-) -> Result<(), String> {
-    let path = PathBuf::from(&calendar_dir);
-    let repo = FileEventRepository::with_path(path.clone());
-
-    // Verify the event exists by loading and checking
-    let events = repo
-        .load_from_path(&path)
-        .map_err(|e: Box<dyn std::error::Error>| e.to_string())?;
-    let existing_event = events
-        .iter()
-        .find(|e| e.id == id)
-        .ok_or_else(|| format!("Event with id '{}' not found", id))?;
+/// Parses a DURATION such as P1D, PT1H30M or P2W; a negative one is rejected.
+fn parse_ics_duration(value: &str) -> Option<chrono::Duration> {
+    let value = value.trim();
+    let body = value.strip_prefix('+').unwrap_or(value).strip_prefix('P')?;
+    let mut total = chrono::Duration::zero();
+    let mut number = String::new();
+    let mut in_time = false;
+    for c in body.chars() {
+        match c {
+            '0'..='9' => number.push(c),
+            'T' => in_time = true,
+            unit => {
+                let n: i64 = number.parse().ok()?;
+                number.clear();
+                // A value too large for a Duration is as unusable as a malformed one
+                let part = match (unit, in_time) {
+                    ('W', false) => chrono::Duration::try_weeks(n),
+                    ('D', false) => chrono::Duration::try_days(n),
+                    ('H', true) => chrono::Duration::try_hours(n),
+                    ('M', true) => chrono::Duration::try_minutes(n),
+                    ('S', true) => chrono::Duration::try_seconds(n),
+                    _ => None,
+                }?;
+                total = total.checked_add(&part)?;
+            }
+        }
+    }
+    number.is_empty().then_some(total)
+}
 
-    // Create updated event with the same ID
-    let updated_event = create_calendar_event(
-        title,
-        description,
+/// The start and end of VEVENT [c] in [zone].
+fn ics_span(c: &IcsComponent, zone: Option<Tz>) -> Result<IcsSpan, IcsRejection> {
+    let start = ics_property_time(c, "DTSTART")?
+        .ok_or_else(|| IcsRejection::Skipped("no DTSTART".to_string()))?;
+    let (start_date, start_time) = ics_local(&start, zone);
+    let end = match (ics_property_time(c, "DTEND")?, c.property("DURATION")) {
+        (Some(end), _) => Some(ics_local(&end, zone)),
+        (None, Some(property)) => {
+            let duration = parse_ics_duration(&property.value)
+                .ok_or_else(|| IcsRejection::Skipped(format!("bad DURATION {}", property.value)))?;
+            let out_of_range =
+                || IcsRejection::Skipped(format!("DURATION {} ends out of range", property.value));
+            Some(match start_time {
+                Some(time) => {
+                    let end = start_date
+                        .and_time(time)
+                        .checked_add_signed(duration)
+                        .ok_or_else(out_of_range)?;
+                    (end.date(), Some(end.time()))
+                }
+                None => (
+                    start_date
+                        .checked_add_signed(duration)
+                        .ok_or_else(out_of_range)?,
+                    None,
+                ),
+            })
+        }
+        (None, None) => None,
+    };
+    let (end_date, end_time) = match (start_time, end) {
+        // The end of an all-day event is the day after its last
+        (None, Some((end, _))) => (end.pred_opt().filter(|last| *last > start_date), None),
+        (Some(start), Some((end, Some(end_time)))) if (end, end_time) != (start_date, start) => {
+            (Some(end).filter(|end| *end != start_date), Some(end_time))
+        }
+        _ => (None, None),
+    };
+    Ok(IcsSpan {
         start_date,
         end_date,
         start_time,
         end_time,
-        is_all_day,
-        recurrence,
-        Some(id.clone()),
-    )?;
-
-    // Delete the old event file by title and save the new one
-    repo.delete_by_title_from_path(&existing_event.title, &path)
-        .map_err(|e: Box<dyn std::error::Error>| e.to_string())?;
-    repo.save_to_path(&updated_event, &path)
-        .map_err(|e| e.to_string())?;
+    })
+}
 
-    Ok(())
+/// Reads an RRULE for an event in [zone]. WKST only matters to weekly rules with
+/// an interval, so it is dropped elsewhere; a UTC UNTIL becomes a date in [zone].
+fn import_rrule(value: &str, zone: Option<Tz>) -> Result<RecurrenceRule, IcsRejection> {
+    let parts: Vec<&str> = value.split(';').map(str::trim).collect();
+    let part = |name: &str| {
+        parts.iter().find_map(|p| {
+            let (n, v) = p.split_once('=')?;
+            n.eq_ignore_ascii_case(name).then_some(v)
+        })
+    };
+    let has_interval = part("INTERVAL").is_some_and(|n| n != "1");
+    let text: Vec<&str> = parts
+        .iter()
+        .copied()
+        .filter(|p| has_interval || !p.to_ascii_uppercase().starts_with("WKST="))
+        .collect();
+    let mut rule = parse_rrule(&text.join(";")).map_err(|e| match e {
+        EventError::InvalidRecurrence { reason, .. } => {
+            IcsRejection::Unsupported(format!("RRULE {}: {}", value, reason))
+        }
+        e => IcsRejection::Unsupported(format!("RRULE {}: {:?}", value, e)),
+    })?;
+    if let Some(until) = part("UNTIL").and_then(|v| v.strip_suffix(['Z', 'z'])) {
+        if let Ok(until) = NaiveDateTime::parse_from_str(until, "%Y%m%dT%H%M%S") {
+            let local = ics_local(&IcsTime::Zoned(until, Tz::UTC), zone).0;
+            rule.until = Some(local.format("%Y-%m-%d").to_string());
+        }
+    }
+    Ok(rule)
 }
 
-/// Deletes an event from the specified calendar directory.
-/// The [id] parameter is the event title, used to find and delete the event file.
-#[flutter_rust_bridge::frb]
-pub fn delete_event(id: String, calendar_dir: String) -> Result<(), String> {
-    let path = PathBuf::from(&calendar_dir);
-    let repo = FileEventRepository::with_path(path.clone());
+/// Maps a VEVENT without RECURRENCE-ID onto an event with id [id].
+fn import_ics_event(c: &IcsComponent, id: String) -> Result<EventRecord, IcsRejection> {
+    if c.property("RDATE").is_some() {
+        return Err(IcsRejection::Unsupported(
+            "RDATE occurrences aren't supported".to_string(),
+        ));
+    }
+    let zone = ics_property_time(c, "DTSTART")?.and_then(|t| t.zone());
+    let span = ics_span(c, zone)?;
+    let mut rules = c.properties_named("RRULE");
+    let rule = rules
+        .next()
+        .map(|p| import_rrule(&p.value, zone))
+        .transpose()?;
+    if rules.next().is_some() {
+        return Err(IcsRejection::Unsupported("more than one RRULE".to_string()));
+    }
+
+    let title = c.text("SUMMARY").unwrap_or_default().trim().to_string();
+    let format_date = |d: NaiveDate| d.format("%Y-%m-%d").to_string();
+    let format_time = |t: NaiveTime| t.format("%H:%M").to_string();
+    let input = EventInput {
+        title: if title.is_empty() {
+            "Untitled".to_string()
+        } else {
+            title
+        },
+        description: c.text("DESCRIPTION").unwrap_or_default(),
+        start_date: format_date(span.start_date),
+        end_date: span.end_date.map(format_date),
+        start_time: span.start_time.map(format_time),
+        end_time: span.end_time.map(format_time),
+        is_all_day: span.start_time.is_none(),
+        recurrence: "none".to_string(),
+        rrule: rule,
+        time_zone: zone.map(|zone| zone.name().to_string()),
+    };
+    let mut record = create_calendar_event(input, Some(id))
+        .map_err(|e| IcsRejection::Skipped(format!("{:?}", e)))?;
+
+    for property in c.properties_named("EXDATE") {
+        for value in property.value.split(',') {
+            let time = parse_ics_time(property, value)?;
+            record.excluded_dates.push(ics_local(&time, zone).0);
+        }
+    }
+    record.excluded_dates.sort();
+    record.excluded_dates.dedup();
+    Ok(record)
+}
 
-    // Delete by title - rcal-lib's delete_by_title_from_path uses the title
-    repo.delete_by_title_from_path(&id, &path)
-        .map_err(|e: Box<dyn std::error::Error>| e.to_string())?;
+/// Applies a VEVENT with RECURRENCE-ID to the series it changes: a cancelled
+/// occurrence is skipped, any other becomes an override of what differs.
+fn import_ics_occurrence(record: &mut EventRecord, c: &IcsComponent) -> Result<(), IcsRejection> {
+    let zone = record.time_zone;
+    let recurrence_id = ics_property_time(c, "RECURRENCE-ID")?
+        .ok_or_else(|| IcsRejection::Skipped("no RECURRENCE-ID".to_string()))?;
+    let date = ics_local(&recurrence_id, zone).0;
+    check_occurrence(record, date)
+        .map_err(|_| IcsRejection::Skipped(format!("the series has no occurrence on {}", date)))?;
+    if c.is_cancelled() {
+        record.excluded_dates.push(date);
+        record.excluded_dates.sort();
+        return Ok(());
+    }
 
+    let base = occurrence_on(record, date);
+    let span = ics_span(c, zone)?;
+    let changed = |value: String, base: String| (value != base).then_some(value);
+    let format_date = |d: NaiveDate| d.format("%Y-%m-%d").to_string();
+    let format_time = |t: Option<NaiveTime>| t.map(|t| t.format("%H:%M").to_string());
+    let is_all_day = span.start_time.is_none();
+    let o = OccurrenceOverride {
+        occurrence_date: format_date(date),
+        title: c
+            .text("SUMMARY")
+            .and_then(|title| changed(title, base.title.clone())),
+        description: c
+            .text("DESCRIPTION")
+            .and_then(|description| changed(description, base.description.clone())),
+        start_date: changed(format_date(span.start_date), format_date(base.start_date)),
+        end_date: changed(
+            format_date(span.end_date.unwrap_or(span.start_date)),
+            format_date(base.effective_end_date()),
+        ),
+        start_time: format_time(span.start_time)
+            .and_then(|t| changed(t, base.start_time.format("%H:%M").to_string())),
+        end_time: format_time(span.end_time)
+            .and_then(|t| changed(t, format_time(base.end_time).unwrap_or_default())),
+        is_all_day: (is_all_day != base.is_all_day).then_some(is_all_day),
+    };
+    validate_override(&o).map_err(|e| IcsRejection::Skipped(format!("{:?}", e)))?;
+    record
+        .overrides
+        .retain(|other| other.occurrence_date != o.occurrence_date);
+    record.overrides.push(o);
+    record
+        .overrides
+        .sort_by(|a, b| a.occurrence_date.cmp(&b.occurrence_date));
     Ok(())
 }
 
-// ============================================================================
-// Event Validation Function
-// ============================================================================
+impl IcsImportReport {
+    fn reject(&mut self, uid: Option<String>, summary: String, rejection: IcsRejection) {
+        match rejection {
+            IcsRejection::Skipped(reason) => self.skipped.push(IcsImportIssue {
+                uid,
+                summary,
+                reason,
+            }),
+            IcsRejection::Unsupported(reason) => self.unsupported.push(IcsImportIssue {
+                uid,
+                summary,
+                reason,
+            }),
+        }
+    }
+}
 
-/// Validates an event and returns validation errors.
-/// Returns Ok(()) if valid, Err(error_message) if invalid.
-/// Uses the existing validation logic from rcal-lib.
+/// Imports the events of an iCalendar file, such as a Google Calendar or
+/// Thunderbird export, into [calendar_dir]. An event keeps its UID as its id, so
+/// importing the same file again skips what is already there, unless
+/// [options] replace existing events. Moved or cancelled occurrences of a
+/// recurring event (RECURRENCE-ID) become overrides and skipped dates of its series.
+/// Only fails when the text isn't iCalendar or the calendar can't be written.
 #[flutter_rust_bridge::frb]
-pub fn validate_event(
-    title: String,
-    start_date: String,
-    end_date: Option<String>,
-    start_time: Option<String>,
-    end_time: Option<String>,
-) -> Result<(), String> {
-    // Validate title
-    if !is_valid_title(&title) {
-        return Err("Title cannot be empty or exceed maximum length".to_string());
+pub fn import_ics(
+    ics_text: String,
+    calendar_dir: String,
+    options: IcsImportOptions,
+) -> Result<IcsImportReport, EventError> {
+    let parse_failure = |message: String| EventError::ParseFailure {
+        file: "ics_text".to_string(),
+        message,
+    };
+    let calendars: Vec<IcsComponent> = parse_ics(&ics_text)
+        .map_err(parse_failure)?
+        .into_iter()
+        .filter(|c| c.name == "VCALENDAR")
+        .collect();
+    if calendars.is_empty() {
+        return Err(parse_failure("no VCALENDAR".to_string()));
     }
 
-    // Parse and validate start date
-    let start = parse_date(&start_date)?;
-
-    // Validate end date >= start date
-    if let Some(ref end_date_str) = end_date {
-        let end = parse_date(end_date_str)?;
-        if !is_valid_date_range(start, Some(end)) {
-            return Err(format!(
-                "End date ({}) cannot be before start date ({})",
-                end_date_str, start_date
-            ));
+    let mut report = IcsImportReport {
+        imported: Vec::new(),
+        skipped: Vec::new(),
+        unsupported: Vec::new(),
+    };
+    let mut series: Vec<EventRecord> = Vec::new();
+    let mut by_uid: HashMap<String, usize> = HashMap::new();
+    let mut seen_uids = HashSet::new();
+    let mut occurrences = Vec::new();
+    for item in calendars.iter().flat_map(|c| &c.components) {
+        let uid = item.text("UID").map(|uid| uid.trim().to_string());
+        let summary = item.text("SUMMARY").unwrap_or_default();
+        match item.name.as_str() {
+            "VEVENT" => {}
+            // Zones are known by their TZID
+            "VTIMEZONE" => continue,
+            other => {
+                let reason = format!("{} components aren't imported", other);
+                report.reject(uid, summary, IcsRejection::Unsupported(reason));
+                continue;
+            }
+        }
+        if item.property("RECURRENCE-ID").is_some() {
+            occurrences.push((item, uid, summary));
+            continue;
+        }
+        if let Some(uid) = &uid {
+            if !seen_uids.insert(uid.clone()) {
+                let reason = "another event in the file has this UID".to_string();
+                report.reject(Some(uid.clone()), summary, IcsRejection::Skipped(reason));
+                continue;
+            }
+        }
+        if item.is_cancelled() {
+            report.reject(uid, summary, IcsRejection::Skipped("cancelled".to_string()));
+            continue;
+        }
+        let id = uid
+            .clone()
+            .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+        match import_ics_event(item, id) {
+            Ok(record) => {
+                by_uid.insert(record.event.id.clone(), series.len());
+                series.push(record);
+            }
+            Err(rejection) => report.reject(uid, summary, rejection),
         }
     }
 
-    // Validate time range for non-all-day events
-    if !start_time.is_none() || !end_time.is_none() {
-        let start_t = match start_time {
-            Some(t) => parse_time(&t)?,
-            None => return Err("Start time is required when end time is specified".to_string()),
+    for (item, uid, summary) in occurrences {
+        let result = match uid.as_ref().and_then(|uid| by_uid.get(uid)) {
+            Some(&i) => import_ics_occurrence(&mut series[i], item),
+            None => Err(IcsRejection::Unsupported(
+                "changes an occurrence of a recurring event that isn't imported".to_string(),
+            )),
         };
+        if let Err(rejection) = result {
+            report.reject(uid, summary, rejection);
+        }
+    }
 
-        let end_t = match end_time {
-            Some(t) => Some(parse_time(&t)?),
+    // Write through rcal-lib's storage, next to or in place of the events there
+    let dir = PathBuf::from(&calendar_dir);
+    let existing: HashMap<String, Option<PathBuf>> = with_event_index(&dir, |index| {
+        Ok(series
+            .iter()
+            .filter_map(|record| {
+                let id = record.event.id.clone();
+                match index.find(&id) {
+                    Ok((path, _)) => Some((id, Some(path.to_path_buf()))),
+                    Err(EventError::DuplicateId(_)) => Some((id, None)),
+                    Err(_) => None,
+                }
+            })
+            .collect())
+    })?;
+    let mut written = Vec::new();
+    for record in series {
+        let id = record.event.id.clone();
+        let replaces = match existing.get(&id) {
             None => None,
+            Some(Some(path)) if options.replace_existing => Some(path.clone()),
+            Some(_) => {
+                let reason = "an event with this id is already in the calendar".to_string();
+                report.reject(Some(id), record.event.title, IcsRejection::Skipped(reason));
+                continue;
+            }
         };
-
-        if !is_valid_time_range(start_t, end_t) {
-            return Err(format!("End time cannot be before start time"));
-        }
+        written.push(write_event_file(&dir, &record, replaces.as_deref())?);
+        written.extend(replaces);
+        report.imported.push(id);
     }
+    reload_event_files(&written);
 
-    Ok(())
+    Ok(report)
 }
 
 // ============================================================================
-// Recurring Event Instance Generation
+// Event-aware Merge Resolution
 // ============================================================================
 
-/// Converts an EventDto to a CalendarEvent
-#[allow(dead_code)]
-fn dto_to_event(dto: &EventDto) -> Result<CalendarEvent, String> {
-    create_calendar_event(
-        dto.title.clone(),
-        dto.description.clone(),
-        dto.start_date.clone(),
-        dto.end_date.clone(),
-        dto.start_time.clone(),
-        dto.end_time.clone(),
-        dto.is_all_day,
-        dto.recurrence.clone(),
-        Some(dto.id.clone()),
-    )
+/// Parses Markdown event file content through rcal-lib's storage layer.
+/// rcal-lib only reads events from a directory, so the content is staged in a temp dir.
+fn parse_event_content(file_name: &str, content: &[u8]) -> Result<EventRecord, EventError> {
+    let dir = tempfile::tempdir()?;
+    parse_staged_event(dir.path(), file_name, content)
 }
 
-/// Generates instances for recurring events within a date range.
-/// Uses rcal's generate_instances_for_range logic to expand recurring events.
-#[flutter_rust_bridge::frb]
-pub fn generate_instances(
-    events: Vec<EventDto>,
-    start_date: String,
-    end_date: String,
-) -> Vec<EventDto> {
-    let start = match parse_date(&start_date) {
-        Ok(d) => d,
-        Err(_) => return vec![],
-    };
-    let end = match parse_date(&end_date) {
-        Ok(d) => d,
-        Err(_) => return vec![],
+/// Parses event file content by staging it alone in [staging], an empty scratch
+/// directory that can be reused for the next file.
+fn parse_staged_event(
+    staging: &Path,
+    file_name: &str,
+    content: &[u8],
+) -> Result<EventRecord, EventError> {
+    let parse_failure = |message: String| EventError::ParseFailure {
+        file: file_name.to_string(),
+        message,
     };
+    let staged = staging.join(file_name);
+    fs::write(&staged, content)?;
+    let repo = FileEventRepository::with_path(staging.to_path_buf());
+    let events = repo.load_from_path(staging);
+    fs::remove_file(&staged)?;
+    let event = events
+        .map_err(|e| parse_failure(e.to_string()))?
+        .into_iter()
+        .find(|e| !e.is_recurring_instance)
+        .ok_or_else(|| parse_failure("No event found".to_string()))?;
 
-    // Convert DTOs to CalendarEvents
-    let calendar_events: Vec<CalendarEvent> = events
-        .iter()
-        .filter_map(|dto| dto_to_event(dto).ok())
-        .collect();
+    // The lines rcal-lib doesn't read
+    let mut record = EventRecord::from(event);
+    for line in String::from_utf8_lossy(content).lines() {
+        read_extra_line(&mut record, line).map_err(|e| match e {
+            EventError::InvalidRecurrence { reason, .. } => parse_failure(reason),
+            EventError::UnknownTimeZone(name) => {
+                parse_failure(format!("unknown time zone {}", name))
+            }
+            e => parse_failure(format!("{:?}", e)),
+        })?;
+    }
+    Ok(record)
+}
 
-    // Use EventService to generate instances
-    let mut service = EventService::with_events(calendar_events);
-    let instances = service.generate_instances_for_range(start, end);
+// Helper function to read a line of an event file that rcal-lib doesn't know into [record]
+fn read_extra_line(record: &mut EventRecord, line: &str) -> Result<(), EventError> {
+    if let Some(text) = line.strip_prefix(RRULE_LINE) {
+        record.rule = Some(parse_rrule(text)?);
+    } else if let Some(text) = line.strip_prefix(EXCEPTIONS_LINE) {
+        for date in text.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            record
+                .excluded_dates
+                .push(parse_date("excluded_dates", date)?);
+        }
+    } else if let Some(text) = line.strip_prefix(OVERRIDE_LINE) {
+        record.overrides.push(parse_override(text)?);
+    } else if let Some(text) = line.strip_prefix(TIME_ZONE_LINE) {
+        record.time_zone = parse_time_zone(Some(text))?;
+    }
+    Ok(())
+}
 
-    // Convert instances back to DTOs
-    instances.iter().map(event_to_dto).collect()
+// Helper function for the lines of [record] that rcal-lib doesn't write
+fn extra_lines(record: &EventRecord) -> Vec<String> {
+    let mut lines = Vec::new();
+    if let Some(zone) = record.time_zone {
+        lines.push(format!("{}{}", TIME_ZONE_LINE, zone.name()));
+    }
+    if let Some(rule) = &record.rule {
+        lines.push(format!("{}{}", RRULE_LINE, format_rrule(rule)));
+    }
+    if !record.excluded_dates.is_empty() {
+        let dates: Vec<String> = record
+            .excluded_dates
+            .iter()
+            .map(|d| d.format("%Y-%m-%d").to_string())
+            .collect();
+        lines.push(format!("{}{}", EXCEPTIONS_LINE, dates.join(", ")));
+    }
+    for o in &record.overrides {
+        lines.push(format!("{}{}", OVERRIDE_LINE, format_override(o)));
+    }
+    lines
 }
 
-// ============================================================================
-// Event Occurrence Check
-// ============================================================================
+/// Renders an event to the Markdown rcal-lib would write for it.
+fn render_event_content(record: &EventRecord) -> Result<Vec<u8>, EventError> {
+    render_event_file(record).map(|(_, content)| content)
+}
 
-/// Converts an EventDto to a CalendarEvent for occurrence check
-#[allow(dead_code)]
-fn dto_to_event_for_occurs_on(dto: &EventDto) -> Result<CalendarEvent, String> {
-    let mut event = create_calendar_event(
-        dto.title.clone(),
-        dto.description.clone(),
-        dto.start_date.clone(),
-        dto.end_date.clone(),
-        dto.start_time.clone(),
-        dto.end_time.clone(),
-        dto.is_all_day,
-        dto.recurrence.clone(),
-        Some(dto.id.clone()),
-    )?;
-    event.is_recurring_instance = dto.is_recurring_instance;
-    Ok(event)
+/// Renders an event to the file name and Markdown rcal-lib would write for it,
+/// followed by the lines for what rcal-lib doesn't store.
+fn render_event_file(record: &EventRecord) -> Result<(String, Vec<u8>), EventError> {
+    let dir = tempfile::tempdir()?;
+    let repo = FileEventRepository::with_path(dir.path().to_path_buf());
+    repo.save_to_path(&record.event, dir.path())
+        .map_err(storage_error)?;
+    let entry = fs::read_dir(dir.path())?
+        .filter_map(|e| e.ok())
+        .find(|e| e.path().is_file())
+        .ok_or_else(|| EventError::Storage("rcal-lib did not write an event file".to_string()))?;
+    let mut content = String::from_utf8_lossy(&fs::read(entry.path())?).into_owned();
+    for line in extra_lines(record) {
+        if !content.is_empty() && !content.ends_with('\n') {
+            content.push('\n');
+        }
+        content.push_str(&line);
+        content.push('\n');
+    }
+    Ok((file_name(&entry.path()), content.into_bytes()))
 }
 
-/// Checks if an event occurs on a specific date.
-/// Uses rcal's CalendarEvent::occurs_on logic.
-#[flutter_rust_bridge::frb]
-pub fn event_occurs_on(event: EventDto, date: String) -> bool {
-    let event = match dto_to_event_for_occurs_on(&event) {
-        Ok(e) => e,
-        Err(_) => return false,
+/// The mergeable fields of an EventDto, as (name, value) pairs.
+fn event_fields(dto: &EventDto) -> Vec<(&'static str, Option<String>)> {
+    vec![
+        ("title", Some(dto.title.clone())),
+        ("description", Some(dto.description.clone())),
+        ("start_date", Some(dto.start_date.clone())),
+        ("end_date", dto.end_date.clone()),
+        ("start_time", dto.start_time.clone()),
+        ("end_time", dto.end_time.clone()),
+        ("is_all_day", Some(dto.is_all_day.to_string())),
+        ("time_zone", dto.time_zone.clone()),
+        ("recurrence", Some(dto.recurrence.clone())),
+        ("rrule", dto.rrule.as_ref().map(format_rrule)),
+        ("excluded_dates", Some(dto.excluded_dates.join(", "))),
+        (
+            "overrides",
+            Some(
+                dto.overrides
+                    .iter()
+                    .map(format_override)
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            ),
+        ),
+    ]
+}
+
+fn set_event_field(dto: &mut EventDto, field: &str, value: Option<String>) {
+    match field {
+        "title" => dto.title = value.unwrap_or_default(),
+        "description" => dto.description = value.unwrap_or_default(),
+        "start_date" => dto.start_date = value.unwrap_or_default(),
+        "end_date" => dto.end_date = value,
+        "start_time" => dto.start_time = value,
+        "end_time" => dto.end_time = value,
+        "is_all_day" => dto.is_all_day = value.as_deref() == Some("true"),
+        "time_zone" => dto.time_zone = value,
+        "recurrence" => dto.recurrence = value.unwrap_or_default(),
+        "rrule" => dto.rrule = value.and_then(|text| parse_rrule(&text).ok()),
+        "excluded_dates" => {
+            dto.excluded_dates = value
+                .unwrap_or_default()
+                .split(',')
+                .map(|d| d.trim().to_string())
+                .filter(|d| !d.is_empty())
+                .collect()
+        }
+        "overrides" => {
+            dto.overrides = value
+                .unwrap_or_default()
+                .lines()
+                .filter_map(|line| parse_override(line).ok())
+                .collect()
+        }
+        _ => {}
+    }
+}
+
+/// Three-way merges two versions of an event field by field.
+/// A field changed on only one side takes that side's value; a field changed on
+/// both sides to different values is contested and reported instead.
+fn merge_event_fields(
+    path: &str,
+    base: Option<&EventDto>,
+    local: &EventDto,
+    remote: &EventDto,
+) -> (EventDto, Vec<EventFieldConflict>) {
+    let mut merged = local.clone();
+    let mut conflicts = Vec::new();
+    let base_fields = base.map(event_fields);
+    let remote_fields = event_fields(remote);
+    for (i, (field, local_value)) in event_fields(local).into_iter().enumerate() {
+        let remote_value = remote_fields[i].1.clone();
+        let base_value = base_fields.as_ref().map(|f| f[i].1.clone());
+        if local_value == remote_value {
+            continue;
+        }
+        match base_value {
+            Some(ref b) if *b == local_value => set_event_field(&mut merged, field, remote_value),
+            Some(ref b) if *b == remote_value => {}
+            _ => conflicts.push(EventFieldConflict {
+                path: path.to_string(),
+                event_id: local.id.clone(),
+                field: field.to_string(),
+                base_value: base_value.flatten(),
+                local_value,
+                remote_value,
+            }),
+        }
+    }
+    (merged, conflicts)
+}
+
+/// Field-merges one conflicted event file. Returns the merged content when
+/// no field is contested, the contested fields otherwise, or None when the
+/// file cannot be treated as an event (deleted on one side, unparseable, different ids).
+fn merge_conflicted_event(
+    repo: &Repository,
+    conflict: &git2::IndexConflict,
+    path: &str,
+) -> Option<Result<Vec<u8>, Vec<EventFieldConflict>>> {
+    if !path.ends_with(".md") {
+        return None;
+    }
+    let file_name = Path::new(path).file_name()?.to_str()?;
+    let parse = |entry: &Option<git2::IndexEntry>| -> Option<EventDto> {
+        let blob = repo.find_blob(entry.as_ref()?.id).ok()?;
+        parse_event_content(file_name, blob.content())
+            .ok()
+            .map(|record| record_to_dto(&record))
     };
+    let local = parse(&conflict.our)?;
+    let remote = parse(&conflict.their)?;
+    let base = parse(&conflict.ancestor);
+    if local.id != remote.id {
+        return None;
+    }
+    let (merged, conflicts) = merge_event_fields(path, base.as_ref(), &local, &remote);
+    if !conflicts.is_empty() {
+        return Some(Err(conflicts));
+    }
+    let record = dto_to_event(&merged).ok()?;
+    render_event_content(&record).ok().map(Ok)
+}
 
-    let target_date = match parse_date(&date) {
-        Ok(d) => d,
-        Err(_) => return false,
+fn git_merge_resolve_events_impl(path: String) -> Result<EventMergeReport, GitError> {
+    let mut repo = open_repository(&path)?;
+    let workdir = repo
+        .workdir()
+        .ok_or_else(|| GitError::Other("Repository has no working directory".to_string()))?
+        .to_path_buf();
+    let mut index = repo.index()?;
+    let conflicts: Vec<git2::IndexConflict> = index.conflicts()?.collect::<Result<_, _>>()?;
+
+    let mut report = EventMergeReport {
+        merged_paths: Vec::new(),
+        unresolved_paths: Vec::new(),
+        conflicts: Vec::new(),
+        committed: false,
     };
+    for conflict in &conflicts {
+        let file_path = match conflict_path(conflict) {
+            Some(path) => path,
+            None => continue,
+        };
+        match merge_conflicted_event(&repo, conflict, &file_path) {
+            Some(Ok(content)) => {
+                fs::write(workdir.join(&file_path), content)?;
+                // Adding the path replaces its conflict entries with the merged stage-0 entry
+                index.add_path(Path::new(&file_path))?;
+                report.merged_paths.push(file_path);
+            }
+            Some(Err(field_conflicts)) => {
+                report.conflicts.extend(field_conflicts);
+                report.unresolved_paths.push(file_path);
+            }
+            None => report.unresolved_paths.push(file_path),
+        }
+    }
+    index.write()?;
 
-    event.occurs_on(target_date)
+    if report.unresolved_paths.is_empty() && repo.state() == git2::RepositoryState::Merge {
+        commit_merge(&repo, "Merge resolved by merging event fields")?;
+        restore_pull_stash(&mut repo)?;
+        report.committed = true;
+    }
+    Ok(report)
+}
+
+/// Resolves conflicted calendar event files by merging them field by field.
+/// Fields changed on only one side are combined; fields both sides changed
+/// differently are returned as conflicts and their files stay unresolved.
+/// When every conflict is resolved the merge is committed.
+#[flutter_rust_bridge::frb]
+pub fn git_merge_resolve_events(path: String) -> Result<EventMergeReport, GitError> {
+    git_merge_resolve_events_impl(path)
 }
@@ -37,7 +37,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 380905477;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 2110218451;
 
 // Section: executor
 
@@ -79,7 +79,7 @@ fn wire__crate__api__add_impl(
         },
     )
 }
-fn wire__crate__api__create_event_impl(
+fn wire__crate__api__cancel_operation_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -87,7 +87,7 @@ fn wire__crate__api__create_event_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "create_event",
+            debug_name: "cancel_operation",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -101,36 +101,18 @@ fn wire__crate__api__create_event_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_title = <String>::sse_decode(&mut deserializer);
-            let api_description = <String>::sse_decode(&mut deserializer);
-            let api_start_date = <String>::sse_decode(&mut deserializer);
-            let api_end_date = <Option<String>>::sse_decode(&mut deserializer);
-            let api_start_time = <Option<String>>::sse_decode(&mut deserializer);
-            let api_end_time = <Option<String>>::sse_decode(&mut deserializer);
-            let api_is_all_day = <bool>::sse_decode(&mut deserializer);
-            let api_recurrence = <String>::sse_decode(&mut deserializer);
-            let api_calendar_dir = <String>::sse_decode(&mut deserializer);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::create_event(
-                        api_title,
-                        api_description,
-                        api_start_date,
-                        api_end_date,
-                        api_start_time,
-                        api_end_time,
-                        api_is_all_day,
-                        api_recurrence,
-                        api_calendar_dir,
-                    )?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::cancel_operation(api_handle))?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__delete_event_impl(
+fn wire__crate__api__clear_credential_callback_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -138,7 +120,7 @@ fn wire__crate__api__delete_event_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "delete_event",
+            debug_name: "clear_credential_callback",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -152,19 +134,19 @@ fn wire__crate__api__delete_event_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_id = <String>::sse_decode(&mut deserializer);
-            let api_calendar_dir = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::delete_event(api_id, api_calendar_dir)?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::clear_credential_callback();
+                    })?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__event_occurs_on_impl(
+fn wire__crate__api__close_calendar_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -172,7 +154,7 @@ fn wire__crate__api__event_occurs_on_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "event_occurs_on",
+            debug_name: "close_calendar",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -186,20 +168,20 @@ fn wire__crate__api__event_occurs_on_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_event = <crate::api::EventDto>::sse_decode(&mut deserializer);
-            let api_date = <String>::sse_decode(&mut deserializer);
+            let api_calendar_dir = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::event_occurs_on(api_event, api_date))?;
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::close_calendar(api_calendar_dir);
+                    })?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__generate_instances_impl(
+fn wire__crate__api__convert_event_to_zone_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -207,7 +189,7 @@ fn wire__crate__api__generate_instances_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "generate_instances",
+            debug_name: "convert_event_to_zone",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -221,24 +203,19 @@ fn wire__crate__api__generate_instances_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_events = <Vec<crate::api::EventDto>>::sse_decode(&mut deserializer);
-            let api_start_date = <String>::sse_decode(&mut deserializer);
-            let api_end_date = <String>::sse_decode(&mut deserializer);
+            let api_event = <crate::api::EventDto>::sse_decode(&mut deserializer);
+            let api_display_zone = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::generate_instances(
-                        api_events,
-                        api_start_date,
-                        api_end_date,
-                    ))?;
+                transform_result_sse::<_, crate::api::EventError>((move || {
+                    let output_ok = crate::api::convert_event_to_zone(api_event, api_display_zone)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__get_all_events_impl(
+fn wire__crate__api__create_event_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -246,7 +223,7 @@ fn wire__crate__api__get_all_events_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_all_events",
+            debug_name: "create_event",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -260,18 +237,19 @@ fn wire__crate__api__get_all_events_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_input = <crate::api::EventInput>::sse_decode(&mut deserializer);
             let api_calendar_dir = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::get_all_events(api_calendar_dir)?;
+                transform_result_sse::<_, crate::api::EventError>((move || {
+                    let output_ok = crate::api::create_event(api_input, api_calendar_dir)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__get_events_in_range_impl(
+fn wire__crate__api__delete_event_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -279,7 +257,7 @@ fn wire__crate__api__get_events_in_range_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_events_in_range",
+            debug_name: "delete_event",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -293,24 +271,19 @@ fn wire__crate__api__get_events_in_range_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_start_date = <String>::sse_decode(&mut deserializer);
-            let api_end_date = <String>::sse_decode(&mut deserializer);
+            let api_id = <String>::sse_decode(&mut deserializer);
             let api_calendar_dir = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::get_events_in_range(
-                        api_start_date,
-                        api_end_date,
-                        api_calendar_dir,
-                    )?;
+                transform_result_sse::<_, crate::api::EventError>((move || {
+                    let output_ok = crate::api::delete_event(api_id, api_calendar_dir)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__git_add_all_impl(
+fn wire__crate__api__event_occurs_on_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -318,7 +291,7 @@ fn wire__crate__api__git_add_all_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "git_add_all",
+            debug_name: "event_occurs_on",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -332,18 +305,19 @@ fn wire__crate__api__git_add_all_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_event = <crate::api::EventDto>::sse_decode(&mut deserializer);
+            let api_date = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, crate::api::GitError>((move || {
-                    let output_ok = crate::api::git_add_all(api_path)?;
+                transform_result_sse::<_, crate::api::EventError>((move || {
+                    let output_ok = crate::api::event_occurs_on(api_event, api_date)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__git_add_remote_impl(
+fn wire__crate__api__format_recurrence_rule_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -351,7 +325,7 @@ fn wire__crate__api__git_add_remote_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "git_add_remote",
+            debug_name: "format_recurrence_rule",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -365,20 +339,19 @@ fn wire__crate__api__git_add_remote_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_path = <String>::sse_decode(&mut deserializer);
-            let api_name = <String>::sse_decode(&mut deserializer);
-            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_rule = <crate::api::RecurrenceRule>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, crate::api::GitError>((move || {
-                    let output_ok = crate::api::git_add_remote(api_path, api_name, api_url)?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::format_recurrence_rule(api_rule))?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__git_checkout_impl(
+fn wire__crate__api__generate_instances_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -386,7 +359,7 @@ fn wire__crate__api__git_checkout_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "git_checkout",
+            debug_name: "generate_instances",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -400,19 +373,21 @@ fn wire__crate__api__git_checkout_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_path = <String>::sse_decode(&mut deserializer);
-            let api_branch = <String>::sse_decode(&mut deserializer);
+            let api_events = <Vec<crate::api::EventDto>>::sse_decode(&mut deserializer);
+            let api_start_date = <String>::sse_decode(&mut deserializer);
+            let api_end_date = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, crate::api::GitError>((move || {
-                    let output_ok = crate::api::git_checkout(api_path, api_branch)?;
+                transform_result_sse::<_, crate::api::EventError>((move || {
+                    let output_ok =
+                        crate::api::generate_instances(api_events, api_start_date, api_end_date)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__git_clone_impl(
+fn wire__crate__api__generate_instances_in_zone_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -420,7 +395,7 @@ fn wire__crate__api__git_clone_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "git_clone",
+            debug_name: "generate_instances_in_zone",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -434,20 +409,18 @@ fn wire__crate__api__git_clone_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_url = <String>::sse_decode(&mut deserializer);
-            let api_path = <String>::sse_decode(&mut deserializer);
-            let api_username = <Option<String>>::sse_decode(&mut deserializer);
-            let api_password = <Option<String>>::sse_decode(&mut deserializer);
-            let api_ssh_key_path = <Option<String>>::sse_decode(&mut deserializer);
+            let api_events = <Vec<crate::api::EventDto>>::sse_decode(&mut deserializer);
+            let api_start_date = <String>::sse_decode(&mut deserializer);
+            let api_end_date = <String>::sse_decode(&mut deserializer);
+            let api_display_zone = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, crate::api::GitError>((move || {
-                    let output_ok = crate::api::git_clone(
-                        api_url,
-                        api_path,
-                        api_username,
-                        api_password,
-                        api_ssh_key_path,
+                transform_result_sse::<_, crate::api::EventError>((move || {
+                    let output_ok = crate::api::generate_instances_in_zone(
+                        api_events,
+                        api_start_date,
+                        api_end_date,
+                        api_display_zone,
                     )?;
                     Ok(output_ok)
                 })())
@@ -455,7 +428,7 @@ fn wire__crate__api__git_clone_impl(
         },
     )
 }
-fn wire__crate__api__git_commit_impl(
+fn wire__crate__api__get_all_events_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -463,7 +436,7 @@ fn wire__crate__api__git_commit_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "git_commit",
+            debug_name: "get_all_events",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -477,19 +450,18 @@ fn wire__crate__api__git_commit_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_path = <String>::sse_decode(&mut deserializer);
-            let api_message = <String>::sse_decode(&mut deserializer);
+            let api_calendar_dir = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, crate::api::GitError>((move || {
-                    let output_ok = crate::api::git_commit(api_path, api_message)?;
+                transform_result_sse::<_, crate::api::EventError>((move || {
+                    let output_ok = crate::api::get_all_events(api_calendar_dir)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__git_current_branch_impl(
+fn wire__crate__api__get_events_in_range_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -497,7 +469,7 @@ fn wire__crate__api__git_current_branch_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "git_current_branch",
+            debug_name: "get_events_in_range",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -511,18 +483,24 @@ fn wire__crate__api__git_current_branch_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_start_date = <String>::sse_decode(&mut deserializer);
+            let api_end_date = <String>::sse_decode(&mut deserializer);
+            let api_calendar_dir = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, crate::api::GitError>((move || {
-                    let output_ok = crate::api::git_current_branch(api_path)?;
+                transform_result_sse::<_, crate::api::EventError>((move || {
+                    let output_ok = crate::api::get_events_in_range(
+                        api_start_date,
+                        api_end_date,
+                        api_calendar_dir,
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__git_diff_impl(
+fn wire__crate__api__git_add_all_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -530,7 +508,7 @@ fn wire__crate__api__git_diff_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "git_diff",
+            debug_name: "git_add_all",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -548,14 +526,14 @@ fn wire__crate__api__git_diff_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, crate::api::GitError>((move || {
-                    let output_ok = crate::api::git_diff(api_path)?;
+                    let output_ok = crate::api::git_add_all(api_path)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__git_fetch_impl(
+fn wire__crate__api__git_add_remote_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -563,7 +541,7 @@ fn wire__crate__api__git_fetch_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "git_fetch",
+            debug_name: "git_add_remote",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -578,27 +556,19 @@ fn wire__crate__api__git_fetch_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_path = <String>::sse_decode(&mut deserializer);
-            let api_remote = <String>::sse_decode(&mut deserializer);
-            let api_username = <Option<String>>::sse_decode(&mut deserializer);
-            let api_password = <Option<String>>::sse_decode(&mut deserializer);
-            let api_ssh_key_path = <Option<String>>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_url = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, crate::api::GitError>((move || {
-                    let output_ok = crate::api::git_fetch(
-                        api_path,
-                        api_remote,
-                        api_username,
-                        api_password,
-                        api_ssh_key_path,
-                    )?;
+                    let output_ok = crate::api::git_add_remote(api_path, api_name, api_url)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__git_has_local_changes_impl(
+fn wire__crate__api__git_checkout_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -606,7 +576,7 @@ fn wire__crate__api__git_has_local_changes_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "git_has_local_changes",
+            debug_name: "git_checkout",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -621,17 +591,18 @@ fn wire__crate__api__git_has_local_changes_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_path = <String>::sse_decode(&mut deserializer);
+            let api_branch = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, crate::api::GitError>((move || {
-                    let output_ok = crate::api::git_has_local_changes(api_path)?;
+                    let output_ok = crate::api::git_checkout(api_path, api_branch)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__git_init_impl(
+fn wire__crate__api__git_clone_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -639,7 +610,7 @@ fn wire__crate__api__git_init_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "git_init",
+            debug_name: "git_clone",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -653,18 +624,28 @@ fn wire__crate__api__git_init_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
             let api_path = <String>::sse_decode(&mut deserializer);
+            let api_username = <Option<String>>::sse_decode(&mut deserializer);
+            let api_password = <Option<String>>::sse_decode(&mut deserializer);
+            let api_ssh_key_path = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, crate::api::GitError>((move || {
-                    let output_ok = crate::api::git_init(api_path)?;
+                    let output_ok = crate::api::git_clone(
+                        api_url,
+                        api_path,
+                        api_username,
+                        api_password,
+                        api_ssh_key_path,
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__git_list_branches_impl(
+fn wire__crate__api__git_clone_with_progress_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -672,7 +653,7 @@ fn wire__crate__api__git_list_branches_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "git_list_branches",
+            debug_name: "git_clone_with_progress",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -686,18 +667,35 @@ fn wire__crate__api__git_list_branches_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
             let api_path = <String>::sse_decode(&mut deserializer);
+            let api_username = <Option<String>>::sse_decode(&mut deserializer);
+            let api_password = <Option<String>>::sse_decode(&mut deserializer);
+            let api_ssh_key_path = <Option<String>>::sse_decode(&mut deserializer);
+            let api_operation = <Option<u64>>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::GitProgress,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, crate::api::GitError>((move || {
-                    let output_ok = crate::api::git_list_branches(api_path)?;
+                    let output_ok = crate::api::git_clone_with_progress(
+                        api_url,
+                        api_path,
+                        api_username,
+                        api_password,
+                        api_ssh_key_path,
+                        api_operation,
+                        api_sink,
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__git_merge_abort_impl(
+fn wire__crate__api__git_commit_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -705,7 +703,7 @@ fn wire__crate__api__git_merge_abort_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "git_merge_abort",
+            debug_name: "git_commit",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -720,17 +718,19 @@ fn wire__crate__api__git_merge_abort_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_path = <String>::sse_decode(&mut deserializer);
+            let api_message = <String>::sse_decode(&mut deserializer);
+            let api_author = <Option<crate::api::GitIdentity>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, crate::api::GitError>((move || {
-                    let output_ok = crate::api::git_merge_abort(api_path)?;
+                    let output_ok = crate::api::git_commit(api_path, api_message, api_author)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__git_merge_prefer_remote_impl(
+fn wire__crate__api__git_current_branch_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -738,7 +738,7 @@ fn wire__crate__api__git_merge_prefer_remote_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "git_merge_prefer_remote",
+            debug_name: "git_current_branch",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -756,14 +756,14 @@ fn wire__crate__api__git_merge_prefer_remote_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, crate::api::GitError>((move || {
-                    let output_ok = crate::api::git_merge_prefer_remote(api_path)?;
+                    let output_ok = crate::api::git_current_branch(api_path)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__git_pull_impl(
+fn wire__crate__api__git_diff_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -771,7 +771,7 @@ fn wire__crate__api__git_pull_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "git_pull",
+            debug_name: "git_diff",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -786,25 +786,17 @@ fn wire__crate__api__git_pull_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_path = <String>::sse_decode(&mut deserializer);
-            let api_username = <Option<String>>::sse_decode(&mut deserializer);
-            let api_password = <Option<String>>::sse_decode(&mut deserializer);
-            let api_ssh_key_path = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, crate::api::GitError>((move || {
-                    let output_ok = crate::api::git_pull(
-                        api_path,
-                        api_username,
-                        api_password,
-                        api_ssh_key_path,
-                    )?;
+                    let output_ok = crate::api::git_diff(api_path)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__git_push_impl(
+fn wire__crate__api__git_fetch_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -812,7 +804,7 @@ fn wire__crate__api__git_push_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "git_push",
+            debug_name: "git_fetch",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -827,17 +819,21 @@ fn wire__crate__api__git_push_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_path = <String>::sse_decode(&mut deserializer);
+            let api_remote = <String>::sse_decode(&mut deserializer);
             let api_username = <Option<String>>::sse_decode(&mut deserializer);
             let api_password = <Option<String>>::sse_decode(&mut deserializer);
             let api_ssh_key_path = <Option<String>>::sse_decode(&mut deserializer);
+            let api_branch = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, crate::api::GitError>((move || {
-                    let output_ok = crate::api::git_push(
+                    let output_ok = crate::api::git_fetch(
                         api_path,
+                        api_remote,
                         api_username,
                         api_password,
                         api_ssh_key_path,
+                        api_branch,
                     )?;
                     Ok(output_ok)
                 })())
@@ -845,7 +841,7 @@ fn wire__crate__api__git_push_impl(
         },
     )
 }
-fn wire__crate__api__git_remove_remote_impl(
+fn wire__crate__api__git_fetch_with_progress_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -853,7 +849,7 @@ fn wire__crate__api__git_remove_remote_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "git_remove_remote",
+            debug_name: "git_fetch_with_progress",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -868,18 +864,36 @@ fn wire__crate__api__git_remove_remote_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_path = <String>::sse_decode(&mut deserializer);
-            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_remote = <String>::sse_decode(&mut deserializer);
+            let api_username = <Option<String>>::sse_decode(&mut deserializer);
+            let api_password = <Option<String>>::sse_decode(&mut deserializer);
+            let api_ssh_key_path = <Option<String>>::sse_decode(&mut deserializer);
+            let api_branch = <Option<String>>::sse_decode(&mut deserializer);
+            let api_operation = <Option<u64>>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::GitProgress,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, crate::api::GitError>((move || {
-                    let output_ok = crate::api::git_remove_remote(api_path, api_name)?;
+                    let output_ok = crate::api::git_fetch_with_progress(
+                        api_path,
+                        api_remote,
+                        api_username,
+                        api_password,
+                        api_ssh_key_path,
+                        api_branch,
+                        api_operation,
+                        api_sink,
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__git_stash_impl(
+fn wire__crate__api__git_get_identity_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -887,7 +901,7 @@ fn wire__crate__api__git_stash_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "git_stash",
+            debug_name: "git_get_identity",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -905,14 +919,14 @@ fn wire__crate__api__git_stash_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, crate::api::GitError>((move || {
-                    let output_ok = crate::api::git_stash(api_path)?;
+                    let output_ok = crate::api::git_get_identity(api_path)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__git_status_impl(
+fn wire__crate__api__git_has_local_changes_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -920,7 +934,7 @@ fn wire__crate__api__git_status_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "git_status",
+            debug_name: "git_has_local_changes",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -938,14 +952,14 @@ fn wire__crate__api__git_status_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, crate::api::GitError>((move || {
-                    let output_ok = crate::api::git_status(api_path)?;
+                    let output_ok = crate::api::git_has_local_changes(api_path)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__init_app_impl(
+fn wire__crate__api__git_init_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -953,7 +967,7 @@ fn wire__crate__api__init_app_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
+            debug_name: "git_init",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -967,19 +981,18 @@ fn wire__crate__api__init_app_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::init_app();
-                    })?;
+                transform_result_sse::<_, crate::api::GitError>((move || {
+                    let output_ok = crate::api::git_init(api_path)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__set_ssl_ca_certs_impl(
+fn wire__crate__api__git_list_branches_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -987,7 +1000,7 @@ fn wire__crate__api__set_ssl_ca_certs_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_ssl_ca_certs",
+            debug_name: "git_list_branches",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1001,18 +1014,18 @@ fn wire__crate__api__set_ssl_ca_certs_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_pem_certs = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, crate::api::GitError>((move || {
-                    let output_ok = crate::api::set_ssl_ca_certs(api_pem_certs)?;
+                    let output_ok = crate::api::git_list_branches(api_path)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__update_event_impl(
+fn wire__crate__api__git_merge_abort_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1020,7 +1033,7 @@ fn wire__crate__api__update_event_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "update_event",
+            debug_name: "git_merge_abort",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1034,38 +1047,18 @@ fn wire__crate__api__update_event_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_id = <String>::sse_decode(&mut deserializer);
-            let api_title = <String>::sse_decode(&mut deserializer);
-            let api_description = <String>::sse_decode(&mut deserializer);
-            let api_start_date = <String>::sse_decode(&mut deserializer);
-            let api_end_date = <Option<String>>::sse_decode(&mut deserializer);
-            let api_start_time = <Option<String>>::sse_decode(&mut deserializer);
-            let api_end_time = <Option<String>>::sse_decode(&mut deserializer);
-            let api_is_all_day = <bool>::sse_decode(&mut deserializer);
-            let api_recurrence = <String>::sse_decode(&mut deserializer);
-            let api_calendar_dir = <String>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::update_event(
-                        api_id,
-                        api_title,
-                        api_description,
-                        api_start_date,
-                        api_end_date,
-                        api_start_time,
-                        api_end_time,
-                        api_is_all_day,
-                        api_recurrence,
-                        api_calendar_dir,
-                    )?;
+                transform_result_sse::<_, crate::api::GitError>((move || {
+                    let output_ok = crate::api::git_merge_abort(api_path)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__validate_event_impl(
+fn wire__crate__api__git_merge_prefer_local_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1073,7 +1066,7 @@ fn wire__crate__api__validate_event_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "validate_event",
+            debug_name: "git_merge_prefer_local",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1087,435 +1080,4349 @@ fn wire__crate__api__validate_event_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_title = <String>::sse_decode(&mut deserializer);
-            let api_start_date = <String>::sse_decode(&mut deserializer);
-            let api_end_date = <Option<String>>::sse_decode(&mut deserializer);
-            let api_start_time = <Option<String>>::sse_decode(&mut deserializer);
-            let api_end_time = <Option<String>>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::validate_event(
-                        api_title,
-                        api_start_date,
-                        api_end_date,
-                        api_start_time,
-                        api_end_time,
-                    )?;
+                transform_result_sse::<_, crate::api::GitError>((move || {
+                    let output_ok = crate::api::git_merge_prefer_local(api_path)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-
-// Section: dart2rust
-
-impl SseDecode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
-    }
-}
-
-impl SseDecode for bool {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
-    }
-}
-
-impl SseDecode for crate::api::EventDto {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <String>::sse_decode(deserializer);
-        let mut var_title = <String>::sse_decode(deserializer);
-        let mut var_description = <String>::sse_decode(deserializer);
-        let mut var_startDate = <String>::sse_decode(deserializer);
-        let mut var_endDate = <Option<String>>::sse_decode(deserializer);
-        let mut var_startTime = <Option<String>>::sse_decode(deserializer);
-        let mut var_endTime = <Option<String>>::sse_decode(deserializer);
-        let mut var_isAllDay = <bool>::sse_decode(deserializer);
-        let mut var_recurrence = <String>::sse_decode(deserializer);
-        let mut var_isRecurringInstance = <bool>::sse_decode(deserializer);
-        return crate::api::EventDto {
-            id: var_id,
-            title: var_title,
-            description: var_description,
-            start_date: var_startDate,
-            end_date: var_endDate,
-            start_time: var_startTime,
-            end_time: var_endTime,
-            is_all_day: var_isAllDay,
-            recurrence: var_recurrence,
-            is_recurring_instance: var_isRecurringInstance,
-        };
-    }
-}
-
-impl SseDecode for crate::api::GitError {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut tag_ = <i32>::sse_decode(deserializer);
-        match tag_ {
-            0 => {
-                let mut var_field0 = <String>::sse_decode(deserializer);
-                return crate::api::GitError::Io(var_field0);
-            }
-            1 => {
-                let mut var_field0 = <String>::sse_decode(deserializer);
-                return crate::api::GitError::Git(var_field0);
-            }
-            2 => {
-                let mut var_field0 = <String>::sse_decode(deserializer);
-                return crate::api::GitError::Auth(var_field0);
+fn wire__crate__api__git_merge_prefer_remote_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "git_merge_prefer_remote",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::GitError>((move || {
+                    let output_ok = crate::api::git_merge_prefer_remote(api_path)?;
+                    Ok(output_ok)
+                })())
             }
-            3 => {
-                let mut var_field0 = <String>::sse_decode(deserializer);
-                return crate::api::GitError::Other(var_field0);
+        },
+    )
+}
+fn wire__crate__api__git_merge_resolve_events_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "git_merge_resolve_events",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::GitError>((move || {
+                    let output_ok = crate::api::git_merge_resolve_events(api_path)?;
+                    Ok(output_ok)
+                })())
             }
-            _ => {
-                unimplemented!("");
+        },
+    )
+}
+fn wire__crate__api__git_merge_resolve_paths_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "git_merge_resolve_paths",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_resolutions = <Vec<crate::api::PathResolution>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::GitError>((move || {
+                    let output_ok = crate::api::git_merge_resolve_paths(api_path, api_resolutions)?;
+                    Ok(output_ok)
+                })())
             }
-        }
-    }
+        },
+    )
 }
-
-impl SseDecode for i32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
-    }
+fn wire__crate__api__git_pull_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "git_pull",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_username = <Option<String>>::sse_decode(&mut deserializer);
+            let api_password = <Option<String>>::sse_decode(&mut deserializer);
+            let api_ssh_key_path = <Option<String>>::sse_decode(&mut deserializer);
+            let api_branch = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::GitError>((move || {
+                    let output_ok = crate::api::git_pull(
+                        api_path,
+                        api_username,
+                        api_password,
+                        api_ssh_key_path,
+                        api_branch,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
 }
-
-impl SseDecode for Vec<String> {
+fn wire__crate__api__git_pull_with_progress_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "git_pull_with_progress",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_username = <Option<String>>::sse_decode(&mut deserializer);
+            let api_password = <Option<String>>::sse_decode(&mut deserializer);
+            let api_ssh_key_path = <Option<String>>::sse_decode(&mut deserializer);
+            let api_branch = <Option<String>>::sse_decode(&mut deserializer);
+            let api_operation = <Option<u64>>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::GitProgress,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::GitError>((move || {
+                    let output_ok = crate::api::git_pull_with_progress(
+                        api_path,
+                        api_username,
+                        api_password,
+                        api_ssh_key_path,
+                        api_branch,
+                        api_operation,
+                        api_sink,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__git_push_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "git_push",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_username = <Option<String>>::sse_decode(&mut deserializer);
+            let api_password = <Option<String>>::sse_decode(&mut deserializer);
+            let api_ssh_key_path = <Option<String>>::sse_decode(&mut deserializer);
+            let api_branch = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::GitError>((move || {
+                    let output_ok = crate::api::git_push(
+                        api_path,
+                        api_username,
+                        api_password,
+                        api_ssh_key_path,
+                        api_branch,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__git_push_with_progress_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "git_push_with_progress",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_username = <Option<String>>::sse_decode(&mut deserializer);
+            let api_password = <Option<String>>::sse_decode(&mut deserializer);
+            let api_ssh_key_path = <Option<String>>::sse_decode(&mut deserializer);
+            let api_branch = <Option<String>>::sse_decode(&mut deserializer);
+            let api_operation = <Option<u64>>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::GitProgress,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::GitError>((move || {
+                    let output_ok = crate::api::git_push_with_progress(
+                        api_path,
+                        api_username,
+                        api_password,
+                        api_ssh_key_path,
+                        api_branch,
+                        api_operation,
+                        api_sink,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__git_remove_remote_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "git_remove_remote",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::GitError>((move || {
+                    let output_ok = crate::api::git_remove_remote(api_path, api_name)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__git_set_identity_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "git_set_identity",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_identity = <crate::api::GitIdentity>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::GitError>((move || {
+                    let output_ok = crate::api::git_set_identity(api_path, api_identity)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__git_stash_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "git_stash",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::GitError>((move || {
+                    let output_ok = crate::api::git_stash(api_path)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__git_status_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "git_status",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::GitError>((move || {
+                    let output_ok = crate::api::git_status(api_path)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__git_sync_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "git_sync",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_username = <Option<String>>::sse_decode(&mut deserializer);
+            let api_password = <Option<String>>::sse_decode(&mut deserializer);
+            let api_ssh_key_path = <Option<String>>::sse_decode(&mut deserializer);
+            let api_branch = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::GitError>((move || {
+                    let output_ok = crate::api::git_sync(
+                        api_path,
+                        api_username,
+                        api_password,
+                        api_ssh_key_path,
+                        api_branch,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__git_sync_with_progress_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "git_sync_with_progress",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_username = <Option<String>>::sse_decode(&mut deserializer);
+            let api_password = <Option<String>>::sse_decode(&mut deserializer);
+            let api_ssh_key_path = <Option<String>>::sse_decode(&mut deserializer);
+            let api_branch = <Option<String>>::sse_decode(&mut deserializer);
+            let api_operation = <Option<u64>>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::GitProgress,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::GitError>((move || {
+                    let output_ok = crate::api::git_sync_with_progress(
+                        api_path,
+                        api_username,
+                        api_password,
+                        api_ssh_key_path,
+                        api_branch,
+                        api_operation,
+                        api_sink,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__ics_import_options_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "ics_import_options_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::IcsImportOptions::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__import_ics_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "import_ics",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_ics_text = <String>::sse_decode(&mut deserializer);
+            let api_calendar_dir = <String>::sse_decode(&mut deserializer);
+            let api_options = <crate::api::IcsImportOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::EventError>((move || {
+                    let output_ok =
+                        crate::api::import_ics(api_ics_text, api_calendar_dir, api_options)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__init_app_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_app",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::init_app();
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__list_known_hosts_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_known_hosts",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::GitError>((move || {
+                    let output_ok = crate::api::list_known_hosts()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__list_time_zones_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_time_zones",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::list_time_zones())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__migrate_event_ids_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "migrate_event_ids",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_calendar_dir = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::EventError>((move || {
+                    let output_ok = crate::api::migrate_event_ids(api_calendar_dir)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__open_calendar_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "open_calendar",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_calendar_dir = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::EventError>((move || {
+                    let output_ok = crate::api::open_calendar(api_calendar_dir)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__override_occurrence_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "override_occurrence",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_changes = <crate::api::OccurrenceOverride>::sse_decode(&mut deserializer);
+            let api_calendar_dir = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::EventError>((move || {
+                    let output_ok =
+                        crate::api::override_occurrence(api_id, api_changes, api_calendar_dir)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__parse_recurrence_rule_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_recurrence_rule",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_text = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::EventError>((move || {
+                    let output_ok = crate::api::parse_recurrence_rule(api_text)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__remove_host_key_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_host_key",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_host = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::GitError>((move || {
+                    let output_ok = crate::api::remove_host_key(api_host)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__set_credential_callback_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "set_credential_callback", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_callback = decode_DartFn_Inputs_credential_request_Output_opt_box_autoadd_git_credentials_AnyhowException(<flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer));deserializer.end(); move |context|  {
+                    transform_result_sse::<_, ()>((move ||  {
+                         let output_ok = Result::<_,()>::Ok({ crate::api::set_credential_callback(api_callback); })?;   Ok(output_ok)
+                    })())
+                } })
+}
+fn wire__crate__api__set_known_hosts_path_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_known_hosts_path",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::GitError>((move || {
+                    let output_ok = crate::api::set_known_hosts_path(api_path)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__set_ssl_ca_certs_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_ssl_ca_certs",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pem_certs = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::GitError>((move || {
+                    let output_ok = crate::api::set_ssl_ca_certs(api_pem_certs)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__set_trust_store_path_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_trust_store_path",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::GitError>((move || {
+                    let output_ok = crate::api::set_trust_store_path(api_path)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__skip_occurrence_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "skip_occurrence",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_date = <String>::sse_decode(&mut deserializer);
+            let api_calendar_dir = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::EventError>((move || {
+                    let output_ok =
+                        crate::api::skip_occurrence(api_id, api_date, api_calendar_dir)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__split_series_from_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "split_series_from",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_date = <String>::sse_decode(&mut deserializer);
+            let api_calendar_dir = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::EventError>((move || {
+                    let output_ok =
+                        crate::api::split_series_from(api_id, api_date, api_calendar_dir)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__start_operation_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_operation",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::start_operation())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__trust_add_ca_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "trust_add_ca",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_host = <String>::sse_decode(&mut deserializer);
+            let api_pem = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::GitError>((move || {
+                    let output_ok = crate::api::trust_add_ca(api_host, api_pem)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__trust_host_key_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "trust_host_key",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_key = <crate::api::HostKey>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::GitError>((move || {
+                    let output_ok = crate::api::trust_host_key(api_key)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__trust_list_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "trust_list",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_host = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::GitError>((move || {
+                    let output_ok = crate::api::trust_list(api_host)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__trust_pin_certificate_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "trust_pin_certificate",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_host = <String>::sse_decode(&mut deserializer);
+            let api_pem = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::GitError>((move || {
+                    let output_ok = crate::api::trust_pin_certificate(api_host, api_pem)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__trust_remove_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "trust_remove",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_host = <String>::sse_decode(&mut deserializer);
+            let api_fingerprint = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::GitError>((move || {
+                    let output_ok = crate::api::trust_remove(api_host, api_fingerprint)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__unwatch_calendar_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unwatch_calendar",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_calendar_dir = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::unwatch_calendar(api_calendar_dir);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__update_event_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_event",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_input = <crate::api::EventInput>::sse_decode(&mut deserializer);
+            let api_calendar_dir = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::EventError>((move || {
+                    let output_ok = crate::api::update_event(api_id, api_input, api_calendar_dir)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__validate_event_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "validate_event",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_title = <String>::sse_decode(&mut deserializer);
+            let api_start_date = <String>::sse_decode(&mut deserializer);
+            let api_end_date = <Option<String>>::sse_decode(&mut deserializer);
+            let api_start_time = <Option<String>>::sse_decode(&mut deserializer);
+            let api_end_time = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::EventError>((move || {
+                    let output_ok = crate::api::validate_event(
+                        api_title,
+                        api_start_date,
+                        api_end_date,
+                        api_start_time,
+                        api_end_time,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__watch_calendar_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "watch_calendar",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_calendar_dir = <String>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::CalendarChange,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::EventError>((move || {
+                    let output_ok = crate::api::watch_calendar(api_calendar_dir, api_sink)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+
+// Section: related_funcs
+
+fn decode_DartFn_Inputs_credential_request_Output_opt_box_autoadd_git_credentials_AnyhowException(
+    dart_opaque: flutter_rust_bridge::DartOpaque,
+) -> impl Fn(
+    crate::api::CredentialRequest,
+) -> flutter_rust_bridge::DartFnFuture<Option<crate::api::GitCredentials>> {
+    use flutter_rust_bridge::IntoDart;
+
+    async fn body(
+        dart_opaque: flutter_rust_bridge::DartOpaque,
+        arg0: crate::api::CredentialRequest,
+    ) -> Option<crate::api::GitCredentials> {
+        let args = vec![arg0.into_into_dart().into_dart()];
+        let message = FLUTTER_RUST_BRIDGE_HANDLER
+            .dart_fn_invoke(dart_opaque, args)
+            .await;
+
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let action = deserializer.cursor.read_u8().unwrap();
+        let ans = match action {
+            0 => std::result::Result::Ok(<Option<crate::api::GitCredentials>>::sse_decode(
+                &mut deserializer,
+            )),
+            1 => std::result::Result::Err(
+                <flutter_rust_bridge::for_generated::anyhow::Error>::sse_decode(&mut deserializer),
+            ),
+            _ => unreachable!(),
+        };
+        deserializer.end();
+        let ans = ans.expect("Dart throws exception but Rust side assume it is not failable");
+        ans
+    }
+
+    move |arg0: crate::api::CredentialRequest| {
+        flutter_rust_bridge::for_generated::convert_into_dart_fn_future(body(
+            dart_opaque.clone(),
+            arg0,
+        ))
+    }
+}
+
+// Section: dart2rust
+
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode for flutter_rust_bridge::DartOpaque {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return unsafe { flutter_rust_bridge::for_generated::sse_decode_dart_opaque(inner) };
+    }
+}
+
+impl SseDecode
+    for StreamSink<crate::api::CalendarChange, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<crate::api::GitProgress, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for crate::api::CalendarChange {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 = <crate::api::EventDto>::sse_decode(deserializer);
+                return crate::api::CalendarChange::EventAdded(var_field0);
+            }
+            1 => {
+                let mut var_field0 = <crate::api::EventDto>::sse_decode(deserializer);
+                return crate::api::CalendarChange::EventChanged(var_field0);
+            }
+            2 => {
+                let mut var_field0 = <crate::api::EventDto>::sse_decode(deserializer);
+                return crate::api::CalendarChange::EventRemoved(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::CertificateError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::CertificateError::Expired;
+            }
+            1 => {
+                return crate::api::CertificateError::NotYetValid;
+            }
+            2 => {
+                return crate::api::CertificateError::UnknownIssuer;
+            }
+            3 => {
+                return crate::api::CertificateError::NameMismatch;
+            }
+            4 => {
+                return crate::api::CertificateError::PinMismatch;
+            }
+            5 => {
+                return crate::api::CertificateError::InvalidSignature;
+            }
+            6 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::CertificateError::Malformed(var_field0);
+            }
+            7 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::CertificateError::Other(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::CredentialRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_usernameFromUrl = <Option<String>>::sse_decode(deserializer);
+        let mut var_allowPassword = <bool>::sse_decode(deserializer);
+        let mut var_allowSshKey = <bool>::sse_decode(deserializer);
+        let mut var_attempt = <u32>::sse_decode(deserializer);
+        return crate::api::CredentialRequest {
+            url: var_url,
+            username_from_url: var_usernameFromUrl,
+            allow_password: var_allowPassword,
+            allow_ssh_key: var_allowSshKey,
+            attempt: var_attempt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::EventDiagnostic {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_reason = <crate::api::EventError>::sse_decode(deserializer);
+        return crate::api::EventDiagnostic {
+            id: var_id,
+            reason: var_reason,
+        };
+    }
+}
+
+impl SseDecode for crate::api::EventDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_description = <String>::sse_decode(deserializer);
+        let mut var_startDate = <String>::sse_decode(deserializer);
+        let mut var_endDate = <Option<String>>::sse_decode(deserializer);
+        let mut var_startTime = <Option<String>>::sse_decode(deserializer);
+        let mut var_endTime = <Option<String>>::sse_decode(deserializer);
+        let mut var_isAllDay = <bool>::sse_decode(deserializer);
+        let mut var_timeZone = <Option<String>>::sse_decode(deserializer);
+        let mut var_recurrence = <String>::sse_decode(deserializer);
+        let mut var_rrule = <Option<crate::api::RecurrenceRule>>::sse_decode(deserializer);
+        let mut var_excludedDates = <Vec<String>>::sse_decode(deserializer);
+        let mut var_overrides = <Vec<crate::api::OccurrenceOverride>>::sse_decode(deserializer);
+        let mut var_occurrenceDate = <Option<String>>::sse_decode(deserializer);
+        let mut var_isRecurringInstance = <bool>::sse_decode(deserializer);
+        return crate::api::EventDto {
+            id: var_id,
+            title: var_title,
+            description: var_description,
+            start_date: var_startDate,
+            end_date: var_endDate,
+            start_time: var_startTime,
+            end_time: var_endTime,
+            is_all_day: var_isAllDay,
+            time_zone: var_timeZone,
+            recurrence: var_recurrence,
+            rrule: var_rrule,
+            excluded_dates: var_excludedDates,
+            overrides: var_overrides,
+            occurrence_date: var_occurrenceDate,
+            is_recurring_instance: var_isRecurringInstance,
+        };
+    }
+}
+
+impl SseDecode for crate::api::EventError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field = <String>::sse_decode(deserializer);
+                let mut var_value = <String>::sse_decode(deserializer);
+                return crate::api::EventError::InvalidDate {
+                    field: var_field,
+                    value: var_value,
+                };
+            }
+            1 => {
+                let mut var_field = <String>::sse_decode(deserializer);
+                let mut var_value = <String>::sse_decode(deserializer);
+                return crate::api::EventError::InvalidTime {
+                    field: var_field,
+                    value: var_value,
+                };
+            }
+            2 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::EventError::MissingField(var_field0);
+            }
+            3 => {
+                let mut var_field = <String>::sse_decode(deserializer);
+                return crate::api::EventError::InvalidRange { field: var_field };
+            }
+            4 => {
+                let mut var_value = <String>::sse_decode(deserializer);
+                let mut var_reason = <String>::sse_decode(deserializer);
+                return crate::api::EventError::InvalidRecurrence {
+                    value: var_value,
+                    reason: var_reason,
+                };
+            }
+            5 => {
+                let mut var_id = <String>::sse_decode(deserializer);
+                let mut var_date = <String>::sse_decode(deserializer);
+                return crate::api::EventError::NoOccurrence {
+                    id: var_id,
+                    date: var_date,
+                };
+            }
+            6 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::EventError::UnknownTimeZone(var_field0);
+            }
+            7 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::EventError::TitleInvalid(var_field0);
+            }
+            8 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::EventError::NotFound(var_field0);
+            }
+            9 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::EventError::DuplicateId(var_field0);
+            }
+            10 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::EventError::Storage(var_field0);
+            }
+            11 => {
+                let mut var_file = <String>::sse_decode(deserializer);
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::api::EventError::ParseFailure {
+                    file: var_file,
+                    message: var_message,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::EventFieldConflict {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_eventId = <String>::sse_decode(deserializer);
+        let mut var_field = <String>::sse_decode(deserializer);
+        let mut var_baseValue = <Option<String>>::sse_decode(deserializer);
+        let mut var_localValue = <Option<String>>::sse_decode(deserializer);
+        let mut var_remoteValue = <Option<String>>::sse_decode(deserializer);
+        return crate::api::EventFieldConflict {
+            path: var_path,
+            event_id: var_eventId,
+            field: var_field,
+            base_value: var_baseValue,
+            local_value: var_localValue,
+            remote_value: var_remoteValue,
+        };
+    }
+}
+
+impl SseDecode for crate::api::EventInput {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_description = <String>::sse_decode(deserializer);
+        let mut var_startDate = <String>::sse_decode(deserializer);
+        let mut var_endDate = <Option<String>>::sse_decode(deserializer);
+        let mut var_startTime = <Option<String>>::sse_decode(deserializer);
+        let mut var_endTime = <Option<String>>::sse_decode(deserializer);
+        let mut var_isAllDay = <bool>::sse_decode(deserializer);
+        let mut var_recurrence = <String>::sse_decode(deserializer);
+        let mut var_rrule = <Option<crate::api::RecurrenceRule>>::sse_decode(deserializer);
+        let mut var_timeZone = <Option<String>>::sse_decode(deserializer);
+        return crate::api::EventInput {
+            title: var_title,
+            description: var_description,
+            start_date: var_startDate,
+            end_date: var_endDate,
+            start_time: var_startTime,
+            end_time: var_endTime,
+            is_all_day: var_isAllDay,
+            recurrence: var_recurrence,
+            rrule: var_rrule,
+            time_zone: var_timeZone,
+        };
+    }
+}
+
+impl SseDecode for crate::api::EventMergeReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_mergedPaths = <Vec<String>>::sse_decode(deserializer);
+        let mut var_unresolvedPaths = <Vec<String>>::sse_decode(deserializer);
+        let mut var_conflicts = <Vec<crate::api::EventFieldConflict>>::sse_decode(deserializer);
+        let mut var_committed = <bool>::sse_decode(deserializer);
+        return crate::api::EventMergeReport {
+            merged_paths: var_mergedPaths,
+            unresolved_paths: var_unresolvedPaths,
+            conflicts: var_conflicts,
+            committed: var_committed,
+        };
+    }
+}
+
+impl SseDecode for crate::api::EventsResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_events = <Vec<crate::api::EventDto>>::sse_decode(deserializer);
+        let mut var_unreadable = <Vec<crate::api::EventError>>::sse_decode(deserializer);
+        return crate::api::EventsResult {
+            events: var_events,
+            unreadable: var_unreadable,
+        };
+    }
+}
+
+impl SseDecode for crate::api::Frequency {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::Frequency::Daily,
+            1 => crate::api::Frequency::Weekly,
+            2 => crate::api::Frequency::Monthly,
+            3 => crate::api::Frequency::Yearly,
+            _ => unreachable!("Invalid variant for Frequency: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::GitCredentials {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_username = <String>::sse_decode(deserializer);
+                let mut var_password = <String>::sse_decode(deserializer);
+                return crate::api::GitCredentials::UserPassword {
+                    username: var_username,
+                    password: var_password,
+                };
+            }
+            1 => {
+                let mut var_username = <Option<String>>::sse_decode(deserializer);
+                let mut var_token = <String>::sse_decode(deserializer);
+                return crate::api::GitCredentials::Token {
+                    username: var_username,
+                    token: var_token,
+                };
+            }
+            2 => {
+                let mut var_username = <Option<String>>::sse_decode(deserializer);
+                let mut var_privateKeyPath = <String>::sse_decode(deserializer);
+                let mut var_publicKeyPath = <Option<String>>::sse_decode(deserializer);
+                let mut var_passphrase = <Option<String>>::sse_decode(deserializer);
+                return crate::api::GitCredentials::SshKeyFile {
+                    username: var_username,
+                    private_key_path: var_privateKeyPath,
+                    public_key_path: var_publicKeyPath,
+                    passphrase: var_passphrase,
+                };
+            }
+            3 => {
+                let mut var_username = <Option<String>>::sse_decode(deserializer);
+                let mut var_privateKey = <String>::sse_decode(deserializer);
+                let mut var_publicKey = <Option<String>>::sse_decode(deserializer);
+                let mut var_passphrase = <Option<String>>::sse_decode(deserializer);
+                return crate::api::GitCredentials::SshKeyMemory {
+                    username: var_username,
+                    private_key: var_privateKey,
+                    public_key: var_publicKey,
+                    passphrase: var_passphrase,
+                };
+            }
+            4 => {
+                let mut var_username = <Option<String>>::sse_decode(deserializer);
+                return crate::api::GitCredentials::SshAgent {
+                    username: var_username,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::GitError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::GitError::Io(var_field0);
+            }
+            1 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::GitError::Git(var_field0);
+            }
+            2 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::GitError::AuthenticationFailed(var_field0);
+            }
+            3 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::GitError::RepositoryNotFound(var_field0);
+            }
+            4 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::GitError::NotFound(var_field0);
+            }
+            5 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::GitError::NetworkUnreachable(var_field0);
+            }
+            6 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::GitError::Locked(var_field0);
+            }
+            7 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::GitError::DetachedHead(var_field0);
+            }
+            8 => {
+                let mut var_field0 = <Vec<String>>::sse_decode(deserializer);
+                return crate::api::GitError::MergeConflict(var_field0);
+            }
+            9 => {
+                let mut var_field0 = <Vec<String>>::sse_decode(deserializer);
+                return crate::api::GitError::StashConflict(var_field0);
+            }
+            10 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::GitError::NonFastForward(var_field0);
+            }
+            11 => {
+                let mut var_host = <String>::sse_decode(deserializer);
+                let mut var_reason = <crate::api::CertificateError>::sse_decode(deserializer);
+                return crate::api::GitError::CertificateInvalid {
+                    host: var_host,
+                    reason: var_reason,
+                };
+            }
+            12 => {
+                let mut var_field0 = <crate::api::HostKey>::sse_decode(deserializer);
+                return crate::api::GitError::UnknownHostKey(var_field0);
+            }
+            13 => {
+                let mut var_field0 = <crate::api::HostKey>::sse_decode(deserializer);
+                return crate::api::GitError::HostKeyMismatch(var_field0);
+            }
+            14 => {
+                return crate::api::GitError::Cancelled;
+            }
+            15 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::GitError::UnbornBranch(var_field0);
+            }
+            16 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::GitError::Other(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::GitIdentity {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_email = <String>::sse_decode(deserializer);
+        return crate::api::GitIdentity {
+            name: var_name,
+            email: var_email,
+        };
+    }
+}
+
+impl SseDecode for crate::api::GitProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_receivedObjects = <usize>::sse_decode(deserializer);
+                let mut var_totalObjects = <usize>::sse_decode(deserializer);
+                let mut var_indexedObjects = <usize>::sse_decode(deserializer);
+                let mut var_receivedBytes = <usize>::sse_decode(deserializer);
+                let mut var_totalDeltas = <usize>::sse_decode(deserializer);
+                let mut var_indexedDeltas = <usize>::sse_decode(deserializer);
+                return crate::api::GitProgress::Transfer {
+                    received_objects: var_receivedObjects,
+                    total_objects: var_totalObjects,
+                    indexed_objects: var_indexedObjects,
+                    received_bytes: var_receivedBytes,
+                    total_deltas: var_totalDeltas,
+                    indexed_deltas: var_indexedDeltas,
+                };
+            }
+            1 => {
+                let mut var_path = <Option<String>>::sse_decode(deserializer);
+                let mut var_completed = <usize>::sse_decode(deserializer);
+                let mut var_total = <usize>::sse_decode(deserializer);
+                return crate::api::GitProgress::Checkout {
+                    path: var_path,
+                    completed: var_completed,
+                    total: var_total,
+                };
+            }
+            2 => {
+                let mut var_current = <usize>::sse_decode(deserializer);
+                let mut var_total = <usize>::sse_decode(deserializer);
+                let mut var_bytes = <usize>::sse_decode(deserializer);
+                return crate::api::GitProgress::PushTransfer {
+                    current: var_current,
+                    total: var_total,
+                    bytes: var_bytes,
+                };
+            }
+            3 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::GitProgress::Sideband(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::HostKey {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_host = <String>::sse_decode(deserializer);
+        let mut var_keyType = <String>::sse_decode(deserializer);
+        let mut var_key = <String>::sse_decode(deserializer);
+        let mut var_fingerprint = <String>::sse_decode(deserializer);
+        return crate::api::HostKey {
+            host: var_host,
+            key_type: var_keyType,
+            key: var_key,
+            fingerprint: var_fingerprint,
+        };
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::IcsImportIssue {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_uid = <Option<String>>::sse_decode(deserializer);
+        let mut var_summary = <String>::sse_decode(deserializer);
+        let mut var_reason = <String>::sse_decode(deserializer);
+        return crate::api::IcsImportIssue {
+            uid: var_uid,
+            summary: var_summary,
+            reason: var_reason,
+        };
+    }
+}
+
+impl SseDecode for crate::api::IcsImportOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_replaceExisting = <bool>::sse_decode(deserializer);
+        return crate::api::IcsImportOptions {
+            replace_existing: var_replaceExisting,
+        };
+    }
+}
+
+impl SseDecode for crate::api::IcsImportReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_imported = <Vec<String>>::sse_decode(deserializer);
+        let mut var_skipped = <Vec<crate::api::IcsImportIssue>>::sse_decode(deserializer);
+        let mut var_unsupported = <Vec<crate::api::IcsImportIssue>>::sse_decode(deserializer);
+        return crate::api::IcsImportReport {
+            imported: var_imported,
+            skipped: var_skipped,
+            unsupported: var_unsupported,
+        };
+    }
+}
+
+impl SseDecode for crate::api::InstancesResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_instances = <Vec<crate::api::EventDto>>::sse_decode(deserializer);
+        let mut var_diagnostics = <Vec<crate::api::EventDiagnostic>>::sse_decode(deserializer);
+        return crate::api::InstancesResult {
+            instances: var_instances,
+            diagnostics: var_diagnostics,
+        };
+    }
+}
+
+impl SseDecode for isize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i64::<NativeEndian>().unwrap() as _
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::EventDiagnostic> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::EventDiagnostic>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::EventDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::EventDto>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::EventError> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::EventError>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::EventFieldConflict> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::EventFieldConflict>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::HostKey> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::HostKey>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::IcsImportIssue> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::IcsImportIssue>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::OccurrenceOverride> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::OccurrenceOverride>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::PathResolution> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::PathResolution>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<i32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<i32>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::StatusEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::StatusEntry>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::TrustedCertificate> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::TrustedCertificate>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::WeekdayRule> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::WeekdayRule>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::api::MergeSide {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::MergeSide::Local,
+            1 => crate::api::MergeSide::Remote,
+            _ => unreachable!("Invalid variant for MergeSide: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::OccurrenceOverride {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_occurrenceDate = <String>::sse_decode(deserializer);
+        let mut var_title = <Option<String>>::sse_decode(deserializer);
+        let mut var_description = <Option<String>>::sse_decode(deserializer);
+        let mut var_startDate = <Option<String>>::sse_decode(deserializer);
+        let mut var_endDate = <Option<String>>::sse_decode(deserializer);
+        let mut var_startTime = <Option<String>>::sse_decode(deserializer);
+        let mut var_endTime = <Option<String>>::sse_decode(deserializer);
+        let mut var_isAllDay = <Option<bool>>::sse_decode(deserializer);
+        return crate::api::OccurrenceOverride {
+            occurrence_date: var_occurrenceDate,
+            title: var_title,
+            description: var_description,
+            start_date: var_startDate,
+            end_date: var_endDate,
+            start_time: var_startTime,
+            end_time: var_endTime,
+            is_all_day: var_isAllDay,
+        };
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<bool>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::GitCredentials> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::GitCredentials>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::GitIdentity> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::GitIdentity>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<i32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<i32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::RecurrenceRule> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::RecurrenceRule>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::api::PathResolution {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_side = <crate::api::MergeSide>::sse_decode(deserializer);
+        return crate::api::PathResolution {
+            path: var_path,
+            side: var_side,
+        };
+    }
+}
+
+impl SseDecode for crate::api::RecurrenceRule {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_frequency = <crate::api::Frequency>::sse_decode(deserializer);
+        let mut var_interval = <u32>::sse_decode(deserializer);
+        let mut var_byDay = <Vec<crate::api::WeekdayRule>>::sse_decode(deserializer);
+        let mut var_byMonthDay = <Vec<i32>>::sse_decode(deserializer);
+        let mut var_bySetPos = <Vec<i32>>::sse_decode(deserializer);
+        let mut var_count = <Option<u32>>::sse_decode(deserializer);
+        let mut var_until = <Option<String>>::sse_decode(deserializer);
+        return crate::api::RecurrenceRule {
+            frequency: var_frequency,
+            interval: var_interval,
+            by_day: var_byDay,
+            by_month_day: var_byMonthDay,
+            by_set_pos: var_bySetPos,
+            count: var_count,
+            until: var_until,
+        };
+    }
+}
+
+impl SseDecode for crate::api::StatusEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_status = <String>::sse_decode(deserializer);
+        return crate::api::StatusEntry {
+            path: var_path,
+            status: var_status,
+        };
+    }
+}
+
+impl SseDecode for crate::api::SyncOutcome {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::SyncOutcome::UpToDate,
+            1 => crate::api::SyncOutcome::FastForward,
+            2 => crate::api::SyncOutcome::Merged,
+            3 => crate::api::SyncOutcome::Pushed,
+            4 => crate::api::SyncOutcome::Fetched,
+            _ => unreachable!("Invalid variant for SyncOutcome: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::SyncReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_localCommit = <Option<String>>::sse_decode(deserializer);
+        let mut var_pull = <crate::api::SyncResult>::sse_decode(deserializer);
+        let mut var_push = <crate::api::SyncResult>::sse_decode(deserializer);
+        let mut var_pushAttempts = <u32>::sse_decode(deserializer);
+        return crate::api::SyncReport {
+            local_commit: var_localCommit,
+            pull: var_pull,
+            push: var_push,
+            push_attempts: var_pushAttempts,
+        };
+    }
+}
+
+impl SseDecode for crate::api::SyncResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_outcome = <crate::api::SyncOutcome>::sse_decode(deserializer);
+        let mut var_oldCommit = <Option<String>>::sse_decode(deserializer);
+        let mut var_newCommit = <Option<String>>::sse_decode(deserializer);
+        let mut var_addedPaths = <Vec<String>>::sse_decode(deserializer);
+        let mut var_modifiedPaths = <Vec<String>>::sse_decode(deserializer);
+        let mut var_deletedPaths = <Vec<String>>::sse_decode(deserializer);
+        let mut var_affectedEventIds = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::SyncResult {
+            outcome: var_outcome,
+            old_commit: var_oldCommit,
+            new_commit: var_newCommit,
+            added_paths: var_addedPaths,
+            modified_paths: var_modifiedPaths,
+            deleted_paths: var_deletedPaths,
+            affected_event_ids: var_affectedEventIds,
+        };
+    }
+}
+
+impl SseDecode for crate::api::TrustKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::TrustKind::CertificateAuthority,
+            1 => crate::api::TrustKind::PinnedLeaf,
+            _ => unreachable!("Invalid variant for TrustKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::TrustedCertificate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_host = <String>::sse_decode(deserializer);
+        let mut var_kind = <crate::api::TrustKind>::sse_decode(deserializer);
+        let mut var_fingerprint = <String>::sse_decode(deserializer);
+        let mut var_subject = <String>::sse_decode(deserializer);
+        let mut var_notAfter = <String>::sse_decode(deserializer);
+        return crate::api::TrustedCertificate {
+            host: var_host,
+            kind: var_kind,
+            fingerprint: var_fingerprint,
+            subject: var_subject,
+            not_after: var_notAfter,
+        };
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for usize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    }
+}
+
+impl SseDecode for crate::api::Weekday {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::Weekday::Monday,
+            1 => crate::api::Weekday::Tuesday,
+            2 => crate::api::Weekday::Wednesday,
+            3 => crate::api::Weekday::Thursday,
+            4 => crate::api::Weekday::Friday,
+            5 => crate::api::Weekday::Saturday,
+            6 => crate::api::Weekday::Sunday,
+            _ => unreachable!("Invalid variant for Weekday: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::WeekdayRule {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_weekday = <crate::api::Weekday>::sse_decode(deserializer);
+        let mut var_ordinal = <Option<i32>>::sse_decode(deserializer);
+        return crate::api::WeekdayRule {
+            weekday: var_weekday,
+            ordinal: var_ordinal,
+        };
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => wire__crate__api__add_impl(port, ptr, rust_vec_len, data_len),
+        2 => wire__crate__api__cancel_operation_impl(port, ptr, rust_vec_len, data_len),
+        3 => wire__crate__api__clear_credential_callback_impl(port, ptr, rust_vec_len, data_len),
+        4 => wire__crate__api__close_calendar_impl(port, ptr, rust_vec_len, data_len),
+        5 => wire__crate__api__convert_event_to_zone_impl(port, ptr, rust_vec_len, data_len),
+        6 => wire__crate__api__create_event_impl(port, ptr, rust_vec_len, data_len),
+        7 => wire__crate__api__delete_event_impl(port, ptr, rust_vec_len, data_len),
+        8 => wire__crate__api__event_occurs_on_impl(port, ptr, rust_vec_len, data_len),
+        9 => wire__crate__api__format_recurrence_rule_impl(port, ptr, rust_vec_len, data_len),
+        10 => wire__crate__api__generate_instances_impl(port, ptr, rust_vec_len, data_len),
+        11 => wire__crate__api__generate_instances_in_zone_impl(port, ptr, rust_vec_len, data_len),
+        12 => wire__crate__api__get_all_events_impl(port, ptr, rust_vec_len, data_len),
+        13 => wire__crate__api__get_events_in_range_impl(port, ptr, rust_vec_len, data_len),
+        14 => wire__crate__api__git_add_all_impl(port, ptr, rust_vec_len, data_len),
+        15 => wire__crate__api__git_add_remote_impl(port, ptr, rust_vec_len, data_len),
+        16 => wire__crate__api__git_checkout_impl(port, ptr, rust_vec_len, data_len),
+        17 => wire__crate__api__git_clone_impl(port, ptr, rust_vec_len, data_len),
+        18 => wire__crate__api__git_clone_with_progress_impl(port, ptr, rust_vec_len, data_len),
+        19 => wire__crate__api__git_commit_impl(port, ptr, rust_vec_len, data_len),
+        20 => wire__crate__api__git_current_branch_impl(port, ptr, rust_vec_len, data_len),
+        21 => wire__crate__api__git_diff_impl(port, ptr, rust_vec_len, data_len),
+        22 => wire__crate__api__git_fetch_impl(port, ptr, rust_vec_len, data_len),
+        23 => wire__crate__api__git_fetch_with_progress_impl(port, ptr, rust_vec_len, data_len),
+        24 => wire__crate__api__git_get_identity_impl(port, ptr, rust_vec_len, data_len),
+        25 => wire__crate__api__git_has_local_changes_impl(port, ptr, rust_vec_len, data_len),
+        26 => wire__crate__api__git_init_impl(port, ptr, rust_vec_len, data_len),
+        27 => wire__crate__api__git_list_branches_impl(port, ptr, rust_vec_len, data_len),
+        28 => wire__crate__api__git_merge_abort_impl(port, ptr, rust_vec_len, data_len),
+        29 => wire__crate__api__git_merge_prefer_local_impl(port, ptr, rust_vec_len, data_len),
+        30 => wire__crate__api__git_merge_prefer_remote_impl(port, ptr, rust_vec_len, data_len),
+        31 => wire__crate__api__git_merge_resolve_events_impl(port, ptr, rust_vec_len, data_len),
+        32 => wire__crate__api__git_merge_resolve_paths_impl(port, ptr, rust_vec_len, data_len),
+        33 => wire__crate__api__git_pull_impl(port, ptr, rust_vec_len, data_len),
+        34 => wire__crate__api__git_pull_with_progress_impl(port, ptr, rust_vec_len, data_len),
+        35 => wire__crate__api__git_push_impl(port, ptr, rust_vec_len, data_len),
+        36 => wire__crate__api__git_push_with_progress_impl(port, ptr, rust_vec_len, data_len),
+        37 => wire__crate__api__git_remove_remote_impl(port, ptr, rust_vec_len, data_len),
+        38 => wire__crate__api__git_set_identity_impl(port, ptr, rust_vec_len, data_len),
+        39 => wire__crate__api__git_stash_impl(port, ptr, rust_vec_len, data_len),
+        40 => wire__crate__api__git_status_impl(port, ptr, rust_vec_len, data_len),
+        41 => wire__crate__api__git_sync_impl(port, ptr, rust_vec_len, data_len),
+        42 => wire__crate__api__git_sync_with_progress_impl(port, ptr, rust_vec_len, data_len),
+        43 => wire__crate__api__ics_import_options_default_impl(port, ptr, rust_vec_len, data_len),
+        44 => wire__crate__api__import_ics_impl(port, ptr, rust_vec_len, data_len),
+        45 => wire__crate__api__init_app_impl(port, ptr, rust_vec_len, data_len),
+        46 => wire__crate__api__list_known_hosts_impl(port, ptr, rust_vec_len, data_len),
+        47 => wire__crate__api__list_time_zones_impl(port, ptr, rust_vec_len, data_len),
+        48 => wire__crate__api__migrate_event_ids_impl(port, ptr, rust_vec_len, data_len),
+        49 => wire__crate__api__open_calendar_impl(port, ptr, rust_vec_len, data_len),
+        50 => wire__crate__api__override_occurrence_impl(port, ptr, rust_vec_len, data_len),
+        51 => wire__crate__api__parse_recurrence_rule_impl(port, ptr, rust_vec_len, data_len),
+        52 => wire__crate__api__remove_host_key_impl(port, ptr, rust_vec_len, data_len),
+        53 => wire__crate__api__set_credential_callback_impl(port, ptr, rust_vec_len, data_len),
+        54 => wire__crate__api__set_known_hosts_path_impl(port, ptr, rust_vec_len, data_len),
+        55 => wire__crate__api__set_ssl_ca_certs_impl(port, ptr, rust_vec_len, data_len),
+        56 => wire__crate__api__set_trust_store_path_impl(port, ptr, rust_vec_len, data_len),
+        57 => wire__crate__api__skip_occurrence_impl(port, ptr, rust_vec_len, data_len),
+        58 => wire__crate__api__split_series_from_impl(port, ptr, rust_vec_len, data_len),
+        59 => wire__crate__api__start_operation_impl(port, ptr, rust_vec_len, data_len),
+        60 => wire__crate__api__trust_add_ca_impl(port, ptr, rust_vec_len, data_len),
+        61 => wire__crate__api__trust_host_key_impl(port, ptr, rust_vec_len, data_len),
+        62 => wire__crate__api__trust_list_impl(port, ptr, rust_vec_len, data_len),
+        63 => wire__crate__api__trust_pin_certificate_impl(port, ptr, rust_vec_len, data_len),
+        64 => wire__crate__api__trust_remove_impl(port, ptr, rust_vec_len, data_len),
+        65 => wire__crate__api__unwatch_calendar_impl(port, ptr, rust_vec_len, data_len),
+        66 => wire__crate__api__update_event_impl(port, ptr, rust_vec_len, data_len),
+        67 => wire__crate__api__validate_event_impl(port, ptr, rust_vec_len, data_len),
+        68 => wire__crate__api__watch_calendar_impl(port, ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::CalendarChange {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::CalendarChange::EventAdded(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::CalendarChange::EventChanged(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::CalendarChange::EventRemoved(field0) => {
+                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::CalendarChange {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::CalendarChange> for crate::api::CalendarChange {
+    fn into_into_dart(self) -> crate::api::CalendarChange {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::CertificateError {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::CertificateError::Expired => [0.into_dart()].into_dart(),
+            crate::api::CertificateError::NotYetValid => [1.into_dart()].into_dart(),
+            crate::api::CertificateError::UnknownIssuer => [2.into_dart()].into_dart(),
+            crate::api::CertificateError::NameMismatch => [3.into_dart()].into_dart(),
+            crate::api::CertificateError::PinMismatch => [4.into_dart()].into_dart(),
+            crate::api::CertificateError::InvalidSignature => [5.into_dart()].into_dart(),
+            crate::api::CertificateError::Malformed(field0) => {
+                [6.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::CertificateError::Other(field0) => {
+                [7.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::CertificateError {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::CertificateError>
+    for crate::api::CertificateError
+{
+    fn into_into_dart(self) -> crate::api::CertificateError {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::CredentialRequest {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.url.into_into_dart().into_dart(),
+            self.username_from_url.into_into_dart().into_dart(),
+            self.allow_password.into_into_dart().into_dart(),
+            self.allow_ssh_key.into_into_dart().into_dart(),
+            self.attempt.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::CredentialRequest {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::CredentialRequest>
+    for crate::api::CredentialRequest
+{
+    fn into_into_dart(self) -> crate::api::CredentialRequest {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::EventDiagnostic {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.reason.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::EventDiagnostic {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::EventDiagnostic>
+    for crate::api::EventDiagnostic
+{
+    fn into_into_dart(self) -> crate::api::EventDiagnostic {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::EventDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.description.into_into_dart().into_dart(),
+            self.start_date.into_into_dart().into_dart(),
+            self.end_date.into_into_dart().into_dart(),
+            self.start_time.into_into_dart().into_dart(),
+            self.end_time.into_into_dart().into_dart(),
+            self.is_all_day.into_into_dart().into_dart(),
+            self.time_zone.into_into_dart().into_dart(),
+            self.recurrence.into_into_dart().into_dart(),
+            self.rrule.into_into_dart().into_dart(),
+            self.excluded_dates.into_into_dart().into_dart(),
+            self.overrides.into_into_dart().into_dart(),
+            self.occurrence_date.into_into_dart().into_dart(),
+            self.is_recurring_instance.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::EventDto {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::EventDto> for crate::api::EventDto {
+    fn into_into_dart(self) -> crate::api::EventDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::EventError {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::EventError::InvalidDate { field, value } => [
+                0.into_dart(),
+                field.into_into_dart().into_dart(),
+                value.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::EventError::InvalidTime { field, value } => [
+                1.into_dart(),
+                field.into_into_dart().into_dart(),
+                value.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::EventError::MissingField(field0) => {
+                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::EventError::InvalidRange { field } => {
+                [3.into_dart(), field.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::EventError::InvalidRecurrence { value, reason } => [
+                4.into_dart(),
+                value.into_into_dart().into_dart(),
+                reason.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::EventError::NoOccurrence { id, date } => [
+                5.into_dart(),
+                id.into_into_dart().into_dart(),
+                date.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::EventError::UnknownTimeZone(field0) => {
+                [6.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::EventError::TitleInvalid(field0) => {
+                [7.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::EventError::NotFound(field0) => {
+                [8.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::EventError::DuplicateId(field0) => {
+                [9.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::EventError::Storage(field0) => {
+                [10.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::EventError::ParseFailure { file, message } => [
+                11.into_dart(),
+                file.into_into_dart().into_dart(),
+                message.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::EventError {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::EventError> for crate::api::EventError {
+    fn into_into_dart(self) -> crate::api::EventError {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::EventFieldConflict {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.path.into_into_dart().into_dart(),
+            self.event_id.into_into_dart().into_dart(),
+            self.field.into_into_dart().into_dart(),
+            self.base_value.into_into_dart().into_dart(),
+            self.local_value.into_into_dart().into_dart(),
+            self.remote_value.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::EventFieldConflict
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::EventFieldConflict>
+    for crate::api::EventFieldConflict
+{
+    fn into_into_dart(self) -> crate::api::EventFieldConflict {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::EventInput {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.title.into_into_dart().into_dart(),
+            self.description.into_into_dart().into_dart(),
+            self.start_date.into_into_dart().into_dart(),
+            self.end_date.into_into_dart().into_dart(),
+            self.start_time.into_into_dart().into_dart(),
+            self.end_time.into_into_dart().into_dart(),
+            self.is_all_day.into_into_dart().into_dart(),
+            self.recurrence.into_into_dart().into_dart(),
+            self.rrule.into_into_dart().into_dart(),
+            self.time_zone.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::EventInput {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::EventInput> for crate::api::EventInput {
+    fn into_into_dart(self) -> crate::api::EventInput {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::EventMergeReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.merged_paths.into_into_dart().into_dart(),
+            self.unresolved_paths.into_into_dart().into_dart(),
+            self.conflicts.into_into_dart().into_dart(),
+            self.committed.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::EventMergeReport {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::EventMergeReport>
+    for crate::api::EventMergeReport
+{
+    fn into_into_dart(self) -> crate::api::EventMergeReport {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::EventsResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.events.into_into_dart().into_dart(),
+            self.unreadable.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::EventsResult {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::EventsResult> for crate::api::EventsResult {
+    fn into_into_dart(self) -> crate::api::EventsResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::Frequency {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Daily => 0.into_dart(),
+            Self::Weekly => 1.into_dart(),
+            Self::Monthly => 2.into_dart(),
+            Self::Yearly => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::Frequency {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::Frequency> for crate::api::Frequency {
+    fn into_into_dart(self) -> crate::api::Frequency {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::GitCredentials {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::GitCredentials::UserPassword { username, password } => [
+                0.into_dart(),
+                username.into_into_dart().into_dart(),
+                password.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::GitCredentials::Token { username, token } => [
+                1.into_dart(),
+                username.into_into_dart().into_dart(),
+                token.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::GitCredentials::SshKeyFile {
+                username,
+                private_key_path,
+                public_key_path,
+                passphrase,
+            } => [
+                2.into_dart(),
+                username.into_into_dart().into_dart(),
+                private_key_path.into_into_dart().into_dart(),
+                public_key_path.into_into_dart().into_dart(),
+                passphrase.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::GitCredentials::SshKeyMemory {
+                username,
+                private_key,
+                public_key,
+                passphrase,
+            } => [
+                3.into_dart(),
+                username.into_into_dart().into_dart(),
+                private_key.into_into_dart().into_dart(),
+                public_key.into_into_dart().into_dart(),
+                passphrase.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::GitCredentials::SshAgent { username } => {
+                [4.into_dart(), username.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::GitCredentials {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::GitCredentials> for crate::api::GitCredentials {
+    fn into_into_dart(self) -> crate::api::GitCredentials {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::GitError {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::GitError::Io(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::GitError::Git(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::GitError::AuthenticationFailed(field0) => {
+                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::GitError::RepositoryNotFound(field0) => {
+                [3.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::GitError::NotFound(field0) => {
+                [4.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::GitError::NetworkUnreachable(field0) => {
+                [5.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::GitError::Locked(field0) => {
+                [6.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::GitError::DetachedHead(field0) => {
+                [7.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::GitError::MergeConflict(field0) => {
+                [8.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::GitError::StashConflict(field0) => {
+                [9.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::GitError::NonFastForward(field0) => {
+                [10.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::GitError::CertificateInvalid { host, reason } => [
+                11.into_dart(),
+                host.into_into_dart().into_dart(),
+                reason.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::GitError::UnknownHostKey(field0) => {
+                [12.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::GitError::HostKeyMismatch(field0) => {
+                [13.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::GitError::Cancelled => [14.into_dart()].into_dart(),
+            crate::api::GitError::UnbornBranch(field0) => {
+                [15.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::GitError::Other(field0) => {
+                [16.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::GitError {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::GitError> for crate::api::GitError {
+    fn into_into_dart(self) -> crate::api::GitError {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::GitIdentity {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.email.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::GitIdentity {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::GitIdentity> for crate::api::GitIdentity {
+    fn into_into_dart(self) -> crate::api::GitIdentity {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::GitProgress {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::GitProgress::Transfer {
+                received_objects,
+                total_objects,
+                indexed_objects,
+                received_bytes,
+                total_deltas,
+                indexed_deltas,
+            } => [
+                0.into_dart(),
+                received_objects.into_into_dart().into_dart(),
+                total_objects.into_into_dart().into_dart(),
+                indexed_objects.into_into_dart().into_dart(),
+                received_bytes.into_into_dart().into_dart(),
+                total_deltas.into_into_dart().into_dart(),
+                indexed_deltas.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::GitProgress::Checkout {
+                path,
+                completed,
+                total,
+            } => [
+                1.into_dart(),
+                path.into_into_dart().into_dart(),
+                completed.into_into_dart().into_dart(),
+                total.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::GitProgress::PushTransfer {
+                current,
+                total,
+                bytes,
+            } => [
+                2.into_dart(),
+                current.into_into_dart().into_dart(),
+                total.into_into_dart().into_dart(),
+                bytes.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::GitProgress::Sideband(field0) => {
+                [3.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::GitProgress {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::GitProgress> for crate::api::GitProgress {
+    fn into_into_dart(self) -> crate::api::GitProgress {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::HostKey {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.host.into_into_dart().into_dart(),
+            self.key_type.into_into_dart().into_dart(),
+            self.key.into_into_dart().into_dart(),
+            self.fingerprint.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::HostKey {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::HostKey> for crate::api::HostKey {
+    fn into_into_dart(self) -> crate::api::HostKey {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::IcsImportIssue {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.uid.into_into_dart().into_dart(),
+            self.summary.into_into_dart().into_dart(),
+            self.reason.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::IcsImportIssue {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::IcsImportIssue> for crate::api::IcsImportIssue {
+    fn into_into_dart(self) -> crate::api::IcsImportIssue {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::IcsImportOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.replace_existing.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::IcsImportOptions {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::IcsImportOptions>
+    for crate::api::IcsImportOptions
+{
+    fn into_into_dart(self) -> crate::api::IcsImportOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::IcsImportReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.imported.into_into_dart().into_dart(),
+            self.skipped.into_into_dart().into_dart(),
+            self.unsupported.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::IcsImportReport {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::IcsImportReport>
+    for crate::api::IcsImportReport
+{
+    fn into_into_dart(self) -> crate::api::IcsImportReport {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::InstancesResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.instances.into_into_dart().into_dart(),
+            self.diagnostics.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::InstancesResult {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::InstancesResult>
+    for crate::api::InstancesResult
+{
+    fn into_into_dart(self) -> crate::api::InstancesResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::MergeSide {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Local => 0.into_dart(),
+            Self::Remote => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::MergeSide {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::MergeSide> for crate::api::MergeSide {
+    fn into_into_dart(self) -> crate::api::MergeSide {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::OccurrenceOverride {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.occurrence_date.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.description.into_into_dart().into_dart(),
+            self.start_date.into_into_dart().into_dart(),
+            self.end_date.into_into_dart().into_dart(),
+            self.start_time.into_into_dart().into_dart(),
+            self.end_time.into_into_dart().into_dart(),
+            self.is_all_day.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::OccurrenceOverride
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::OccurrenceOverride>
+    for crate::api::OccurrenceOverride
+{
+    fn into_into_dart(self) -> crate::api::OccurrenceOverride {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::PathResolution {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.path.into_into_dart().into_dart(),
+            self.side.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::PathResolution {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::PathResolution> for crate::api::PathResolution {
+    fn into_into_dart(self) -> crate::api::PathResolution {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::RecurrenceRule {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.frequency.into_into_dart().into_dart(),
+            self.interval.into_into_dart().into_dart(),
+            self.by_day.into_into_dart().into_dart(),
+            self.by_month_day.into_into_dart().into_dart(),
+            self.by_set_pos.into_into_dart().into_dart(),
+            self.count.into_into_dart().into_dart(),
+            self.until.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::RecurrenceRule {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::RecurrenceRule> for crate::api::RecurrenceRule {
+    fn into_into_dart(self) -> crate::api::RecurrenceRule {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::StatusEntry {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.path.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::StatusEntry {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::StatusEntry> for crate::api::StatusEntry {
+    fn into_into_dart(self) -> crate::api::StatusEntry {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::SyncOutcome {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::UpToDate => 0.into_dart(),
+            Self::FastForward => 1.into_dart(),
+            Self::Merged => 2.into_dart(),
+            Self::Pushed => 3.into_dart(),
+            Self::Fetched => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::SyncOutcome {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::SyncOutcome> for crate::api::SyncOutcome {
+    fn into_into_dart(self) -> crate::api::SyncOutcome {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::SyncReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.local_commit.into_into_dart().into_dart(),
+            self.pull.into_into_dart().into_dart(),
+            self.push.into_into_dart().into_dart(),
+            self.push_attempts.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::SyncReport {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::SyncReport> for crate::api::SyncReport {
+    fn into_into_dart(self) -> crate::api::SyncReport {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::SyncResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.outcome.into_into_dart().into_dart(),
+            self.old_commit.into_into_dart().into_dart(),
+            self.new_commit.into_into_dart().into_dart(),
+            self.added_paths.into_into_dart().into_dart(),
+            self.modified_paths.into_into_dart().into_dart(),
+            self.deleted_paths.into_into_dart().into_dart(),
+            self.affected_event_ids.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::SyncResult {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::SyncResult> for crate::api::SyncResult {
+    fn into_into_dart(self) -> crate::api::SyncResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::TrustKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::CertificateAuthority => 0.into_dart(),
+            Self::PinnedLeaf => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::TrustKind {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TrustKind> for crate::api::TrustKind {
+    fn into_into_dart(self) -> crate::api::TrustKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::TrustedCertificate {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.host.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.fingerprint.into_into_dart().into_dart(),
+            self.subject.into_into_dart().into_dart(),
+            self.not_after.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::TrustedCertificate
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TrustedCertificate>
+    for crate::api::TrustedCertificate
+{
+    fn into_into_dart(self) -> crate::api::TrustedCertificate {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::Weekday {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Monday => 0.into_dart(),
+            Self::Tuesday => 1.into_dart(),
+            Self::Wednesday => 2.into_dart(),
+            Self::Thursday => 3.into_dart(),
+            Self::Friday => 4.into_dart(),
+            Self::Saturday => 5.into_dart(),
+            Self::Sunday => 6.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::Weekday {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::Weekday> for crate::api::Weekday {
+    fn into_into_dart(self) -> crate::api::Weekday {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::WeekdayRule {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.weekday.into_into_dart().into_dart(),
+            self.ordinal.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::WeekdayRule {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WeekdayRule> for crate::api::WeekdayRule {
+    fn into_into_dart(self) -> crate::api::WeekdayRule {
+        self
+    }
+}
+
+impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(format!("{:?}", self), serializer);
+    }
+}
+
+impl SseEncode for flutter_rust_bridge::DartOpaque {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <usize>::sse_encode(self.encode(), serializer);
+    }
+}
+
+impl SseEncode
+    for StreamSink<crate::api::CalendarChange, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<crate::api::GitProgress, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+    }
+}
+
+impl SseEncode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::CalendarChange {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::CalendarChange::EventAdded(field0) => {
+                <i32>::sse_encode(0, serializer);
+                <crate::api::EventDto>::sse_encode(field0, serializer);
+            }
+            crate::api::CalendarChange::EventChanged(field0) => {
+                <i32>::sse_encode(1, serializer);
+                <crate::api::EventDto>::sse_encode(field0, serializer);
+            }
+            crate::api::CalendarChange::EventRemoved(field0) => {
+                <i32>::sse_encode(2, serializer);
+                <crate::api::EventDto>::sse_encode(field0, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::CertificateError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::CertificateError::Expired => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::CertificateError::NotYetValid => {
+                <i32>::sse_encode(1, serializer);
+            }
+            crate::api::CertificateError::UnknownIssuer => {
+                <i32>::sse_encode(2, serializer);
+            }
+            crate::api::CertificateError::NameMismatch => {
+                <i32>::sse_encode(3, serializer);
+            }
+            crate::api::CertificateError::PinMismatch => {
+                <i32>::sse_encode(4, serializer);
+            }
+            crate::api::CertificateError::InvalidSignature => {
+                <i32>::sse_encode(5, serializer);
+            }
+            crate::api::CertificateError::Malformed(field0) => {
+                <i32>::sse_encode(6, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::CertificateError::Other(field0) => {
+                <i32>::sse_encode(7, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::CredentialRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.url, serializer);
+        <Option<String>>::sse_encode(self.username_from_url, serializer);
+        <bool>::sse_encode(self.allow_password, serializer);
+        <bool>::sse_encode(self.allow_ssh_key, serializer);
+        <u32>::sse_encode(self.attempt, serializer);
+    }
+}
+
+impl SseEncode for crate::api::EventDiagnostic {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <crate::api::EventError>::sse_encode(self.reason, serializer);
+    }
+}
+
+impl SseEncode for crate::api::EventDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.title, serializer);
+        <String>::sse_encode(self.description, serializer);
+        <String>::sse_encode(self.start_date, serializer);
+        <Option<String>>::sse_encode(self.end_date, serializer);
+        <Option<String>>::sse_encode(self.start_time, serializer);
+        <Option<String>>::sse_encode(self.end_time, serializer);
+        <bool>::sse_encode(self.is_all_day, serializer);
+        <Option<String>>::sse_encode(self.time_zone, serializer);
+        <String>::sse_encode(self.recurrence, serializer);
+        <Option<crate::api::RecurrenceRule>>::sse_encode(self.rrule, serializer);
+        <Vec<String>>::sse_encode(self.excluded_dates, serializer);
+        <Vec<crate::api::OccurrenceOverride>>::sse_encode(self.overrides, serializer);
+        <Option<String>>::sse_encode(self.occurrence_date, serializer);
+        <bool>::sse_encode(self.is_recurring_instance, serializer);
+    }
+}
+
+impl SseEncode for crate::api::EventError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::EventError::InvalidDate { field, value } => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(field, serializer);
+                <String>::sse_encode(value, serializer);
+            }
+            crate::api::EventError::InvalidTime { field, value } => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(field, serializer);
+                <String>::sse_encode(value, serializer);
+            }
+            crate::api::EventError::MissingField(field0) => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::EventError::InvalidRange { field } => {
+                <i32>::sse_encode(3, serializer);
+                <String>::sse_encode(field, serializer);
+            }
+            crate::api::EventError::InvalidRecurrence { value, reason } => {
+                <i32>::sse_encode(4, serializer);
+                <String>::sse_encode(value, serializer);
+                <String>::sse_encode(reason, serializer);
+            }
+            crate::api::EventError::NoOccurrence { id, date } => {
+                <i32>::sse_encode(5, serializer);
+                <String>::sse_encode(id, serializer);
+                <String>::sse_encode(date, serializer);
+            }
+            crate::api::EventError::UnknownTimeZone(field0) => {
+                <i32>::sse_encode(6, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::EventError::TitleInvalid(field0) => {
+                <i32>::sse_encode(7, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::EventError::NotFound(field0) => {
+                <i32>::sse_encode(8, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::EventError::DuplicateId(field0) => {
+                <i32>::sse_encode(9, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::EventError::Storage(field0) => {
+                <i32>::sse_encode(10, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::EventError::ParseFailure { file, message } => {
+                <i32>::sse_encode(11, serializer);
+                <String>::sse_encode(file, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::EventFieldConflict {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.path, serializer);
+        <String>::sse_encode(self.event_id, serializer);
+        <String>::sse_encode(self.field, serializer);
+        <Option<String>>::sse_encode(self.base_value, serializer);
+        <Option<String>>::sse_encode(self.local_value, serializer);
+        <Option<String>>::sse_encode(self.remote_value, serializer);
+    }
+}
+
+impl SseEncode for crate::api::EventInput {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<String>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.title, serializer);
+        <String>::sse_encode(self.description, serializer);
+        <String>::sse_encode(self.start_date, serializer);
+        <Option<String>>::sse_encode(self.end_date, serializer);
+        <Option<String>>::sse_encode(self.start_time, serializer);
+        <Option<String>>::sse_encode(self.end_time, serializer);
+        <bool>::sse_encode(self.is_all_day, serializer);
+        <String>::sse_encode(self.recurrence, serializer);
+        <Option<crate::api::RecurrenceRule>>::sse_encode(self.rrule, serializer);
+        <Option<String>>::sse_encode(self.time_zone, serializer);
+    }
+}
+
+impl SseEncode for crate::api::EventMergeReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<String>>::sse_encode(self.merged_paths, serializer);
+        <Vec<String>>::sse_encode(self.unresolved_paths, serializer);
+        <Vec<crate::api::EventFieldConflict>>::sse_encode(self.conflicts, serializer);
+        <bool>::sse_encode(self.committed, serializer);
+    }
+}
+
+impl SseEncode for crate::api::EventsResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::EventDto>>::sse_encode(self.events, serializer);
+        <Vec<crate::api::EventError>>::sse_encode(self.unreadable, serializer);
+    }
+}
+
+impl SseEncode for crate::api::Frequency {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::Frequency::Daily => 0,
+                crate::api::Frequency::Weekly => 1,
+                crate::api::Frequency::Monthly => 2,
+                crate::api::Frequency::Yearly => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::GitCredentials {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::GitCredentials::UserPassword { username, password } => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(username, serializer);
+                <String>::sse_encode(password, serializer);
+            }
+            crate::api::GitCredentials::Token { username, token } => {
+                <i32>::sse_encode(1, serializer);
+                <Option<String>>::sse_encode(username, serializer);
+                <String>::sse_encode(token, serializer);
+            }
+            crate::api::GitCredentials::SshKeyFile {
+                username,
+                private_key_path,
+                public_key_path,
+                passphrase,
+            } => {
+                <i32>::sse_encode(2, serializer);
+                <Option<String>>::sse_encode(username, serializer);
+                <String>::sse_encode(private_key_path, serializer);
+                <Option<String>>::sse_encode(public_key_path, serializer);
+                <Option<String>>::sse_encode(passphrase, serializer);
+            }
+            crate::api::GitCredentials::SshKeyMemory {
+                username,
+                private_key,
+                public_key,
+                passphrase,
+            } => {
+                <i32>::sse_encode(3, serializer);
+                <Option<String>>::sse_encode(username, serializer);
+                <String>::sse_encode(private_key, serializer);
+                <Option<String>>::sse_encode(public_key, serializer);
+                <Option<String>>::sse_encode(passphrase, serializer);
+            }
+            crate::api::GitCredentials::SshAgent { username } => {
+                <i32>::sse_encode(4, serializer);
+                <Option<String>>::sse_encode(username, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::EventDto> {
+impl SseEncode for crate::api::GitError {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::EventDto>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::GitError::Io(field0) => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::GitError::Git(field0) => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::GitError::AuthenticationFailed(field0) => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::GitError::RepositoryNotFound(field0) => {
+                <i32>::sse_encode(3, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::GitError::NotFound(field0) => {
+                <i32>::sse_encode(4, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::GitError::NetworkUnreachable(field0) => {
+                <i32>::sse_encode(5, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::GitError::Locked(field0) => {
+                <i32>::sse_encode(6, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::GitError::DetachedHead(field0) => {
+                <i32>::sse_encode(7, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::GitError::MergeConflict(field0) => {
+                <i32>::sse_encode(8, serializer);
+                <Vec<String>>::sse_encode(field0, serializer);
+            }
+            crate::api::GitError::StashConflict(field0) => {
+                <i32>::sse_encode(9, serializer);
+                <Vec<String>>::sse_encode(field0, serializer);
+            }
+            crate::api::GitError::NonFastForward(field0) => {
+                <i32>::sse_encode(10, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::GitError::CertificateInvalid { host, reason } => {
+                <i32>::sse_encode(11, serializer);
+                <String>::sse_encode(host, serializer);
+                <crate::api::CertificateError>::sse_encode(reason, serializer);
+            }
+            crate::api::GitError::UnknownHostKey(field0) => {
+                <i32>::sse_encode(12, serializer);
+                <crate::api::HostKey>::sse_encode(field0, serializer);
+            }
+            crate::api::GitError::HostKeyMismatch(field0) => {
+                <i32>::sse_encode(13, serializer);
+                <crate::api::HostKey>::sse_encode(field0, serializer);
+            }
+            crate::api::GitError::Cancelled => {
+                <i32>::sse_encode(14, serializer);
+            }
+            crate::api::GitError::UnbornBranch(field0) => {
+                <i32>::sse_encode(15, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::GitError::Other(field0) => {
+                <i32>::sse_encode(16, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::GitIdentity {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.email, serializer);
+    }
+}
+
+impl SseEncode for crate::api::GitProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::GitProgress::Transfer {
+                received_objects,
+                total_objects,
+                indexed_objects,
+                received_bytes,
+                total_deltas,
+                indexed_deltas,
+            } => {
+                <i32>::sse_encode(0, serializer);
+                <usize>::sse_encode(received_objects, serializer);
+                <usize>::sse_encode(total_objects, serializer);
+                <usize>::sse_encode(indexed_objects, serializer);
+                <usize>::sse_encode(received_bytes, serializer);
+                <usize>::sse_encode(total_deltas, serializer);
+                <usize>::sse_encode(indexed_deltas, serializer);
+            }
+            crate::api::GitProgress::Checkout {
+                path,
+                completed,
+                total,
+            } => {
+                <i32>::sse_encode(1, serializer);
+                <Option<String>>::sse_encode(path, serializer);
+                <usize>::sse_encode(completed, serializer);
+                <usize>::sse_encode(total, serializer);
+            }
+            crate::api::GitProgress::PushTransfer {
+                current,
+                total,
+                bytes,
+            } => {
+                <i32>::sse_encode(2, serializer);
+                <usize>::sse_encode(current, serializer);
+                <usize>::sse_encode(total, serializer);
+                <usize>::sse_encode(bytes, serializer);
+            }
+            crate::api::GitProgress::Sideband(field0) => {
+                <i32>::sse_encode(3, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::HostKey {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.host, serializer);
+        <String>::sse_encode(self.key_type, serializer);
+        <String>::sse_encode(self.key, serializer);
+        <String>::sse_encode(self.fingerprint, serializer);
+    }
+}
+
+impl SseEncode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::IcsImportIssue {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.uid, serializer);
+        <String>::sse_encode(self.summary, serializer);
+        <String>::sse_encode(self.reason, serializer);
+    }
+}
+
+impl SseEncode for crate::api::IcsImportOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.replace_existing, serializer);
+    }
+}
+
+impl SseEncode for crate::api::IcsImportReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<String>>::sse_encode(self.imported, serializer);
+        <Vec<crate::api::IcsImportIssue>>::sse_encode(self.skipped, serializer);
+        <Vec<crate::api::IcsImportIssue>>::sse_encode(self.unsupported, serializer);
+    }
+}
+
+impl SseEncode for crate::api::InstancesResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::EventDto>>::sse_encode(self.instances, serializer);
+        <Vec<crate::api::EventDiagnostic>>::sse_encode(self.diagnostics, serializer);
+    }
+}
+
+impl SseEncode for isize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer
+            .cursor
+            .write_i64::<NativeEndian>(self as _)
+            .unwrap();
+    }
+}
+
+impl SseEncode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::EventDiagnostic> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::EventDiagnostic>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::EventDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::EventDto>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::EventError> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::EventError>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::EventFieldConflict> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::EventFieldConflict>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::HostKey> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::HostKey>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::IcsImportIssue> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::IcsImportIssue>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::OccurrenceOverride> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::OccurrenceOverride>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::PathResolution> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::PathResolution>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<i32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <i32>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u8>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseEncode for Vec<crate::api::StatusEntry> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::StatusEntry>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::StatusEntry> {
+impl SseEncode for Vec<crate::api::TrustedCertificate> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::StatusEntry>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::TrustedCertificate>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Option<String> {
+impl SseEncode for Vec<crate::api::WeekdayRule> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::WeekdayRule>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseDecode for crate::api::StatusEntry {
+impl SseEncode for crate::api::MergeSide {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_path = <String>::sse_decode(deserializer);
-        let mut var_status = <String>::sse_decode(deserializer);
-        return crate::api::StatusEntry {
-            path: var_path,
-            status: var_status,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::MergeSide::Local => 0,
+                crate::api::MergeSide::Remote => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseDecode for u8 {
+impl SseEncode for crate::api::OccurrenceOverride {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.occurrence_date, serializer);
+        <Option<String>>::sse_encode(self.title, serializer);
+        <Option<String>>::sse_encode(self.description, serializer);
+        <Option<String>>::sse_encode(self.start_date, serializer);
+        <Option<String>>::sse_encode(self.end_date, serializer);
+        <Option<String>>::sse_encode(self.start_time, serializer);
+        <Option<String>>::sse_encode(self.end_time, serializer);
+        <Option<bool>>::sse_encode(self.is_all_day, serializer);
     }
 }
 
-impl SseDecode for () {
+impl SseEncode for Option<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
-}
-
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire__crate__api__add_impl(port, ptr, rust_vec_len, data_len),
-        2 => wire__crate__api__create_event_impl(port, ptr, rust_vec_len, data_len),
-        3 => wire__crate__api__delete_event_impl(port, ptr, rust_vec_len, data_len),
-        4 => wire__crate__api__event_occurs_on_impl(port, ptr, rust_vec_len, data_len),
-        5 => wire__crate__api__generate_instances_impl(port, ptr, rust_vec_len, data_len),
-        6 => wire__crate__api__get_all_events_impl(port, ptr, rust_vec_len, data_len),
-        7 => wire__crate__api__get_events_in_range_impl(port, ptr, rust_vec_len, data_len),
-        8 => wire__crate__api__git_add_all_impl(port, ptr, rust_vec_len, data_len),
-        9 => wire__crate__api__git_add_remote_impl(port, ptr, rust_vec_len, data_len),
-        10 => wire__crate__api__git_checkout_impl(port, ptr, rust_vec_len, data_len),
-        11 => wire__crate__api__git_clone_impl(port, ptr, rust_vec_len, data_len),
-        12 => wire__crate__api__git_commit_impl(port, ptr, rust_vec_len, data_len),
-        13 => wire__crate__api__git_current_branch_impl(port, ptr, rust_vec_len, data_len),
-        14 => wire__crate__api__git_diff_impl(port, ptr, rust_vec_len, data_len),
-        15 => wire__crate__api__git_fetch_impl(port, ptr, rust_vec_len, data_len),
-        16 => wire__crate__api__git_has_local_changes_impl(port, ptr, rust_vec_len, data_len),
-        17 => wire__crate__api__git_init_impl(port, ptr, rust_vec_len, data_len),
-        18 => wire__crate__api__git_list_branches_impl(port, ptr, rust_vec_len, data_len),
-        19 => wire__crate__api__git_merge_abort_impl(port, ptr, rust_vec_len, data_len),
-        20 => wire__crate__api__git_merge_prefer_remote_impl(port, ptr, rust_vec_len, data_len),
-        21 => wire__crate__api__git_pull_impl(port, ptr, rust_vec_len, data_len),
-        22 => wire__crate__api__git_push_impl(port, ptr, rust_vec_len, data_len),
-        23 => wire__crate__api__git_remove_remote_impl(port, ptr, rust_vec_len, data_len),
-        24 => wire__crate__api__git_stash_impl(port, ptr, rust_vec_len, data_len),
-        25 => wire__crate__api__git_status_impl(port, ptr, rust_vec_len, data_len),
-        26 => wire__crate__api__init_app_impl(port, ptr, rust_vec_len, data_len),
-        27 => wire__crate__api__set_ssl_ca_certs_impl(port, ptr, rust_vec_len, data_len),
-        28 => wire__crate__api__update_event_impl(port, ptr, rust_vec_len, data_len),
-        29 => wire__crate__api__validate_event_impl(port, ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
+        }
     }
 }
 
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        _ => unreachable!(),
+impl SseEncode for Option<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <bool>::sse_encode(value, serializer);
+        }
     }
 }
 
-// Section: rust2dart
-
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::EventDto {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.title.into_into_dart().into_dart(),
-            self.description.into_into_dart().into_dart(),
-            self.start_date.into_into_dart().into_dart(),
-            self.end_date.into_into_dart().into_dart(),
-            self.start_time.into_into_dart().into_dart(),
-            self.end_time.into_into_dart().into_dart(),
-            self.is_all_day.into_into_dart().into_dart(),
-            self.recurrence.into_into_dart().into_dart(),
-            self.is_recurring_instance.into_into_dart().into_dart(),
-        ]
-        .into_dart()
-    }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::EventDto {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::EventDto> for crate::api::EventDto {
-    fn into_into_dart(self) -> crate::api::EventDto {
-        self
+impl SseEncode for Option<crate::api::GitCredentials> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::GitCredentials>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::GitError {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            crate::api::GitError::Io(field0) => {
-                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::GitError::Git(field0) => {
-                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::GitError::Auth(field0) => {
-                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::GitError::Other(field0) => {
-                [3.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            _ => {
-                unimplemented!("");
-            }
+
+impl SseEncode for Option<crate::api::GitIdentity> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::GitIdentity>::sse_encode(value, serializer);
         }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::GitError {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::GitError> for crate::api::GitError {
-    fn into_into_dart(self) -> crate::api::GitError {
-        self
+
+impl SseEncode for Option<i32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <i32>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::StatusEntry {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.path.into_into_dart().into_dart(),
-            self.status.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Option<crate::api::RecurrenceRule> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::RecurrenceRule>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::StatusEntry {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::StatusEntry> for crate::api::StatusEntry {
-    fn into_into_dart(self) -> crate::api::StatusEntry {
-        self
+
+impl SseEncode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u32>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for String {
+impl SseEncode for Option<u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u64>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for crate::api::PathResolution {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <String>::sse_encode(self.path, serializer);
+        <crate::api::MergeSide>::sse_encode(self.side, serializer);
     }
 }
 
-impl SseEncode for crate::api::EventDto {
+impl SseEncode for crate::api::RecurrenceRule {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.id, serializer);
-        <String>::sse_encode(self.title, serializer);
-        <String>::sse_encode(self.description, serializer);
-        <String>::sse_encode(self.start_date, serializer);
-        <Option<String>>::sse_encode(self.end_date, serializer);
-        <Option<String>>::sse_encode(self.start_time, serializer);
-        <Option<String>>::sse_encode(self.end_time, serializer);
-        <bool>::sse_encode(self.is_all_day, serializer);
-        <String>::sse_encode(self.recurrence, serializer);
-        <bool>::sse_encode(self.is_recurring_instance, serializer);
+        <crate::api::Frequency>::sse_encode(self.frequency, serializer);
+        <u32>::sse_encode(self.interval, serializer);
+        <Vec<crate::api::WeekdayRule>>::sse_encode(self.by_day, serializer);
+        <Vec<i32>>::sse_encode(self.by_month_day, serializer);
+        <Vec<i32>>::sse_encode(self.by_set_pos, serializer);
+        <Option<u32>>::sse_encode(self.count, serializer);
+        <Option<String>>::sse_encode(self.until, serializer);
     }
 }
 
-impl SseEncode for crate::api::GitError {
+impl SseEncode for crate::api::StatusEntry {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        match self {
-            crate::api::GitError::Io(field0) => {
-                <i32>::sse_encode(0, serializer);
-                <String>::sse_encode(field0, serializer);
-            }
-            crate::api::GitError::Git(field0) => {
-                <i32>::sse_encode(1, serializer);
-                <String>::sse_encode(field0, serializer);
-            }
-            crate::api::GitError::Auth(field0) => {
-                <i32>::sse_encode(2, serializer);
-                <String>::sse_encode(field0, serializer);
-            }
-            crate::api::GitError::Other(field0) => {
-                <i32>::sse_encode(3, serializer);
-                <String>::sse_encode(field0, serializer);
-            }
-            _ => {
-                unimplemented!("");
-            }
-        }
+        <String>::sse_encode(self.path, serializer);
+        <String>::sse_encode(self.status, serializer);
     }
 }
 
-impl SseEncode for i32 {
+impl SseEncode for crate::api::SyncOutcome {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        <i32>::sse_encode(
+            match self {
+                crate::api::SyncOutcome::UpToDate => 0,
+                crate::api::SyncOutcome::FastForward => 1,
+                crate::api::SyncOutcome::Merged => 2,
+                crate::api::SyncOutcome::Pushed => 3,
+                crate::api::SyncOutcome::Fetched => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for Vec<String> {
+impl SseEncode for crate::api::SyncReport {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <String>::sse_encode(item, serializer);
-        }
+        <Option<String>>::sse_encode(self.local_commit, serializer);
+        <crate::api::SyncResult>::sse_encode(self.pull, serializer);
+        <crate::api::SyncResult>::sse_encode(self.push, serializer);
+        <u32>::sse_encode(self.push_attempts, serializer);
     }
 }
 
-impl SseEncode for Vec<crate::api::EventDto> {
+impl SseEncode for crate::api::SyncResult {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::EventDto>::sse_encode(item, serializer);
-        }
+        <crate::api::SyncOutcome>::sse_encode(self.outcome, serializer);
+        <Option<String>>::sse_encode(self.old_commit, serializer);
+        <Option<String>>::sse_encode(self.new_commit, serializer);
+        <Vec<String>>::sse_encode(self.added_paths, serializer);
+        <Vec<String>>::sse_encode(self.modified_paths, serializer);
+        <Vec<String>>::sse_encode(self.deleted_paths, serializer);
+        <Vec<String>>::sse_encode(self.affected_event_ids, serializer);
     }
 }
 
-impl SseEncode for Vec<u8> {
+impl SseEncode for crate::api::TrustKind {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u8>::sse_encode(item, serializer);
-        }
+        <i32>::sse_encode(
+            match self {
+                crate::api::TrustKind::CertificateAuthority => 0,
+                crate::api::TrustKind::PinnedLeaf => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for Vec<crate::api::StatusEntry> {
+impl SseEncode for crate::api::TrustedCertificate {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::StatusEntry>::sse_encode(item, serializer);
-        }
+        <String>::sse_encode(self.host, serializer);
+        <crate::api::TrustKind>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.fingerprint, serializer);
+        <String>::sse_encode(self.subject, serializer);
+        <String>::sse_encode(self.not_after, serializer);
     }
 }
 
-impl SseEncode for Option<String> {
+impl SseEncode for u32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <String>::sse_encode(value, serializer);
-        }
+        serializer.cursor.write_u32::<NativeEndian>(self).unwrap();
     }
 }
 
-impl SseEncode for crate::api::StatusEntry {
+impl SseEncode for u64 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.path, serializer);
-        <String>::sse_encode(self.status, serializer);
+        serializer.cursor.write_u64::<NativeEndian>(self).unwrap();
     }
 }
 
@@ -1531,6 +5438,45 @@ impl SseEncode for () {
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
 }
 
+impl SseEncode for usize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer
+            .cursor
+            .write_u64::<NativeEndian>(self as _)
+            .unwrap();
+    }
+}
+
+impl SseEncode for crate::api::Weekday {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::Weekday::Monday => 0,
+                crate::api::Weekday::Tuesday => 1,
+                crate::api::Weekday::Wednesday => 2,
+                crate::api::Weekday::Thursday => 3,
+                crate::api::Weekday::Friday => 4,
+                crate::api::Weekday::Saturday => 5,
+                crate::api::Weekday::Sunday => 6,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::WeekdayRule {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::Weekday>::sse_encode(self.weekday, serializer);
+        <Option<i32>>::sse_encode(self.ordinal, serializer);
+    }
+}
+
 #[cfg(not(target_family = "wasm"))]
 mod io {
     // This file is automatically generated, so please do not edit it.
@@ -1,3 +1,5 @@
+import 'dart:async';
+
 import 'package:flutter_test/flutter_test.dart';
 import 'package:mcal/providers/event_provider.dart';
 import 'package:mcal/models/event.dart';
@@ -6,12 +8,27 @@ import 'package:mcal/frb_generated.dart';
 import 'package:mcal/api.dart';
 import 'test_helpers.dart';
 
+/// Result the mock returns for pull, push and fetch
+const _syncResult = SyncResult(
+  outcome: SyncOutcome.upToDate,
+  addedPaths: [],
+  modifiedPaths: [],
+  deletedPaths: [],
+  affectedEventIds: [],
+);
+
 /// Simple mock implementation of RustLibApi for testing
 class MockRustLibApi implements RustLibApi {
   @override
   Future<int> crateApiAdd({required int left, required int right}) async =>
       left + right;
 
+  @override
+  Future<bool> crateApiCancelOperation({required BigInt handle}) async => false;
+
+  @override
+  Future<void> crateApiClearCredentialCallback() async {}
+
   @override
   Future<String> crateApiGitAddAll({required String path}) async =>
       'Staged files';
@@ -38,10 +55,21 @@ class MockRustLibApi implements RustLibApi {
     String? sshKeyPath,
   }) async => 'Cloned repository';
 
+  @override
+  Stream<GitProgress> crateApiGitCloneWithProgress({
+    required String url,
+    required String path,
+    String? username,
+    String? password,
+    String? sshKeyPath,
+    BigInt? operation,
+  }) => const Stream.empty();
+
   @override
   Future<String> crateApiGitCommit({
     required String path,
     required String message,
+    GitIdentity? author,
   }) async => 'Committed changes';
 
   @override
@@ -52,13 +80,29 @@ class MockRustLibApi implements RustLibApi {
   Future<String> crateApiGitDiff({required String path}) async => '';
 
   @override
-  Future<String> crateApiGitFetch({
+  Future<SyncResult> crateApiGitFetch({
+    required String path,
+    required String remote,
+    String? username,
+    String? password,
+    String? sshKeyPath,
+    String? branch,
+  }) async => _syncResult;
+
+  @override
+  Stream<GitProgress> crateApiGitFetchWithProgress({
     required String path,
     required String remote,
     String? username,
     String? password,
     String? sshKeyPath,
-  }) async => 'Fetch completed';
+    String? branch,
+    BigInt? operation,
+  }) => const Stream.empty();
+
+  @override
+  Future<GitIdentity?> crateApiGitGetIdentity({required String path}) async =>
+      null;
 
   @override
   Future<bool> crateApiGitHasLocalChanges({required String path}) async =>
@@ -76,25 +120,67 @@ class MockRustLibApi implements RustLibApi {
   Future<String> crateApiGitMergeAbort({required String path}) async =>
       'Merge aborted';
 
+  @override
+  Future<String> crateApiGitMergePreferLocal({required String path}) async =>
+      'Merge prefer local';
+
   @override
   Future<String> crateApiGitMergePreferRemote({required String path}) async =>
       'Merge prefer remote';
 
   @override
-  Future<String> crateApiGitPull({
+  Future<EventMergeReport> crateApiGitMergeResolveEvents({
+    required String path,
+  }) async => const EventMergeReport(
+    mergedPaths: [],
+    unresolvedPaths: [],
+    conflicts: [],
+    committed: true,
+  );
+
+  @override
+  Future<List<String>> crateApiGitMergeResolvePaths({
+    required String path,
+    required List<PathResolution> resolutions,
+  }) async => [];
+
+  @override
+  Future<SyncResult> crateApiGitPull({
+    required String path,
+    String? username,
+    String? password,
+    String? sshKeyPath,
+    String? branch,
+  }) async => _syncResult;
+
+  @override
+  Stream<GitProgress> crateApiGitPullWithProgress({
     required String path,
     String? username,
     String? password,
     String? sshKeyPath,
-  }) async => 'Pulled 0 changes';
+    String? branch,
+    BigInt? operation,
+  }) => const Stream.empty();
 
   @override
-  Future<String> crateApiGitPush({
+  Future<SyncResult> crateApiGitPush({
     required String path,
     String? username,
     String? password,
     String? sshKeyPath,
-  }) async => 'Pushed 1 commit';
+    String? branch,
+  }) async => _syncResult;
+
+  @override
+  Stream<GitProgress> crateApiGitPushWithProgress({
+    required String path,
+    String? username,
+    String? password,
+    String? sshKeyPath,
+    String? branch,
+    BigInt? operation,
+  }) => const Stream.empty();
 
   @override
   Future<String> crateApiGitRemoveRemote({
@@ -102,6 +188,12 @@ class MockRustLibApi implements RustLibApi {
     required String name,
   }) async => 'Remote removed';
 
+  @override
+  Future<String> crateApiGitSetIdentity({
+    required String path,
+    required GitIdentity identity,
+  }) async => 'Identity set';
+
   @override
   Future<String> crateApiGitStash({required String path}) async =>
       'Stashed changes';
@@ -110,26 +202,96 @@ class MockRustLibApi implements RustLibApi {
   Future<List<StatusEntry>> crateApiGitStatus({required String path}) async =>
       [];
 
+  @override
+  Future<SyncReport> crateApiGitSync({
+    required String path,
+    String? username,
+    String? password,
+    String? sshKeyPath,
+    String? branch,
+  }) async => const SyncReport(
+    pull: _syncResult,
+    push: _syncResult,
+    pushAttempts: 1,
+  );
+
+  @override
+  Stream<GitProgress> crateApiGitSyncWithProgress({
+    required String path,
+    String? username,
+    String? password,
+    String? sshKeyPath,
+    String? branch,
+    BigInt? operation,
+  }) => const Stream.empty();
+
   @override
   Future<void> crateApiInitApp() async {}
 
+  @override
+  Future<List<HostKey>> crateApiListKnownHosts() async => [];
+
+  @override
+  Future<bool> crateApiRemoveHostKey({required String host}) async => false;
+
+  @override
+  Future<void> crateApiSetCredentialCallback({
+    required FutureOr<GitCredentials?> Function(CredentialRequest) callback,
+  }) async {}
+
+  @override
+  Future<void> crateApiSetKnownHostsPath({required String path}) async {}
+
   @override
   Future<void> crateApiSetSslCaCerts({required List<String> pemCerts}) async {}
 
+  @override
+  Future<void> crateApiSetTrustStorePath({required String path}) async {}
+
+  @override
+  Future<BigInt> crateApiStartOperation() async => BigInt.one;
+
+  @override
+  Future<List<TrustedCertificate>> crateApiTrustAddCa({
+    required String host,
+    required String pem,
+  }) async => [];
+
+  @override
+  Future<void> crateApiTrustHostKey({required HostKey key}) async {}
+
+  @override
+  Future<List<TrustedCertificate>> crateApiTrustList({String? host}) async =>
+      [];
+
+  @override
+  Future<List<TrustedCertificate>> crateApiTrustPinCertificate({
+    required String host,
+    required String pem,
+  }) async => [];
+
+  @override
+  Future<bool> crateApiTrustRemove({
+    required String host,
+    required String fingerprint,
+  }) async => false;
+
   // RCAL Event API Methods
 
+  @override
+  Future<void> crateApiCloseCalendar({required String calendarDir}) async {}
+
+  @override
+  Future<EventDto> crateApiConvertEventToZone({
+    required EventDto event,
+    required String displayZone,
+  }) async => event;
+
   @override
   Future<String> crateApiCreateEvent({
-    required String title,
-    required String description,
-    required String startDate,
-    String? endDate,
-    String? startTime,
-    String? endTime,
-    required bool isAllDay,
-    required String recurrence,
+    required EventInput input,
     required String calendarDir,
-  }) async => 'Event created: $title';
+  }) async => 'Event created: ${input.title}';
 
   @override
   Future<void> crateApiDeleteEvent({
@@ -138,30 +300,113 @@ class MockRustLibApi implements RustLibApi {
   }) async {}
 
   @override
-  Future<List<EventDto>> crateApiGetAllEvents({
+  Future<bool> crateApiEventOccursOn({
+    required EventDto event,
+    required String date,
+  }) async => false;
+
+  @override
+  Future<String> crateApiFormatRecurrenceRule({
+    required RecurrenceRule rule,
+  }) async => '';
+
+  @override
+  Future<InstancesResult> crateApiGenerateInstances({
+    required List<EventDto> events,
+    required String startDate,
+    required String endDate,
+  }) async => const InstancesResult(instances: [], diagnostics: []);
+
+  @override
+  Future<InstancesResult> crateApiGenerateInstancesInZone({
+    required List<EventDto> events,
+    required String startDate,
+    required String endDate,
+    required String displayZone,
+  }) async => const InstancesResult(instances: [], diagnostics: []);
+
+  @override
+  Future<EventsResult> crateApiGetAllEvents({
     required String calendarDir,
-  }) async => [];
+  }) async => const EventsResult(events: [], unreadable: []);
 
   @override
-  Future<List<EventDto>> crateApiGetEventsInRange({
+  Future<EventsResult> crateApiGetEventsInRange({
     required String startDate,
     required String endDate,
     required String calendarDir,
+  }) async => const EventsResult(events: [], unreadable: []);
+
+  @override
+  Future<IcsImportOptions> crateApiIcsImportOptionsDefault() async =>
+      const IcsImportOptions(replaceExisting: false);
+
+  @override
+  Future<IcsImportReport> crateApiImportIcs({
+    required String icsText,
+    required String calendarDir,
+    required IcsImportOptions options,
+  }) async => const IcsImportReport(imported: [], skipped: [], unsupported: []);
+
+  @override
+  Future<List<String>> crateApiListTimeZones() async => [];
+
+  @override
+  Future<List<String>> crateApiMigrateEventIds({
+    required String calendarDir,
   }) async => [];
 
+  @override
+  Future<void> crateApiOpenCalendar({required String calendarDir}) async {}
+
+  @override
+  Future<void> crateApiOverrideOccurrence({
+    required String id,
+    required OccurrenceOverride changes,
+    required String calendarDir,
+  }) async {}
+
+  @override
+  Future<RecurrenceRule> crateApiParseRecurrenceRule({
+    required String text,
+  }) async => throw UnimplementedError();
+
+  @override
+  Future<void> crateApiSkipOccurrence({
+    required String id,
+    required String date,
+    required String calendarDir,
+  }) async {}
+
+  @override
+  Future<String> crateApiSplitSeriesFrom({
+    required String id,
+    required String date,
+    required String calendarDir,
+  }) async => id;
+
+  @override
+  Future<void> crateApiUnwatchCalendar({required String calendarDir}) async {}
+
   @override
   Future<void> crateApiUpdateEvent({
     required String id,
+    required EventInput input,
+    required String calendarDir,
+  }) async {}
+
+  @override
+  Future<void> crateApiValidateEvent({
     required String title,
-    required String description,
     required String startDate,
     String? endDate,
     String? startTime,
     String? endTime,
-    required bool isAllDay,
-    required String recurrence,
-    required String calendarDir,
   }) async {}
+
+  @override
+  Stream<CalendarChange> crateApiWatchCalendar({required String calendarDir}) =>
+      const Stream.empty();
 }
 
 void main() {
@@ -32,19 +32,12 @@ void main() {
     var eventCounter = 0;
     when(
       mockApi.crateApiCreateEvent(
-        title: anyNamed('title'),
-        description: anyNamed('description'),
-        startDate: anyNamed('startDate'),
-        endDate: anyNamed('endDate'),
-        startTime: anyNamed('startTime'),
-        endTime: anyNamed('endTime'),
-        isAllDay: anyNamed('isAllDay'),
-        recurrence: anyNamed('recurrence'),
+        input: anyNamed('input'),
         calendarDir: anyNamed('calendarDir'),
       ),
     ).thenAnswer((invocation) async {
       // Return unique ID based on event title
-      final title = invocation.namedArguments[#title] as String?;
+      final title = (invocation.namedArguments[#input] as EventInput).title;
       return 'event_${eventCounter++}_$title'.replaceAll(' ', '_');
     });
 
@@ -59,7 +52,9 @@ void main() {
     var loadedEvents = <EventDto>[];
     when(
       mockApi.crateApiGetAllEvents(calendarDir: anyNamed('calendarDir')),
-    ).thenAnswer((_) async => loadedEvents);
+    ).thenAnswer(
+      (_) async => EventsResult(events: loadedEvents, unreadable: []),
+    );
 
     // Store reference to update loadedEvents
     // This is a workaround - in real implementation, events would be persisted
@@ -70,19 +65,12 @@ void main() {
         endDate: anyNamed('endDate'),
         calendarDir: anyNamed('calendarDir'),
       ),
-    ).thenAnswer((_) async => <EventDto>[]);
+    ).thenAnswer((_) async => const EventsResult(events: [], unreadable: []));
 
     when(
       mockApi.crateApiUpdateEvent(
         id: anyNamed('id'),
-        title: anyNamed('title'),
-        description: anyNamed('description'),
-        startDate: anyNamed('startDate'),
-        endDate: anyNamed('endDate'),
-        startTime: anyNamed('startTime'),
-        endTime: anyNamed('endTime'),
-        isAllDay: anyNamed('isAllDay'),
-        recurrence: anyNamed('recurrence'),
+        input: anyNamed('input'),
         calendarDir: anyNamed('calendarDir'),
       ),
     ).thenAnswer((_) async {});
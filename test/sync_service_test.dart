@@ -18,6 +18,15 @@ void main() {
 
   final Map<String, String?> mockStorage = {};
 
+  // Builds the SyncResult a stubbed pull, push or fetch returns
+  SyncResult syncResult(SyncOutcome outcome) => SyncResult(
+    outcome: outcome,
+    addedPaths: [],
+    modifiedPaths: [],
+    deletedPaths: [],
+    affectedEventIds: [],
+  );
+
   setUp(() async {
     mockStorage.clear();
     mockApi = MockRustLibApi();
@@ -69,7 +78,7 @@ void main() {
         password: anyNamed('password'),
         sshKeyPath: anyNamed('sshKeyPath'),
       ),
-    ).thenAnswer((_) async => 'Fetched');
+    ).thenAnswer((_) async => syncResult(SyncOutcome.fetched));
     when(
       mockApi.crateApiGitCheckout(
         path: anyNamed('path'),
@@ -109,7 +118,7 @@ void main() {
         password: anyNamed('password'),
         sshKeyPath: anyNamed('sshKeyPath'),
       ),
-    ).thenAnswer((_) async => 'Pulled');
+    ).thenAnswer((_) async => syncResult(SyncOutcome.fastForward));
     await expectLater(syncService.pullSync(), completes);
   });
 
@@ -144,7 +153,7 @@ void main() {
         password: anyNamed('password'),
         sshKeyPath: anyNamed('sshKeyPath'),
       ),
-    ).thenAnswer((_) async => 'Pushed');
+    ).thenAnswer((_) async => syncResult(SyncOutcome.pushed));
     await expectLater(syncService.pushSync(), completes);
   });
 
@@ -184,7 +193,7 @@ void main() {
         password: anyNamed('password'),
         sshKeyPath: anyNamed('sshKeyPath'),
       ),
-    ).thenAnswer((_) async => 'Pulled');
+    ).thenAnswer((_) async => syncResult(SyncOutcome.fastForward));
     await expectLater(syncService.pullSync(), completes);
   });
 
@@ -199,7 +208,7 @@ void main() {
         password: anyNamed('password'),
         sshKeyPath: anyNamed('sshKeyPath'),
       ),
-    ).thenAnswer((_) async => 'Pulled');
+    ).thenAnswer((_) async => syncResult(SyncOutcome.fastForward));
     await expectLater(syncService.pullSync(), completes);
     verify(
       mockApi.crateApiGitPull(
@@ -3,12 +3,12 @@
 // Do not manually edit this file.
 
 // ignore_for_file: no_leading_underscores_for_library_prefixes
-import 'dart:async' as _i3;
+import 'dart:async' as _i4;
 
-import 'package:mcal/api.dart' as _i5;
-import 'package:mcal/frb_generated.dart' as _i2;
+import 'package:mcal/api.dart' as _i2;
+import 'package:mcal/frb_generated.dart' as _i3;
 import 'package:mockito/mockito.dart' as _i1;
-import 'package:mockito/src/dummies.dart' as _i4;
+import 'package:mockito/src/dummies.dart' as _i5;
 
 // ignore_for_file: type=lint
 // ignore_for_file: avoid_redundant_argument_values
@@ -24,67 +24,146 @@ import 'package:mockito/src/dummies.dart' as _i4;
 // ignore_for_file: camel_case_types
 // ignore_for_file: subtype_of_sealed_class
 
+class _FakeEventDto_0 extends _i1.SmartFake implements _i2.EventDto {
+  _FakeEventDto_0(Object parent, Invocation parentInvocation)
+    : super(parent, parentInvocation);
+}
+
+class _FakeInstancesResult_1 extends _i1.SmartFake
+    implements _i2.InstancesResult {
+  _FakeInstancesResult_1(Object parent, Invocation parentInvocation)
+    : super(parent, parentInvocation);
+}
+
+class _FakeEventsResult_2 extends _i1.SmartFake implements _i2.EventsResult {
+  _FakeEventsResult_2(Object parent, Invocation parentInvocation)
+    : super(parent, parentInvocation);
+}
+
+class _FakeSyncResult_3 extends _i1.SmartFake implements _i2.SyncResult {
+  _FakeSyncResult_3(Object parent, Invocation parentInvocation)
+    : super(parent, parentInvocation);
+}
+
+class _FakeEventMergeReport_4 extends _i1.SmartFake
+    implements _i2.EventMergeReport {
+  _FakeEventMergeReport_4(Object parent, Invocation parentInvocation)
+    : super(parent, parentInvocation);
+}
+
+class _FakeSyncReport_5 extends _i1.SmartFake implements _i2.SyncReport {
+  _FakeSyncReport_5(Object parent, Invocation parentInvocation)
+    : super(parent, parentInvocation);
+}
+
+class _FakeIcsImportOptions_6 extends _i1.SmartFake
+    implements _i2.IcsImportOptions {
+  _FakeIcsImportOptions_6(Object parent, Invocation parentInvocation)
+    : super(parent, parentInvocation);
+}
+
+class _FakeIcsImportReport_7 extends _i1.SmartFake
+    implements _i2.IcsImportReport {
+  _FakeIcsImportReport_7(Object parent, Invocation parentInvocation)
+    : super(parent, parentInvocation);
+}
+
+class _FakeRecurrenceRule_8 extends _i1.SmartFake
+    implements _i2.RecurrenceRule {
+  _FakeRecurrenceRule_8(Object parent, Invocation parentInvocation)
+    : super(parent, parentInvocation);
+}
+
 /// A class which mocks [RustLibApi].
 ///
 /// See the documentation for Mockito's code generation for more information.
-class MockRustLibApi extends _i1.Mock implements _i2.RustLibApi {
+class MockRustLibApi extends _i1.Mock implements _i3.RustLibApi {
   MockRustLibApi() {
     _i1.throwOnMissingStub(this);
   }
 
   @override
-  _i3.Future<int> crateApiAdd({required int? left, required int? right}) =>
+  _i4.Future<int> crateApiAdd({required int? left, required int? right}) =>
       (super.noSuchMethod(
             Invocation.method(#crateApiAdd, [], {#left: left, #right: right}),
-            returnValue: _i3.Future<int>.value(0),
+            returnValue: _i4.Future<int>.value(0),
           )
-          as _i3.Future<int>);
+          as _i4.Future<int>);
 
   @override
-  _i3.Future<String> crateApiCreateEvent({
-    required String? title,
-    required String? description,
-    required String? startDate,
-    String? endDate,
-    String? startTime,
-    String? endTime,
-    required bool? isAllDay,
-    required String? recurrence,
+  _i4.Future<bool> crateApiCancelOperation({required BigInt? handle}) =>
+      (super.noSuchMethod(
+            Invocation.method(#crateApiCancelOperation, [], {#handle: handle}),
+            returnValue: _i4.Future<bool>.value(false),
+          )
+          as _i4.Future<bool>);
+
+  @override
+  _i4.Future<void> crateApiClearCredentialCallback() =>
+      (super.noSuchMethod(
+            Invocation.method(#crateApiClearCredentialCallback, []),
+            returnValue: _i4.Future<void>.value(),
+            returnValueForMissingStub: _i4.Future<void>.value(),
+          )
+          as _i4.Future<void>);
+
+  @override
+  _i4.Future<void> crateApiCloseCalendar({required String? calendarDir}) =>
+      (super.noSuchMethod(
+            Invocation.method(#crateApiCloseCalendar, [], {
+              #calendarDir: calendarDir,
+            }),
+            returnValue: _i4.Future<void>.value(),
+            returnValueForMissingStub: _i4.Future<void>.value(),
+          )
+          as _i4.Future<void>);
+
+  @override
+  _i4.Future<_i2.EventDto> crateApiConvertEventToZone({
+    required _i2.EventDto? event,
+    required String? displayZone,
+  }) =>
+      (super.noSuchMethod(
+            Invocation.method(#crateApiConvertEventToZone, [], {
+              #event: event,
+              #displayZone: displayZone,
+            }),
+            returnValue: _i4.Future<_i2.EventDto>.value(
+              _FakeEventDto_0(
+                this,
+                Invocation.method(#crateApiConvertEventToZone, [], {
+                  #event: event,
+                  #displayZone: displayZone,
+                }),
+              ),
+            ),
+          )
+          as _i4.Future<_i2.EventDto>);
+
+  @override
+  _i4.Future<String> crateApiCreateEvent({
+    required _i2.EventInput? input,
     required String? calendarDir,
   }) =>
       (super.noSuchMethod(
             Invocation.method(#crateApiCreateEvent, [], {
-              #title: title,
-              #description: description,
-              #startDate: startDate,
-              #endDate: endDate,
-              #startTime: startTime,
-              #endTime: endTime,
-              #isAllDay: isAllDay,
-              #recurrence: recurrence,
+              #input: input,
               #calendarDir: calendarDir,
             }),
-            returnValue: _i3.Future<String>.value(
-              _i4.dummyValue<String>(
+            returnValue: _i4.Future<String>.value(
+              _i5.dummyValue<String>(
                 this,
                 Invocation.method(#crateApiCreateEvent, [], {
-                  #title: title,
-                  #description: description,
-                  #startDate: startDate,
-                  #endDate: endDate,
-                  #startTime: startTime,
-                  #endTime: endTime,
-                  #isAllDay: isAllDay,
-                  #recurrence: recurrence,
+                  #input: input,
                   #calendarDir: calendarDir,
                 }),
               ),
             ),
           )
-          as _i3.Future<String>);
+          as _i4.Future<String>);
 
   @override
-  _i3.Future<void> crateApiDeleteEvent({
+  _i4.Future<void> crateApiDeleteEvent({
     required String? id,
     required String? calendarDir,
   }) =>
@@ -93,14 +172,14 @@ class MockRustLibApi extends _i1.Mock implements _i2.RustLibApi {
               #id: id,
               #calendarDir: calendarDir,
             }),
-            returnValue: _i3.Future<void>.value(),
-            returnValueForMissingStub: _i3.Future<void>.value(),
+            returnValue: _i4.Future<void>.value(),
+            returnValueForMissingStub: _i4.Future<void>.value(),
           )
-          as _i3.Future<void>);
+          as _i4.Future<void>);
 
   @override
-  _i3.Future<bool> crateApiEventOccursOn({
-    required _i5.EventDto? event,
+  _i4.Future<bool> crateApiEventOccursOn({
+    required _i2.EventDto? event,
     required String? date,
   }) =>
       (super.noSuchMethod(
@@ -108,13 +187,30 @@ class MockRustLibApi extends _i1.Mock implements _i2.RustLibApi {
               #event: event,
               #date: date,
             }),
-            returnValue: _i3.Future<bool>.value(false),
+            returnValue: _i4.Future<bool>.value(false),
+          )
+          as _i4.Future<bool>);
+
+  @override
+  _i4.Future<String> crateApiFormatRecurrenceRule({
+    required _i2.RecurrenceRule? rule,
+  }) =>
+      (super.noSuchMethod(
+            Invocation.method(#crateApiFormatRecurrenceRule, [], {#rule: rule}),
+            returnValue: _i4.Future<String>.value(
+              _i5.dummyValue<String>(
+                this,
+                Invocation.method(#crateApiFormatRecurrenceRule, [], {
+                  #rule: rule,
+                }),
+              ),
+            ),
           )
-          as _i3.Future<bool>);
+          as _i4.Future<String>);
 
   @override
-  _i3.Future<List<_i5.EventDto>> crateApiGenerateInstances({
-    required List<_i5.EventDto>? events,
+  _i4.Future<_i2.InstancesResult> crateApiGenerateInstances({
+    required List<_i2.EventDto>? events,
     required String? startDate,
     required String? endDate,
   }) =>
@@ -124,24 +220,68 @@ class MockRustLibApi extends _i1.Mock implements _i2.RustLibApi {
               #startDate: startDate,
               #endDate: endDate,
             }),
-            returnValue: _i3.Future<List<_i5.EventDto>>.value(<_i5.EventDto>[]),
+            returnValue: _i4.Future<_i2.InstancesResult>.value(
+              _FakeInstancesResult_1(
+                this,
+                Invocation.method(#crateApiGenerateInstances, [], {
+                  #events: events,
+                  #startDate: startDate,
+                  #endDate: endDate,
+                }),
+              ),
+            ),
           )
-          as _i3.Future<List<_i5.EventDto>>);
+          as _i4.Future<_i2.InstancesResult>);
 
   @override
-  _i3.Future<List<_i5.EventDto>> crateApiGetAllEvents({
+  _i4.Future<_i2.InstancesResult> crateApiGenerateInstancesInZone({
+    required List<_i2.EventDto>? events,
+    required String? startDate,
+    required String? endDate,
+    required String? displayZone,
+  }) =>
+      (super.noSuchMethod(
+            Invocation.method(#crateApiGenerateInstancesInZone, [], {
+              #events: events,
+              #startDate: startDate,
+              #endDate: endDate,
+              #displayZone: displayZone,
+            }),
+            returnValue: _i4.Future<_i2.InstancesResult>.value(
+              _FakeInstancesResult_1(
+                this,
+                Invocation.method(#crateApiGenerateInstancesInZone, [], {
+                  #events: events,
+                  #startDate: startDate,
+                  #endDate: endDate,
+                  #displayZone: displayZone,
+                }),
+              ),
+            ),
+          )
+          as _i4.Future<_i2.InstancesResult>);
+
+  @override
+  _i4.Future<_i2.EventsResult> crateApiGetAllEvents({
     required String? calendarDir,
   }) =>
       (super.noSuchMethod(
             Invocation.method(#crateApiGetAllEvents, [], {
               #calendarDir: calendarDir,
             }),
-            returnValue: _i3.Future<List<_i5.EventDto>>.value(<_i5.EventDto>[]),
+            returnValue: _i4.Future<_i2.EventsResult>.value(
+              _FakeEventsResult_2(
+                this,
+                Invocation.method(#crateApiGetAllEvents, [], {
+                  #calendarDir: calendarDir,
+                }),
+              ),
+            ),
           )
-          as _i3.Future<List<_i5.EventDto>>);
+          as _i4.Future<_i2.EventsResult>);
 
   @override
-  _i3.Future<List<_i5.EventDto>> crateApiGetEventsInRange({
+  _i4.Future<_i2.EventsResult> crateApiGetEventsInRange({
     required String? startDate,
     required String? endDate,
     required String? calendarDir,
@@ -152,25 +292,34 @@ class MockRustLibApi extends _i1.Mock implements _i2.RustLibApi {
               #endDate: endDate,
               #calendarDir: calendarDir,
             }),
-            returnValue: _i3.Future<List<_i5.EventDto>>.value(<_i5.EventDto>[]),
+            returnValue: _i4.Future<_i2.EventsResult>.value(
+              _FakeEventsResult_2(
+                this,
+                Invocation.method(#crateApiGetEventsInRange, [], {
+                  #startDate: startDate,
+                  #endDate: endDate,
+                  #calendarDir: calendarDir,
+                }),
+              ),
+            ),
           )
-          as _i3.Future<List<_i5.EventDto>>);
+          as _i4.Future<_i2.EventsResult>);
 
   @override
-  _i3.Future<String> crateApiGitAddAll({required String? path}) =>
+  _i4.Future<String> crateApiGitAddAll({required String? path}) =>
       (super.noSuchMethod(
             Invocation.method(#crateApiGitAddAll, [], {#path: path}),
-            returnValue: _i3.Future<String>.value(
-              _i4.dummyValue<String>(
+            returnValue: _i4.Future<String>.value(
+              _i5.dummyValue<String>(
                 this,
                 Invocation.method(#crateApiGitAddAll, [], {#path: path}),
               ),
             ),
           )
-          as _i3.Future<String>);
+          as _i4.Future<String>);
 
   @override
-  _i3.Future<String> crateApiGitAddRemote({
+  _i4.Future<String> crateApiGitAddRemote({
     required String? path,
     required String? name,
     required String? url,
@@ -181,8 +330,8 @@ class MockRustLibApi extends _i1.Mock implements _i2.RustLibApi {
               #name: name,
               #url: url,
             }),
-            returnValue: _i3.Future<String>.value(
-              _i4.dummyValue<String>(
+            returnValue: _i4.Future<String>.value(
+              _i5.dummyValue<String>(
                 this,
                 Invocation.method(#crateApiGitAddRemote, [], {
                   #path: path,
@@ -192,10 +341,10 @@ class MockRustLibApi extends _i1.Mock implements _i2.RustLibApi {
               ),
             ),
           )
-          as _i3.Future<String>);
+          as _i4.Future<String>);
 
   @override
-  _i3.Future<String> crateApiGitCheckout({
+  _i4.Future<String> crateApiGitCheckout({
     required String? path,
     required String? branch,
   }) =>
@@ -204,8 +353,8 @@ class MockRustLibApi extends _i1.Mock implements _i2.RustLibApi {
               #path: path,
               #branch: branch,
             }),
-            returnValue: _i3.Future<String>.value(
-              _i4.dummyValue<String>(
+            returnValue: _i4.Future<String>.value(
+              _i5.dummyValue<String>(
                 this,
                 Invocation.method(#crateApiGitCheckout, [], {
                   #path: path,
@@ -214,10 +363,10 @@ class MockRustLibApi extends _i1.Mock implements _i2.RustLibApi {
               ),
             ),
           )
-          as _i3.Future<String>);
+          as _i4.Future<String>);
 
   @override
-  _i3.Future<String> crateApiGitClone({
+  _i4.Future<String> crateApiGitClone({
     required String? url,
     required String? path,
     String? username,
@@ -232,8 +381,8 @@ class MockRustLibApi extends _i1.Mock implements _i2.RustLibApi {
               #password: password,
               #sshKeyPath: sshKeyPath,
             }),
-            returnValue: _i3.Future<String>.value(
-              _i4.dummyValue<String>(
+            returnValue: _i4.Future<String>.value(
+              _i5.dummyValue<String>(
                 this,
                 Invocation.method(#crateApiGitClone, [], {
                   #url: url,
@@ -245,63 +394,89 @@ class MockRustLibApi extends _i1.Mock implements _i2.RustLibApi {
               ),
             ),
           )
-          as _i3.Future<String>);
+          as _i4.Future<String>);
+
+  @override
+  _i4.Stream<_i2.GitProgress> crateApiGitCloneWithProgress({
+    required String? url,
+    required String? path,
+    String? username,
+    String? password,
+    String? sshKeyPath,
+    BigInt? operation,
+  }) =>
+      (super.noSuchMethod(
+            Invocation.method(#crateApiGitCloneWithProgress, [], {
+              #url: url,
+              #path: path,
+              #username: username,
+              #password: password,
+              #sshKeyPath: sshKeyPath,
+              #operation: operation,
+            }),
+            returnValue: _i4.Stream<_i2.GitProgress>.empty(),
+          )
+          as _i4.Stream<_i2.GitProgress>);
 
   @override
-  _i3.Future<String> crateApiGitCommit({
+  _i4.Future<String> crateApiGitCommit({
     required String? path,
     required String? message,
+    _i2.GitIdentity? author,
   }) =>
       (super.noSuchMethod(
             Invocation.method(#crateApiGitCommit, [], {
               #path: path,
               #message: message,
+              #author: author,
             }),
-            returnValue: _i3.Future<String>.value(
-              _i4.dummyValue<String>(
+            returnValue: _i4.Future<String>.value(
+              _i5.dummyValue<String>(
                 this,
                 Invocation.method(#crateApiGitCommit, [], {
                   #path: path,
                   #message: message,
+                  #author: author,
                 }),
               ),
             ),
           )
-          as _i3.Future<String>);
+          as _i4.Future<String>);
 
   @override
-  _i3.Future<String> crateApiGitCurrentBranch({required String? path}) =>
+  _i4.Future<String> crateApiGitCurrentBranch({required String? path}) =>
       (super.noSuchMethod(
             Invocation.method(#crateApiGitCurrentBranch, [], {#path: path}),
-            returnValue: _i3.Future<String>.value(
-              _i4.dummyValue<String>(
+            returnValue: _i4.Future<String>.value(
+              _i5.dummyValue<String>(
                 this,
                 Invocation.method(#crateApiGitCurrentBranch, [], {#path: path}),
               ),
             ),
           )
-          as _i3.Future<String>);
+          as _i4.Future<String>);
 
   @override
-  _i3.Future<String> crateApiGitDiff({required String? path}) =>
+  _i4.Future<String> crateApiGitDiff({required String? path}) =>
       (super.noSuchMethod(
             Invocation.method(#crateApiGitDiff, [], {#path: path}),
-            returnValue: _i3.Future<String>.value(
-              _i4.dummyValue<String>(
+            returnValue: _i4.Future<String>.value(
+              _i5.dummyValue<String>(
                 this,
                 Invocation.method(#crateApiGitDiff, [], {#path: path}),
               ),
             ),
           )
-          as _i3.Future<String>);
+          as _i4.Future<String>);
 
   @override
-  _i3.Future<String> crateApiGitFetch({
+  _i4.Future<_i2.SyncResult> crateApiGitFetch({
     required String? path,
     required String? remote,
     String? username,
     String? password,
     String? sshKeyPath,
+    String? branch,
   }) =>
       (super.noSuchMethod(
             Invocation.method(#crateApiGitFetch, [], {
@@ -310,9 +485,10 @@ class MockRustLibApi extends _i1.Mock implements _i2.RustLibApi {
               #username: username,
               #password: password,
               #sshKeyPath: sshKeyPath,
+              #branch: branch,
             }),
-            returnValue: _i3.Future<String>.value(
-              _i4.dummyValue<String>(
+            returnValue: _i4.Future<_i2.SyncResult>.value(
+              _FakeSyncResult_3(
                 this,
                 Invocation.method(#crateApiGitFetch, [], {
                   #path: path,
@@ -320,60 +496,110 @@ class MockRustLibApi extends _i1.Mock implements _i2.RustLibApi {
                   #username: username,
                   #password: password,
                   #sshKeyPath: sshKeyPath,
+                  #branch: branch,
                 }),
               ),
             ),
           )
-          as _i3.Future<String>);
+          as _i4.Future<_i2.SyncResult>);
 
   @override
-  _i3.Future<bool> crateApiGitHasLocalChanges({required String? path}) =>
+  _i4.Stream<_i2.GitProgress> crateApiGitFetchWithProgress({
+    required String? path,
+    required String? remote,
+    String? username,
+    String? password,
+    String? sshKeyPath,
+    String? branch,
+    BigInt? operation,
+  }) =>
+      (super.noSuchMethod(
+            Invocation.method(#crateApiGitFetchWithProgress, [], {
+              #path: path,
+              #remote: remote,
+              #username: username,
+              #password: password,
+              #sshKeyPath: sshKeyPath,
+              #branch: branch,
+              #operation: operation,
+            }),
+            returnValue: _i4.Stream<_i2.GitProgress>.empty(),
+          )
+          as _i4.Stream<_i2.GitProgress>);
+
+  @override
+  _i4.Future<_i2.GitIdentity?> crateApiGitGetIdentity({
+    required String? path,
+  }) =>
+      (super.noSuchMethod(
+            Invocation.method(#crateApiGitGetIdentity, [], {#path: path}),
+            returnValue: _i4.Future<_i2.GitIdentity?>.value(),
+          )
+          as _i4.Future<_i2.GitIdentity?>);
+
+  @override
+  _i4.Future<bool> crateApiGitHasLocalChanges({required String? path}) =>
       (super.noSuchMethod(
             Invocation.method(#crateApiGitHasLocalChanges, [], {#path: path}),
-            returnValue: _i3.Future<bool>.value(false),
+            returnValue: _i4.Future<bool>.value(false),
           )
-          as _i3.Future<bool>);
+          as _i4.Future<bool>);
 
   @override
-  _i3.Future<String> crateApiGitInit({required String? path}) =>
+  _i4.Future<String> crateApiGitInit({required String? path}) =>
       (super.noSuchMethod(
             Invocation.method(#crateApiGitInit, [], {#path: path}),
-            returnValue: _i3.Future<String>.value(
-              _i4.dummyValue<String>(
+            returnValue: _i4.Future<String>.value(
+              _i5.dummyValue<String>(
                 this,
                 Invocation.method(#crateApiGitInit, [], {#path: path}),
               ),
             ),
           )
-          as _i3.Future<String>);
+          as _i4.Future<String>);
 
   @override
-  _i3.Future<List<String>> crateApiGitListBranches({required String? path}) =>
+  _i4.Future<List<String>> crateApiGitListBranches({required String? path}) =>
       (super.noSuchMethod(
             Invocation.method(#crateApiGitListBranches, [], {#path: path}),
-            returnValue: _i3.Future<List<String>>.value(<String>[]),
+            returnValue: _i4.Future<List<String>>.value(<String>[]),
           )
-          as _i3.Future<List<String>>);
+          as _i4.Future<List<String>>);
 
   @override
-  _i3.Future<String> crateApiGitMergeAbort({required String? path}) =>
+  _i4.Future<String> crateApiGitMergeAbort({required String? path}) =>
       (super.noSuchMethod(
             Invocation.method(#crateApiGitMergeAbort, [], {#path: path}),
-            returnValue: _i3.Future<String>.value(
-              _i4.dummyValue<String>(
+            returnValue: _i4.Future<String>.value(
+              _i5.dummyValue<String>(
                 this,
                 Invocation.method(#crateApiGitMergeAbort, [], {#path: path}),
               ),
             ),
           )
-          as _i3.Future<String>);
+          as _i4.Future<String>);
+
+  @override
+  _i4.Future<String> crateApiGitMergePreferLocal({required String? path}) =>
+      (super.noSuchMethod(
+            Invocation.method(#crateApiGitMergePreferLocal, [], {#path: path}),
+            returnValue: _i4.Future<String>.value(
+              _i5.dummyValue<String>(
+                this,
+                Invocation.method(#crateApiGitMergePreferLocal, [], {
+                  #path: path,
+                }),
+              ),
+            ),
+          )
+          as _i4.Future<String>);
 
   @override
-  _i3.Future<String> crateApiGitMergePreferRemote({required String? path}) =>
+  _i4.Future<String> crateApiGitMergePreferRemote({required String? path}) =>
       (super.noSuchMethod(
             Invocation.method(#crateApiGitMergePreferRemote, [], {#path: path}),
-            returnValue: _i3.Future<String>.value(
-              _i4.dummyValue<String>(
+            returnValue: _i4.Future<String>.value(
+              _i5.dummyValue<String>(
                 this,
                 Invocation.method(#crateApiGitMergePreferRemote, [], {
                   #path: path,
@@ -381,14 +607,48 @@ class MockRustLibApi extends _i1.Mock implements _i2.RustLibApi {
               ),
             ),
           )
-          as _i3.Future<String>);
+          as _i4.Future<String>);
+
+  @override
+  _i4.Future<_i2.EventMergeReport> crateApiGitMergeResolveEvents({
+    required String? path,
+  }) =>
+      (super.noSuchMethod(
+            Invocation.method(#crateApiGitMergeResolveEvents, [], {
+              #path: path,
+            }),
+            returnValue: _i4.Future<_i2.EventMergeReport>.value(
+              _FakeEventMergeReport_4(
+                this,
+                Invocation.method(#crateApiGitMergeResolveEvents, [], {
+                  #path: path,
+                }),
+              ),
+            ),
+          )
+          as _i4.Future<_i2.EventMergeReport>);
+
+  @override
+  _i4.Future<List<String>> crateApiGitMergeResolvePaths({
+    required String? path,
+    required List<_i2.PathResolution>? resolutions,
+  }) =>
+      (super.noSuchMethod(
+            Invocation.method(#crateApiGitMergeResolvePaths, [], {
+              #path: path,
+              #resolutions: resolutions,
+            }),
+            returnValue: _i4.Future<List<String>>.value(<String>[]),
+          )
+          as _i4.Future<List<String>>);
 
   @override
-  _i3.Future<String> crateApiGitPull({
+  _i4.Future<_i2.SyncResult> crateApiGitPull({
     required String? path,
     String? username,
     String? password,
     String? sshKeyPath,
+    String? branch,
   }) =>
       (super.noSuchMethod(
             Invocation.method(#crateApiGitPull, [], {
@@ -396,27 +656,52 @@ class MockRustLibApi extends _i1.Mock implements _i2.RustLibApi {
               #username: username,
               #password: password,
               #sshKeyPath: sshKeyPath,
+              #branch: branch,
             }),
-            returnValue: _i3.Future<String>.value(
-              _i4.dummyValue<String>(
+            returnValue: _i4.Future<_i2.SyncResult>.value(
+              _FakeSyncResult_3(
                 this,
                 Invocation.method(#crateApiGitPull, [], {
                   #path: path,
                   #username: username,
                   #password: password,
                   #sshKeyPath: sshKeyPath,
+                  #branch: branch,
                 }),
               ),
             ),
           )
-          as _i3.Future<String>);
+          as _i4.Future<_i2.SyncResult>);
 
   @override
-  _i3.Future<String> crateApiGitPush({
+  _i4.Stream<_i2.GitProgress> crateApiGitPullWithProgress({
     required String? path,
     String? username,
     String? password,
     String? sshKeyPath,
+    String? branch,
+    BigInt? operation,
+  }) =>
+      (super.noSuchMethod(
+            Invocation.method(#crateApiGitPullWithProgress, [], {
+              #path: path,
+              #username: username,
+              #password: password,
+              #sshKeyPath: sshKeyPath,
+              #branch: branch,
+              #operation: operation,
+            }),
+            returnValue: _i4.Stream<_i2.GitProgress>.empty(),
+          )
+          as _i4.Stream<_i2.GitProgress>);
+
+  @override
+  _i4.Future<_i2.SyncResult> crateApiGitPush({
+    required String? path,
+    String? username,
+    String? password,
+    String? sshKeyPath,
+    String? branch,
   }) =>
       (super.noSuchMethod(
             Invocation.method(#crateApiGitPush, [], {
@@ -424,23 +709,47 @@ class MockRustLibApi extends _i1.Mock implements _i2.RustLibApi {
               #username: username,
               #password: password,
               #sshKeyPath: sshKeyPath,
+              #branch: branch,
             }),
-            returnValue: _i3.Future<String>.value(
-              _i4.dummyValue<String>(
+            returnValue: _i4.Future<_i2.SyncResult>.value(
+              _FakeSyncResult_3(
                 this,
                 Invocation.method(#crateApiGitPush, [], {
                   #path: path,
                   #username: username,
                   #password: password,
                   #sshKeyPath: sshKeyPath,
+                  #branch: branch,
                 }),
               ),
             ),
           )
-          as _i3.Future<String>);
+          as _i4.Future<_i2.SyncResult>);
+
+  @override
+  _i4.Stream<_i2.GitProgress> crateApiGitPushWithProgress({
+    required String? path,
+    String? username,
+    String? password,
+    String? sshKeyPath,
+    String? branch,
+    BigInt? operation,
+  }) =>
+      (super.noSuchMethod(
+            Invocation.method(#crateApiGitPushWithProgress, [], {
+              #path: path,
+              #username: username,
+              #password: password,
+              #sshKeyPath: sshKeyPath,
+              #branch: branch,
+              #operation: operation,
+            }),
+            returnValue: _i4.Stream<_i2.GitProgress>.empty(),
+          )
+          as _i4.Stream<_i2.GitProgress>);
 
   @override
-  _i3.Future<String> crateApiGitRemoveRemote({
+  _i4.Future<String> crateApiGitRemoveRemote({
     required String? path,
     required String? name,
   }) =>
@@ -449,8 +758,8 @@ class MockRustLibApi extends _i1.Mock implements _i2.RustLibApi {
               #path: path,
               #name: name,
             }),
-            returnValue: _i3.Future<String>.value(
-              _i4.dummyValue<String>(
+            returnValue: _i4.Future<String>.value(
+              _i5.dummyValue<String>(
                 this,
                 Invocation.method(#crateApiGitRemoveRemote, [], {
                   #path: path,
@@ -459,86 +768,431 @@ class MockRustLibApi extends _i1.Mock implements _i2.RustLibApi {
               ),
             ),
           )
-          as _i3.Future<String>);
+          as _i4.Future<String>);
+
+  @override
+  _i4.Future<String> crateApiGitSetIdentity({
+    required String? path,
+    required _i2.GitIdentity? identity,
+  }) =>
+      (super.noSuchMethod(
+            Invocation.method(#crateApiGitSetIdentity, [], {
+              #path: path,
+              #identity: identity,
+            }),
+            returnValue: _i4.Future<String>.value(
+              _i5.dummyValue<String>(
+                this,
+                Invocation.method(#crateApiGitSetIdentity, [], {
+                  #path: path,
+                  #identity: identity,
+                }),
+              ),
+            ),
+          )
+          as _i4.Future<String>);
 
   @override
-  _i3.Future<String> crateApiGitStash({required String? path}) =>
+  _i4.Future<String> crateApiGitStash({required String? path}) =>
       (super.noSuchMethod(
             Invocation.method(#crateApiGitStash, [], {#path: path}),
-            returnValue: _i3.Future<String>.value(
-              _i4.dummyValue<String>(
+            returnValue: _i4.Future<String>.value(
+              _i5.dummyValue<String>(
                 this,
                 Invocation.method(#crateApiGitStash, [], {#path: path}),
               ),
             ),
           )
-          as _i3.Future<String>);
+          as _i4.Future<String>);
 
   @override
-  _i3.Future<List<_i5.StatusEntry>> crateApiGitStatus({
+  _i4.Future<List<_i2.StatusEntry>> crateApiGitStatus({
     required String? path,
   }) =>
       (super.noSuchMethod(
             Invocation.method(#crateApiGitStatus, [], {#path: path}),
-            returnValue: _i3.Future<List<_i5.StatusEntry>>.value(
-              <_i5.StatusEntry>[],
+            returnValue: _i4.Future<List<_i2.StatusEntry>>.value(
+              <_i2.StatusEntry>[],
+            ),
+          )
+          as _i4.Future<List<_i2.StatusEntry>>);
+
+  @override
+  _i4.Future<_i2.SyncReport> crateApiGitSync({
+    required String? path,
+    String? username,
+    String? password,
+    String? sshKeyPath,
+    String? branch,
+  }) =>
+      (super.noSuchMethod(
+            Invocation.method(#crateApiGitSync, [], {
+              #path: path,
+              #username: username,
+              #password: password,
+              #sshKeyPath: sshKeyPath,
+              #branch: branch,
+            }),
+            returnValue: _i4.Future<_i2.SyncReport>.value(
+              _FakeSyncReport_5(
+                this,
+                Invocation.method(#crateApiGitSync, [], {
+                  #path: path,
+                  #username: username,
+                  #password: password,
+                  #sshKeyPath: sshKeyPath,
+                  #branch: branch,
+                }),
+              ),
+            ),
+          )
+          as _i4.Future<_i2.SyncReport>);
+
+  @override
+  _i4.Stream<_i2.GitProgress> crateApiGitSyncWithProgress({
+    required String? path,
+    String? username,
+    String? password,
+    String? sshKeyPath,
+    String? branch,
+    BigInt? operation,
+  }) =>
+      (super.noSuchMethod(
+            Invocation.method(#crateApiGitSyncWithProgress, [], {
+              #path: path,
+              #username: username,
+              #password: password,
+              #sshKeyPath: sshKeyPath,
+              #branch: branch,
+              #operation: operation,
+            }),
+            returnValue: _i4.Stream<_i2.GitProgress>.empty(),
+          )
+          as _i4.Stream<_i2.GitProgress>);
+
+  @override
+  _i4.Future<_i2.IcsImportOptions> crateApiIcsImportOptionsDefault() =>
+      (super.noSuchMethod(
+            Invocation.method(#crateApiIcsImportOptionsDefault, []),
+            returnValue: _i4.Future<_i2.IcsImportOptions>.value(
+              _FakeIcsImportOptions_6(
+                this,
+                Invocation.method(#crateApiIcsImportOptionsDefault, []),
+              ),
+            ),
+          )
+          as _i4.Future<_i2.IcsImportOptions>);
+
+  @override
+  _i4.Future<_i2.IcsImportReport> crateApiImportIcs({
+    required String? icsText,
+    required String? calendarDir,
+    required _i2.IcsImportOptions? options,
+  }) =>
+      (super.noSuchMethod(
+            Invocation.method(#crateApiImportIcs, [], {
+              #icsText: icsText,
+              #calendarDir: calendarDir,
+              #options: options,
+            }),
+            returnValue: _i4.Future<_i2.IcsImportReport>.value(
+              _FakeIcsImportReport_7(
+                this,
+                Invocation.method(#crateApiImportIcs, [], {
+                  #icsText: icsText,
+                  #calendarDir: calendarDir,
+                  #options: options,
+                }),
+              ),
             ),
           )
-          as _i3.Future<List<_i5.StatusEntry>>);
+          as _i4.Future<_i2.IcsImportReport>);
 
   @override
-  _i3.Future<void> crateApiInitApp() =>
+  _i4.Future<void> crateApiInitApp() =>
       (super.noSuchMethod(
             Invocation.method(#crateApiInitApp, []),
-            returnValue: _i3.Future<void>.value(),
-            returnValueForMissingStub: _i3.Future<void>.value(),
+            returnValue: _i4.Future<void>.value(),
+            returnValueForMissingStub: _i4.Future<void>.value(),
+          )
+          as _i4.Future<void>);
+
+  @override
+  _i4.Future<List<_i2.HostKey>> crateApiListKnownHosts() =>
+      (super.noSuchMethod(
+            Invocation.method(#crateApiListKnownHosts, []),
+            returnValue: _i4.Future<List<_i2.HostKey>>.value(<_i2.HostKey>[]),
+          )
+          as _i4.Future<List<_i2.HostKey>>);
+
+  @override
+  _i4.Future<List<String>> crateApiListTimeZones() =>
+      (super.noSuchMethod(
+            Invocation.method(#crateApiListTimeZones, []),
+            returnValue: _i4.Future<List<String>>.value(<String>[]),
+          )
+          as _i4.Future<List<String>>);
+
+  @override
+  _i4.Future<List<String>> crateApiMigrateEventIds({
+    required String? calendarDir,
+  }) =>
+      (super.noSuchMethod(
+            Invocation.method(#crateApiMigrateEventIds, [], {
+              #calendarDir: calendarDir,
+            }),
+            returnValue: _i4.Future<List<String>>.value(<String>[]),
+          )
+          as _i4.Future<List<String>>);
+
+  @override
+  _i4.Future<void> crateApiOpenCalendar({required String? calendarDir}) =>
+      (super.noSuchMethod(
+            Invocation.method(#crateApiOpenCalendar, [], {
+              #calendarDir: calendarDir,
+            }),
+            returnValue: _i4.Future<void>.value(),
+            returnValueForMissingStub: _i4.Future<void>.value(),
+          )
+          as _i4.Future<void>);
+
+  @override
+  _i4.Future<void> crateApiOverrideOccurrence({
+    required String? id,
+    required _i2.OccurrenceOverride? changes,
+    required String? calendarDir,
+  }) =>
+      (super.noSuchMethod(
+            Invocation.method(#crateApiOverrideOccurrence, [], {
+              #id: id,
+              #changes: changes,
+              #calendarDir: calendarDir,
+            }),
+            returnValue: _i4.Future<void>.value(),
+            returnValueForMissingStub: _i4.Future<void>.value(),
+          )
+          as _i4.Future<void>);
+
+  @override
+  _i4.Future<_i2.RecurrenceRule> crateApiParseRecurrenceRule({
+    required String? text,
+  }) =>
+      (super.noSuchMethod(
+            Invocation.method(#crateApiParseRecurrenceRule, [], {#text: text}),
+            returnValue: _i4.Future<_i2.RecurrenceRule>.value(
+              _FakeRecurrenceRule_8(
+                this,
+                Invocation.method(#crateApiParseRecurrenceRule, [], {
+                  #text: text,
+                }),
+              ),
+            ),
+          )
+          as _i4.Future<_i2.RecurrenceRule>);
+
+  @override
+  _i4.Future<bool> crateApiRemoveHostKey({required String? host}) =>
+      (super.noSuchMethod(
+            Invocation.method(#crateApiRemoveHostKey, [], {#host: host}),
+            returnValue: _i4.Future<bool>.value(false),
+          )
+          as _i4.Future<bool>);
+
+  @override
+  _i4.Future<void> crateApiSetCredentialCallback({
+    required _i4.FutureOr<_i2.GitCredentials?> Function(
+      _i2.CredentialRequest,
+    )?
+    callback,
+  }) =>
+      (super.noSuchMethod(
+            Invocation.method(#crateApiSetCredentialCallback, [], {
+              #callback: callback,
+            }),
+            returnValue: _i4.Future<void>.value(),
+            returnValueForMissingStub: _i4.Future<void>.value(),
           )
-          as _i3.Future<void>);
+          as _i4.Future<void>);
 
   @override
-  _i3.Future<void> crateApiSetSslCaCerts({required List<String>? pemCerts}) =>
+  _i4.Future<void> crateApiSetKnownHostsPath({required String? path}) =>
+      (super.noSuchMethod(
+            Invocation.method(#crateApiSetKnownHostsPath, [], {#path: path}),
+            returnValue: _i4.Future<void>.value(),
+            returnValueForMissingStub: _i4.Future<void>.value(),
+          )
+          as _i4.Future<void>);
+
+  @override
+  _i4.Future<void> crateApiSetSslCaCerts({required List<String>? pemCerts}) =>
       (super.noSuchMethod(
             Invocation.method(#crateApiSetSslCaCerts, [], {
               #pemCerts: pemCerts,
             }),
-            returnValue: _i3.Future<void>.value(),
-            returnValueForMissingStub: _i3.Future<void>.value(),
+            returnValue: _i4.Future<void>.value(),
+            returnValueForMissingStub: _i4.Future<void>.value(),
           )
-          as _i3.Future<void>);
+          as _i4.Future<void>);
 
   @override
-  _i3.Future<void> crateApiUpdateEvent({
+  _i4.Future<void> crateApiSetTrustStorePath({required String? path}) =>
+      (super.noSuchMethod(
+            Invocation.method(#crateApiSetTrustStorePath, [], {#path: path}),
+            returnValue: _i4.Future<void>.value(),
+            returnValueForMissingStub: _i4.Future<void>.value(),
+          )
+          as _i4.Future<void>);
+
+  @override
+  _i4.Future<void> crateApiSkipOccurrence({
     required String? id,
-    required String? title,
-    required String? description,
-    required String? startDate,
-    String? endDate,
-    String? startTime,
-    String? endTime,
-    required bool? isAllDay,
-    required String? recurrence,
+    required String? date,
+    required String? calendarDir,
+  }) =>
+      (super.noSuchMethod(
+            Invocation.method(#crateApiSkipOccurrence, [], {
+              #id: id,
+              #date: date,
+              #calendarDir: calendarDir,
+            }),
+            returnValue: _i4.Future<void>.value(),
+            returnValueForMissingStub: _i4.Future<void>.value(),
+          )
+          as _i4.Future<void>);
+
+  @override
+  _i4.Future<String> crateApiSplitSeriesFrom({
+    required String? id,
+    required String? date,
+    required String? calendarDir,
+  }) =>
+      (super.noSuchMethod(
+            Invocation.method(#crateApiSplitSeriesFrom, [], {
+              #id: id,
+              #date: date,
+              #calendarDir: calendarDir,
+            }),
+            returnValue: _i4.Future<String>.value(
+              _i5.dummyValue<String>(
+                this,
+                Invocation.method(#crateApiSplitSeriesFrom, [], {
+                  #id: id,
+                  #date: date,
+                  #calendarDir: calendarDir,
+                }),
+              ),
+            ),
+          )
+          as _i4.Future<String>);
+
+  @override
+  _i4.Future<BigInt> crateApiStartOperation() =>
+      (super.noSuchMethod(
+            Invocation.method(#crateApiStartOperation, []),
+            returnValue: _i4.Future<BigInt>.value(
+              _i5.dummyValue<BigInt>(
+                this,
+                Invocation.method(#crateApiStartOperation, []),
+              ),
+            ),
+          )
+          as _i4.Future<BigInt>);
+
+  @override
+  _i4.Future<List<_i2.TrustedCertificate>> crateApiTrustAddCa({
+    required String? host,
+    required String? pem,
+  }) =>
+      (super.noSuchMethod(
+            Invocation.method(#crateApiTrustAddCa, [], {
+              #host: host,
+              #pem: pem,
+            }),
+            returnValue: _i4.Future<List<_i2.TrustedCertificate>>.value(
+              <_i2.TrustedCertificate>[],
+            ),
+          )
+          as _i4.Future<List<_i2.TrustedCertificate>>);
+
+  @override
+  _i4.Future<void> crateApiTrustHostKey({required _i2.HostKey? key}) =>
+      (super.noSuchMethod(
+            Invocation.method(#crateApiTrustHostKey, [], {#key: key}),
+            returnValue: _i4.Future<void>.value(),
+            returnValueForMissingStub: _i4.Future<void>.value(),
+          )
+          as _i4.Future<void>);
+
+  @override
+  _i4.Future<List<_i2.TrustedCertificate>> crateApiTrustList({String? host}) =>
+      (super.noSuchMethod(
+            Invocation.method(#crateApiTrustList, [], {#host: host}),
+            returnValue: _i4.Future<List<_i2.TrustedCertificate>>.value(
+              <_i2.TrustedCertificate>[],
+            ),
+          )
+          as _i4.Future<List<_i2.TrustedCertificate>>);
+
+  @override
+  _i4.Future<List<_i2.TrustedCertificate>> crateApiTrustPinCertificate({
+    required String? host,
+    required String? pem,
+  }) =>
+      (super.noSuchMethod(
+            Invocation.method(#crateApiTrustPinCertificate, [], {
+              #host: host,
+              #pem: pem,
+            }),
+            returnValue: _i4.Future<List<_i2.TrustedCertificate>>.value(
+              <_i2.TrustedCertificate>[],
+            ),
+          )
+          as _i4.Future<List<_i2.TrustedCertificate>>);
+
+  @override
+  _i4.Future<bool> crateApiTrustRemove({
+    required String? host,
+    required String? fingerprint,
+  }) =>
+      (super.noSuchMethod(
+            Invocation.method(#crateApiTrustRemove, [], {
+              #host: host,
+              #fingerprint: fingerprint,
+            }),
+            returnValue: _i4.Future<bool>.value(false),
+          )
+          as _i4.Future<bool>);
+
+  @override
+  _i4.Future<void> crateApiUnwatchCalendar({required String? calendarDir}) =>
+      (super.noSuchMethod(
+            Invocation.method(#crateApiUnwatchCalendar, [], {
+              #calendarDir: calendarDir,
+            }),
+            returnValue: _i4.Future<void>.value(),
+            returnValueForMissingStub: _i4.Future<void>.value(),
+          )
+          as _i4.Future<void>);
+
+  @override
+  _i4.Future<void> crateApiUpdateEvent({
+    required String? id,
+    required _i2.EventInput? input,
     required String? calendarDir,
   }) =>
       (super.noSuchMethod(
             Invocation.method(#crateApiUpdateEvent, [], {
               #id: id,
-              #title: title,
-              #description: description,
-              #startDate: startDate,
-              #endDate: endDate,
-              #startTime: startTime,
-              #endTime: endTime,
-              #isAllDay: isAllDay,
-              #recurrence: recurrence,
+              #input: input,
               #calendarDir: calendarDir,
             }),
-            returnValue: _i3.Future<void>.value(),
-            returnValueForMissingStub: _i3.Future<void>.value(),
+            returnValue: _i4.Future<void>.value(),
+            returnValueForMissingStub: _i4.Future<void>.value(),
           )
-          as _i3.Future<void>);
+          as _i4.Future<void>);
 
   @override
-  _i3.Future<void> crateApiValidateEvent({
+  _i4.Future<void> crateApiValidateEvent({
     required String? title,
     required String? startDate,
     String? endDate,
@@ -553,8 +1207,20 @@ class MockRustLibApi extends _i1.Mock implements _i2.RustLibApi {
               #startTime: startTime,
               #endTime: endTime,
             }),
-            returnValue: _i3.Future<void>.value(),
-            returnValueForMissingStub: _i3.Future<void>.value(),
+            returnValue: _i4.Future<void>.value(),
+            returnValueForMissingStub: _i4.Future<void>.value(),
+          )
+          as _i4.Future<void>);
+
+  @override
+  _i4.Stream<_i2.CalendarChange> crateApiWatchCalendar({
+    required String? calendarDir,
+  }) =>
+      (super.noSuchMethod(
+            Invocation.method(#crateApiWatchCalendar, [], {
+              #calendarDir: calendarDir,
+            }),
+            returnValue: _i4.Stream<_i2.CalendarChange>.empty(),
           )
-          as _i3.Future<void>);
+          as _i4.Stream<_i2.CalendarChange>);
 }